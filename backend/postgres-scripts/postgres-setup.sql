@@ -1,31 +1,59 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
 CREATE TABLE locations (
     id SMALLSERIAL PRIMARY KEY,
     location_name VARCHAR(64) NOT NULL,
-
-    CONSTRAINT locations_location_name_uk UNIQUE (location_name)
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+
+    CONSTRAINT locations_location_name_uk UNIQUE (location_name),
+    CONSTRAINT locations_coordinates_chk CHECK (
+        (latitude IS NULL) = (longitude IS NULL)
+        AND latitude BETWEEN -90 AND 90
+        AND longitude BETWEEN -180 AND 180
+    )
 );
 
 COMMENT ON TABLE locations IS
-    'Table containing unique buildings where GenCon events occur.';
+    'Table containing unique buildings where GenCon events occur. Coordinates are in decimal degrees and are used to estimate walking times.';
+
+CREATE INDEX locations_location_name_trgm_idx ON locations USING GIN (location_name gin_trgm_ops);
 
 CREATE TABLE rooms (
     id SERIAL PRIMARY KEY,
     location_id SMALLINT NOT NULL,
     room_name VARCHAR(255) NOT NULL,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    wheelchair_accessible BOOLEAN NULL DEFAULT NULL,
+    low_sensory BOOLEAN NULL DEFAULT NULL,
 
     CONSTRAINT rooms_location_id_fk
         FOREIGN KEY (location_id)
         REFERENCES locations(id),
-    CONSTRAINT rooms_location_id_room_name_uk UNIQUE (location_id, room_name)
+    CONSTRAINT rooms_location_id_room_name_uk UNIQUE (location_id, room_name),
+    CONSTRAINT rooms_coordinates_chk CHECK (
+        (latitude IS NULL) = (longitude IS NULL)
+        AND latitude BETWEEN -90 AND 90
+        AND longitude BETWEEN -180 AND 180
+    )
 );
 
 COMMENT ON TABLE rooms IS
     'Table containing individual rooms of buildings listed in the locations table.';
 
+COMMENT ON COLUMN rooms.wheelchair_accessible IS
+    'Whether the room can be reached and used from a wheelchair. NULL when nobody has checked.';
+
+COMMENT ON COLUMN rooms.low_sensory IS
+    'Whether the room is a quiet, low-sensory space. NULL when nobody has checked.';
+
 CREATE TABLE sections (
     id SERIAL PRIMARY KEY,
     room_id INTEGER NOT NULL,
     section_name VARCHAR(255) NOT NULL,
+    wheelchair_accessible BOOLEAN NULL DEFAULT NULL,
+    low_sensory BOOLEAN NULL DEFAULT NULL,
 
     CONSTRAINT sections_room_id_fk
         FOREIGN KEY (room_id)
@@ -36,6 +64,78 @@ CREATE TABLE sections (
 COMMENT ON TABLE sections IS
     'Table containing sections of larger rooms (e.g. ballrooms) where GenCon events can occur';
 
+COMMENT ON COLUMN sections.wheelchair_accessible IS
+    'Whether the section can be reached and used from a wheelchair. NULL falls back to the value of the room.';
+
+COMMENT ON COLUMN sections.low_sensory IS
+    'Whether the section is a quiet, low-sensory space. NULL falls back to the value of the room.';
+
+CREATE TABLE location_aliases (
+    alias VARCHAR(255) NOT NULL,
+    location_id SMALLINT NOT NULL,
+
+    CONSTRAINT location_aliases_pk PRIMARY KEY (alias),
+    CONSTRAINT location_aliases_location_id_fk
+        FOREIGN KEY (location_id)
+        REFERENCES locations(id)
+);
+
+COMMENT ON TABLE location_aliases IS
+    'Alternate names of buildings which imports treat as the canonical building. Aliases are stored trimmed, with single spaces, and lowercased.';
+
+CREATE TABLE room_aliases (
+    location_id SMALLINT NOT NULL,
+    alias VARCHAR(255) NOT NULL,
+    room_id INTEGER NOT NULL,
+
+    CONSTRAINT room_aliases_pk PRIMARY KEY (location_id, alias),
+    CONSTRAINT room_aliases_location_id_fk
+        FOREIGN KEY (location_id)
+        REFERENCES locations(id),
+    CONSTRAINT room_aliases_room_id_fk
+        FOREIGN KEY (room_id)
+        REFERENCES rooms(id)
+);
+
+COMMENT ON TABLE room_aliases IS
+    'Alternate names of rooms within a building which imports treat as the canonical room. Aliases are stored trimmed, with single spaces, and lowercased.';
+
+CREATE TABLE section_aliases (
+    room_id INTEGER NOT NULL,
+    alias VARCHAR(255) NOT NULL,
+    section_id INTEGER NOT NULL,
+
+    CONSTRAINT section_aliases_pk PRIMARY KEY (room_id, alias),
+    CONSTRAINT section_aliases_room_id_fk
+        FOREIGN KEY (room_id)
+        REFERENCES rooms(id),
+    CONSTRAINT section_aliases_section_id_fk
+        FOREIGN KEY (section_id)
+        REFERENCES sections(id)
+);
+
+COMMENT ON TABLE section_aliases IS
+    'Alternate names of sections within a room which imports treat as the canonical section. Aliases are stored trimmed, with single spaces, and lowercased.';
+
+CREATE TABLE walking_times (
+    location_id SMALLINT NOT NULL,
+    other_location_id SMALLINT NOT NULL,
+    walking_minutes SMALLINT NOT NULL,
+
+    CONSTRAINT walking_times_pk PRIMARY KEY (location_id, other_location_id),
+    CONSTRAINT walking_times_location_id_fk
+        FOREIGN KEY (location_id)
+        REFERENCES locations(id),
+    CONSTRAINT walking_times_other_location_id_fk
+        FOREIGN KEY (other_location_id)
+        REFERENCES locations(id),
+    CONSTRAINT walking_times_location_order_chk CHECK (location_id < other_location_id),
+    CONSTRAINT walking_times_walking_minutes_chk CHECK (walking_minutes >= 0)
+);
+
+COMMENT ON TABLE walking_times IS
+    'Measured walking times between pairs of buildings, which apply in both directions. Each pair is stored once with the lower location ID first.';
+
 CREATE TABLE game_systems (
     id BIGSERIAL PRIMARY KEY,
     system_name TEXT NOT NULL,
@@ -46,6 +146,21 @@ CREATE TABLE game_systems (
 COMMENT ON TABLE game_systems IS
     'Table containing unique game systems which can be referenced by events';
 
+CREATE INDEX game_systems_system_name_trgm_idx ON game_systems USING GIN (system_name gin_trgm_ops);
+
+CREATE TABLE game_system_aliases (
+    alias VARCHAR(255) NOT NULL,
+    game_system_id BIGINT NOT NULL,
+
+    CONSTRAINT game_system_aliases_pk PRIMARY KEY (alias),
+    CONSTRAINT game_system_aliases_game_system_id_fk
+        FOREIGN KEY (game_system_id)
+        REFERENCES game_systems(id)
+);
+
+COMMENT ON TABLE game_system_aliases IS
+    'Alternate names of game systems which imports treat as the canonical game system. Aliases are stored trimmed, with single spaces, and lowercased.';
+
 CREATE TABLE game_masters (
     id BIGSERIAL PRIMARY KEY,
     gm_name VARCHAR(512) NOT NULL,
@@ -56,6 +171,8 @@ CREATE TABLE game_masters (
 COMMENT ON TABLE game_masters IS
     'Table containing names of individuals running events at GenCon. Multiple GMs may run the same event.';
 
+CREATE INDEX game_masters_gm_name_trgm_idx ON game_masters USING GIN (gm_name gin_trgm_ops);
+
 CREATE TABLE contacts (
     id BIGSERIAL PRIMARY KEY,
     contact_email VARCHAR(128) NOT NULL,
@@ -79,6 +196,7 @@ COMMENT ON TABLE websites IS
 CREATE TABLE groups (
     id BIGSERIAL PRIMARY KEY,
     group_name VARCHAR(128) NOT NULL,
+    share_contact BOOLEAN NOT NULL DEFAULT FALSE,
 
     CONSTRAINT groups_group_name_uk UNIQUE (group_name)
 );
@@ -86,16 +204,43 @@ CREATE TABLE groups (
 COMMENT ON TABLE groups IS
     'Table containing the unique names of organizing groups for GenCon events';
 
+COMMENT ON COLUMN groups.share_contact IS
+    'Whether the group agreed to have the contact email from its events shown on its profile';
+
+CREATE INDEX groups_group_name_trgm_idx ON groups USING GIN (group_name gin_trgm_ops);
+
+CREATE TABLE group_aliases (
+    alias VARCHAR(255) NOT NULL,
+    group_id BIGINT NOT NULL,
+
+    CONSTRAINT group_aliases_pk PRIMARY KEY (alias),
+    CONSTRAINT group_aliases_group_id_fk
+        FOREIGN KEY (group_id)
+        REFERENCES groups(id)
+);
+
+COMMENT ON TABLE group_aliases IS
+    'Alternate names of organizing groups which imports treat as the canonical group. Aliases are stored trimmed, with single spaces, and lowercased.';
+
 CREATE TABLE event_types (
     id SERIAL PRIMARY KEY,
-    event_type TEXT NOT NULL,
+    code TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    description TEXT NULL DEFAULT NULL,
+    icon VARCHAR(255) NULL DEFAULT NULL,
 
-    CONSTRAINT event_types_event_type_uk UNIQUE (event_type)
+    CONSTRAINT event_types_code_uk UNIQUE (code)
 );
 
 COMMENT ON TABLE event_types IS
     'Table containing different categories that GenCon events fall into';
 
+COMMENT ON COLUMN event_types.code IS
+    'Short code GenCon lists before the type name and uses in event IDs, such as BGM in "BGM - Board Game"';
+
+COMMENT ON COLUMN event_types.display_name IS
+    'Human-readable name of the type. Taken from the first import listing the code and kept as edited afterwards.';
+
 
 CREATE TABLE materials (
     id BIGSERIAL PRIMARY KEY,
@@ -107,6 +252,34 @@ CREATE TABLE materials (
 COMMENT ON TABLE materials IS
     'Table containing unique descriptions of necessary materials for events';
 
+CREATE TABLE material_items (
+    id BIGSERIAL PRIMARY KEY,
+    item_name VARCHAR(512) NOT NULL,
+
+    CONSTRAINT material_items_item_name_uk UNIQUE (item_name)
+);
+
+COMMENT ON TABLE material_items IS
+    'Individual items events ask players to bring, such as "dice" or "character sheet provided". Names are stored lowercased with single spaces.';
+
+CREATE TABLE materials_material_items (
+    materials_id BIGINT NOT NULL,
+    material_item_id BIGINT NOT NULL,
+
+    CONSTRAINT materials_material_items_pk PRIMARY KEY (materials_id, material_item_id),
+    CONSTRAINT materials_material_items_materials_id_fk
+        FOREIGN KEY (materials_id)
+        REFERENCES materials(id),
+    CONSTRAINT materials_material_items_material_item_id_fk
+        FOREIGN KEY (material_item_id)
+        REFERENCES material_items(id)
+);
+
+CREATE INDEX materials_material_items_material_item_id_idx ON materials_material_items(material_item_id);
+
+COMMENT ON TABLE materials_material_items IS
+    'Items listed in each materials description. Descriptions with no items, such as "None", mean no materials are required.';
+
 CREATE TYPE AGEREQUIREMENT AS ENUM ('Everyone', 'KidsOnly', 'Teen', 'Mature', 'Adult');
 CREATE TYPE EXPERIENCEREQUIREMENT AS ENUM ('None', 'Some', 'Expert');
 
@@ -134,6 +307,9 @@ CREATE TABLE events (
     tickets_available SMALLINT NOT NULL,
     min_players SMALLINT NOT NULL,
     max_players SMALLINT NOT NULL,
+    cancelled BOOLEAN NOT NULL DEFAULT FALSE,
+    content_tags TEXT[] NOT NULL DEFAULT '{}',
+    search_document TSVECTOR NOT NULL DEFAULT ''::TSVECTOR,
 
     CONSTRAINT events_game_system_id_fk
         FOREIGN KEY (game_system_id)
@@ -164,6 +340,10 @@ CREATE TABLE events (
 );
 
 CREATE INDEX events_year_idx on events(year);
+CREATE INDEX events_start_dt_idx on events(start_dt);
+CREATE INDEX events_search_document_idx ON events USING GIN (search_document);
+CREATE INDEX events_title_trgm_idx ON events USING GIN (title gin_trgm_ops);
+CREATE INDEX events_content_tags_idx ON events USING GIN (content_tags);
 
 COMMENT ON TABLE events IS
     'Central table representing a single event at GenCon. Each event is considered unique on the basis of the Game ID assigned by GenCon staffers.';
@@ -171,6 +351,12 @@ COMMENT ON TABLE events IS
 COMMENT ON COLUMN events.game_id IS
     'Unique alphanumeric event ID assigned by GenCon organizers. It follows a predictable pattern - (3 letter event type) + (last 2 numbers of year) + ND + (6 digit incrementing event number)';
 
+COMMENT ON COLUMN events.content_tags IS
+    'Normalized tags describing event content, such as content warnings (e.g. horror) or a low-sensory format. Tags are lowercased with words joined by hyphens.';
+
+COMMENT ON COLUMN events.search_document IS
+    'Weighted full-text search document built from the title, game system, organizer group, game masters, and description. Rebuilt whenever an import touches the event.';
+
 CREATE TABLE tournaments (
      id BIGSERIAL PRIMARY KEY,
      tournament_name TEXT NOT NULL,
@@ -351,3 +537,97 @@ CREATE TRIGGER enforce_unique_section_join
     REFERENCING NEW TABLE AS new_sections
     FOR EACH STATEMENT
     EXECUTE PROCEDURE trg_enforce_unique_section_join();
+
+CREATE TABLE watchlist_entries (
+    user_id VARCHAR(128) NOT NULL,
+    event_id BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+
+    CONSTRAINT watchlist_entries_event_id_fk
+        FOREIGN KEY (event_id)
+        REFERENCES events(id)
+        ON DELETE CASCADE,
+    CONSTRAINT watchlist_entries_user_id_event_id_uk UNIQUE (user_id, event_id)
+);
+
+CREATE INDEX watchlist_entries_event_id_idx ON watchlist_entries(event_id);
+
+COMMENT ON TABLE watchlist_entries IS
+    'Events that users have asked to be notified about when ticket availability, start time, or location change, or when the event is cancelled.';
+
+CREATE TABLE webhook_subscriptions (
+    id BIGSERIAL PRIMARY KEY,
+    target_url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    topics TEXT[] NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+COMMENT ON TABLE webhook_subscriptions IS
+    'Downstream systems which receive signed webhook calls when imports complete or events change. The secret is used to compute an HMAC-SHA256 signature of each payload.';
+
+CREATE TABLE webhook_deliveries (
+    id BIGSERIAL PRIMARY KEY,
+    subscription_id BIGINT NOT NULL,
+    topic TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending',
+    attempts SMALLINT NOT NULL DEFAULT 0,
+    last_status_code SMALLINT,
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    last_attempt_at TIMESTAMP WITH TIME ZONE,
+    next_attempt_at TIMESTAMP WITH TIME ZONE DEFAULT now(),
+
+    CONSTRAINT webhook_deliveries_subscription_id_fk
+        FOREIGN KEY (subscription_id)
+        REFERENCES webhook_subscriptions(id)
+        ON DELETE CASCADE,
+    CONSTRAINT webhook_deliveries_status_chk CHECK (status IN ('pending', 'succeeded', 'failed'))
+);
+
+CREATE INDEX webhook_deliveries_subscription_id_idx ON webhook_deliveries(subscription_id, created_at);
+CREATE INDEX webhook_deliveries_due_idx ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
+
+COMMENT ON TABLE webhook_deliveries IS
+    'Log of webhook calls made to each subscription, including how many attempts were made and the outcome of the latest attempt.';
+
+COMMENT ON COLUMN webhook_deliveries.next_attempt_at IS
+    'When a pending delivery is due to be sent next. Servers claim due deliveries by pushing this back while they send them, and it is cleared once the delivery succeeds or fails for good.';
+
+CREATE TABLE saved_searches (
+    id BIGSERIAL PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    search_name VARCHAR(128) NOT NULL,
+    query_params TEXT NOT NULL,
+    filter JSONB NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    last_checked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE INDEX saved_searches_user_id_idx ON saved_searches(user_id);
+
+COMMENT ON TABLE saved_searches IS
+    'Event list filters users saved to run again. query_params holds the event list query string the search was saved from and filter holds the parsed criteria the import pipeline evaluates.';
+
+CREATE TABLE saved_search_matches (
+    saved_search_id BIGINT NOT NULL,
+    event_id BIGINT NOT NULL,
+    flagged_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    seen BOOLEAN NOT NULL DEFAULT false,
+
+    CONSTRAINT saved_search_matches_pk PRIMARY KEY (saved_search_id, event_id),
+    CONSTRAINT saved_search_matches_saved_search_id_fk
+        FOREIGN KEY (saved_search_id)
+        REFERENCES saved_searches(id)
+        ON DELETE CASCADE,
+    CONSTRAINT saved_search_matches_event_id_fk
+        FOREIGN KEY (event_id)
+        REFERENCES events(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX saved_search_matches_event_id_idx ON saved_search_matches(event_id);
+
+COMMENT ON TABLE saved_search_matches IS
+    'Events found matching each saved search. Matches the user has not checked yet are unseen.';
@@ -1,3 +1,8 @@
+use crate::domain::search::{PageCursor, PageSelection};
+use crate::dto;
+use crate::routing_utils::Json;
+use axum::http::{StatusCode, Uri};
+use axum::response::ErrorResponse;
 use serde::Deserialize;
 use tracing::*;
 use utoipa::IntoParams;
@@ -5,32 +10,24 @@ use validator::Validate;
 
 pub mod swagger_main;
 
+pub mod access;
+pub mod accessibility;
+pub mod contacts;
 pub mod cors;
 pub mod days;
 pub mod event_import;
+pub mod event_stream;
 pub mod events;
+pub mod game_masters;
+pub mod game_systems;
+pub mod locations;
 pub mod organizers;
+pub mod schedules;
+pub mod search;
 #[cfg(test)]
 pub mod test_util;
-
-/// Represents an inclusive start (page_start) and exclusive end (page_end) index range for paginated results.
-struct PageRange {
-    pub page_start: usize,
-    pub page_end: usize,
-}
-
-#[instrument]
-/// Calculates the start and end indices of a set of results based on
-/// the requested page and the number of results per page
-fn determine_page_limits(page: u16, results_per_page: u16) -> PageRange {
-    let page_start = (page as usize - 1) * results_per_page as usize;
-    let page_end = page_start + results_per_page as usize;
-
-    PageRange {
-        page_start,
-        page_end,
-    }
-}
+pub mod users;
+pub mod webhooks;
 
 #[instrument]
 /// Calculates the total number of pages given the page size and total result count.
@@ -45,18 +42,66 @@ fn total_pages(results_per_page: u16, total_results: usize) -> u16 {
     pages as u16
 }
 
+/// Builds a link to the page of results the cursor points at, keeping every other query
+/// parameter of the current request
+fn page_link(current_uri: &Uri, cursor: &PageCursor) -> String {
+    let mut query_params: Vec<&str> = current_uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|param| {
+            let name = param.split('=').next().unwrap_or_default();
+            !name.is_empty() && name != "cursor" && name != "page"
+        })
+        .collect();
+    let cursor_param = format!("cursor={}", cursor.encode());
+    query_params.push(&cursor_param);
+
+    format!("{}?{}", current_uri.path(), query_params.join("&"))
+}
+
 #[derive(Validate, Debug, Deserialize, IntoParams)]
 #[into_params(parameter_in = Query)]
 #[serde(rename_all = "kebab-case")]
 pub struct PaginationQueryParams {
     #[validate(range(min = 1))]
-    /// The page of results to return (default 1)
+    /// The page of results to return (default 1). Ignored when a cursor is passed.
     pub page: Option<u16>,
 
+    #[validate(length(max = 2048))]
+    /// Opaque cursor from the `next` or `prev` link of a previous response. Unlike page
+    /// numbers, cursors keep pointing at the same place when events are added or removed.
+    pub cursor: Option<String>,
+
     #[validate(range(min = 1))]
     /// The number of results to return per page
     pub limit: Option<u16>,
 }
 
+impl PaginationQueryParams {
+    /// Determines the page requested, preferring the cursor over the page number. Returns None
+    /// if the cursor can't be decoded.
+    fn page_selection(&self) -> Option<PageSelection> {
+        match &self.cursor {
+            Some(cursor) => PageCursor::decode(cursor).map(PageSelection::Cursor),
+            None => Some(PageSelection::Number(self.page.unwrap_or(1))),
+        }
+    }
+}
+
+/// Builds the error returned when a page cursor is malformed or belongs to a different sort
+fn invalid_cursor() -> ErrorResponse {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(dto::BasicError {
+            error_code: "invalid_cursor".to_owned(),
+            error_description: "The page cursor is invalid or was created for a different sort."
+                .to_owned(),
+            extra_info: None,
+        }),
+    )
+        .into()
+}
+
 /// Size of one mebibyte (MiB) in bytes.
 static MEBIBYTE: usize = 1024 * 1024;
@@ -0,0 +1,220 @@
+use crate::SharedData;
+use crate::domain::access::{UserTokenVerifier, Viewer};
+use crate::dto;
+use crate::routing_utils::Json;
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::{HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::*;
+
+/// Bearer tokens which grant callers access beyond what anonymous callers have. Only digests of
+/// the member and admin tokens are kept, so comparing them doesn't reveal how much of a token was
+/// guessed. User tokens are verified by their signature instead, as they're issued elsewhere.
+#[derive(Default)]
+pub struct AccessTokens {
+    member_digests: HashSet<[u8; 32]>,
+    admin_digests: HashSet<[u8; 32]>,
+    user_tokens: Option<UserTokenVerifier>,
+}
+
+/// Hashes a bearer token for storage and comparison
+fn token_digest(token: &str) -> [u8; 32] {
+    Sha256::digest(token.as_bytes()).into()
+}
+
+impl AccessTokens {
+    /// Creates the set of tokens from comma separated lists of member and admin tokens. User
+    /// tokens are only accepted if the secret they're signed with is passed.
+    pub fn new(member_tokens: &str, admin_tokens: &str, user_token_secret: Option<&str>) -> Self {
+        let digests = |tokens: &str| {
+            tokens
+                .split(',')
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+                .map(token_digest)
+                .collect()
+        };
+
+        Self {
+            member_digests: digests(member_tokens),
+            admin_digests: digests(admin_tokens),
+            user_tokens: user_token_secret.map(UserTokenVerifier::new),
+        }
+    }
+
+    /// Returns the ID of the user a token was issued to, or None if it isn't a valid user token
+    fn user_for<'token>(&self, token: &'token str) -> Option<&'token str> {
+        self.user_tokens.as_ref()?.user_id_for(token)
+    }
+
+    /// Determines who presented the token, or returns None if the token isn't recognized
+    fn viewer_for(&self, token: &str) -> Option<Viewer> {
+        let digest = token_digest(token);
+        if self.admin_digests.contains(&digest) {
+            Some(Viewer::Admin)
+        } else if self.member_digests.contains(&digest) || self.user_for(token).is_some() {
+            Some(Viewer::Member)
+        } else {
+            None
+        }
+    }
+}
+
+/// Turns away callers who aren't admins before they can perform the passed action
+pub fn require_admin(viewer: Viewer, action: &'static str) -> Result<(), AdminOnly> {
+    if viewer == Viewer::Admin {
+        Ok(())
+    } else {
+        Err(AdminOnly { viewer, action })
+    }
+}
+
+/// Rejection for a non-admin attempting an admin action. Anonymous callers get a 401 so they know
+/// to present a token, while callers whose token isn't an admin token get a 403.
+pub struct AdminOnly {
+    viewer: Viewer,
+    action: &'static str,
+}
+
+impl IntoResponse for AdminOnly {
+    fn into_response(self) -> Response {
+        let Self { viewer, action } = self;
+        warn!(?viewer, "Attempted to {action} without admin access.");
+        let status = if viewer == Viewer::Anonymous {
+            StatusCode::UNAUTHORIZED
+        } else {
+            StatusCode::FORBIDDEN
+        };
+
+        (
+            status,
+            Json(dto::BasicError {
+                error_code: "admin_only".to_owned(),
+                error_description: format!("Only admins can {action}."),
+                extra_info: None,
+            }),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug)]
+/// A caller who presented a valid user token, which is needed to read or change a user's own
+/// data such as their watchlist
+pub struct SignedInUser {
+    user_id: String,
+}
+
+impl SignedInUser {
+    /// Turns away callers trying to reach the data of a user other than themselves
+    pub fn require_user(&self, user_id: &str) -> Result<(), OtherUser> {
+        if self.user_id == user_id {
+            Ok(())
+        } else {
+            Err(OtherUser)
+        }
+    }
+}
+
+/// Rejection for a signed-in user attempting to reach another user's data
+pub struct OtherUser;
+
+impl IntoResponse for OtherUser {
+    fn into_response(self) -> Response {
+        warn!("Attempted to reach another user's data.");
+        (
+            StatusCode::FORBIDDEN,
+            Json(dto::BasicError {
+                error_code: "other_user".to_owned(),
+                error_description: "User tokens only grant access to the data of the user they \
+                    were issued to."
+                    .to_owned(),
+                extra_info: None,
+            }),
+        )
+            .into_response()
+    }
+}
+
+/// Reads the token from an `Authorization: Bearer <token>` header
+fn bearer_token(auth_header: &HeaderValue) -> Option<&str> {
+    auth_header
+        .to_str()
+        .ok()
+        .and_then(|auth_value| auth_value.strip_prefix("Bearer "))
+        .map(str::trim)
+}
+
+/// Identifies the caller from the `Authorization: Bearer <token>` header. Callers without the
+/// header are anonymous, while callers presenting an unknown token are turned away so they don't
+/// quietly receive less than they expect.
+#[async_trait]
+impl FromRequestParts<Arc<SharedData>> for Viewer {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<SharedData>,
+    ) -> Result<Self, Self::Rejection> {
+        let Some(auth_header) = parts.headers.get(header::AUTHORIZATION) else {
+            return Ok(Viewer::Anonymous);
+        };
+
+        bearer_token(auth_header)
+            .and_then(|token| state.access_tokens.viewer_for(token))
+            .ok_or_else(|| {
+                warn!("Rejected a request with an unrecognized access token.");
+                (
+                    StatusCode::UNAUTHORIZED,
+                    Json(dto::BasicError {
+                        error_code: "invalid_access_token".to_owned(),
+                        error_description: "The Authorization header must contain a recognized \
+                            bearer token."
+                            .to_owned(),
+                        extra_info: None,
+                    }),
+                )
+                    .into_response()
+            })
+    }
+}
+
+/// Identifies the signed-in user from the user token in the `Authorization: Bearer <token>`
+/// header. Callers without a valid user token are turned away.
+#[async_trait]
+impl FromRequestParts<Arc<SharedData>> for SignedInUser {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<SharedData>,
+    ) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(bearer_token)
+            .and_then(|token| state.access_tokens.user_for(token))
+            .map(|user_id| SignedInUser {
+                user_id: user_id.to_owned(),
+            })
+            .ok_or_else(|| {
+                warn!("Rejected a request without a valid user token.");
+                (
+                    StatusCode::UNAUTHORIZED,
+                    Json(dto::BasicError {
+                        error_code: "user_token_required".to_owned(),
+                        error_description: "The Authorization header must contain a bearer token \
+                            issued to a user."
+                            .to_owned(),
+                        extra_info: None,
+                    }),
+                )
+                    .into_response()
+            })
+    }
+}
@@ -0,0 +1,58 @@
+use crate::domain::accessibility::AccessibilityError;
+use crate::dto;
+use crate::routing_utils::{GenericErrorResponse, Json};
+use axum::http::StatusCode;
+use axum::response::ErrorResponse;
+use tracing::*;
+
+/// Builds the error returned when accessibility or content metadata can't be saved
+pub fn accessibility_error_response(accessibility_err: AccessibilityError) -> ErrorResponse {
+    match accessibility_err {
+        AccessibilityError::NotFound(place) => {
+            error!(place_id = place.id, place_type = %place.ref_type, "Place not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_location".to_owned(),
+                    error_description: format!(
+                        "The requested {} is not in the system.",
+                        place.ref_type.to_string().to_lowercase()
+                    ),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        AccessibilityError::EventNotFound(event_id) => {
+            error!(event_id, "Event not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_event".to_owned(),
+                    error_description: "There is no event in the system with the given ID."
+                        .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        AccessibilityError::InvalidTag(tag) => {
+            error!(tag, "Invalid content tag.");
+            (
+                StatusCode::BAD_REQUEST,
+                Json(dto::BasicError {
+                    error_code: "invalid_content_tag".to_owned(),
+                    error_description: format!(
+                        "\"{tag}\" can't be used as a content tag. Tags need letters or digits and can be at most 64 characters long."
+                    ),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        AccessibilityError::PortError(port_err) => {
+            error!("Failed to save accessibility or content metadata: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::api::access::require_admin;
+use crate::domain::access::Viewer;
+use crate::external_connections::{
+    TransactableExternalConnectivity, TxOrSourceError, with_transaction,
+};
+use crate::routing_utils::{GenericErrorResponse, Json};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::State;
+use axum::response::ErrorResponse;
+use axum::routing::delete;
+use std::sync::Arc;
+use tracing::*;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(purge_contacts))]
+/// OpenAPI struct which registers contact data APIs with swagger
+pub struct ContactsApi;
+
+/// Constant which defines the "contacts" group of API endpoints
+pub const CONTACTS_API_GROUP: &str = "Contacts";
+
+/// Returns a router containing all routes for the "/api/contacts" set of endpoints
+pub fn contacts_routes() -> Router<Arc<SharedData>> {
+    Router::new().route(
+        "/",
+        delete(async |State(app_data): AppState, viewer: Viewer| {
+            let contact_svc = domain::contact::ContactService;
+            let mut ext_cxn = app_data.ext_cxn.clone();
+
+            purge_contacts(viewer, &contact_svc, &mut ext_cxn).await
+        }),
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contacts",
+    tag = CONTACTS_API_GROUP,
+    responses(
+        (status = 200, description = "Contact data was deleted", body = ContactPurgeResponse),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(contact_port, ext_cxn))]
+/// Delete every organizer contact email, such as once the convention is over
+///
+/// Requires an admin access token. Importing events again brings back the contacts they list.
+async fn purge_contacts(
+    viewer: Viewer,
+    contact_port: &impl domain::contact::driving_ports::ContactPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<Json<dto::ContactPurgeResponse>, ErrorResponse> {
+    require_admin(viewer, "delete contact data")?;
+
+    let purged_contacts = with_transaction(ext_cxn, async |txn| {
+        contact_port
+            .purge_contacts(&persistence::contact::DbContactPurger, txn)
+            .await
+    })
+    .await
+    .map_err(
+        |txn_err: TxOrSourceError<u64, domain::contact::ContactError>| -> ErrorResponse {
+            match txn_err {
+                TxOrSourceError::Source(domain::contact::ContactError::PortError(port_err)) => {
+                    error!("Failed to delete contacts: {port_err}");
+                    GenericErrorResponse(port_err).into()
+                }
+                TxOrSourceError::TxBegin(tx_err) => {
+                    error!("Contact purge failure - failed to start database transaction: {tx_err}");
+                    GenericErrorResponse(tx_err).into()
+                }
+                TxOrSourceError::TxCommit {
+                    transaction_err, ..
+                } => {
+                    error!(
+                        "Contact purge failure - failed to commit the transaction: {transaction_err}"
+                    );
+                    GenericErrorResponse(transaction_err).into()
+                }
+            }
+        },
+    )?;
+
+    info!(purged_contacts, "Deleted contacts.");
+    Ok(Json(dto::ContactPurgeResponse { purged_contacts }))
+}
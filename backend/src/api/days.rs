@@ -1,14 +1,16 @@
 use crate::api::{PaginationQueryParams, events};
+use crate::domain::search::EventSearchResult;
 use crate::dto::TimeBlockedEventsResponse;
 use crate::external_connections::ExternalConnectivity;
-use crate::routing_utils::{Json, ValidationErrorResponse};
-use crate::{AppState, SharedData, api, dto};
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, api, domain, dto, persistence};
 use axum::Router;
-use axum::extract::{Path, Query, State};
+use axum::extract::{OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
+use axum::http::Uri;
 use axum::response::ErrorResponse;
 use axum::routing::get;
-use chrono::NaiveTime;
+use chrono::{NaiveTime, Timelike};
 use tracing::*;
 
 use std::sync::Arc;
@@ -40,10 +42,24 @@ pub fn day_routes() -> Router<Arc<SharedData>> {
                 async |State(app_data): AppState,
                        Path(day_id): Path<u32>,
                        Query(filter): Query<events::EventListQueryParams>,
-                       Query(pagination): Query<api::PaginationQueryParams>| {
+                       Query(sort): Query<events::EventSortQueryParams>,
+                       Query(facet_params): Query<events::FacetQueryParams>,
+                       Query(pagination): Query<api::PaginationQueryParams>,
+                       OriginalUri(uri): OriginalUri| {
+                    let search_svc = domain::search::SearchService;
                     let mut ext_cxn = app_data.ext_cxn.clone();
 
-                    list_events_by_day(day_id, &filter, &pagination, &mut ext_cxn).await
+                    list_events_by_day(
+                        &uri,
+                        day_id,
+                        &filter,
+                        &sort,
+                        &facet_params,
+                        &pagination,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
                 },
             ),
         )
@@ -56,6 +72,8 @@ pub fn day_routes() -> Router<Arc<SharedData>> {
     params(
         ("day_id" = u32, Path, description = "The ID of the day to look up the list of events for (YYYYMMDD format)"),
         events::EventListQueryParams,
+        events::EventSortQueryParams,
+        events::FacetQueryParams,
         PaginationQueryParams,
     ),
     responses(
@@ -74,62 +92,96 @@ pub fn day_routes() -> Router<Arc<SharedData>> {
         (status = 500, response = dto::err_resps::BasicError500),
     ),
 )]
-#[instrument(skip(filter, _ext_cxn))]
-/// List events that occur on a certain day
+#[instrument(skip(uri, filter, search_port, ext_cxn))]
+/// List events that occur on a certain day, optionally with counts of the events each filter
+/// value would produce
+#[allow(clippy::too_many_arguments)]
 async fn list_events_by_day(
+    uri: &Uri,
     day_id: u32,
     filter: &events::EventListQueryParams,
+    sort: &events::EventSortQueryParams,
+    facet_params: &events::FacetQueryParams,
     pagination: &api::PaginationQueryParams,
-    _ext_cxn: &mut impl ExternalConnectivity,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
 ) -> Result<Json<TimeBlockedEventsResponse>, ErrorResponse> {
     filter.validate().map_err(ValidationErrorResponse)?;
     pagination.validate().map_err(ValidationErrorResponse)?;
 
-    let event_data = events::event_data();
-    let events_for_day_opt = event_data.events_by_day.get(&day_id);
-    let events_for_day = match events_for_day_opt {
-        Some(event_list) => event_list,
-        None => {
-            error!(day_id, "Day doesn't exist.");
-            return Err((
-                StatusCode::NOT_FOUND,
-                Json(dto::BasicError {
-                    error_code: "no_matching_day".to_owned(),
-                    error_description: "The requested date was not found in the system.".to_owned(),
-                    extra_info: None,
-                }),
-            )
-                .into());
-        }
+    let Some(dto::DateDto(day)) = dto::DateDto::try_from_date_id(day_id) else {
+        error!(day_id, "Day ID is not a valid date.");
+        return Err(no_matching_day());
+    };
+    let Some(page) = pagination.page_selection() else {
+        error!("Page cursor could not be decoded.");
+        return Err(super::invalid_cursor());
     };
-
-    let mut events_on_day = events_for_day.clone();
-    info_span!("event_filtering").in_scope(|| {
-        for time_block in events_on_day.iter_mut() {
-            time_block
-                .events
-                .retain(|event| super::events::matches_event_filter(event, filter));
-        }
-    });
-    events_on_day.retain(|block| !block.events.is_empty());
-
-    let page = pagination.page.unwrap_or(1);
     let results_per_page = pagination.limit.unwrap_or(50);
-    let total_results = super::events::paginate_events(&mut events_on_day, page, results_per_page);
-    let total_pages = super::total_pages(results_per_page, total_results);
 
-    events_on_day.retain(|block| !block.events.is_empty());
+    let event_filter = filter.into();
+    let search_page = search_port
+        .search_day(
+            day,
+            &event_filter,
+            sort.sort.map(Into::into).unwrap_or_default(),
+            sort.sort_direction.map(Into::into),
+            &page,
+            results_per_page,
+            &persistence::search::DbEventSearcher,
+            ext_cxn,
+        )
+        .await
+        .map_err(|search_err| match search_err {
+            domain::search::SearchError::DayNotFound(_) => {
+                error!(day_id, "Day doesn't exist.");
+                no_matching_day()
+            }
+            domain::search::SearchError::InvalidCursor => {
+                error!("Page cursor doesn't match the requested sort.");
+                super::invalid_cursor()
+            }
+            domain::search::SearchError::PortError(port_err) => {
+                error!("Failed to search events on day: {port_err}");
+                GenericErrorResponse(port_err).into()
+            }
+        })?;
 
+    let facets = if facet_params.include_facets == Some(true) {
+        let facets = search_port
+            .facets(
+                &domain::search::DateRange::day(day),
+                &event_filter,
+                &persistence::search::DbFacetCounter,
+                ext_cxn,
+            )
+            .await
+            .map_err(|facet_err| {
+                error!("Failed to count event facets: {facet_err}");
+                GenericErrorResponse(facet_err)
+            })?;
+        Some(facets.into())
+    } else {
+        None
+    };
+
+    let total_pages = super::total_pages(results_per_page, search_page.total_results as usize);
+    let total_events = search_page.events.len();
     let resp = TimeBlockedEventsResponse {
-        pagination_info: dto::PaginationInfo { page, total_pages },
-        events_by_time: events_on_day,
+        pagination_info: dto::PaginationInfo {
+            page: search_page.page_number,
+            total_pages,
+            next: search_page
+                .next
+                .map(|cursor| super::page_link(uri, &cursor)),
+            prev: search_page
+                .prev
+                .map(|cursor| super::page_link(uri, &cursor)),
+        },
+        events_by_time: hourly_blocks(search_page.events),
+        facets,
     };
 
-    let total_events: usize = resp
-        .events_by_time
-        .iter()
-        .map(|time_block| time_block.events.len())
-        .sum();
     info!(
         day_id,
         total_events,
@@ -139,6 +191,39 @@ async fn list_events_by_day(
     Ok(Json(resp))
 }
 
+/// Builds the error returned when a day has no events
+fn no_matching_day() -> ErrorResponse {
+    (
+        StatusCode::NOT_FOUND,
+        Json(dto::BasicError {
+            error_code: "no_matching_day".to_owned(),
+            error_description: "The requested date was not found in the system.".to_owned(),
+            extra_info: None,
+        }),
+    )
+        .into()
+}
+
+/// Groups events into blocks by the hour they start in. Only consecutive events share a block, so
+/// events keep the order they were passed in even when they aren't sorted by start time.
+pub(super) fn hourly_blocks(events: Vec<EventSearchResult>) -> Vec<dto::EventBlock> {
+    let mut blocks: Vec<dto::EventBlock> = Vec::new();
+    for event in events {
+        let block_time = NaiveTime::from_hms_opt(event.start.hour(), 0, 0)
+            .expect("the start of an hour should be a valid time");
+        let summary = dto::EventSummary::from(event);
+        match blocks.last_mut() {
+            Some(block) if block.represented_time.0 == block_time => block.events.push(summary),
+            _ => blocks.push(dto::EventBlock {
+                represented_time: dto::TimeDto(block_time),
+                events: vec![summary],
+            }),
+        }
+    }
+
+    blocks
+}
+
 #[utoipa::path(
     get,
     path = "/api/days/{day_id}/time-info",
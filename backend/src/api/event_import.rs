@@ -1,4 +1,6 @@
 use crate::api::MEBIBYTE;
+use crate::domain::watchlist::driving_ports::WatchlistPort;
+use crate::domain::webhook::driving_ports::WebhookPort;
 use crate::dto::IngestEventConvertErr;
 use crate::external_connections::{
     TransactableExternalConnectivity, TxOrSourceError, with_transaction,
@@ -11,6 +13,7 @@ use axum::http::StatusCode;
 use axum::response::ErrorResponse;
 use axum::routing::post;
 use axum::{Json, Router};
+use chrono::Utc;
 use std::sync::Arc;
 use tracing::*;
 use utoipa::OpenApi;
@@ -32,9 +35,19 @@ pub fn event_import_routes() -> Router<Arc<SharedData>> {
                 |State(app_state): AppState,
                  Json(import_request): Json<dto::EventImportRequest>| async move {
                     let evt_svc = domain::event::EventService;
+                    let live_update_svc = domain::live_update::LiveUpdateService;
                     let mut ext_cxn = app_state.ext_cxn.clone();
 
-                    import_events(import_request, &evt_svc, &mut ext_cxn).await
+                    let import_outcome =
+                        import_events(import_request, &evt_svc, &live_update_svc, &mut ext_cxn)
+                            .await?;
+                    publish_import_webhooks(&import_outcome, &mut ext_cxn).await;
+                    notify_import_watchers(&import_outcome, Arc::clone(&app_state));
+
+                    Ok::<_, ErrorResponse>((
+                        StatusCode::CREATED,
+                        Json(dto::ImportReport::from(&import_outcome)),
+                    ))
                 },
             ),
         )
@@ -47,7 +60,7 @@ pub fn event_import_routes() -> Router<Arc<SharedData>> {
     tag = EVENT_IMPORT_GROUP,
     request_body = EventImportRequest,
     responses(
-        (status = 201, description = "Events successfully upserted."),
+        (status = 201, description = "Events successfully upserted.", body = ImportReport),
         (
             status = 400,
             description = "Event had bad start time",
@@ -95,8 +108,9 @@ pub fn event_import_routes() -> Router<Arc<SharedData>> {
 async fn import_events(
     import_request: dto::EventImportRequest,
     event_port: &impl domain::event::driving_ports::EventPort,
+    live_update_port: &impl domain::live_update::driving_ports::LiveUpdatePort,
     ext_cxn: &mut impl TransactableExternalConnectivity,
-) -> Result<StatusCode, ErrorResponse> {
+) -> Result<domain::event::ImportOutcome, ErrorResponse> {
     let mut ingest_vec: Vec<domain::event::IngestEvent> =
         Vec::with_capacity(import_request.event_data.len());
 
@@ -140,16 +154,19 @@ async fn import_events(
         }
     }
 
-    with_transaction(ext_cxn, async |txn| {
-        event_port.import_events(
+    let import_outcome = with_transaction(ext_cxn, async |txn| {
+        let import_outcome = event_port.import_events(
             &ingest_vec,
 
+            &persistence::metadata::DbMetadataAliasReader,
             &persistence::metadata::DbEventTypeSaver,
             &persistence::metadata::DbGameSystemSaver,
             &persistence::metadata::DbContactSaver,
             &persistence::metadata::DbGroupSaver,
             &persistence::metadata::DbWebsiteSaver,
             &persistence::metadata::DbMaterialsSaver,
+            &persistence::metadata::DbMaterialItemSaver,
+            &persistence::metadata::DbMaterialItemLinker,
 
             &persistence::game_master::GameMasterDbSaver,
 
@@ -161,21 +178,103 @@ async fn import_events(
             &persistence::event::DbEventDetector,
             &persistence::event::DbEventWriter,
 
+            &persistence::watchlist::DbEventSnapshotReader,
+
+            &persistence::search::DbSearchIndexer,
+            &persistence::saved_search::DbSavedSearchReader,
+            &persistence::saved_search::DbSearchMatchRecorder,
+
+            &mut *txn,
+        ).await?;
+        // Announced inside the transaction so listeners only hear about committed changes
+        live_update_port.announce_import(
+            &import_outcome,
+            &persistence::live_update::DbChangeNotifier,
             txn,
-        ).await
-    }).await.map_err(|txn_err: TxOrSourceError<Vec<i64>, anyhow::Error>| {
+        ).await?;
+
+        Ok(import_outcome)
+    }).await.map_err(|txn_err: TxOrSourceError<domain::event::ImportOutcome, anyhow::Error>| {
         match txn_err {
             TxOrSourceError::Source(src_err) => error!(?src_err, "Import failure - logic issue"),
             TxOrSourceError::TxBegin(tx_err) => error!("Import failure - failed to start database transaction: {tx_err}"),
             TxOrSourceError::TxCommit { successful_result, transaction_err} =>
                 error!(
                     "Import failure - successfully ingested {num_events} events but failed to commit the transaction: {transaction_err}",
-                    num_events=successful_result.len()
+                    num_events=successful_result.event_ids.len()
                 ),
         }
 
         GenericErrorResponse(anyhow!("Could not import events."))
     })?;
 
-    Ok(StatusCode::CREATED)
+    for unparseable in &import_outcome.unparseable_values {
+        warn!(
+            game_id = unparseable.game_id,
+            field = ?unparseable.field,
+            value = unparseable.value,
+            "Left out unparseable value."
+        );
+    }
+
+    Ok(import_outcome)
+}
+
+#[instrument(skip_all, fields(total_events = import_outcome.event_ids.len()))]
+/// Logs webhook deliveries describing a committed import. The delivery worker sends them, so
+/// slow or unreachable subscribers don't hold up the import response.
+async fn publish_import_webhooks(
+    import_outcome: &domain::event::ImportOutcome,
+    ext_cxn: &mut persistence::ExternalConnectivity,
+) {
+    let messages = domain::webhook::WebhookMessage::from_import(import_outcome, Utc::now());
+    let publish_result = domain::webhook::WebhookService
+        .publish(
+            &messages,
+            &persistence::webhook::DbSubscriptionStore,
+            &persistence::webhook::DbDeliveryLog,
+            ext_cxn,
+        )
+        .await;
+    match publish_result {
+        Ok(pending_deliveries) => {
+            info!(
+                total_deliveries = pending_deliveries.len(),
+                "Logged import webhook deliveries."
+            )
+        }
+        // The import itself succeeded, so webhook failures shouldn't fail the request
+        Err(publish_err) => error!("Failed to publish import webhooks: {publish_err:#}"),
+    }
+}
+
+#[instrument(skip_all, fields(total_changed = import_outcome.watched_changes.len()))]
+/// Tells watchers about the changes a committed import made to their events. Sent in the
+/// background so a slow notification webhook doesn't hold up the import response.
+fn notify_import_watchers(
+    import_outcome: &domain::event::ImportOutcome,
+    app_state: Arc<SharedData>,
+) {
+    if import_outcome.watched_changes.is_empty() {
+        return;
+    }
+
+    let changed_events = import_outcome.watched_changes.clone();
+    let mut ext_cxn = app_state.ext_cxn.clone();
+    tokio::spawn(
+        async move {
+            let notify_result = domain::watchlist::WatchlistService
+                .notify_watchers(
+                    &changed_events,
+                    &persistence::watchlist::DbWatchlistReader,
+                    &app_state.notification_sender,
+                    &mut ext_cxn,
+                )
+                .await;
+            if let Err(notify_err) = notify_result {
+                error!("Failed to notify watchers of imported changes: {notify_err:#}");
+            }
+        }
+        .in_current_span(),
+    );
 }
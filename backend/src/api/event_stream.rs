@@ -0,0 +1,234 @@
+use crate::api::events::EventListQueryParams;
+use crate::domain::live_update::driving_ports::LiveUpdatePort;
+use crate::domain::live_update::{EventUpdate, UpdateFilter};
+use crate::dto::CommaSeparated;
+use crate::routing_utils::ValidationErrorResponse;
+use crate::{domain, dto, persistence};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::*;
+use utoipa::{IntoParams, OpenApi};
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(stream_event_updates,))]
+/// OpenAPI struct which registers the live event update stream with swagger
+pub struct EventStreamApi;
+
+/// Sending half of the channel which fans each announced batch of event updates out to every
+/// connected stream
+pub type EventUpdateSender = broadcast::Sender<Arc<Vec<EventUpdate>>>;
+
+/// Number of update batches buffered per stream before a slow client starts missing updates
+const UPDATE_BUFFER_SIZE: usize = 64;
+
+/// Number of messages waiting to be written to a single client
+const STREAM_BUFFER_SIZE: usize = 512;
+
+/// How long to wait before reconnecting after the change listener fails
+const LISTENER_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters selecting specific events to receive live updates for
+pub struct EventStreamQueryParams {
+    /// Comma separated list of event IDs to receive updates for
+    pub event_ids: Option<CommaSeparated<u32>>,
+}
+
+/// Starts a background task which relays event change announcements from Postgres to connected
+/// streams, returning the sender streams subscribe to
+pub fn start_update_relay(
+    db: PgPool,
+    ext_cxn: persistence::ExternalConnectivity,
+) -> EventUpdateSender {
+    let (update_sender, _) = broadcast::channel(UPDATE_BUFFER_SIZE);
+    tokio::spawn(relay_event_updates(db, ext_cxn, update_sender.clone()));
+
+    update_sender
+}
+
+/// Listens for event change announcements for as long as the application runs, reading the
+/// changed events and broadcasting them to connected streams
+async fn relay_event_updates(
+    db: PgPool,
+    mut ext_cxn: persistence::ExternalConnectivity,
+    update_sender: EventUpdateSender,
+) {
+    loop {
+        let mut listener = match persistence::live_update::DbChangeListener::connect(&db).await {
+            Ok(listener) => listener,
+            Err(connect_err) => {
+                error!("Could not listen for event changes: {connect_err:#}");
+                tokio::time::sleep(LISTENER_RETRY_DELAY).await;
+                continue;
+            }
+        };
+        info!("Listening for event changes.");
+
+        loop {
+            let changed_ids = match listener.next_changed_ids().await {
+                Ok(changed_ids) => changed_ids,
+                Err(listen_err) => {
+                    error!("Event change listener failed: {listen_err:#}");
+                    break;
+                }
+            };
+            // Skip the database round trip when nobody is watching
+            if update_sender.receiver_count() == 0 {
+                continue;
+            }
+
+            let read_result = domain::live_update::LiveUpdateService
+                .read_updates(
+                    &changed_ids,
+                    &persistence::live_update::DbEventUpdateReader,
+                    &mut ext_cxn,
+                )
+                .await;
+            match read_result {
+                Ok(updates) => {
+                    // Sending only fails if every stream disconnected in the meantime
+                    let _ = update_sender.send(Arc::new(updates));
+                }
+                Err(read_err) => error!("Could not read changed events: {read_err:#}"),
+            }
+        }
+
+        tokio::time::sleep(LISTENER_RETRY_DELAY).await;
+    }
+}
+
+/// Converts event list filters and requested event IDs into a live update filter
+fn to_update_filter(
+    stream_params: &EventStreamQueryParams,
+    filter: &EventListQueryParams,
+) -> UpdateFilter {
+    UpdateFilter {
+        event_ids: stream_params
+            .event_ids
+            .as_ref()
+            .map(|ids| ids.0.iter().map(|id| *id as i64).collect::<HashSet<i64>>()),
+        filter: filter.into(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/events/stream",
+    tag = super::events::EVENTS_API_GROUP,
+    params(
+        EventStreamQueryParams,
+        EventListQueryParams,
+    ),
+    responses(
+        (
+            status = 200,
+            description = "A text/event-stream of live updates. Each `event-update` message carries \
+                an EventUpdateMessage as JSON. A `resync` message means updates were missed and \
+                the client should reload the events it is showing.",
+            content_type = "text/event-stream",
+            body = EventUpdateMessage,
+        ),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+    ),
+)]
+#[instrument(skip_all)]
+/// Stream ticket counts and detail changes for events as soon as an import commits. Filters work
+/// the same way as the event list, and can be narrowed to specific event IDs.
+pub(super) async fn stream_event_updates(
+    stream_params: &EventStreamQueryParams,
+    filter: &EventListQueryParams,
+    update_sender: &EventUpdateSender,
+    ext_cxn: persistence::ExternalConnectivity,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>> + use<>>, ValidationErrorResponse> {
+    filter.validate().map_err(ValidationErrorResponse)?;
+
+    let (message_sender, message_receiver) = mpsc::channel(STREAM_BUFFER_SIZE);
+    tokio::spawn(
+        forward_matching_updates(
+            update_sender.subscribe(),
+            to_update_filter(stream_params, filter),
+            ext_cxn,
+            message_sender,
+        )
+        .in_current_span(),
+    );
+
+    info!("Live update stream opened.");
+    Ok(Sse::new(ReceiverStream::new(message_receiver)).keep_alive(KeepAlive::default()))
+}
+
+/// Passes each announced update matching the filter along to a single client until the client
+/// disconnects. Filters with search text are checked with the search index, so each batch of
+/// updates costs those clients one database query.
+async fn forward_matching_updates(
+    mut update_receiver: broadcast::Receiver<Arc<Vec<EventUpdate>>>,
+    update_filter: UpdateFilter,
+    mut ext_cxn: persistence::ExternalConnectivity,
+    message_sender: mpsc::Sender<Result<Event, axum::Error>>,
+) {
+    loop {
+        let received_updates = tokio::select! {
+            _ = message_sender.closed() => break,
+            received_updates = update_receiver.recv() => received_updates,
+        };
+        let messages: Vec<Result<Event, axum::Error>> = match received_updates {
+            Ok(updates) => {
+                let matching_result = domain::live_update::LiveUpdateService
+                    .matching_updates(
+                        &updates,
+                        &update_filter,
+                        &persistence::live_update::DbUpdateSearcher,
+                        &mut ext_cxn,
+                    )
+                    .await;
+                match matching_result {
+                    Ok(matching_updates) => matching_updates
+                        .iter()
+                        .map(|update| {
+                            Event::default()
+                                .event("event-update")
+                                .id(update.event_id.to_string())
+                                .json_data(dto::EventUpdateMessage::from(update))
+                        })
+                        .collect(),
+                    Err(matching_err) => {
+                        // The client can't tell which updates it should have received
+                        error!("Could not match changed events: {matching_err:#}");
+                        vec![Ok(resync_message(updates.len() as u64))]
+                    }
+                }
+            }
+            Err(RecvError::Lagged(missed_batches)) => {
+                warn!(missed_batches, "Live update stream fell behind.");
+                vec![Ok(resync_message(missed_batches))]
+            }
+            Err(RecvError::Closed) => break,
+        };
+
+        for message in messages {
+            if message_sender.send(message).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    info!("Live update stream closed.");
+}
+
+/// Tells a client it missed updates and should reload the events it is showing
+fn resync_message(missed_updates: u64) -> Event {
+    Event::default()
+        .event("resync")
+        .data(missed_updates.to_string())
+}
@@ -1,41 +1,54 @@
+use crate::api::access::require_admin;
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
 use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
 
 use axum::Router;
-use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::{StatusCode, Uri};
 use axum::response::ErrorResponse;
-use axum::routing::get;
-use chrono::NaiveTime;
+use axum::routing::{get, put};
+use chrono::{NaiveDate, NaiveTime, Utc};
 use fake::Fake;
 use serde::Deserialize;
 use tracing::*;
 use utoipa::{IntoParams, OpenApi};
 use validator::{Validate, ValidationError};
 
-use crate::api::{PageRange, determine_page_limits};
+use crate::domain::access::Viewer;
+use crate::domain::contact::ContactOwner;
+use crate::domain::event::{AgeRequirement, ExperienceLevel};
 use crate::dto::{
     CommaSeparated, EventBlock, EventDay, EventDetailResponse, EventSummary, GameSystem, Location,
-    TimeDto,
+    TimeDto, TimeSpanDto,
 };
-use crate::external_connections::ExternalConnectivity;
-use crate::routing_utils::{Json, ValidationErrorResponse};
-use crate::{AppState, SharedData, dto};
+use crate::external_connections::{
+    ExternalConnectivity, TransactableExternalConnectivity, TxOrSourceError, with_transaction,
+};
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
 
 #[derive(OpenApi)]
 #[openapi(paths(
+    list_events,
     list_event_counts_by_day,
     retrieve_event_detail,
+    delete_event,
     retrieve_game_systems,
     retrieve_event_types,
-    retrieve_locations
+    update_event_type,
+    set_content_tags,
+    retrieve_locations,
+    list_alternate_sessions,
 ))]
 /// OpenAPI struct which registers documentation for "event" API endpoints with swagger
 pub struct EventsApi;
 
+/// The most busy time spans an event list can be filtered by
+const MAX_BUSY_SPANS: usize = 50;
+
 /// Constant string which defines the API group for "event" endpoints in swagger
 pub const EVENTS_API_GROUP: &str = "Events";
 
@@ -43,7 +56,6 @@ pub const EVENTS_API_GROUP: &str = "Events";
 #[into_params(parameter_in = Query)]
 #[validate(schema(function = "validate_eventlist_query"))]
 #[serde(rename_all = "kebab-case")]
-#[expect(dead_code)]
 /// Query parameters for filtering events in the event list
 pub struct EventListQueryParams {
     /// Lower bound for available tickets in returned events (default 0)
@@ -65,17 +77,54 @@ pub struct EventListQueryParams {
     pub groups: Option<CommaSeparated<u32>>,
     /// Comma separated list of location IDs to filter for
     pub locations: Option<CommaSeparated<u32>>,
+    /// Comma separated list of room IDs to filter for, including events in sections of the rooms
+    pub rooms: Option<CommaSeparated<u32>>,
+    /// Comma separated list of room section IDs to filter for
+    pub sections: Option<CommaSeparated<u32>>,
     /// Whether or not to show events that are part of a tournament (default true)
     pub show_tournaments: Option<bool>,
+    /// Only show events which don't ask players to bring any materials (default false)
+    pub no_materials_required: Option<bool>,
+    /// Only show events in rooms or sections known to be wheelchair accessible (default false)
+    pub wheelchair_accessible: Option<bool>,
+    /// Only show events in quiet, low-sensory rooms or sections, or tagged low-sensory (default
+    /// false)
+    pub low_sensory: Option<bool>,
+
+    #[validate(custom(function = "validate_content_tag_list"))]
+    /// Comma separated list of content tags returned events must all have
+    pub content_tags: Option<CommaSeparated<String>>,
+
+    #[validate(custom(function = "validate_content_tag_list"))]
+    /// Comma separated list of content tags, such as content warnings, returned events must not
+    /// have any of
+    pub exclude_content_tags: Option<CommaSeparated<String>>,
+
     /// Time in HH:MM 24-hour format, the earliest start time of returned events
     pub start_time: Option<TimeDto>,
     /// Time in HH:MM 24-hour format, the latest start time of returned events
     pub end_time: Option<TimeDto>,
+    /// Time in HH:MM 24-hour format, the earliest time returned events may end. Events ending
+    /// after midnight end later than any time.
+    pub earliest_end_time: Option<TimeDto>,
+    /// Time in HH:MM 24-hour format, the latest time returned events may end. Events ending
+    /// after midnight end later than any time.
+    pub latest_end_time: Option<TimeDto>,
+    /// Span of local time in YYYY-MM-DDTHH:MM/YYYY-MM-DDTHH:MM format which returned events
+    /// must start and end within
+    #[param(value_type = Option<String>, example = "2024-08-01T13:00/2024-08-01T17:00")]
+    pub fits_within: Option<TimeSpanDto>,
+    /// Comma separated spans of local time in YYYY-MM-DDTHH:MM/YYYY-MM-DDTHH:MM format, at most
+    /// 50. Events overlapping any of them are left out.
+    #[param(value_type = Option<String>)]
+    pub busy: Option<CommaSeparated<TimeSpanDto>>,
     /// The shortest duration of returned events
     pub min_duration: Option<f32>,
     /// The longest duration of returned events
     pub max_duration: Option<f32>,
-    /// Partial event title to search for
+    /// Words to search event titles, descriptions, game systems, groups, and game masters for.
+    /// Matching ignores case and word endings, "quoted phrases" must appear together, "or"
+    /// matches either side, and a leading "-" excludes a word.
     pub search_text: Option<String>,
     /// The lowest event price that should be returned in results
     pub cost_min: Option<u16>,
@@ -83,6 +132,75 @@ pub struct EventListQueryParams {
     pub cost_max: Option<u16>,
 }
 
+#[derive(Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for ordering lists of events
+pub struct EventSortQueryParams {
+    /// How to order returned events: start-time (default), title, cost, duration,
+    /// tickets-available, or relevance. Relevance ranks search-text matches and falls back to
+    /// start time without search text.
+    pub sort: Option<dto::EventSortOption>,
+    /// Direction to order events in, asc or desc (default asc, or desc when sorting by relevance)
+    pub sort_direction: Option<dto::SortDirectionOption>,
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for requesting filter counts alongside lists of events
+pub struct FacetQueryParams {
+    /// Whether to include counts of the events each filter value would produce (default false)
+    pub include_facets: Option<bool>,
+}
+
+#[derive(Validate, Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[validate(schema(function = "validate_date_range_query"))]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for limiting event lists to a range of days
+pub struct DateRangeQueryParams {
+    /// ID of the first day to list events from (YYYYMMDD format, default the first day of the
+    /// convention)
+    pub first_day: Option<u32>,
+    /// ID of the last day to list events from (YYYYMMDD format, default the last day of the
+    /// convention)
+    pub last_day: Option<u32>,
+}
+
+impl From<&DateRangeQueryParams> for domain::search::DateRange {
+    fn from(value: &DateRangeQueryParams) -> Self {
+        let parse = |day_id: Option<u32>| {
+            day_id
+                .and_then(dto::DateDto::try_from_date_id)
+                .map(|dto::DateDto(date)| date)
+        };
+
+        Self {
+            first: parse(value.first_day),
+            last: parse(value.last_day),
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for arranging lists of events
+pub struct EventGroupingQueryParams {
+    /// Whether to group events by the day and then the hour they start in (default false)
+    pub group_by_day: Option<bool>,
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for listing alternate sessions of an event
+pub struct AlternateSessionQueryParams {
+    /// Whether to leave out sessions with no tickets left (default false)
+    pub available_only: Option<bool>,
+}
+
 #[instrument(skip(query_params))]
 /// Performs custom validation to ensure the validity of event filters
 fn validate_eventlist_query(query_params: &EventListQueryParams) -> Result<(), ValidationError> {
@@ -99,6 +217,39 @@ fn validate_eventlist_query(query_params: &EventListQueryParams) -> Result<(), V
         }
     }
 
+    // Validate earliest_end_time <= latest_end_time
+    if let (Some(TimeDto(earliest_end)), Some(TimeDto(latest_end))) = (
+        &query_params.earliest_end_time,
+        &query_params.latest_end_time,
+    ) && earliest_end > latest_end
+    {
+        return Err(
+            ValidationError::new("bad_event_end_time_order").with_message(Cow::Borrowed(
+                "Earliest end time must be less than or equal to latest end time.",
+            )),
+        );
+    }
+
+    // Validate busy spans are limited and every time span ends after it starts
+    if query_params
+        .busy
+        .as_ref()
+        .is_some_and(|busy| busy.0.len() > MAX_BUSY_SPANS)
+    {
+        return Err(ValidationError::new("too_many_busy_spans")
+            .with_message(Cow::Borrowed("At most 50 busy time spans may be passed.")));
+    }
+    let spans = query_params
+        .fits_within
+        .iter()
+        .chain(query_params.busy.iter().flat_map(|busy| busy.0.iter()));
+    for span in spans {
+        if span.start >= span.end {
+            return Err(ValidationError::new("bad_time_span_order")
+                .with_message(Cow::Borrowed("Time spans must end after they start.")));
+        }
+    }
+
     // Validate min_duration <= max_duration
     if let (Some(min_duration), Some(max_duration)) =
         (&query_params.min_duration, &query_params.max_duration)
@@ -123,6 +274,34 @@ fn validate_eventlist_query(query_params: &EventListQueryParams) -> Result<(), V
     Ok(())
 }
 
+#[instrument]
+/// Ensures day IDs are real dates and the first day doesn't come after the last
+fn validate_date_range_query(query_params: &DateRangeQueryParams) -> Result<(), ValidationError> {
+    let parse = |day_id: Option<u32>| match day_id {
+        Some(day_id) => dto::DateDto::try_from_date_id(day_id)
+            .map(|dto::DateDto(date)| Some(date))
+            .ok_or_else(|| {
+                ValidationError::new("invalid_day_id")
+                    .with_message(Cow::Borrowed("Day IDs must be dates in YYYYMMDD format."))
+            }),
+        None => Ok(None),
+    };
+
+    if let (Some(first_day), Some(last_day)) = (
+        parse(query_params.first_day)?,
+        parse(query_params.last_day)?,
+    ) && first_day > last_day
+    {
+        return Err(
+            ValidationError::new("bad_day_order").with_message(Cow::Borrowed(
+                "The first day cannot come after the last day.",
+            )),
+        );
+    }
+
+    Ok(())
+}
+
 #[instrument]
 /// Validates experience requirement values in filters
 fn validate_experience_list(type_list: &CommaSeparated<String>) -> Result<(), ValidationError> {
@@ -151,181 +330,266 @@ fn validate_age_list(age_list: &CommaSeparated<String>) -> Result<(), Validation
     Ok(())
 }
 
-/// Performs filtering on generated event data. This will eventually be moved into database queries
-/// once we remove the current API stubs.
-pub(super) fn matches_event_filter(
-    evt_summary: &dto::EventSummary,
-    filter: &EventListQueryParams,
-) -> bool {
-    // Time filter
-    if let Some(TimeDto(min_start)) = &filter.start_time {
-        if evt_summary.event_time.0 < *min_start {
-            return false;
-        }
-    }
-    if let Some(TimeDto(max_start)) = &filter.end_time {
-        if evt_summary.event_time.0 > *max_start {
-            return false;
-        }
-    }
-
-    // Search text filter
-    if let Some(search_text) = &filter.search_text {
-        if !evt_summary.title.contains(search_text) {
-            return false;
-        }
-    }
-
-    // Ticket availability filter
-    if let Some(min_available_tix) = filter.min_available_tickets {
-        if evt_summary.tickets.available < min_available_tix {
-            return false;
+#[instrument]
+/// Validates content tag values in filters
+fn validate_content_tag_list(tag_list: &CommaSeparated<String>) -> Result<(), ValidationError> {
+    for str_to_check in tag_list.0.iter() {
+        if domain::accessibility::normalize_content_tag(str_to_check).is_none() {
+            return Err(ValidationError::new("invalid_content_tag").with_message(Cow::Owned(
+                format!("One or more content tags ({str_to_check}) were not valid. Tags need letters or digits and can be at most 64 characters long"),
+            )));
         }
     }
 
-    // Duration filter
-    if let Some(min_duration) = filter.min_duration {
-        if evt_summary.duration < min_duration {
-            return false;
-        }
-    }
-    if let Some(max_duration) = filter.max_duration {
-        if evt_summary.duration > max_duration {
-            return false;
-        }
-    }
+    Ok(())
+}
 
-    // Cost filter
-    if let (Some(min_cost), Some(actual_cost)) = (filter.cost_min, evt_summary.cost) {
-        if actual_cost < min_cost {
-            return false;
+impl From<&EventListQueryParams> for domain::search::EventFilter {
+    fn from(filter: &EventListQueryParams) -> Self {
+        fn ids<T: From<u32>>(ids: &Option<CommaSeparated<u32>>) -> Option<Vec<T>> {
+            ids.as_ref()
+                .map(|ids| ids.0.iter().map(|id| T::from(*id)).collect())
         }
-    }
-    if let (Some(max_cost), Some(actual_cost)) = (filter.cost_max, evt_summary.cost) {
-        if actual_cost > max_cost {
-            return false;
-        }
-    }
-
-    true
-}
-
-#[instrument(skip(blocks))]
-/// Removes events from event blocks if they are outside the range of the requested page, skipping
-/// [results_already_processed] events if a previous pagination already ran on the set of blocks.
-/// Returns the total number of events that were examined during the pagination process.
-/// This function is temporary and will be replaced with database filters once we remove the event
-/// stubs.
-pub(super) fn paginate_additional_events(
-    blocks: &mut [EventBlock],
-    page: u16,
-    results_per_page: u16,
-    results_already_processed: usize,
-) -> usize {
-    let mut processed_results = results_already_processed;
-    let PageRange {
-        page_start,
-        page_end,
-    } = determine_page_limits(page, results_per_page);
-
-    // If we're before the result page, get up to the result page
-    if processed_results < page_start {
-        for block in blocks.iter_mut() {
-            if block.events.len() + processed_results < page_start {
-                processed_results += block.events.len();
-                block.events.clear()
-            } else {
-                let events_outside_page = page_start - processed_results;
-                processed_results += events_outside_page;
-                block.events.drain(..events_outside_page);
-            }
+        fn content_tags(tags: &Option<CommaSeparated<String>>) -> Option<Vec<String>> {
+            tags.as_ref().map(|tags| {
+                tags.0
+                    .iter()
+                    .filter_map(|tag| domain::accessibility::normalize_content_tag(tag))
+                    .collect()
+            })
         }
-    }
-
-    // If we went through all events and didn't hit the page start, just return the processed event count
-    if blocks.iter().all(|block| block.events.is_empty()) {
-        return processed_results;
-    }
 
-    // Consume as many results as we can up to the page limit
-    for block in blocks.iter_mut() {
-        if processed_results + block.events.len() < page_end {
-            // If the entirety of this block is within the page, keep everything
-            processed_results += block.events.len()
-        } else if processed_results > page_end {
-            // If we're beyond the page limit, clear out everything else
-            processed_results += block.events.len();
-            block.events.clear();
-        } else {
-            // We only need to clear out the last few results (the ones off the end of the page)
-            let results_to_keep = page_end - processed_results;
-            processed_results += block.events.len();
-            block.events.drain(results_to_keep..);
+        Self {
+            min_available_tickets: filter.min_available_tickets,
+            event_type_ids: filter
+                .event_types
+                .as_ref()
+                .map(|ids| ids.0.iter().map(|id| *id as i32).collect()),
+            experience_levels: filter.experience.as_ref().map(|levels| {
+                levels
+                    .0
+                    .iter()
+                    .filter_map(|level| match level.as_str() {
+                        "none" => Some(ExperienceLevel::None),
+                        "some" => Some(ExperienceLevel::Some),
+                        "expert" => Some(ExperienceLevel::Expert),
+                        _ => None,
+                    })
+                    .collect()
+            }),
+            age_requirements: filter.age.as_ref().map(|ages| {
+                ages.0
+                    .iter()
+                    .filter_map(|age| match age.as_str() {
+                        "everyone" => Some(AgeRequirement::Everyone),
+                        "kidsonly" => Some(AgeRequirement::KidsOnly),
+                        "teen" => Some(AgeRequirement::Teen),
+                        "mature" => Some(AgeRequirement::Mature),
+                        "adult" => Some(AgeRequirement::Adult),
+                        _ => None,
+                    })
+                    .collect()
+            }),
+            game_system_ids: ids(&filter.game_systems),
+            group_ids: ids(&filter.groups),
+            building_ids: filter
+                .locations
+                .as_ref()
+                .map(|ids| ids.0.iter().map(|id| *id as i32).collect()),
+            room_ids: filter
+                .rooms
+                .as_ref()
+                .map(|ids| ids.0.iter().map(|id| *id as i32).collect()),
+            section_ids: filter
+                .sections
+                .as_ref()
+                .map(|ids| ids.0.iter().map(|id| *id as i32).collect()),
+            exclude_tournaments: filter.show_tournaments == Some(false),
+            no_materials_required: filter.no_materials_required == Some(true),
+            wheelchair_accessible: filter.wheelchair_accessible == Some(true),
+            low_sensory: filter.low_sensory == Some(true),
+            content_tags: content_tags(&filter.content_tags),
+            exclude_content_tags: content_tags(&filter.exclude_content_tags),
+            earliest_start: filter.start_time.as_ref().map(|TimeDto(time)| *time),
+            latest_start: filter.end_time.as_ref().map(|TimeDto(time)| *time),
+            earliest_end: filter.earliest_end_time.as_ref().map(|TimeDto(time)| *time),
+            latest_end: filter.latest_end_time.as_ref().map(|TimeDto(time)| *time),
+            fits_within: filter.fits_within.map(Into::into),
+            busy: filter
+                .busy
+                .as_ref()
+                .map(|spans| spans.0.iter().map(|span| (*span).into()).collect())
+                .unwrap_or_default(),
+            min_duration_hours: filter.min_duration,
+            max_duration_hours: filter.max_duration,
+            search_text: filter.search_text.clone(),
+            cost_min: filter.cost_min.map(u32::from),
+            cost_max: filter.cost_max.map(u32::from),
         }
     }
-
-    processed_results
-}
-
-#[instrument(skip(blocks))]
-/// Removes events outside the requested page starting from the beginning, assuming no events
-/// have already been processed. Returns the number of total events encountered across all blocks.
-/// This will be replaced with database filters once we remove the stubbed out APIs.
-pub(super) fn paginate_events(
-    blocks: &mut [EventBlock],
-    page: u16,
-    results_per_page: u16,
-) -> usize {
-    paginate_additional_events(blocks, page, results_per_page, 0)
 }
 
 /// Returns a router containing all "/api/events" routes
 pub fn events_routes() -> Router<Arc<SharedData>> {
     Router::new()
+        .route(
+            "/",
+            get(
+                async |State(app_data): AppState,
+                       Query(dates): Query<DateRangeQueryParams>,
+                       Query(filter): Query<EventListQueryParams>,
+                       Query(sort): Query<EventSortQueryParams>,
+                       Query(grouping): Query<EventGroupingQueryParams>,
+                       Query(facet_params): Query<FacetQueryParams>,
+                       Query(pagination): Query<super::PaginationQueryParams>,
+                       OriginalUri(uri): OriginalUri| {
+                    let search_svc = domain::search::SearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_events(
+                        &uri,
+                        &dates,
+                        &filter,
+                        &sort,
+                        &grouping,
+                        &facet_params,
+                        &pagination,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
         .route(
             "/counts/daily",
             get(
                 async |State(app_data): AppState, Query(filter): Query<EventListQueryParams>| {
+                    let search_svc = domain::search::SearchService;
                     let mut ext_cxn = app_data.ext_cxn.clone();
 
-                    list_event_counts_by_day(&filter, &mut ext_cxn).await
+                    list_event_counts_by_day(&filter, &search_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/stream",
+            get(
+                async |State(app_data): AppState,
+                       Query(stream_params): Query<super::event_stream::EventStreamQueryParams>,
+                       Query(filter): Query<EventListQueryParams>| {
+                    super::event_stream::stream_event_updates(
+                        &stream_params,
+                        &filter,
+                        &app_data.event_updates,
+                        app_data.ext_cxn.clone(),
+                    )
+                    .await
                 },
             ),
         )
         .route(
             "/:event_id",
             get(
-                async |State(app_data): AppState, Path(event_id): Path<u32>| {
+                async |State(app_data): AppState, Path(event_id): Path<u32>, viewer: Viewer| {
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    retrieve_event_detail(event_id, viewer, &app_data.contact_policy, &mut ext_cxn)
+                        .await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState, viewer: Viewer, Path(event_id): Path<u32>| {
+                    let evt_svc = domain::event::EventService;
+                    let webhook_svc = domain::webhook::WebhookService;
                     let mut ext_cxn = app_data.ext_cxn.clone();
 
-                    retrieve_event_detail(event_id, &mut ext_cxn).await
+                    delete_event(viewer, event_id, &evt_svc, &webhook_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/:event_id/alternate-sessions",
+            get(
+                async |State(app_data): AppState,
+                       Path(event_id): Path<u32>,
+                       Query(params): Query<AlternateSessionQueryParams>| {
+                    let session_svc = domain::session::SessionService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_alternate_sessions(event_id, &params, &session_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/:event_id/content-tags",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(event_id): Path<u64>,
+                       Json(tags_request): Json<dto::ContentTagsRequest>| {
+                    let accessibility_svc = domain::accessibility::AccessibilityService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    set_content_tags(
+                        viewer,
+                        event_id,
+                        tags_request,
+                        &accessibility_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
                 },
             ),
         )
         .route(
             "/game-systems",
             get(async |State(app_data): AppState| {
+                let game_system_svc = domain::game_system::GameSystemService;
                 let mut ext_cxn = app_data.ext_cxn.clone();
 
-                retrieve_game_systems(&mut ext_cxn).await
+                retrieve_game_systems(&game_system_svc, &mut ext_cxn).await
             }),
         )
         .route(
             "/locations",
             get(async |State(app_data): AppState| {
+                let location_svc = domain::location::LocationService;
                 let mut ext_cxn = app_data.ext_cxn.clone();
 
-                retrieve_locations(&mut ext_cxn).await
+                retrieve_locations(&location_svc, &mut ext_cxn).await
             }),
         )
         .route(
             "/types",
             get(async |State(app_data): AppState| {
+                let event_type_svc = domain::event_type::EventTypeService;
                 let mut ext_cxn = app_data.ext_cxn.clone();
 
-                retrieve_event_types(&mut ext_cxn).await
+                retrieve_event_types(&event_type_svc, &mut ext_cxn).await
             }),
         )
+        .route(
+            "/types/:event_type_id",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(event_type_id): Path<u32>,
+                       Json(update_request): Json<dto::EventTypeUpdateRequest>| {
+                    let event_type_svc = domain::event_type::EventTypeService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    update_event_type(
+                        viewer,
+                        event_type_id,
+                        update_request,
+                        &event_type_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
 }
 
 /// Generates and caches a random set of events for a day for the stubbed out APIs.
@@ -465,6 +729,184 @@ fn event_detail_cache() -> MutexGuard<'static, HashMap<u32, dto::EventDetailResp
     retrieved_mutex.lock().unwrap()
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/events",
+    params(
+        DateRangeQueryParams,
+        EventListQueryParams,
+        EventSortQueryParams,
+        EventGroupingQueryParams,
+        FacetQueryParams,
+        super::PaginationQueryParams,
+    ),
+    tag = EVENTS_API_GROUP,
+    responses(
+        (status = 200, description = "Events successfully retrieved", body = EventListResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 500, response = dto::err_resps::BasicError500),
+    )
+)]
+#[instrument(skip(uri, filter, search_port, ext_cxn))]
+#[allow(clippy::too_many_arguments)]
+/// Lists events across the whole convention or a range of days
+///
+/// Events can be filtered, sorted, and paged the same way as the events of a single day.
+async fn list_events(
+    uri: &Uri,
+    dates: &DateRangeQueryParams,
+    filter: &EventListQueryParams,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::EventListResponse>, ErrorResponse> {
+    dates.validate().map_err(ValidationErrorResponse)?;
+    filter.validate().map_err(ValidationErrorResponse)?;
+
+    let resp = event_list(
+        uri,
+        &dates.into(),
+        &filter.into(),
+        sort,
+        grouping,
+        facet_params,
+        pagination,
+        search_port,
+        ext_cxn,
+    )
+    .await?;
+    Ok(Json(resp))
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Searches for a page of events within the date range, grouping them by day if requested
+pub(super) async fn event_list(
+    uri: &Uri,
+    date_range: &domain::search::DateRange,
+    event_filter: &domain::search::EventFilter,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<dto::EventListResponse, ErrorResponse> {
+    pagination.validate().map_err(ValidationErrorResponse)?;
+
+    let Some(page) = pagination.page_selection() else {
+        error!("Page cursor could not be decoded.");
+        return Err(super::invalid_cursor());
+    };
+    let results_per_page = pagination.limit.unwrap_or(50);
+    let search_page = search_port
+        .search_events(
+            date_range,
+            event_filter,
+            sort.sort.map(Into::into).unwrap_or_default(),
+            sort.sort_direction.map(Into::into),
+            &page,
+            results_per_page,
+            &persistence::search::DbEventSearcher,
+            ext_cxn,
+        )
+        .await
+        .map_err(|search_err| match search_err {
+            domain::search::SearchError::InvalidCursor => {
+                error!("Page cursor doesn't match the requested sort.");
+                super::invalid_cursor()
+            }
+            search_err => {
+                error!("Failed to search events: {search_err}");
+                GenericErrorResponse(search_err.into()).into()
+            }
+        })?;
+
+    let facets = if facet_params.include_facets == Some(true) {
+        let facets = search_port
+            .facets(
+                date_range,
+                event_filter,
+                &persistence::search::DbFacetCounter,
+                ext_cxn,
+            )
+            .await
+            .map_err(|facet_err| {
+                error!("Failed to count event facets: {facet_err}");
+                GenericErrorResponse(facet_err)
+            })?;
+        Some(facets.into())
+    } else {
+        None
+    };
+
+    let total_pages = super::total_pages(results_per_page, search_page.total_results as usize);
+    let total_events = search_page.events.len();
+    let (events, events_by_day) = if grouping.group_by_day == Some(true) {
+        (None, Some(daily_blocks(search_page.events)))
+    } else {
+        let events = search_page
+            .events
+            .into_iter()
+            .map(|event| dto::DatedEventSummary {
+                day_id: dto::DateDto(event.start.date_naive()).date_id(),
+                event: event.into(),
+            })
+            .collect();
+        (Some(events), None)
+    };
+    let resp = dto::EventListResponse {
+        pagination_info: dto::PaginationInfo {
+            page: search_page.page_number,
+            total_pages,
+            next: search_page
+                .next
+                .map(|cursor| super::page_link(uri, &cursor)),
+            prev: search_page
+                .prev
+                .map(|cursor| super::page_link(uri, &cursor)),
+        },
+        events,
+        events_by_day,
+        facets,
+    };
+
+    info!(
+        total_events,
+        result_page = resp.pagination_info.page,
+        "Returned events."
+    );
+    Ok(resp)
+}
+
+/// Groups events by the day they start on and then into hourly blocks. Only consecutive events
+/// share a group, so events keep the order they were passed in.
+pub(super) fn daily_blocks(
+    events: Vec<domain::search::EventSearchResult>,
+) -> Vec<dto::DayEventBlocks> {
+    let mut days: Vec<(NaiveDate, Vec<domain::search::EventSearchResult>)> = Vec::new();
+    for event in events {
+        let date = event.start.date_naive();
+        match days.last_mut() {
+            Some((day, day_events)) if *day == date => day_events.push(event),
+            _ => days.push((date, vec![event])),
+        }
+    }
+
+    days.into_iter()
+        .map(|(date, day_events)| {
+            let date = dto::DateDto(date);
+            dto::DayEventBlocks {
+                day_id: date.date_id(),
+                date,
+                events_by_time: super::days::hourly_blocks(day_events),
+            }
+        })
+        .collect()
+}
+
 #[utoipa::path(
     get,
     path = "/api/events/counts/daily",
@@ -478,50 +920,44 @@ fn event_detail_cache() -> MutexGuard<'static, HashMap<u32, dto::EventDetailResp
         (status = 500, response = dto::err_resps::BasicError500),
     )
 )]
-#[instrument(skip(filter))]
+#[instrument(skip_all)]
 /// Lists the number of events by day for the current GenCon year
 ///
 /// If filtering query parameters are supplied, the counts of events returned are the number
 /// of events by day which match the query.
 async fn list_event_counts_by_day(
     filter: &EventListQueryParams,
-    _: &mut impl ExternalConnectivity,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
 ) -> Result<Json<dto::DaysResponse>, ErrorResponse> {
     filter.validate().map_err(ValidationErrorResponse)?;
 
-    let mut event_data = event_data().clone();
-    info_span!("filtering_events").in_scope(|| {
-        for (_, events_on_day) in event_data.events_by_day.iter_mut() {
-            for block in events_on_day.iter_mut() {
-                block.events.retain(|evt| matches_event_filter(evt, filter));
-            }
-        }
-    });
-
-    let mut days: Vec<EventDay> = event_data
-        .events_by_day
-        .iter()
-        .map(|(day_id, event_list)| {
-            let total_events: u16 = event_list
-                .iter()
-                .map(|block| block.events.len() as u16)
-                .sum();
+    let day_counts = search_port
+        .count_by_day(
+            &filter.into(),
+            &persistence::search::DbEventSearcher,
+            ext_cxn,
+        )
+        .await
+        .map_err(|count_err| {
+            error!("Failed to count events by day: {count_err}");
+            GenericErrorResponse(count_err)
+        })?;
 
+    let days: Vec<EventDay> = day_counts
+        .into_iter()
+        .map(|day_count| {
+            let date = dto::DateDto(day_count.date);
             EventDay {
-                day_id: *day_id,
-                date: dto::DateDto::from_date_id(*day_id),
-                total_events,
+                day_id: date.date_id(),
+                date,
+                total_events: day_count.total_events as u16,
             }
         })
         .collect();
-    days.sort_by(|day1, day2| day1.day_id.cmp(&day2.day_id));
-    let dummy_resp_data = dto::DaysResponse { days };
 
-    info!(
-        total_day_count = dummy_resp_data.days.len(),
-        "Retrieved event counts.",
-    );
-    Ok(Json(dummy_resp_data))
+    info!(total_day_count = days.len(), "Retrieved event counts.");
+    Ok(Json(dto::DaysResponse { days }))
 }
 
 #[utoipa::path(
@@ -533,6 +969,7 @@ async fn list_event_counts_by_day(
     ),
     responses(
         (status = 200, description = "Event successfully retrieved", body = EventDetailResponse),
+        (status = 401, response = dto::err_resps::BasicError401),
         (
             status = 404,
             description = "No GenCon events exist with the given ID",
@@ -546,10 +983,15 @@ async fn list_event_counts_by_day(
         (status = 500, response = dto::err_resps::BasicError500),
     ),
 )]
-#[instrument]
+#[instrument(skip(contact_policy))]
 /// Retrieve detailed information about a single GenCon event
+///
+/// The organizer's contact email is only included for callers passing a member or admin access
+/// token. Other callers get a relay address in its place when a contact relay is configured.
 async fn retrieve_event_detail(
     event_id: u32,
+    viewer: Viewer,
+    contact_policy: &domain::contact::ContactPolicy,
     _: &mut impl ExternalConnectivity,
 ) -> Result<Json<EventDetailResponse>, ErrorResponse> {
     // Find the event in the event blocks
@@ -587,7 +1029,7 @@ async fn retrieve_event_detail(
     };
 
     let mut event_cache = event_detail_cache();
-    let event_to_return = match event_cache.get(&event_id) {
+    let mut event_to_return = match event_cache.get(&event_id) {
         Some(evt) => evt.clone(),
         None => {
             let newly_created_detail: EventDetailResponse = dto::DetailFromBlock {
@@ -603,6 +1045,11 @@ async fn retrieve_event_detail(
             newly_created_detail
         }
     };
+    event_to_return.contact = contact_policy.visible_contact(
+        event_to_return.contact.take(),
+        ContactOwner::Event(event_id as i64),
+        viewer,
+    );
 
     info!(
         %event_id,
@@ -612,6 +1059,98 @@ async fn retrieve_event_detail(
     Ok(Json(event_to_return))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/events/{event_id}",
+    tag = EVENTS_API_GROUP,
+    params(
+        ("event_id" = u32, Path, description = "The ID of the event to delete"),
+    ),
+    responses(
+        (status = 204, description = "The event was deleted"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(event_port, webhook_port, ext_cxn))]
+/// Permanently delete an event, such as one imported by mistake
+///
+/// Requires an admin access token. The event is also removed from watchlists and saved search
+/// matches, and webhook subscribers to `event.deleted` are notified.
+async fn delete_event(
+    viewer: Viewer,
+    event_id: u32,
+    event_port: &impl domain::event::driving_ports::EventPort,
+    webhook_port: &impl domain::webhook::driving_ports::WebhookPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "delete events")?;
+
+    // Deliveries are logged in the same transaction, so subscribers hear about every deletion
+    with_transaction(ext_cxn, async |txn| {
+        event_port
+            .delete_event(
+                event_id as i64,
+                &persistence::event::DbEventRemover,
+                &mut *txn,
+            )
+            .await?;
+        webhook_port
+            .publish(
+                &[domain::webhook::WebhookMessage::from_deletion(
+                    event_id as i64,
+                    Utc::now(),
+                )],
+                &persistence::webhook::DbSubscriptionStore,
+                &persistence::webhook::DbDeliveryLog,
+                txn,
+            )
+            .await
+            .map_err(domain::event::EventDeleteError::PortError)?;
+
+        Ok(())
+    })
+    .await
+    .map_err(
+        |txn_err: TxOrSourceError<(), domain::event::EventDeleteError>| -> ErrorResponse {
+            match txn_err {
+                TxOrSourceError::Source(domain::event::EventDeleteError::NotFound(event_id)) => {
+                    error!(event_id, "Event not found.");
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(dto::BasicError {
+                            error_code: "no_matching_event".to_owned(),
+                            error_description: "There is no event in the system with the given ID."
+                                .to_owned(),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                TxOrSourceError::Source(domain::event::EventDeleteError::PortError(port_err)) => {
+                    error!("Failed to delete event: {port_err:#}");
+                    GenericErrorResponse(port_err).into()
+                }
+                TxOrSourceError::TxBegin(tx_err) => {
+                    error!("Delete failure - failed to start database transaction: {tx_err}");
+                    GenericErrorResponse(tx_err).into()
+                }
+                TxOrSourceError::TxCommit {
+                    transaction_err, ..
+                } => {
+                    error!("Delete failure - failed to commit the transaction: {transaction_err}");
+                    GenericErrorResponse(transaction_err).into()
+                }
+            }
+        },
+    )?;
+
+    info!("Event deleted.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     get,
     path = "/api/events/locations",
@@ -621,34 +1160,29 @@ async fn retrieve_event_detail(
         (status = 500, response = dto::err_resps::BasicError500),
     ),
 )]
-#[instrument(skip(_ext_cxn))]
+#[instrument(skip_all)]
 /// List the set of known buildings in the system
 async fn retrieve_locations(
-    _ext_cxn: &mut impl ExternalConnectivity,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
 ) -> Result<Json<Vec<dto::LocationPart>>, ErrorResponse> {
     info!("Retrieving locations.");
-    let mut seen_ids: HashSet<u32> = HashSet::new();
-    let unique_buildings: Vec<dto::LocationPart> = locations()
-        .iter()
-        .filter_map(|location| {
-            let building = match location.building {
-                Some(ref building) => building,
-                None => return None,
-            };
-            if seen_ids.contains(&building.id) {
-                return None;
-            }
-
-            seen_ids.insert(building.id);
-            Some(building.clone())
+    let buildings: Vec<dto::LocationPart> = location_port
+        .location_hierarchy(&persistence::location::DbLocationReader, ext_cxn)
+        .await
+        .map_err(|hierarchy_err| {
+            error!("Failed to retrieve buildings: {hierarchy_err}");
+            GenericErrorResponse(hierarchy_err)
+        })?
+        .into_iter()
+        .map(|location| dto::LocationPart {
+            id: location.id as u32,
+            name: location.name,
         })
         .collect();
 
-    info!(
-        total_retrieved = unique_buildings.len(),
-        "Buildings retrieved."
-    );
-    Ok(Json(unique_buildings))
+    info!(total_retrieved = buildings.len(), "Buildings retrieved.");
+    Ok(Json(buildings))
 }
 
 #[utoipa::path(
@@ -660,22 +1194,148 @@ async fn retrieve_locations(
         (status = 500, response = dto::err_resps::BasicError500),
     ),
 )]
-#[instrument(skip(_ext_cxn))]
-/// List the set of known game types in the system
+#[instrument(skip_all)]
+/// List the catalog of event types along with how many of the current year's events each covers
 async fn retrieve_event_types(
-    _ext_cxn: &mut impl ExternalConnectivity,
+    event_type_port: &impl domain::event_type::driving_ports::EventTypePort,
+    ext_cxn: &mut impl ExternalConnectivity,
 ) -> Result<Json<Vec<dto::EventType>>, ErrorResponse> {
-    let evt_types: Vec<dto::EventType> = event_types()
-        .iter()
-        .enumerate()
-        .map(|(idx, evt_type)| dto::EventType {
-            id: (idx + 1) as u32,
-            type_name: evt_type.clone(),
-        })
-        .collect();
+    let evt_types = event_type_port
+        .event_type_catalog(&persistence::event_type::DbEventTypeReader, ext_cxn)
+        .await
+        .map_err(|catalog_err| {
+            error!("Failed to retrieve event types: {catalog_err}");
+            GenericErrorResponse(catalog_err)
+        })?;
 
     info!(total_retrieved = evt_types.len(), "Event types retrieved.");
-    Ok(Json(evt_types))
+    Ok(Json(evt_types.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/events/types/{event_type_id}",
+    tag = EVENTS_API_GROUP,
+    params(
+        ("event_type_id" = u32, Path, description = "The ID of the event type to update"),
+    ),
+    request_body = EventTypeUpdateRequest,
+    responses(
+        (status = 204, description = "The event type was updated"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(update_request, event_type_port, ext_cxn))]
+/// Set the display name, description, and icon of an event type
+///
+/// Requires an admin access token. Imports only set the name of types they create, so edits are
+/// kept through later imports.
+async fn update_event_type(
+    viewer: Viewer,
+    event_type_id: u32,
+    update_request: dto::EventTypeUpdateRequest,
+    event_type_port: &impl domain::event_type::driving_ports::EventTypePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change event types")?;
+
+    update_request.validate().map_err(ValidationErrorResponse)?;
+
+    event_type_port
+        .update_event_type(
+            event_type_id as i32,
+            update_request.into(),
+            &persistence::event_type::DbEventTypeWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(|update_err| -> ErrorResponse {
+            match update_err {
+                domain::event_type::EventTypeUpdateError::NotFound(event_type_id) => {
+                    error!(event_type_id, "Event type not found.");
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(dto::BasicError {
+                            error_code: "no_matching_event_type".to_owned(),
+                            error_description: "The requested event type is not in the system."
+                                .to_owned(),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                domain::event_type::EventTypeUpdateError::BlankName => {
+                    error!("Event type name was blank.");
+                    (
+                        StatusCode::BAD_REQUEST,
+                        Json(dto::BasicError {
+                            error_code: "blank_name".to_owned(),
+                            error_description: "Event type names can't be blank.".to_owned(),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                domain::event_type::EventTypeUpdateError::PortError(port_err) => {
+                    error!("Failed to update event type: {port_err}");
+                    GenericErrorResponse(port_err).into()
+                }
+            }
+        })?;
+
+    info!("Event type updated.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/events/{event_id}/content-tags",
+    tag = EVENTS_API_GROUP,
+    params(
+        ("event_id" = u64, Path, description = "The ID of the event to tag"),
+    ),
+    request_body = ContentTagsRequest,
+    responses(
+        (status = 200, description = "The event's content tags were replaced", body = ContentTagsResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(tags_request, accessibility_port, ext_cxn))]
+/// Replace the content tags of an event, such as content warnings
+///
+/// Requires an admin access token. Tags are saved lowercased with words joined by hyphens. Imports
+/// which list content tags replace the tags set here.
+async fn set_content_tags(
+    viewer: Viewer,
+    event_id: u64,
+    tags_request: dto::ContentTagsRequest,
+    accessibility_port: &impl domain::accessibility::driving_ports::AccessibilityPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::ContentTagsResponse>, ErrorResponse> {
+    require_admin(viewer, "change accessibility and content metadata")?;
+
+    tags_request.validate().map_err(ValidationErrorResponse)?;
+
+    let tags = accessibility_port
+        .set_content_tags(
+            event_id as i64,
+            &tags_request.tags,
+            &persistence::accessibility::DbAccessibilityWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(super::accessibility::accessibility_error_response)?;
+
+    info!(total_tags = tags.len(), "Event content tags saved.");
+    Ok(Json(dto::ContentTagsResponse { tags }))
 }
 
 #[utoipa::path(
@@ -687,13 +1347,94 @@ async fn retrieve_event_types(
         (status = 500, response = dto::err_resps::BasicError500)
     )
 )]
-#[instrument(skip(_ext_cxn))]
-/// Lists known game systems in the current GenCon year
+#[instrument(skip_all)]
+/// Lists known game systems, ordered by name
 async fn retrieve_game_systems(
-    _ext_cxn: &mut impl ExternalConnectivity,
-) -> Result<Json<&'static [GameSystem]>, ErrorResponse> {
-    let systems = game_systems();
+    game_system_port: &impl domain::game_system::driving_ports::GameSystemPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<Vec<GameSystem>>, ErrorResponse> {
+    let systems: Vec<GameSystem> = game_system_port
+        .game_systems(&persistence::game_system::DbGameSystemReader, ext_cxn)
+        .await
+        .map_err(|game_system_err| {
+            error!("Failed to retrieve game systems: {game_system_err}");
+            GenericErrorResponse(game_system_err)
+        })?
+        .into_iter()
+        .map(Into::into)
+        .collect();
 
     info!(total_systems = systems.len(), "Game systems retrieved.");
     Ok(Json(systems))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/events/{event_id}/alternate-sessions",
+    tag = EVENTS_API_GROUP,
+    params(
+        ("event_id" = u32, Path, description = "The ID of the event to find other sessions of"),
+        AlternateSessionQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Alternate sessions successfully retrieved", body = AlternateSessionsResponse),
+        (
+            status = 404,
+            description = "No GenCon events exist with the given ID",
+            body = BasicError,
+            example = json!({
+                "errorCode": "no_matching_event",
+                "errorDescription": "There is no event in the system with the given ID.",
+                "extraInfo": null
+            }),
+        ),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(session_port, ext_cxn))]
+/// List other sessions of an event (same title, game system, and group) held at a different time
+async fn list_alternate_sessions(
+    event_id: u32,
+    params: &AlternateSessionQueryParams,
+    session_port: &impl domain::session::driving_ports::SessionPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::AlternateSessionsResponse>, ErrorResponse> {
+    let alternates = session_port
+        .alternate_sessions(
+            event_id as i64,
+            params.available_only.unwrap_or(false),
+            &persistence::session::DbSessionReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|session_err| -> ErrorResponse {
+            match session_err {
+                domain::session::AlternateSessionError::EventNotFound(_) => {
+                    error!(event_id, "Event not found.");
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(dto::BasicError {
+                            error_code: "no_matching_event".to_owned(),
+                            error_description: "There is no event in the system with the given ID."
+                                .to_owned(),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                domain::session::AlternateSessionError::PortError(port_err) => {
+                    error!("Failed to look up alternate sessions: {port_err}");
+                    GenericErrorResponse(port_err).into()
+                }
+            }
+        })?;
+
+    info!(
+        total_sessions = alternates.len(),
+        "Retrieved alternate sessions."
+    );
+    Ok(Json(dto::AlternateSessionsResponse {
+        event_id: event_id as i64,
+        sessions: alternates.into_iter().map(Into::into).collect(),
+    }))
+}
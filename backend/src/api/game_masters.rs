@@ -0,0 +1,196 @@
+use crate::external_connections::ExternalConnectivity;
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::ErrorResponse;
+use axum::routing::get;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::*;
+use utoipa::{IntoParams, OpenApi};
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(list_game_masters, get_game_master))]
+/// OpenAPI struct which registers game master APIs with swagger
+pub struct GameMastersApi;
+
+/// Constant which defines the "game masters" group of API endpoints
+pub const GAME_MASTERS_API_GROUP: &str = "Game Masters";
+
+/// Number of game masters returned per page when no limit is requested
+const DEFAULT_GAME_MASTER_LIMIT: u16 = 50;
+
+#[derive(Deserialize, Validate, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for searching game masters
+pub struct GameMasterListQueryParams {
+    #[validate(length(max = 200))]
+    /// Only list game masters whose names contain this text or closely resemble it, ignoring case
+    pub name: Option<String>,
+
+    #[validate(range(min = 1))]
+    /// The page of game masters to return (default 1)
+    pub page: Option<u16>,
+
+    #[validate(range(min = 1, max = 200))]
+    /// The number of game masters to return per page (default 50)
+    pub limit: Option<u16>,
+}
+
+/// Returns a router containing all routes for the "/api/game-masters" set of endpoints
+pub fn game_masters_routes() -> Router<Arc<SharedData>> {
+    Router::new()
+        .route(
+            "/",
+            get(
+                async |State(app_data): AppState,
+                       Query(params): Query<GameMasterListQueryParams>| {
+                    let game_master_svc = domain::game_master::GameMasterService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_game_masters(&params, &game_master_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/:game_master_id",
+            get(
+                async |State(app_data): AppState, Path(game_master_id): Path<u32>| {
+                    let game_master_svc = domain::game_master::GameMasterService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    get_game_master(game_master_id, &game_master_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/game-masters",
+    tag = GAME_MASTERS_API_GROUP,
+    params(
+        GameMasterListQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Game masters successfully retrieved", body = GameMastersResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(game_master_port, ext_cxn))]
+/// List game masters along with how many events they run this year and across every year
+async fn list_game_masters(
+    params: &GameMasterListQueryParams,
+    game_master_port: &impl domain::game_master::driving_ports::GameMasterPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::GameMastersResponse>, ErrorResponse> {
+    params.validate().map_err(ValidationErrorResponse)?;
+
+    let page = params.page.unwrap_or(1);
+    let results_per_page = params.limit.unwrap_or(DEFAULT_GAME_MASTER_LIMIT);
+    let game_master_page = game_master_port
+        .game_masters(
+            params.name.as_deref(),
+            page,
+            results_per_page,
+            &persistence::game_master::DbGameMasterReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|search_err| {
+            error!("Failed to search game masters: {search_err}");
+            GenericErrorResponse(search_err)
+        })?;
+
+    info!(
+        total_results = game_master_page.total_results,
+        "Returned game masters."
+    );
+    Ok(Json(dto::GameMastersResponse {
+        page,
+        total_pages: super::total_pages(results_per_page, game_master_page.total_results as usize),
+        total_results: game_master_page.total_results,
+        game_masters: game_master_page
+            .game_masters
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/game-masters/{game_master_id}",
+    tag = GAME_MASTERS_API_GROUP,
+    params(
+        ("game_master_id" = u32, Path, description = "The ID of the game master to describe"),
+    ),
+    responses(
+        (status = 200, description = "Game master successfully retrieved", body = GameMasterDetailResponse),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(game_master_port, ext_cxn))]
+/// Get every event a game master runs this year or ran in prior years, grouped by year
+///
+/// Cancelled events are included and marked as cancelled.
+async fn get_game_master(
+    game_master_id: u32,
+    game_master_port: &impl domain::game_master::driving_ports::GameMasterPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::GameMasterDetailResponse>, ErrorResponse> {
+    let profile = game_master_port
+        .game_master_profile(
+            game_master_id as i64,
+            &persistence::game_master::DbGameMasterReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|game_master_err| -> ErrorResponse {
+            match game_master_err {
+                domain::game_master::GameMasterError::NotFound(game_master_id) => {
+                    error!(game_master_id, "Game master not found.");
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(dto::BasicError {
+                            error_code: "no_matching_game_master".to_owned(),
+                            error_description: "The requested game master is not in the system."
+                                .to_owned(),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                domain::game_master::GameMasterError::PortError(port_err) => {
+                    error!("Failed to retrieve game master: {port_err}");
+                    GenericErrorResponse(port_err).into()
+                }
+            }
+        })?;
+
+    // Events arrive ordered by year, so each year's events are consecutive
+    let total_events = profile.events.len();
+    let mut years: Vec<dto::GameMasterYear> = Vec::new();
+    for event in profile.events {
+        match years.last_mut() {
+            Some(year) if year.year == event.year => year.events.push(event.into()),
+            _ => years.push(dto::GameMasterYear {
+                year: event.year,
+                events: vec![event.into()],
+            }),
+        }
+    }
+
+    info!(total_events, "Returned game master.");
+    Ok(Json(dto::GameMasterDetailResponse {
+        id: profile.game_master.id as u32,
+        name: profile.game_master.name,
+        years,
+    }))
+}
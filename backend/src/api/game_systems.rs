@@ -0,0 +1,528 @@
+use crate::api::access::require_admin;
+use crate::api::events::{
+    DateRangeQueryParams, EventGroupingQueryParams, EventListQueryParams, EventSortQueryParams,
+    FacetQueryParams,
+};
+use crate::domain::access::Viewer;
+use crate::domain::metadata::MetadataKind;
+use crate::external_connections::{
+    ExternalConnectivity, TransactableExternalConnectivity, TxOrSourceError, with_transaction,
+};
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::{StatusCode, Uri};
+use axum::response::ErrorResponse;
+use axum::routing::{get, post, put};
+use std::sync::Arc;
+use tracing::*;
+use utoipa::OpenApi;
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_game_system,
+    list_game_system_aliases,
+    add_game_system_alias,
+    remove_game_system_alias,
+    merge_game_system,
+))]
+/// OpenAPI struct which registers game system APIs with swagger
+pub struct GameSystemsApi;
+
+/// Constant which defines the "game systems" group of API endpoints
+pub const GAME_SYSTEMS_API_GROUP: &str = "Game Systems";
+
+/// Returns a router containing all routes for the "/api/game-systems" set of endpoints
+pub fn game_systems_routes() -> Router<Arc<SharedData>> {
+    Router::new()
+        .route(
+            "/aliases",
+            get(async |State(app_data): AppState| {
+                let metadata_svc = domain::metadata::MetadataService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                list_game_system_aliases(&metadata_svc, &mut ext_cxn).await
+            }),
+        )
+        .route(
+            "/:game_system_id",
+            get(
+                async |State(app_data): AppState,
+                       Path(game_system_id): Path<u32>,
+                       Query(dates): Query<DateRangeQueryParams>,
+                       Query(filter): Query<EventListQueryParams>,
+                       Query(sort): Query<EventSortQueryParams>,
+                       Query(grouping): Query<EventGroupingQueryParams>,
+                       Query(facet_params): Query<FacetQueryParams>,
+                       Query(pagination): Query<super::PaginationQueryParams>,
+                       OriginalUri(uri): OriginalUri| {
+                    let game_system_svc = domain::game_system::GameSystemService;
+                    let search_svc = domain::search::SearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    get_game_system(
+                        &uri,
+                        game_system_id,
+                        &dates,
+                        &filter,
+                        &sort,
+                        &grouping,
+                        &facet_params,
+                        &pagination,
+                        &game_system_svc,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:game_system_id/aliases/:alias",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((game_system_id, alias)): Path<(u32, String)>| {
+                    let metadata_svc = domain::metadata::MetadataService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    add_game_system_alias(
+                        viewer,
+                        game_system_id,
+                        &alias,
+                        &metadata_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((game_system_id, alias)): Path<(u32, String)>| {
+                    let metadata_svc = domain::metadata::MetadataService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    remove_game_system_alias(
+                        viewer,
+                        game_system_id,
+                        &alias,
+                        &metadata_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:game_system_id/merges",
+            post(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(game_system_id): Path<u32>,
+                       Json(merge_request): Json<dto::MergeRequest>| {
+                    let metadata_svc = domain::metadata::MetadataService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    merge_game_system(
+                        viewer,
+                        game_system_id,
+                        merge_request,
+                        &metadata_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/game-systems/{game_system_id}",
+    params(
+        ("game_system_id" = u32, Path, description = "The ID of the game system to describe"),
+        DateRangeQueryParams,
+        EventListQueryParams,
+        EventSortQueryParams,
+        EventGroupingQueryParams,
+        FacetQueryParams,
+        super::PaginationQueryParams,
+    ),
+    tag = GAME_SYSTEMS_API_GROUP,
+    responses(
+        (status = 200, description = "Game system successfully retrieved", body = GameSystemDetailResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    )
+)]
+#[instrument(skip_all, fields(game_system_id))]
+#[allow(clippy::too_many_arguments)]
+/// Get a game system's event count, seats, prices, days, and organizing groups along with a page
+/// of its events
+///
+/// The summary covers all of the current year's uncancelled events using the system. The events
+/// can be filtered, sorted, and paged the same way as the full event list, though any game
+/// system filter is replaced by this game system.
+async fn get_game_system(
+    uri: &Uri,
+    game_system_id: u32,
+    dates: &DateRangeQueryParams,
+    filter: &EventListQueryParams,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    game_system_port: &impl domain::game_system::driving_ports::GameSystemPort,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::GameSystemDetailResponse>, ErrorResponse> {
+    dates.validate().map_err(ValidationErrorResponse)?;
+    filter.validate().map_err(ValidationErrorResponse)?;
+
+    let profile = game_system_port
+        .game_system_profile(
+            game_system_id as i64,
+            &persistence::game_system::DbGameSystemReader,
+            &mut *ext_cxn,
+        )
+        .await
+        .map_err(|game_system_err| -> ErrorResponse {
+            match game_system_err {
+                domain::game_system::GameSystemError::NotFound(game_system_id) => {
+                    error!(game_system_id, "Game system not found.");
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(dto::BasicError {
+                            error_code: "no_matching_game_system".to_owned(),
+                            error_description: "The requested game system is not in the system."
+                                .to_owned(),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                domain::game_system::GameSystemError::PortError(port_err) => {
+                    error!("Failed to retrieve game system: {port_err}");
+                    GenericErrorResponse(port_err).into()
+                }
+            }
+        })?;
+
+    let mut event_filter: domain::search::EventFilter = filter.into();
+    event_filter.game_system_ids = Some(vec![profile.game_system.id]);
+    let events = super::events::event_list(
+        uri,
+        &dates.into(),
+        &event_filter,
+        sort,
+        grouping,
+        facet_params,
+        pagination,
+        search_port,
+        ext_cxn,
+    )
+    .await?;
+
+    info!(
+        result_page = events.pagination_info.page,
+        "Returned game system."
+    );
+    Ok(Json(dto::GameSystemDetailResponse {
+        id: profile.game_system.id as u32,
+        name: profile.game_system.system_name,
+        total_events: profile.total_events,
+        total_seats: profile.total_seats,
+        price_range: profile
+            .price_range
+            .map(|(min_cost, max_cost)| dto::PriceRange { min_cost, max_cost }),
+        days_offered: profile
+            .days
+            .into_iter()
+            .map(|day| dto::DateDto(day).date_id())
+            .collect(),
+        groups: profile.groups.into_iter().map(Into::into).collect(),
+        events,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/game-systems/aliases",
+    tag = GAME_SYSTEMS_API_GROUP,
+    responses(
+        (status = 200, description = "Successfully retrieved aliases", body = Vec<MetadataAlias>),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all)]
+/// List the alternate names imports treat as existing game systems
+async fn list_game_system_aliases(
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<Vec<dto::MetadataAlias>>, ErrorResponse> {
+    let aliases = metadata_port
+        .metadata_aliases(&persistence::metadata::DbMetadataAliasReader, ext_cxn)
+        .await
+        .map_err(|alias_err| {
+            error!("Failed to retrieve game system aliases: {alias_err}");
+            GenericErrorResponse(alias_err)
+        })?;
+
+    Ok(Json(dto::MetadataAlias::sorted(aliases.game_systems)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/game-systems/{game_system_id}/aliases/{alias}",
+    tag = GAME_SYSTEMS_API_GROUP,
+    params(
+        ("game_system_id" = u32, Path, description = "The ID of the game system the alias refers to"),
+        ("alias" = String, Path, description = "Another name for the game system"),
+    ),
+    responses(
+        (status = 204, description = "Imports will treat the alias as the game system"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(metadata_port, ext_cxn))]
+/// Add an alias for a game system
+///
+/// Requires an admin access token. Imports swap the alias for the game system's name, ignoring case
+/// and extra whitespace. Numeric systems (e.g. 1830) can be pointed at their full names this way.
+async fn add_game_system_alias(
+    viewer: Viewer,
+    game_system_id: u32,
+    alias: &str,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change game system aliases and merges")?;
+
+    metadata_port
+        .set_alias(
+            MetadataKind::GameSystem,
+            game_system_id as i64,
+            alias,
+            &persistence::metadata::DbMetadataAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(metadata_update_error_response)?;
+
+    info!("Alias saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/game-systems/{game_system_id}/aliases/{alias}",
+    tag = GAME_SYSTEMS_API_GROUP,
+    params(
+        ("game_system_id" = u32, Path, description = "The ID of the game system the alias refers to"),
+        ("alias" = String, Path, description = "The alias to remove"),
+    ),
+    responses(
+        (status = 204, description = "The alias was removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(metadata_port, ext_cxn))]
+/// Remove an alias of a game system
+///
+/// Requires an admin access token.
+async fn remove_game_system_alias(
+    viewer: Viewer,
+    game_system_id: u32,
+    alias: &str,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change game system aliases and merges")?;
+
+    metadata_port
+        .remove_alias(
+            MetadataKind::GameSystem,
+            game_system_id as i64,
+            alias,
+            &persistence::metadata::DbMetadataAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(metadata_update_error_response)?;
+
+    info!("Alias removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/game-systems/{game_system_id}/merges",
+    tag = GAME_SYSTEMS_API_GROUP,
+    params(
+        ("game_system_id" = u32, Path, description = "The ID of the game system to keep"),
+    ),
+    request_body = MergeRequest,
+    responses(
+        (status = 204, description = "The duplicate was merged into the game system"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(metadata_port, ext_cxn))]
+/// Merge a duplicate game system into this one
+///
+/// Requires an admin access token. The duplicate's events and aliases move to this game system, and
+/// the duplicate's name becomes an alias so later imports land here.
+async fn merge_game_system(
+    viewer: Viewer,
+    game_system_id: u32,
+    merge_request: dto::MergeRequest,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change game system aliases and merges")?;
+
+    merge_records(
+        MetadataKind::GameSystem,
+        game_system_id,
+        merge_request.duplicate_id,
+        metadata_port,
+        ext_cxn,
+    )
+    .await
+}
+
+/// Merges the duplicate game system or group into the target inside a transaction, so a failed
+/// merge leaves both intact
+pub(super) async fn merge_records(
+    kind: MetadataKind,
+    target_id: u32,
+    duplicate_id: u32,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    with_transaction(ext_cxn, async |txn| {
+        metadata_port
+            .merge_records(
+                kind,
+                target_id as i64,
+                duplicate_id as i64,
+                &persistence::metadata::DbMetadataMerger,
+                &persistence::search::DbSearchIndexer,
+                txn,
+            )
+            .await
+    })
+    .await
+    .map_err(
+        |txn_err: TxOrSourceError<(), domain::metadata::MetadataUpdateError>| match txn_err {
+            TxOrSourceError::Source(merge_err) => metadata_update_error_response(merge_err),
+            TxOrSourceError::TxBegin(tx_err) => {
+                error!("Merge failure - failed to start database transaction: {tx_err}");
+                GenericErrorResponse(tx_err).into()
+            }
+            TxOrSourceError::TxCommit {
+                transaction_err, ..
+            } => {
+                error!("Merge failure - failed to commit the transaction: {transaction_err}");
+                GenericErrorResponse(transaction_err).into()
+            }
+        },
+    )?;
+
+    info!("Merged duplicate into target.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Converts game system and group alias and merge errors into API error responses
+pub(super) fn metadata_update_error_response(
+    update_err: domain::metadata::MetadataUpdateError,
+) -> ErrorResponse {
+    let not_found = |error_code: &str, error_description: String| -> ErrorResponse {
+        (
+            StatusCode::NOT_FOUND,
+            Json(dto::BasicError {
+                error_code: error_code.to_owned(),
+                error_description,
+                extra_info: None,
+            }),
+        )
+            .into()
+    };
+    let bad_request = |error_code: &str, error_description: String| -> ErrorResponse {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(dto::BasicError {
+                error_code: error_code.to_owned(),
+                error_description,
+                extra_info: None,
+            }),
+        )
+            .into()
+    };
+
+    match update_err {
+        domain::metadata::MetadataUpdateError::NotFound(kind, id) => {
+            error!(%kind, id, "Record not found.");
+            let error_code = match kind {
+                MetadataKind::GameSystem => "no_matching_game_system",
+                MetadataKind::Group => "no_matching_group",
+            };
+            not_found(
+                error_code,
+                format!(
+                    "The requested {} is not in the system.",
+                    kind.to_string().to_lowercase()
+                ),
+            )
+        }
+        domain::metadata::MetadataUpdateError::AliasNotFound(kind, id, alias) => {
+            error!(%kind, id, alias, "Alias not found.");
+            not_found(
+                "no_matching_alias",
+                format!(
+                    "The requested {} has no such alias.",
+                    kind.to_string().to_lowercase()
+                ),
+            )
+        }
+        domain::metadata::MetadataUpdateError::MergeIntoSelf(kind) => {
+            error!(%kind, "Attempted to merge a record into itself.");
+            bad_request(
+                "merge_into_self",
+                format!(
+                    "A {} cannot be merged into itself.",
+                    kind.to_string().to_lowercase()
+                ),
+            )
+        }
+        domain::metadata::MetadataUpdateError::InvalidAlias => {
+            error!("Attempted to save a blank or overly long alias.");
+            bad_request(
+                "invalid_alias",
+                format!(
+                    "Aliases must contain between 1 and {} characters.",
+                    domain::location::MAX_ALIAS_LENGTH
+                ),
+            )
+        }
+        domain::metadata::MetadataUpdateError::PortError(port_err) => {
+            error!("Metadata update failed: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
@@ -0,0 +1,1729 @@
+use crate::api::access::require_admin;
+use crate::api::events::{
+    DateRangeQueryParams, EventGroupingQueryParams, EventListQueryParams, EventSortQueryParams,
+    FacetQueryParams,
+};
+use crate::domain::access::Viewer;
+use crate::domain::location::{Ref, RefType};
+use crate::external_connections::{
+    ExternalConnectivity, TransactableExternalConnectivity, TxOrSourceError, with_transaction,
+};
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::{StatusCode, Uri};
+use axum::response::ErrorResponse;
+use axum::routing::{get, post, put};
+use std::sync::Arc;
+use tracing::*;
+use utoipa::OpenApi;
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_locations,
+    list_location_events,
+    list_room_events,
+    list_section_events,
+    get_venue_geography,
+    set_location_coordinates,
+    clear_location_coordinates,
+    set_room_coordinates,
+    clear_room_coordinates,
+    set_room_accessibility,
+    set_section_accessibility,
+    save_walking_time,
+    delete_walking_time,
+    list_location_aliases,
+    add_location_alias,
+    remove_location_alias,
+    add_room_alias,
+    remove_room_alias,
+    add_section_alias,
+    remove_section_alias,
+    merge_location,
+    merge_room,
+    merge_section,
+))]
+/// OpenAPI struct which registers location APIs with swagger
+pub struct LocationsApi;
+
+/// Constant which defines the "locations" group of API endpoints
+pub const LOCATIONS_API_GROUP: &str = "Locations";
+
+/// Returns a router containing all routes for the "/api/locations" set of endpoints
+pub fn locations_routes() -> Router<Arc<SharedData>> {
+    Router::new()
+        .route(
+            "/",
+            get(async |State(app_data): AppState| {
+                let location_svc = domain::location::LocationService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                list_locations(&location_svc, &mut ext_cxn).await
+            }),
+        )
+        .route(
+            "/geography",
+            get(async |State(app_data): AppState| {
+                let venue_svc = domain::venue::VenueService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                get_venue_geography(&venue_svc, &mut ext_cxn).await
+            }),
+        )
+        .route(
+            "/aliases",
+            get(async |State(app_data): AppState| {
+                let location_svc = domain::location::LocationService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                list_location_aliases(&location_svc, &mut ext_cxn).await
+            }),
+        )
+        .route(
+            "/:location_id/aliases/:alias",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((location_id, alias)): Path<(u16, String)>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    add_location_alias(viewer, location_id, &alias, &location_svc, &mut ext_cxn)
+                        .await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((location_id, alias)): Path<(u16, String)>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    remove_location_alias(viewer, location_id, &alias, &location_svc, &mut ext_cxn)
+                        .await
+                },
+            ),
+        )
+        .route(
+            "/rooms/:room_id/aliases/:alias",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((room_id, alias)): Path<(u32, String)>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    add_room_alias(viewer, room_id, &alias, &location_svc, &mut ext_cxn).await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((room_id, alias)): Path<(u32, String)>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    remove_room_alias(viewer, room_id, &alias, &location_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/sections/:section_id/aliases/:alias",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((section_id, alias)): Path<(u32, String)>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    add_section_alias(viewer, section_id, &alias, &location_svc, &mut ext_cxn).await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((section_id, alias)): Path<(u32, String)>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    remove_section_alias(viewer, section_id, &alias, &location_svc, &mut ext_cxn)
+                        .await
+                },
+            ),
+        )
+        .route(
+            "/:location_id/merges",
+            post(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(location_id): Path<u16>,
+                       Json(merge_request): Json<dto::MergeRequest>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    merge_location(
+                        viewer,
+                        location_id,
+                        merge_request,
+                        &location_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/rooms/:room_id/merges",
+            post(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(room_id): Path<u32>,
+                       Json(merge_request): Json<dto::MergeRequest>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    merge_room(viewer, room_id, merge_request, &location_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/sections/:section_id/merges",
+            post(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(section_id): Path<u32>,
+                       Json(merge_request): Json<dto::MergeRequest>| {
+                    let location_svc = domain::location::LocationService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    merge_section(
+                        viewer,
+                        section_id,
+                        merge_request,
+                        &location_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:location_id/coordinates",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(location_id): Path<u16>,
+                       Json(coordinates): Json<dto::CoordinatesRequest>| {
+                    let venue_svc = domain::venue::VenueService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    set_location_coordinates(
+                        viewer,
+                        location_id,
+                        coordinates,
+                        &venue_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState, viewer: Viewer, Path(location_id): Path<u16>| {
+                    let venue_svc = domain::venue::VenueService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    clear_location_coordinates(viewer, location_id, &venue_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/rooms/:room_id/coordinates",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(room_id): Path<u32>,
+                       Json(coordinates): Json<dto::CoordinatesRequest>| {
+                    let venue_svc = domain::venue::VenueService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    set_room_coordinates(viewer, room_id, coordinates, &venue_svc, &mut ext_cxn)
+                        .await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState, viewer: Viewer, Path(room_id): Path<u32>| {
+                    let venue_svc = domain::venue::VenueService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    clear_room_coordinates(viewer, room_id, &venue_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/rooms/:room_id/accessibility",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(room_id): Path<u32>,
+                       Json(accessibility): Json<dto::AccessibilityRequest>| {
+                    let accessibility_svc = domain::accessibility::AccessibilityService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    set_room_accessibility(
+                        viewer,
+                        room_id,
+                        accessibility,
+                        &accessibility_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/sections/:section_id/accessibility",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(section_id): Path<u32>,
+                       Json(accessibility): Json<dto::AccessibilityRequest>| {
+                    let accessibility_svc = domain::accessibility::AccessibilityService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    set_section_accessibility(
+                        viewer,
+                        section_id,
+                        accessibility,
+                        &accessibility_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:location_id/walking-times/:other_location_id",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((location_id, other_location_id)): Path<(u16, u16)>,
+                       Json(walking_time): Json<dto::WalkingTimeRequest>| {
+                    let venue_svc = domain::venue::VenueService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    save_walking_time(
+                        viewer,
+                        location_id,
+                        other_location_id,
+                        walking_time,
+                        &venue_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((location_id, other_location_id)): Path<(u16, u16)>| {
+                    let venue_svc = domain::venue::VenueService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    delete_walking_time(
+                        viewer,
+                        location_id,
+                        other_location_id,
+                        &venue_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:location_id/events",
+            get(
+                async |State(app_data): AppState,
+                       Path(location_id): Path<u16>,
+                       Query(dates): Query<DateRangeQueryParams>,
+                       Query(filter): Query<EventListQueryParams>,
+                       Query(sort): Query<EventSortQueryParams>,
+                       Query(grouping): Query<EventGroupingQueryParams>,
+                       Query(facet_params): Query<FacetQueryParams>,
+                       Query(pagination): Query<super::PaginationQueryParams>,
+                       OriginalUri(uri): OriginalUri| {
+                    let location_svc = domain::location::LocationService;
+                    let search_svc = domain::search::SearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_location_events(
+                        &uri,
+                        location_id,
+                        &dates,
+                        &filter,
+                        &sort,
+                        &grouping,
+                        &facet_params,
+                        &pagination,
+                        &location_svc,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/rooms/:room_id/events",
+            get(
+                async |State(app_data): AppState,
+                       Path(room_id): Path<u32>,
+                       Query(dates): Query<DateRangeQueryParams>,
+                       Query(filter): Query<EventListQueryParams>,
+                       Query(sort): Query<EventSortQueryParams>,
+                       Query(grouping): Query<EventGroupingQueryParams>,
+                       Query(facet_params): Query<FacetQueryParams>,
+                       Query(pagination): Query<super::PaginationQueryParams>,
+                       OriginalUri(uri): OriginalUri| {
+                    let location_svc = domain::location::LocationService;
+                    let search_svc = domain::search::SearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_room_events(
+                        &uri,
+                        room_id,
+                        &dates,
+                        &filter,
+                        &sort,
+                        &grouping,
+                        &facet_params,
+                        &pagination,
+                        &location_svc,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/sections/:section_id/events",
+            get(
+                async |State(app_data): AppState,
+                       Path(section_id): Path<u32>,
+                       Query(dates): Query<DateRangeQueryParams>,
+                       Query(filter): Query<EventListQueryParams>,
+                       Query(sort): Query<EventSortQueryParams>,
+                       Query(grouping): Query<EventGroupingQueryParams>,
+                       Query(facet_params): Query<FacetQueryParams>,
+                       Query(pagination): Query<super::PaginationQueryParams>,
+                       OriginalUri(uri): OriginalUri| {
+                    let location_svc = domain::location::LocationService;
+                    let search_svc = domain::search::SearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_section_events(
+                        &uri,
+                        section_id,
+                        &dates,
+                        &filter,
+                        &sort,
+                        &grouping,
+                        &facet_params,
+                        &pagination,
+                        &location_svc,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/locations",
+    tag = LOCATIONS_API_GROUP,
+    responses(
+        (status = 200, description = "Successfully retrieved every building, room, and section", body = LocationsResponse),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all)]
+/// List every building along with the rooms and sections inside it
+async fn list_locations(
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::LocationsResponse>, ErrorResponse> {
+    let hierarchy = location_port
+        .location_hierarchy(&persistence::location::DbLocationReader, ext_cxn)
+        .await
+        .map_err(|hierarchy_err| {
+            error!("Failed to retrieve locations: {hierarchy_err}");
+            GenericErrorResponse(hierarchy_err)
+        })?;
+
+    info!(total_locations = hierarchy.len(), "Retrieved locations.");
+    Ok(Json(dto::LocationsResponse {
+        locations: hierarchy.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/locations/{location_id}/events",
+    params(
+        ("location_id" = u16, Path, description = "The ID of the building to list events for"),
+        DateRangeQueryParams,
+        EventListQueryParams,
+        EventSortQueryParams,
+        EventGroupingQueryParams,
+        FacetQueryParams,
+        super::PaginationQueryParams,
+    ),
+    tag = LOCATIONS_API_GROUP,
+    responses(
+        (status = 200, description = "Events successfully retrieved", body = EventListResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    )
+)]
+#[instrument(skip_all, fields(location_id))]
+#[allow(clippy::too_many_arguments)]
+/// List events taking place in a building across days, including events in its rooms and
+/// sections
+///
+/// Events can be filtered, sorted, and paged the same way as the full event list.
+async fn list_location_events(
+    uri: &Uri,
+    location_id: u16,
+    dates: &DateRangeQueryParams,
+    filter: &EventListQueryParams,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::EventListResponse>, ErrorResponse> {
+    let place = Ref {
+        id: location_id as i32,
+        ref_type: RefType::Location,
+    };
+    place_events(
+        uri,
+        &place,
+        dates,
+        filter,
+        sort,
+        grouping,
+        facet_params,
+        pagination,
+        location_port,
+        search_port,
+        ext_cxn,
+    )
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/locations/rooms/{room_id}/events",
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room to list events for"),
+        DateRangeQueryParams,
+        EventListQueryParams,
+        EventSortQueryParams,
+        EventGroupingQueryParams,
+        FacetQueryParams,
+        super::PaginationQueryParams,
+    ),
+    tag = LOCATIONS_API_GROUP,
+    responses(
+        (status = 200, description = "Events successfully retrieved", body = EventListResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    )
+)]
+#[instrument(skip_all, fields(room_id))]
+#[allow(clippy::too_many_arguments)]
+/// List events taking place in a room across days, including events in its sections
+///
+/// Events can be filtered, sorted, and paged the same way as the full event list.
+async fn list_room_events(
+    uri: &Uri,
+    room_id: u32,
+    dates: &DateRangeQueryParams,
+    filter: &EventListQueryParams,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::EventListResponse>, ErrorResponse> {
+    let place = Ref {
+        id: room_id as i32,
+        ref_type: RefType::Room,
+    };
+    place_events(
+        uri,
+        &place,
+        dates,
+        filter,
+        sort,
+        grouping,
+        facet_params,
+        pagination,
+        location_port,
+        search_port,
+        ext_cxn,
+    )
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/locations/sections/{section_id}/events",
+    params(
+        ("section_id" = u32, Path, description = "The ID of the room section to list events for"),
+        DateRangeQueryParams,
+        EventListQueryParams,
+        EventSortQueryParams,
+        EventGroupingQueryParams,
+        FacetQueryParams,
+        super::PaginationQueryParams,
+    ),
+    tag = LOCATIONS_API_GROUP,
+    responses(
+        (status = 200, description = "Events successfully retrieved", body = EventListResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    )
+)]
+#[instrument(skip_all, fields(section_id))]
+#[allow(clippy::too_many_arguments)]
+/// List events taking place in a section of a room across days
+///
+/// Events can be filtered, sorted, and paged the same way as the full event list.
+async fn list_section_events(
+    uri: &Uri,
+    section_id: u32,
+    dates: &DateRangeQueryParams,
+    filter: &EventListQueryParams,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::EventListResponse>, ErrorResponse> {
+    let place = Ref {
+        id: section_id as i32,
+        ref_type: RefType::Section,
+    };
+    place_events(
+        uri,
+        &place,
+        dates,
+        filter,
+        sort,
+        grouping,
+        facet_params,
+        pagination,
+        location_port,
+        search_port,
+        ext_cxn,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Lists the events matching the filter which take place at the location, room, or section
+async fn place_events(
+    uri: &Uri,
+    place: &Ref,
+    dates: &DateRangeQueryParams,
+    filter: &EventListQueryParams,
+    sort: &EventSortQueryParams,
+    grouping: &EventGroupingQueryParams,
+    facet_params: &FacetQueryParams,
+    pagination: &super::PaginationQueryParams,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::EventListResponse>, ErrorResponse> {
+    dates.validate().map_err(ValidationErrorResponse)?;
+    filter.validate().map_err(ValidationErrorResponse)?;
+
+    let event_filter = location_port
+        .scope_to_place(
+            place,
+            filter.into(),
+            &persistence::location::DbLocationReader,
+            &mut *ext_cxn,
+        )
+        .await
+        .map_err(|location_err| -> ErrorResponse {
+            match location_err {
+                domain::location::LocationError::NotFound(place) => {
+                    error!(place_id = place.id, place_type = %place.ref_type, "Place not found.");
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(dto::BasicError {
+                            error_code: "no_matching_location".to_owned(),
+                            error_description: format!(
+                                "The requested {} is not in the system.",
+                                place.ref_type.to_string().to_lowercase()
+                            ),
+                            extra_info: None,
+                        }),
+                    )
+                        .into()
+                }
+                domain::location::LocationError::PortError(port_err) => {
+                    error!("Failed to look up place: {port_err}");
+                    GenericErrorResponse(port_err).into()
+                }
+            }
+        })?;
+
+    let resp = super::events::event_list(
+        uri,
+        &dates.into(),
+        &event_filter,
+        sort,
+        grouping,
+        facet_params,
+        pagination,
+        search_port,
+        ext_cxn,
+    )
+    .await?;
+
+    info!(
+        result_page = resp.pagination_info.page,
+        "Returned events at place."
+    );
+    Ok(Json(resp))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/locations/geography",
+    tag = LOCATIONS_API_GROUP,
+    responses(
+        (status = 200, description = "Successfully retrieved venue geography", body = VenueGeographyResponse),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all)]
+/// Get the coordinates of buildings and rooms along with the walking times recorded between
+/// buildings
+///
+/// Schedule suggestions use these to work out how much time is needed to get from one event to
+/// the next.
+async fn get_venue_geography(
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::VenueGeographyResponse>, ErrorResponse> {
+    let venue_map = venue_port
+        .venue_map(&persistence::venue::DbVenueMapReader, ext_cxn)
+        .await
+        .map_err(|venue_err| {
+            error!("Failed to retrieve venue geography: {venue_err}");
+            GenericErrorResponse(venue_err)
+        })?;
+
+    Ok(Json(venue_map.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/{location_id}/coordinates",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of the building to place on the map"),
+    ),
+    request_body = CoordinatesRequest,
+    responses(
+        (status = 204, description = "The building's coordinates were saved"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(venue_port, ext_cxn))]
+/// Set the coordinates of a building
+///
+/// Requires an admin access token.
+async fn set_location_coordinates(
+    viewer: Viewer,
+    location_id: u16,
+    coordinates: dto::CoordinatesRequest,
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change venue coordinates and walking times")?;
+
+    coordinates.validate().map_err(ValidationErrorResponse)?;
+
+    venue_port
+        .set_coordinates(
+            &Ref {
+                id: location_id as i32,
+                ref_type: RefType::Location,
+            },
+            Some((&coordinates).into()),
+            &persistence::venue::DbVenueMapWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(venue_error_response)?;
+
+    info!("Building coordinates saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/locations/{location_id}/coordinates",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of the building to remove from the map"),
+    ),
+    responses(
+        (status = 204, description = "The building's coordinates were cleared"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(venue_port, ext_cxn))]
+/// Clear the coordinates of a building
+///
+/// Requires an admin access token.
+async fn clear_location_coordinates(
+    viewer: Viewer,
+    location_id: u16,
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change venue coordinates and walking times")?;
+
+    venue_port
+        .set_coordinates(
+            &Ref {
+                id: location_id as i32,
+                ref_type: RefType::Location,
+            },
+            None,
+            &persistence::venue::DbVenueMapWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(venue_error_response)?;
+
+    info!("Building coordinates cleared.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/rooms/{room_id}/coordinates",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room to place on the map"),
+    ),
+    request_body = CoordinatesRequest,
+    responses(
+        (status = 204, description = "The room's coordinates were saved"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(venue_port, ext_cxn))]
+/// Set the coordinates of a room
+///
+/// Requires an admin access token. Room coordinates are preferred over building coordinates when
+/// estimating walking times, and allow walks between distant rooms of the same building to be
+/// timed.
+async fn set_room_coordinates(
+    viewer: Viewer,
+    room_id: u32,
+    coordinates: dto::CoordinatesRequest,
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change venue coordinates and walking times")?;
+
+    coordinates.validate().map_err(ValidationErrorResponse)?;
+
+    venue_port
+        .set_coordinates(
+            &Ref {
+                id: room_id as i32,
+                ref_type: RefType::Room,
+            },
+            Some((&coordinates).into()),
+            &persistence::venue::DbVenueMapWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(venue_error_response)?;
+
+    info!("Room coordinates saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/locations/rooms/{room_id}/coordinates",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room to remove from the map"),
+    ),
+    responses(
+        (status = 204, description = "The room's coordinates were cleared"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(venue_port, ext_cxn))]
+/// Clear the coordinates of a room
+///
+/// Requires an admin access token.
+async fn clear_room_coordinates(
+    viewer: Viewer,
+    room_id: u32,
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change venue coordinates and walking times")?;
+
+    venue_port
+        .set_coordinates(
+            &Ref {
+                id: room_id as i32,
+                ref_type: RefType::Room,
+            },
+            None,
+            &persistence::venue::DbVenueMapWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(venue_error_response)?;
+
+    info!("Room coordinates cleared.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/{location_id}/walking-times/{other_location_id}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of one of the buildings"),
+        ("other_location_id" = u16, Path, description = "The ID of the other building"),
+    ),
+    request_body = WalkingTimeRequest,
+    responses(
+        (status = 204, description = "The walking time was saved"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(venue_port, ext_cxn))]
+/// Record how long it takes to walk between two buildings
+///
+/// Requires an admin access token. The time applies in both directions and replaces any time
+/// already recorded for the pair. Recorded times are preferred over estimates from coordinates.
+async fn save_walking_time(
+    viewer: Viewer,
+    location_id: u16,
+    other_location_id: u16,
+    walking_time: dto::WalkingTimeRequest,
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change venue coordinates and walking times")?;
+
+    walking_time.validate().map_err(ValidationErrorResponse)?;
+
+    venue_port
+        .save_walking_time(
+            &domain::venue::WalkingTime {
+                location_id: location_id as i32,
+                other_location_id: other_location_id as i32,
+                minutes: walking_time.minutes,
+            },
+            &persistence::venue::DbVenueMapWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(venue_error_response)?;
+
+    info!("Walking time saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/locations/{location_id}/walking-times/{other_location_id}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of one of the buildings"),
+        ("other_location_id" = u16, Path, description = "The ID of the other building"),
+    ),
+    responses(
+        (status = 204, description = "The walking time was removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(venue_port, ext_cxn))]
+/// Remove the walking time recorded between two buildings
+///
+/// Requires an admin access token.
+async fn delete_walking_time(
+    viewer: Viewer,
+    location_id: u16,
+    other_location_id: u16,
+    venue_port: &impl domain::venue::driving_ports::VenuePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change venue coordinates and walking times")?;
+
+    venue_port
+        .delete_walking_time(
+            location_id as i32,
+            other_location_id as i32,
+            &persistence::venue::DbVenueMapWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(venue_error_response)?;
+
+    info!("Walking time removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/rooms/{room_id}/accessibility",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room to describe"),
+    ),
+    request_body = AccessibilityRequest,
+    responses(
+        (status = 204, description = "The room's accessibility was saved"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(accessibility_port, ext_cxn))]
+/// Set the accessibility of a room
+///
+/// Requires an admin access token. Sections of the room share its accessibility unless it's set
+/// on the section itself.
+async fn set_room_accessibility(
+    viewer: Viewer,
+    room_id: u32,
+    accessibility: dto::AccessibilityRequest,
+    accessibility_port: &impl domain::accessibility::driving_ports::AccessibilityPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change accessibility and content metadata")?;
+
+    accessibility_port
+        .set_accessibility(
+            &Ref {
+                id: room_id as i32,
+                ref_type: RefType::Room,
+            },
+            &(&accessibility).into(),
+            &persistence::accessibility::DbAccessibilityWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(super::accessibility::accessibility_error_response)?;
+
+    info!("Room accessibility saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/sections/{section_id}/accessibility",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("section_id" = u32, Path, description = "The ID of the room section to describe"),
+    ),
+    request_body = AccessibilityRequest,
+    responses(
+        (status = 204, description = "The section's accessibility was saved"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(accessibility_port, ext_cxn))]
+/// Set the accessibility of a room section
+///
+/// Requires an admin access token. Attributes left out fall back to those of the section's room.
+async fn set_section_accessibility(
+    viewer: Viewer,
+    section_id: u32,
+    accessibility: dto::AccessibilityRequest,
+    accessibility_port: &impl domain::accessibility::driving_ports::AccessibilityPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change accessibility and content metadata")?;
+
+    accessibility_port
+        .set_accessibility(
+            &Ref {
+                id: section_id as i32,
+                ref_type: RefType::Section,
+            },
+            &(&accessibility).into(),
+            &persistence::accessibility::DbAccessibilityWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(super::accessibility::accessibility_error_response)?;
+
+    info!("Section accessibility saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Converts venue errors into API error responses
+fn venue_error_response(venue_err: domain::venue::VenueError) -> ErrorResponse {
+    match venue_err {
+        domain::venue::VenueError::NotFound(place) => {
+            error!(place_id = place.id, place_type = %place.ref_type, "Place not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_location".to_owned(),
+                    error_description: format!(
+                        "The requested {} is not in the system.",
+                        place.ref_type.to_string().to_lowercase()
+                    ),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::venue::VenueError::SameLocation => {
+            error!("Walking time requested within a single building.");
+            (
+                StatusCode::BAD_REQUEST,
+                Json(dto::BasicError {
+                    error_code: "same_location".to_owned(),
+                    error_description:
+                        "Walking times can only be recorded between two different buildings."
+                            .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::venue::VenueError::NoWalkingTime(location_id, other_location_id) => {
+            error!(location_id, other_location_id, "Walking time not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_walking_time".to_owned(),
+                    error_description: "No walking time is recorded between the buildings."
+                        .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::venue::VenueError::PortError(port_err) => {
+            error!("Venue geography request failed: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/{location_id}/aliases/{alias}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of the building the alias refers to"),
+        ("alias" = String, Path, description = "Another name for the building"),
+    ),
+    responses(
+        (status = 204, description = "Imports will treat the alias as the building"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Add an alias for a building
+///
+/// Requires an admin access token. Imports swap the alias for the building's name, ignoring case
+/// and extra whitespace.
+async fn add_location_alias(
+    viewer: Viewer,
+    location_id: u16,
+    alias: &str,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change location aliases")?;
+
+    let place = Ref {
+        id: location_id as i32,
+        ref_type: RefType::Location,
+    };
+    location_port
+        .set_alias(
+            &place,
+            alias,
+            &persistence::location::DbLocationAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(location_update_error_response)?;
+
+    info!("Alias saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/locations/{location_id}/aliases/{alias}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of the building the alias refers to"),
+        ("alias" = String, Path, description = "The alias to remove"),
+    ),
+    responses(
+        (status = 204, description = "The alias was removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Remove an alias of a building
+///
+/// Requires an admin access token.
+async fn remove_location_alias(
+    viewer: Viewer,
+    location_id: u16,
+    alias: &str,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change location aliases")?;
+
+    let place = Ref {
+        id: location_id as i32,
+        ref_type: RefType::Location,
+    };
+    location_port
+        .remove_alias(
+            &place,
+            alias,
+            &persistence::location::DbLocationAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(location_update_error_response)?;
+
+    info!("Alias removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/rooms/{room_id}/aliases/{alias}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room the alias refers to"),
+        ("alias" = String, Path, description = "Another name for the room"),
+    ),
+    responses(
+        (status = 204, description = "Imports will treat the alias as the room"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Add an alias for a room
+///
+/// Requires an admin access token. Imports swap the alias for the room's name, ignoring case and
+/// extra whitespace.
+async fn add_room_alias(
+    viewer: Viewer,
+    room_id: u32,
+    alias: &str,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change location aliases")?;
+
+    let place = Ref {
+        id: room_id as i32,
+        ref_type: RefType::Room,
+    };
+    location_port
+        .set_alias(
+            &place,
+            alias,
+            &persistence::location::DbLocationAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(location_update_error_response)?;
+
+    info!("Alias saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/locations/rooms/{room_id}/aliases/{alias}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room the alias refers to"),
+        ("alias" = String, Path, description = "The alias to remove"),
+    ),
+    responses(
+        (status = 204, description = "The alias was removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Remove an alias of a room
+///
+/// Requires an admin access token.
+async fn remove_room_alias(
+    viewer: Viewer,
+    room_id: u32,
+    alias: &str,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change location aliases")?;
+
+    let place = Ref {
+        id: room_id as i32,
+        ref_type: RefType::Room,
+    };
+    location_port
+        .remove_alias(
+            &place,
+            alias,
+            &persistence::location::DbLocationAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(location_update_error_response)?;
+
+    info!("Alias removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/locations/sections/{section_id}/aliases/{alias}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("section_id" = u32, Path, description = "The ID of the section the alias refers to"),
+        ("alias" = String, Path, description = "Another name for the section"),
+    ),
+    responses(
+        (status = 204, description = "Imports will treat the alias as the section"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Add an alias for a section
+///
+/// Requires an admin access token. Imports swap the alias for the section's name, ignoring case and
+/// extra whitespace.
+async fn add_section_alias(
+    viewer: Viewer,
+    section_id: u32,
+    alias: &str,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change location aliases")?;
+
+    let place = Ref {
+        id: section_id as i32,
+        ref_type: RefType::Section,
+    };
+    location_port
+        .set_alias(
+            &place,
+            alias,
+            &persistence::location::DbLocationAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(location_update_error_response)?;
+
+    info!("Alias saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/locations/sections/{section_id}/aliases/{alias}",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("section_id" = u32, Path, description = "The ID of the section the alias refers to"),
+        ("alias" = String, Path, description = "The alias to remove"),
+    ),
+    responses(
+        (status = 204, description = "The alias was removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Remove an alias of a section
+///
+/// Requires an admin access token.
+async fn remove_section_alias(
+    viewer: Viewer,
+    section_id: u32,
+    alias: &str,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change location aliases")?;
+
+    let place = Ref {
+        id: section_id as i32,
+        ref_type: RefType::Section,
+    };
+    location_port
+        .remove_alias(
+            &place,
+            alias,
+            &persistence::location::DbLocationAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(location_update_error_response)?;
+
+    info!("Alias removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/locations/{location_id}/merges",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("location_id" = u16, Path, description = "The ID of the building to keep"),
+    ),
+    request_body = MergeRequest,
+    responses(
+        (status = 204, description = "The duplicate was merged into the building"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Merge a duplicate building into this one
+///
+/// Requires an admin access token. The duplicate's events, rooms, aliases, and walking times move
+/// to this building, rooms with the same name are merged, and the duplicate's name becomes an alias
+/// so later imports land here.
+async fn merge_location(
+    viewer: Viewer,
+    location_id: u16,
+    merge_request: dto::MergeRequest,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "merge locations")?;
+
+    let target = Ref {
+        id: location_id as i32,
+        ref_type: RefType::Location,
+    };
+    merge_places(&target, merge_request.duplicate_id, location_port, ext_cxn).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/locations/rooms/{room_id}/merges",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("room_id" = u32, Path, description = "The ID of the room to keep"),
+    ),
+    request_body = MergeRequest,
+    responses(
+        (status = 204, description = "The duplicate was merged into the room"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Merge a duplicate room into this one
+///
+/// Requires an admin access token. Both rooms must be in the same building. The duplicate's events,
+/// sections, and aliases move to this room, sections with the same name are merged, and the
+/// duplicate's name becomes an alias so later imports land here.
+async fn merge_room(
+    viewer: Viewer,
+    room_id: u32,
+    merge_request: dto::MergeRequest,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "merge locations")?;
+
+    let target = Ref {
+        id: room_id as i32,
+        ref_type: RefType::Room,
+    };
+    merge_places(&target, merge_request.duplicate_id, location_port, ext_cxn).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/locations/sections/{section_id}/merges",
+    tag = LOCATIONS_API_GROUP,
+    params(
+        ("section_id" = u32, Path, description = "The ID of the section to keep"),
+    ),
+    request_body = MergeRequest,
+    responses(
+        (status = 204, description = "The duplicate was merged into the section"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(location_port, ext_cxn))]
+/// Merge a duplicate section into this one
+///
+/// Requires an admin access token. Both sections must be in the same room. The duplicate's events
+/// and aliases move to this section, and the duplicate's name becomes an alias so later imports
+/// land here.
+async fn merge_section(
+    viewer: Viewer,
+    section_id: u32,
+    merge_request: dto::MergeRequest,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "merge locations")?;
+
+    let target = Ref {
+        id: section_id as i32,
+        ref_type: RefType::Section,
+    };
+    merge_places(&target, merge_request.duplicate_id, location_port, ext_cxn).await
+}
+
+/// Merges the duplicate into the target inside a transaction, so a failed merge leaves both intact
+async fn merge_places(
+    target: &Ref,
+    duplicate_id: u32,
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    with_transaction(ext_cxn, async |txn| {
+        location_port
+            .merge_places(
+                target,
+                duplicate_id as i32,
+                &persistence::location::DbLocationReader,
+                &persistence::location::DbLocationMerger,
+                txn,
+            )
+            .await
+    })
+    .await
+    .map_err(
+        |txn_err: TxOrSourceError<(), domain::location::LocationUpdateError>| match txn_err {
+            TxOrSourceError::Source(merge_err) => location_update_error_response(merge_err),
+            TxOrSourceError::TxBegin(tx_err) => {
+                error!("Merge failure - failed to start database transaction: {tx_err}");
+                GenericErrorResponse(tx_err).into()
+            }
+            TxOrSourceError::TxCommit {
+                transaction_err, ..
+            } => {
+                error!("Merge failure - failed to commit the transaction: {transaction_err}");
+                GenericErrorResponse(transaction_err).into()
+            }
+        },
+    )?;
+
+    info!("Merged duplicate into target.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Converts location alias and merge errors into API error responses
+fn location_update_error_response(
+    update_err: domain::location::LocationUpdateError,
+) -> ErrorResponse {
+    let bad_request = |error_code: &str, error_description: String| -> ErrorResponse {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(dto::BasicError {
+                error_code: error_code.to_owned(),
+                error_description,
+                extra_info: None,
+            }),
+        )
+            .into()
+    };
+
+    match update_err {
+        domain::location::LocationUpdateError::NotFound(place) => {
+            error!(place_id = place.id, place_type = %place.ref_type, "Place not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_location".to_owned(),
+                    error_description: format!(
+                        "The requested {} is not in the system.",
+                        place.ref_type.to_string().to_lowercase()
+                    ),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::location::LocationUpdateError::AliasNotFound(place, alias) => {
+            error!(place_id = place.id, place_type = %place.ref_type, alias, "Alias not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_alias".to_owned(),
+                    error_description: format!(
+                        "The requested {} has no such alias.",
+                        place.ref_type.to_string().to_lowercase()
+                    ),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::location::LocationUpdateError::MergeIntoSelf(ref_type) => {
+            error!(%ref_type, "Attempted to merge a place into itself.");
+            bad_request(
+                "merge_into_self",
+                format!(
+                    "A {} cannot be merged into itself.",
+                    ref_type.to_string().to_lowercase()
+                ),
+            )
+        }
+        domain::location::LocationUpdateError::DifferentParents => {
+            error!("Attempted to merge places with different parents.");
+            bad_request(
+                "different_parents",
+                "Rooms can only be merged within a building, and sections within a room."
+                    .to_owned(),
+            )
+        }
+        domain::location::LocationUpdateError::InvalidAlias => {
+            error!("Attempted to save a blank or overly long alias.");
+            bad_request(
+                "invalid_alias",
+                format!(
+                    "Aliases must contain between 1 and {} characters.",
+                    domain::location::MAX_ALIAS_LENGTH
+                ),
+            )
+        }
+        domain::location::LocationUpdateError::PortError(port_err) => {
+            error!("Location update failed: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/locations/aliases",
+    tag = LOCATIONS_API_GROUP,
+    responses(
+        (status = 200, description = "Successfully retrieved aliases", body = LocationAliasesResponse),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all)]
+/// List the alternate names imports treat as existing buildings, rooms, and sections
+async fn list_location_aliases(
+    location_port: &impl domain::location::driving_ports::LocationPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::LocationAliasesResponse>, ErrorResponse> {
+    let aliases = location_port
+        .location_aliases(&persistence::location::DbLocationReader, ext_cxn)
+        .await
+        .map_err(|alias_err| {
+            error!("Failed to retrieve location aliases: {alias_err}");
+            GenericErrorResponse(alias_err)
+        })?;
+
+    Ok(Json(aliases.into()))
+}
@@ -1,17 +1,30 @@
-use crate::external_connections::ExternalConnectivity;
-use crate::routing_utils::Json;
-use crate::{AppState, SharedData, dto};
+use crate::api::access::require_admin;
+use crate::api::game_systems::{merge_records, metadata_update_error_response};
+use crate::domain::access::Viewer;
+use crate::domain::metadata::MetadataKind;
+use crate::external_connections::{ExternalConnectivity, TransactableExternalConnectivity};
+use crate::routing_utils::{GenericErrorResponse, Json};
+use crate::{AppState, SharedData, domain, dto, persistence};
 use axum::Router;
-use axum::extract::State;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use axum::response::ErrorResponse;
-use axum::routing::get;
+use axum::routing::{get, post, put};
 use fake::{Fake, Faker};
 use std::sync::{Arc, OnceLock};
 use tracing::*;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
-#[openapi(paths(list_organizer_groups))]
+#[openapi(paths(
+    list_organizer_groups,
+    get_group,
+    set_group_contact_sharing,
+    list_group_aliases,
+    add_group_alias,
+    remove_group_alias,
+    merge_group,
+))]
 /// OpenAPI struct which registers organizer-related documentation with Swagger
 pub struct OrganizersApi;
 
@@ -20,14 +33,103 @@ pub const ORGANIZERS_API_GROUP: &str = "Organizers";
 
 /// Returns a router containing all routes for the "/api/organizers" endpoints
 pub fn organizers_routes() -> Router<Arc<SharedData>> {
-    Router::new().route(
-        "/groups",
-        get(async |State(app_data): AppState| {
-            let mut ext_cxn = app_data.ext_cxn.clone();
+    Router::new()
+        .route(
+            "/groups",
+            get(async |State(app_data): AppState| {
+                let organizer_svc = domain::organizer::OrganizerService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
 
-            list_organizer_groups(&mut ext_cxn).await
-        }),
-    )
+                list_organizer_groups(&organizer_svc, &mut ext_cxn).await
+            }),
+        )
+        .route(
+            "/groups/:group_id",
+            get(
+                async |State(app_data): AppState, Path(group_id): Path<u32>, viewer: Viewer| {
+                    let organizer_svc = domain::organizer::OrganizerService;
+                    let search_svc = domain::search::SearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    get_group(
+                        group_id,
+                        viewer,
+                        &app_data.contact_policy,
+                        &organizer_svc,
+                        &search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/groups/:group_id/contact-sharing",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(group_id): Path<u32>,
+                       Json(sharing_request): Json<dto::ContactSharingRequest>| {
+                    let organizer_svc = domain::organizer::OrganizerService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    set_group_contact_sharing(
+                        viewer,
+                        group_id,
+                        sharing_request,
+                        &organizer_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/groups/aliases",
+            get(async |State(app_data): AppState| {
+                let metadata_svc = domain::metadata::MetadataService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                list_group_aliases(&metadata_svc, &mut ext_cxn).await
+            }),
+        )
+        .route(
+            "/groups/:group_id/aliases/:alias",
+            put(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((group_id, alias)): Path<(u32, String)>| {
+                    let metadata_svc = domain::metadata::MetadataService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    add_group_alias(viewer, group_id, &alias, &metadata_svc, &mut ext_cxn).await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path((group_id, alias)): Path<(u32, String)>| {
+                    let metadata_svc = domain::metadata::MetadataService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    remove_group_alias(viewer, group_id, &alias, &metadata_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/groups/:group_id/merges",
+            post(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(group_id): Path<u32>,
+                       Json(merge_request): Json<dto::MergeRequest>| {
+                    let metadata_svc = domain::metadata::MetadataService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    merge_group(viewer, group_id, merge_request, &metadata_svc, &mut ext_cxn).await
+                },
+            ),
+        )
 }
 
 /// Generates a deterministic set of sample organizer groups for stubbed APIs and caches them
@@ -51,14 +153,318 @@ pub(super) fn sample_organizer_groups() -> &'static [dto::Group] {
     path = "/api/organizers/groups",
     tag = ORGANIZERS_API_GROUP,
     responses(
-        (status = 200, description = "Organizers successfully retrieved", body = [Group]),
+        (status = 200, description = "Organizers successfully retrieved", body = [GroupSummary]),
         (status = 500, response = dto::err_resps::BasicError500),
     )
 )]
-#[instrument(skip(_ext_cxn))]
-/// List known organizing groups for the current GenCon year
+#[instrument(skip_all)]
+/// List known organizing groups along with how many of the current GenCon year's events each runs
 async fn list_organizer_groups(
-    _ext_cxn: &mut impl ExternalConnectivity,
-) -> Result<Json<&'static [dto::Group]>, ErrorResponse> {
-    Ok(Json(sample_organizer_groups()))
+    organizer_port: &impl domain::organizer::driving_ports::OrganizerPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<Vec<dto::GroupSummary>>, ErrorResponse> {
+    let groups = organizer_port
+        .groups(&persistence::organizer::DbGroupReader, ext_cxn)
+        .await
+        .map_err(|group_err| {
+            error!("Failed to retrieve groups: {group_err}");
+            GenericErrorResponse(group_err)
+        })?;
+
+    info!(total_groups = groups.len(), "Returned groups.");
+    Ok(Json(groups.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizers/groups/{group_id}",
+    tag = ORGANIZERS_API_GROUP,
+    params(
+        ("group_id" = u32, Path, description = "The ID of the group to describe"),
+    ),
+    responses(
+        (status = 200, description = "Group successfully retrieved", body = GroupProfileResponse),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    )
+)]
+#[instrument(skip(contact_policy, organizer_port, search_port, ext_cxn))]
+/// Get an organizing group's website, contact, and game systems along with all of its events
+///
+/// Covers the current year's uncancelled events. The contact email is included if the group
+/// agreed to share it or the caller passes a member or admin access token. Otherwise it's left
+/// out, or replaced with a relay address when a contact relay is configured.
+async fn get_group(
+    group_id: u32,
+    viewer: Viewer,
+    contact_policy: &domain::contact::ContactPolicy,
+    organizer_port: &impl domain::organizer::driving_ports::OrganizerPort,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::GroupProfileResponse>, ErrorResponse> {
+    let profile = organizer_port
+        .group_profile(
+            group_id as i64,
+            viewer,
+            contact_policy,
+            &persistence::organizer::DbGroupReader,
+            &mut *ext_cxn,
+        )
+        .await
+        .map_err(organizer_error_response)?;
+
+    // Every event fits on one page since the page holds as many events as the group runs
+    let events = search_port
+        .search_events(
+            &domain::search::DateRange::default(),
+            &domain::search::EventFilter {
+                group_ids: Some(vec![profile.group.id]),
+                ..Default::default()
+            },
+            domain::search::EventSort::default(),
+            None,
+            &domain::search::PageSelection::Number(1),
+            profile.total_events.clamp(1, u16::MAX as u64) as u16,
+            &persistence::search::DbEventSearcher,
+            ext_cxn,
+        )
+        .await
+        .map_err(|search_err| {
+            error!("Failed to search group events: {search_err}");
+            GenericErrorResponse(search_err.into())
+        })?;
+
+    info!(total_events = events.events.len(), "Returned group.");
+    Ok(Json(dto::GroupProfileResponse {
+        id: profile.group.id as u32,
+        name: profile.group.name,
+        total_events: profile.total_events,
+        website: profile.website,
+        contact: profile.contact,
+        game_systems: profile.game_systems.into_iter().map(Into::into).collect(),
+        events_by_day: super::events::daily_blocks(events.events),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizers/groups/{group_id}/contact-sharing",
+    tag = ORGANIZERS_API_GROUP,
+    params(
+        ("group_id" = u32, Path, description = "The ID of the group"),
+    ),
+    request_body = ContactSharingRequest,
+    responses(
+        (status = 204, description = "The group's choice was saved"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(organizer_port, ext_cxn))]
+/// Choose whether an organizing group's contact email is shown on its profile
+///
+/// Requires an admin access token. Contacts are hidden until the group agrees to share them.
+async fn set_group_contact_sharing(
+    viewer: Viewer,
+    group_id: u32,
+    sharing_request: dto::ContactSharingRequest,
+    organizer_port: &impl domain::organizer::driving_ports::OrganizerPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change whether group contacts are shared")?;
+
+    organizer_port
+        .set_contact_sharing(
+            group_id as i64,
+            sharing_request.share_contact,
+            &persistence::organizer::DbGroupWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(organizer_error_response)?;
+
+    info!("Contact sharing saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Maps organizer errors to their HTTP responses
+fn organizer_error_response(organizer_err: domain::organizer::OrganizerError) -> ErrorResponse {
+    match organizer_err {
+        domain::organizer::OrganizerError::NotFound(group_id) => {
+            error!(group_id, "Group not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_group".to_owned(),
+                    error_description: "The requested group is not in the system.".to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::organizer::OrganizerError::PortError(port_err) => {
+            error!("Failed to access group: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizers/groups/aliases",
+    tag = ORGANIZERS_API_GROUP,
+    responses(
+        (status = 200, description = "Successfully retrieved aliases", body = Vec<MetadataAlias>),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all)]
+/// List the alternate names imports treat as existing organizing groups
+async fn list_group_aliases(
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<Vec<dto::MetadataAlias>>, ErrorResponse> {
+    let aliases = metadata_port
+        .metadata_aliases(&persistence::metadata::DbMetadataAliasReader, ext_cxn)
+        .await
+        .map_err(|alias_err| {
+            error!("Failed to retrieve group aliases: {alias_err}");
+            GenericErrorResponse(alias_err)
+        })?;
+
+    Ok(Json(dto::MetadataAlias::sorted(aliases.groups)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizers/groups/{group_id}/aliases/{alias}",
+    tag = ORGANIZERS_API_GROUP,
+    params(
+        ("group_id" = u32, Path, description = "The ID of the group the alias refers to"),
+        ("alias" = String, Path, description = "Another name for the group"),
+    ),
+    responses(
+        (status = 204, description = "Imports will treat the alias as the group"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(metadata_port, ext_cxn))]
+/// Add an alias for an organizing group
+///
+/// Requires an admin access token. Imports swap the alias for the group's name, ignoring case and
+/// extra whitespace.
+async fn add_group_alias(
+    viewer: Viewer,
+    group_id: u32,
+    alias: &str,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change group aliases and merges")?;
+
+    metadata_port
+        .set_alias(
+            MetadataKind::Group,
+            group_id as i64,
+            alias,
+            &persistence::metadata::DbMetadataAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(metadata_update_error_response)?;
+
+    info!("Alias saved.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizers/groups/{group_id}/aliases/{alias}",
+    tag = ORGANIZERS_API_GROUP,
+    params(
+        ("group_id" = u32, Path, description = "The ID of the group the alias refers to"),
+        ("alias" = String, Path, description = "The alias to remove"),
+    ),
+    responses(
+        (status = 204, description = "The alias was removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(metadata_port, ext_cxn))]
+/// Remove an alias of an organizing group
+///
+/// Requires an admin access token.
+async fn remove_group_alias(
+    viewer: Viewer,
+    group_id: u32,
+    alias: &str,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change group aliases and merges")?;
+
+    metadata_port
+        .remove_alias(
+            MetadataKind::Group,
+            group_id as i64,
+            alias,
+            &persistence::metadata::DbMetadataAliasWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(metadata_update_error_response)?;
+
+    info!("Alias removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizers/groups/{group_id}/merges",
+    tag = ORGANIZERS_API_GROUP,
+    params(
+        ("group_id" = u32, Path, description = "The ID of the group to keep"),
+    ),
+    request_body = MergeRequest,
+    responses(
+        (status = 204, description = "The duplicate was merged into the group"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(metadata_port, ext_cxn))]
+/// Merge a duplicate organizing group into this one
+///
+/// Requires an admin access token. The duplicate's events and aliases move to this group, and the
+/// duplicate's name becomes an alias so later imports land here.
+async fn merge_group(
+    viewer: Viewer,
+    group_id: u32,
+    merge_request: dto::MergeRequest,
+    metadata_port: &impl domain::metadata::driving_ports::MetadataPort,
+    ext_cxn: &mut impl TransactableExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "change group aliases and merges")?;
+
+    merge_records(
+        MetadataKind::Group,
+        group_id,
+        merge_request.duplicate_id,
+        metadata_port,
+        ext_cxn,
+    )
+    .await
 }
@@ -0,0 +1,90 @@
+use crate::external_connections::ExternalConnectivity;
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::State;
+use axum::response::ErrorResponse;
+use axum::routing::post;
+use std::sync::Arc;
+use tracing::*;
+use utoipa::OpenApi;
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(suggest_schedule,))]
+/// OpenAPI struct which registers schedule APIs with swagger
+pub struct SchedulesApi;
+
+/// Constant which defines the "schedules" group of API endpoints
+pub const SCHEDULES_API_GROUP: &str = "Schedules";
+
+/// Returns a router containing all routes for the "/api/schedules" set of endpoints
+pub fn schedules_routes() -> Router<Arc<SharedData>> {
+    Router::new().route(
+        "/suggestions",
+        post(
+            async |State(app_data): AppState,
+                   Json(suggestion_request): Json<dto::ScheduleSuggestionRequest>| {
+                let schedule_svc = domain::schedule::ScheduleService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                suggest_schedule(suggestion_request, &schedule_svc, &mut ext_cxn).await
+            },
+        ),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/schedules/suggestions",
+    tag = SCHEDULES_API_GROUP,
+    request_body = ScheduleSuggestionRequest,
+    responses(
+        (status = 200, description = "Schedule successfully built", body = ScheduleSuggestionResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all, fields(total_wishes = suggestion_request.wishes.len()))]
+/// Builds the most valuable schedule of non-overlapping events out of a ranked wish list,
+/// listing which wishes were dropped and why
+async fn suggest_schedule(
+    suggestion_request: dto::ScheduleSuggestionRequest,
+    schedule_port: &impl domain::schedule::driving_ports::SchedulePort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::ScheduleSuggestionResponse>, ErrorResponse> {
+    suggestion_request
+        .validate()
+        .map_err(ValidationErrorResponse)?;
+
+    let wishes: Vec<domain::schedule::Wish> = suggestion_request
+        .wishes
+        .iter()
+        .map(|wish| domain::schedule::Wish {
+            event_id: wish.event_id as i64,
+            weight: wish.weight,
+        })
+        .collect();
+    let constraints = domain::schedule::ScheduleConstraints::from(&suggestion_request);
+
+    let schedule = schedule_port
+        .suggest_schedule(
+            &wishes,
+            &constraints,
+            &persistence::schedule::DbCandidateReader,
+            &persistence::venue::DbVenueMapReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|schedule_err| {
+            error!("Failed to build a schedule suggestion: {schedule_err}");
+            GenericErrorResponse(schedule_err)
+        })?;
+
+    Ok(Json(dto::ScheduleSuggestionResponse {
+        scheduled_events: schedule.events.into_iter().map(Into::into).collect(),
+        dropped_wishes: schedule.dropped.into_iter().map(Into::into).collect(),
+        total_cost: schedule.total_cost,
+        total_value: schedule.total_value,
+    }))
+}
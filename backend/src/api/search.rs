@@ -0,0 +1,95 @@
+use crate::external_connections::ExternalConnectivity;
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::{Query, State};
+use axum::response::ErrorResponse;
+use axum::routing::get;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::*;
+use utoipa::{IntoParams, OpenApi};
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(suggest_search_text,))]
+/// OpenAPI struct which registers search APIs with swagger
+pub struct SearchApi;
+
+/// Constant which defines the "search" group of API endpoints
+pub const SEARCH_API_GROUP: &str = "Search";
+
+/// Number of suggestions returned when no limit is requested
+const DEFAULT_SUGGESTION_LIMIT: u16 = 10;
+
+#[derive(Deserialize, Validate, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for autocompleting search text
+pub struct SuggestQueryParams {
+    #[validate(length(max = 200))]
+    /// Partially typed search text. Text shorter than 2 characters returns no suggestions.
+    pub search_text: String,
+
+    #[validate(range(min = 1, max = 50))]
+    /// The maximum number of suggestions to return (default 10)
+    pub limit: Option<u16>,
+}
+
+/// Returns a router containing all routes for the "/api/search" set of endpoints
+pub fn search_routes() -> Router<Arc<SharedData>> {
+    Router::new().route(
+        "/suggest",
+        get(
+            async |State(app_data): AppState, Query(params): Query<SuggestQueryParams>| {
+                let search_svc = domain::search::SearchService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                suggest_search_text(&params, &search_svc, &mut ext_cxn).await
+            },
+        ),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/search/suggest",
+    tag = SEARCH_API_GROUP,
+    params(
+        SuggestQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Suggestions successfully retrieved", body = SearchSuggestionsResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(search_port, ext_cxn))]
+/// Autocomplete search text with matching event titles, game systems, groups, game masters, and
+/// locations, tolerating typos. Each suggestion says what it refers to so it can be turned into a
+/// filter.
+async fn suggest_search_text(
+    params: &SuggestQueryParams,
+    search_port: &impl domain::search::driving_ports::SearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::SearchSuggestionsResponse>, ErrorResponse> {
+    params.validate().map_err(ValidationErrorResponse)?;
+
+    let suggestions = search_port
+        .suggest(
+            &params.search_text,
+            params.limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT),
+            &persistence::search::DbSuggestionReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|suggest_err| {
+            error!("Failed to suggest search text: {suggest_err}");
+            GenericErrorResponse(suggest_err)
+        })?;
+
+    info!(total = suggestions.len(), "Suggested search text.");
+    Ok(Json(dto::SearchSuggestionsResponse {
+        suggestions: suggestions.into_iter().map(Into::into).collect(),
+    }))
+}
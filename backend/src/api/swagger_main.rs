@@ -15,10 +15,19 @@ struct TodoApi;
 pub fn build_documentation() -> SwaggerUi {
     let mut api_docs = TodoApi::openapi();
     api_docs.merge(dto::OpenApiSchemas::openapi());
+    api_docs.merge(super::contacts::ContactsApi::openapi());
     api_docs.merge(super::days::DaysApi::openapi());
     api_docs.merge(super::events::EventsApi::openapi());
+    api_docs.merge(super::event_stream::EventStreamApi::openapi());
+    api_docs.merge(super::game_masters::GameMastersApi::openapi());
+    api_docs.merge(super::game_systems::GameSystemsApi::openapi());
+    api_docs.merge(super::locations::LocationsApi::openapi());
     api_docs.merge(super::organizers::OrganizersApi::openapi());
     api_docs.merge(super::event_import::EventImportApi::openapi());
+    api_docs.merge(super::schedules::SchedulesApi::openapi());
+    api_docs.merge(super::search::SearchApi::openapi());
+    api_docs.merge(super::users::UsersApi::openapi());
+    api_docs.merge(super::webhooks::WebhooksApi::openapi());
 
     SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api_docs)
 }
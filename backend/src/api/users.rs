@@ -0,0 +1,578 @@
+use crate::api::access::SignedInUser;
+use crate::external_connections::ExternalConnectivity;
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, persistence};
+use axum::Router;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::http::Uri;
+use axum::response::ErrorResponse;
+use axum::routing::{delete, get, put};
+use std::borrow::Cow;
+use std::sync::Arc;
+use tracing::*;
+use utoipa::OpenApi;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_watched_events,
+    watch_event,
+    unwatch_event,
+    list_saved_searches,
+    save_search,
+    delete_saved_search,
+    check_new_matches,
+))]
+/// OpenAPI struct which registers user APIs with swagger
+pub struct UsersApi;
+
+/// Constant which defines the "users" group of API endpoints
+pub const USERS_API_GROUP: &str = "Users";
+
+/// Longest user ID accepted by the user endpoints
+const MAX_USER_ID_LEN: usize = 128;
+
+/// Returns a router containing all routes for the "/api/users" set of endpoints
+pub fn users_routes() -> Router<Arc<SharedData>> {
+    Router::new()
+        .route(
+            "/:user_id/watchlist",
+            get(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path(user_id): Path<String>| {
+                    let watchlist_svc = domain::watchlist::WatchlistService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_watched_events(caller, &user_id, &watchlist_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/:user_id/watchlist/:event_id",
+            put(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path((user_id, event_id)): Path<(String, u32)>| {
+                    let watchlist_svc = domain::watchlist::WatchlistService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    watch_event(caller, &user_id, event_id, &watchlist_svc, &mut ext_cxn).await
+                },
+            )
+            .delete(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path((user_id, event_id)): Path<(String, u32)>| {
+                    let watchlist_svc = domain::watchlist::WatchlistService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    unwatch_event(caller, &user_id, event_id, &watchlist_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/:user_id/saved-searches",
+            get(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path(user_id): Path<String>| {
+                    let saved_search_svc = domain::saved_search::SavedSearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_saved_searches(caller, &user_id, &saved_search_svc, &mut ext_cxn).await
+                },
+            )
+            .post(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path(user_id): Path<String>,
+                       Json(search_request): Json<dto::SavedSearchRequest>| {
+                    let saved_search_svc = domain::saved_search::SavedSearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    save_search(
+                        caller,
+                        &user_id,
+                        search_request,
+                        &saved_search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:user_id/saved-searches/:search_id",
+            delete(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path((user_id, search_id)): Path<(String, i64)>| {
+                    let saved_search_svc = domain::saved_search::SavedSearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    delete_saved_search(
+                        caller,
+                        &user_id,
+                        search_id,
+                        &saved_search_svc,
+                        &mut ext_cxn,
+                    )
+                    .await
+                },
+            ),
+        )
+        .route(
+            "/:user_id/saved-searches/:search_id/new-matches",
+            get(
+                async |State(app_data): AppState,
+                       caller: SignedInUser,
+                       Path((user_id, search_id)): Path<(String, i64)>| {
+                    let saved_search_svc = domain::saved_search::SavedSearchService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    check_new_matches(caller, &user_id, search_id, &saved_search_svc, &mut ext_cxn)
+                        .await
+                },
+            ),
+        )
+}
+
+/// Rejects user IDs which are empty or too long to store
+fn validate_user_id(user_id: &str) -> Result<(), ValidationErrorResponse> {
+    if user_id.is_empty() || user_id.len() > MAX_USER_ID_LEN {
+        let mut validation_errors = ValidationErrors::new();
+        validation_errors.add(
+            "userId",
+            ValidationError::new("length").with_message(Cow::Owned(format!(
+                "User IDs must be between 1 and {MAX_USER_ID_LEN} characters long."
+            ))),
+        );
+        return Err(ValidationErrorResponse(validation_errors));
+    }
+
+    Ok(())
+}
+
+/// Converts watchlist errors into API error responses
+fn watchlist_error_response(watchlist_err: domain::watchlist::WatchlistError) -> ErrorResponse {
+    match watchlist_err {
+        domain::watchlist::WatchlistError::EventNotFound(event_id) => {
+            error!(event_id, "Watched event not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_event".to_owned(),
+                    error_description:
+                        "The requested event is not in the system or not on the watchlist."
+                            .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::watchlist::WatchlistError::PortError(port_err) => {
+            error!("Watchlist update failed: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/{user_id}/watchlist",
+    tag = USERS_API_GROUP,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user whose watchlist should be retrieved"),
+    ),
+    responses(
+        (status = 200, description = "Watchlist successfully retrieved", body = WatchlistResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(watchlist_port, ext_cxn))]
+/// List the events a user is watching for ticket, time, location, and cancellation changes
+///
+/// Requires a user token issued to the user.
+async fn list_watched_events(
+    caller: SignedInUser,
+    user_id: &str,
+    watchlist_port: &impl domain::watchlist::driving_ports::WatchlistPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::WatchlistResponse>, ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+
+    let watched_events = watchlist_port
+        .watched_events(
+            user_id,
+            &persistence::watchlist::DbWatchlistReader,
+            &persistence::watchlist::DbEventSnapshotReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|watchlist_err| {
+            error!("Failed to retrieve watchlist: {watchlist_err}");
+            GenericErrorResponse(watchlist_err)
+        })?;
+
+    info!(total_events = watched_events.len(), "Retrieved watchlist.");
+    Ok(Json(dto::WatchlistResponse {
+        user_id: user_id.to_owned(),
+        events: watched_events.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/users/{user_id}/watchlist/{event_id}",
+    tag = USERS_API_GROUP,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user watching the event"),
+        ("event_id" = u32, Path, description = "The ID of the event to watch"),
+    ),
+    responses(
+        (status = 204, description = "The event is on the user's watchlist"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(watchlist_port, ext_cxn))]
+/// Add an event to a user's watchlist
+///
+/// Requires a user token issued to the user.
+async fn watch_event(
+    caller: SignedInUser,
+    user_id: &str,
+    event_id: u32,
+    watchlist_port: &impl domain::watchlist::driving_ports::WatchlistPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+
+    watchlist_port
+        .watch_event(
+            user_id,
+            event_id as i64,
+            &persistence::watchlist::DbWatchlistWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(watchlist_error_response)?;
+
+    info!("Event added to watchlist.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/{user_id}/watchlist/{event_id}",
+    tag = USERS_API_GROUP,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user watching the event"),
+        ("event_id" = u32, Path, description = "The ID of the event to stop watching"),
+    ),
+    responses(
+        (status = 204, description = "The event was removed from the user's watchlist"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(watchlist_port, ext_cxn))]
+/// Remove an event from a user's watchlist
+///
+/// Requires a user token issued to the user.
+async fn unwatch_event(
+    caller: SignedInUser,
+    user_id: &str,
+    event_id: u32,
+    watchlist_port: &impl domain::watchlist::driving_ports::WatchlistPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+
+    watchlist_port
+        .unwatch_event(
+            user_id,
+            event_id as i64,
+            &persistence::watchlist::DbWatchlistWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(watchlist_error_response)?;
+
+    info!("Event removed from watchlist.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Converts saved search errors into API error responses
+fn saved_search_error_response(
+    saved_search_err: domain::saved_search::SavedSearchError,
+) -> ErrorResponse {
+    match saved_search_err {
+        domain::saved_search::SavedSearchError::NotFound(search_id) => {
+            error!(search_id, "Saved search not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_saved_search".to_owned(),
+                    error_description: "The user has no saved search with the requested ID."
+                        .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::saved_search::SavedSearchError::TooManySearches => {
+            error!("User has too many saved searches.");
+            (
+                StatusCode::BAD_REQUEST,
+                Json(dto::BasicError {
+                    error_code: "too_many_saved_searches".to_owned(),
+                    error_description: format!(
+                        "Users can save at most {} searches. Delete one before saving another.",
+                        domain::saved_search::MAX_SAVED_SEARCHES_PER_USER
+                    ),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::saved_search::SavedSearchError::PortError(port_err) => {
+            error!("Saved search request failed: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
+
+/// Parses an event list query string, with or without its leading "?". Returns None if the
+/// query string isn't valid.
+fn parse_search_query(query: &str) -> Option<super::events::EventListQueryParams> {
+    let uri: Uri = format!("/?{}", query.trim_start_matches('?'))
+        .parse()
+        .ok()?;
+    let Query(params) = Query::try_from_uri(&uri).ok()?;
+
+    Some(params)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/{user_id}/saved-searches",
+    tag = USERS_API_GROUP,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user whose saved searches should be retrieved"),
+    ),
+    responses(
+        (status = 200, description = "Saved searches successfully retrieved", body = SavedSearchesResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(saved_search_port, ext_cxn))]
+/// List the event searches a user has saved
+///
+/// Requires a user token issued to the user.
+async fn list_saved_searches(
+    caller: SignedInUser,
+    user_id: &str,
+    saved_search_port: &impl domain::saved_search::driving_ports::SavedSearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::SavedSearchesResponse>, ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+
+    let searches = saved_search_port
+        .saved_searches(
+            user_id,
+            &persistence::saved_search::DbSavedSearchReader,
+            ext_cxn,
+        )
+        .await
+        .map_err(|list_err| {
+            error!("Failed to retrieve saved searches: {list_err}");
+            GenericErrorResponse(list_err)
+        })?;
+
+    info!(total_searches = searches.len(), "Retrieved saved searches.");
+    Ok(Json(dto::SavedSearchesResponse {
+        user_id: user_id.to_owned(),
+        searches: searches.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/{user_id}/saved-searches",
+    tag = USERS_API_GROUP,
+    request_body = SavedSearchRequest,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user saving the search"),
+    ),
+    responses(
+        (status = 201, description = "Search saved. Events already matching it won't be reported as new matches.", body = SavedSearch),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(search_request, saved_search_port, ext_cxn), fields(search_name = search_request.name))]
+/// Save event list filters so the user can check for events newly matching them
+///
+/// Requires a user token issued to the user.
+async fn save_search(
+    caller: SignedInUser,
+    user_id: &str,
+    search_request: dto::SavedSearchRequest,
+    saved_search_port: &impl domain::saved_search::driving_ports::SavedSearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<(StatusCode, Json<dto::SavedSearch>), ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+    search_request.validate().map_err(ValidationErrorResponse)?;
+    let Some(query_params) = parse_search_query(&search_request.query) else {
+        error!("Saved search query could not be parsed.");
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(dto::BasicError {
+                error_code: "invalid_search_query".to_owned(),
+                error_description: "The query is not a valid event list query string.".to_owned(),
+                extra_info: None,
+            }),
+        )
+            .into());
+    };
+    query_params.validate().map_err(ValidationErrorResponse)?;
+
+    let new_search = domain::saved_search::NewSavedSearch {
+        name: search_request.name,
+        query: search_request.query.trim_start_matches('?').to_owned(),
+        filter: (&query_params).into(),
+    };
+    let saved_search = saved_search_port
+        .save_search(
+            user_id,
+            &new_search,
+            &persistence::saved_search::DbSavedSearchReader,
+            &persistence::saved_search::DbSavedSearchWriter,
+            &persistence::saved_search::DbSearchMatchRecorder,
+            ext_cxn,
+        )
+        .await
+        .map_err(saved_search_error_response)?;
+
+    info!(search_id = saved_search.id, "Search saved.");
+    Ok((StatusCode::CREATED, Json(saved_search.into())))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/{user_id}/saved-searches/{search_id}",
+    tag = USERS_API_GROUP,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user who saved the search"),
+        ("search_id" = i64, Path, description = "The ID of the saved search to delete"),
+    ),
+    responses(
+        (status = 204, description = "The saved search was deleted"),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(saved_search_port, ext_cxn))]
+/// Delete one of a user's saved searches
+///
+/// Requires a user token issued to the user.
+async fn delete_saved_search(
+    caller: SignedInUser,
+    user_id: &str,
+    search_id: i64,
+    saved_search_port: &impl domain::saved_search::driving_ports::SavedSearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+
+    saved_search_port
+        .delete_saved_search(
+            user_id,
+            search_id,
+            &persistence::saved_search::DbSavedSearchWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(saved_search_error_response)?;
+
+    info!("Saved search deleted.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/{user_id}/saved-searches/{search_id}/new-matches",
+    tag = USERS_API_GROUP,
+    params(
+        ("user_id" = String, Path, description = "The ID of the user who saved the search"),
+        ("search_id" = i64, Path, description = "The ID of the saved search to check"),
+    ),
+    responses(
+        (status = 200, description = "Events newly matching the search. Returned matches won't be returned again.", body = SavedSearchMatchesResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(saved_search_port, ext_cxn))]
+/// List the events imports found matching a saved search since the user last checked it
+///
+/// Requires a user token issued to the user.
+async fn check_new_matches(
+    caller: SignedInUser,
+    user_id: &str,
+    search_id: i64,
+    saved_search_port: &impl domain::saved_search::driving_ports::SavedSearchPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::SavedSearchMatchesResponse>, ErrorResponse> {
+    caller.require_user(user_id)?;
+    validate_user_id(user_id)?;
+
+    let (saved_search, new_matches) = saved_search_port
+        .check_new_matches(
+            user_id,
+            search_id,
+            &persistence::saved_search::DbSavedSearchReader,
+            &persistence::saved_search::DbSavedSearchWriter,
+            ext_cxn,
+        )
+        .await
+        .map_err(saved_search_error_response)?;
+
+    info!(
+        total_matches = new_matches.len(),
+        "Checked saved search for new matches."
+    );
+    Ok(Json(dto::SavedSearchMatchesResponse {
+        search: saved_search.into(),
+        new_matches: new_matches.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,350 @@
+use crate::api::access::require_admin;
+use crate::domain::access::Viewer;
+use crate::domain::webhook::driving_ports::WebhookPort;
+use crate::external_connections::ExternalConnectivity;
+use crate::routing_utils::{GenericErrorResponse, Json, ValidationErrorResponse};
+use crate::{AppState, SharedData, domain, dto, outbound, persistence};
+use axum::Router;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::ErrorResponse;
+use axum::routing::{delete, get};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use tracing::*;
+use utoipa::{IntoParams, OpenApi};
+use validator::Validate;
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_subscription,
+    list_subscriptions,
+    delete_subscription,
+    list_deliveries,
+))]
+/// OpenAPI struct which registers webhook APIs with swagger
+pub struct WebhooksApi;
+
+/// Constant which defines the "webhooks" group of API endpoints
+pub const WEBHOOKS_API_GROUP: &str = "Webhooks";
+
+/// Number of deliveries returned when no limit is requested
+const DEFAULT_DELIVERY_LIMIT: u16 = 50;
+
+/// How often the delivery worker checks the log for deliveries which are due
+const DELIVERY_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Deserialize, Validate, IntoParams, Debug)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "kebab-case")]
+/// Query parameters for listing the deliveries made to a webhook subscription
+pub struct DeliveryQueryParams {
+    #[validate(range(min = 1, max = 500))]
+    /// The maximum number of deliveries to return, newest first (default 50)
+    pub limit: Option<u16>,
+}
+
+/// Returns a router containing all routes for the "/api/webhooks" set of endpoints
+pub fn webhooks_routes() -> Router<Arc<SharedData>> {
+    Router::new()
+        .route(
+            "/",
+            get(async |State(app_data): AppState, viewer: Viewer| {
+                let webhook_svc = domain::webhook::WebhookService;
+                let mut ext_cxn = app_data.ext_cxn.clone();
+
+                list_subscriptions(viewer, &webhook_svc, &mut ext_cxn).await
+            })
+            .post(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Json(subscription_request): Json<dto::WebhookSubscriptionRequest>| {
+                    let webhook_svc = domain::webhook::WebhookService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    create_subscription(viewer, subscription_request, &webhook_svc, &mut ext_cxn)
+                        .await
+                },
+            ),
+        )
+        .route(
+            "/:subscription_id",
+            delete(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(subscription_id): Path<u32>| {
+                    let webhook_svc = domain::webhook::WebhookService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    delete_subscription(viewer, subscription_id, &webhook_svc, &mut ext_cxn).await
+                },
+            ),
+        )
+        .route(
+            "/:subscription_id/deliveries",
+            get(
+                async |State(app_data): AppState,
+                       viewer: Viewer,
+                       Path(subscription_id): Path<u32>,
+                       Query(params): Query<DeliveryQueryParams>| {
+                    let webhook_svc = domain::webhook::WebhookService;
+                    let mut ext_cxn = app_data.ext_cxn.clone();
+
+                    list_deliveries(viewer, subscription_id, &params, &webhook_svc, &mut ext_cxn)
+                        .await
+                },
+            ),
+        )
+}
+
+/// Starts a background task which sends logged webhook deliveries as they come due. Every server
+/// runs one, and deliveries are claimed in the database so only one server sends each attempt.
+pub fn start_delivery_worker(
+    ext_cxn: persistence::ExternalConnectivity,
+    webhook_deliverer: outbound::webhook::HttpWebhookDeliverer,
+) {
+    tokio::spawn(send_due_deliveries(ext_cxn, webhook_deliverer));
+}
+
+/// Checks the delivery log for due deliveries for as long as the application runs, sending
+/// batches until none are left each time
+async fn send_due_deliveries(
+    mut ext_cxn: persistence::ExternalConnectivity,
+    webhook_deliverer: outbound::webhook::HttpWebhookDeliverer,
+) {
+    let retry_policy = domain::webhook::RetryPolicy::default();
+    let mut poll_timer = tokio::time::interval(DELIVERY_POLL_INTERVAL);
+    poll_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        poll_timer.tick().await;
+        loop {
+            let deliver_result = domain::webhook::WebhookService
+                .deliver_due(
+                    &retry_policy,
+                    &webhook_deliverer,
+                    &persistence::webhook::DbDeliveryLog,
+                    &mut ext_cxn,
+                )
+                .await;
+            match deliver_result {
+                Ok(0) => break,
+                Ok(total_attempted) => debug!(total_attempted, "Attempted webhook deliveries."),
+                Err(deliver_err) => {
+                    error!("Could not send due webhook deliveries: {deliver_err:#}");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Converts webhook errors into API error responses
+fn webhook_error_response(webhook_err: domain::webhook::WebhookError) -> ErrorResponse {
+    match webhook_err {
+        domain::webhook::WebhookError::SubscriptionNotFound(subscription_id) => {
+            error!(subscription_id, "Webhook subscription not found.");
+            (
+                StatusCode::NOT_FOUND,
+                Json(dto::BasicError {
+                    error_code: "no_matching_subscription".to_owned(),
+                    error_description:
+                        "There is no webhook subscription in the system with the given ID."
+                            .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::webhook::WebhookError::NonPublicTarget(target_url) => {
+            error!(target_url, "Webhook target is not publicly reachable.");
+            (
+                StatusCode::BAD_REQUEST,
+                Json(dto::BasicError {
+                    error_code: "non_public_target".to_owned(),
+                    error_description: "Webhook targets can't be on loopback, private, or \
+                        link-local addresses."
+                        .to_owned(),
+                    extra_info: None,
+                }),
+            )
+                .into()
+        }
+        domain::webhook::WebhookError::PortError(port_err) => {
+            error!("Webhook request failed: {port_err}");
+            GenericErrorResponse(port_err).into()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = WEBHOOKS_API_GROUP,
+    request_body = WebhookSubscriptionRequest,
+    responses(
+        (status = 201, description = "Webhook subscription created", body = WebhookSubscription),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all, fields(target_url = subscription_request.target_url))]
+/// Register a URL to receive signed webhook calls when imports complete or events change
+///
+/// Requires an admin access token. Targets on loopback, private, or link-local addresses are
+/// refused.
+async fn create_subscription(
+    viewer: Viewer,
+    subscription_request: dto::WebhookSubscriptionRequest,
+    webhook_port: &impl domain::webhook::driving_ports::WebhookPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<(StatusCode, Json<dto::WebhookSubscription>), ErrorResponse> {
+    require_admin(viewer, "manage webhook subscriptions")?;
+
+    subscription_request
+        .validate()
+        .map_err(ValidationErrorResponse)?;
+
+    let subscription = webhook_port
+        .create_subscription(
+            &subscription_request.into(),
+            &persistence::webhook::DbSubscriptionStore,
+            ext_cxn,
+        )
+        .await
+        .map_err(webhook_error_response)?;
+
+    info!(
+        subscription_id = subscription.id,
+        "Webhook subscription created."
+    );
+    Ok((StatusCode::CREATED, Json(subscription.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    tag = WEBHOOKS_API_GROUP,
+    responses(
+        (status = 200, description = "Webhook subscriptions successfully retrieved", body = WebhookSubscriptionsResponse),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip_all)]
+/// List all registered webhook subscriptions
+///
+/// Requires an admin access token.
+async fn list_subscriptions(
+    viewer: Viewer,
+    webhook_port: &impl domain::webhook::driving_ports::WebhookPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::WebhookSubscriptionsResponse>, ErrorResponse> {
+    require_admin(viewer, "manage webhook subscriptions")?;
+
+    let subscriptions = webhook_port
+        .list_subscriptions(&persistence::webhook::DbSubscriptionStore, ext_cxn)
+        .await
+        .map_err(|list_err| {
+            error!("Failed to list webhook subscriptions: {list_err}");
+            GenericErrorResponse(list_err)
+        })?;
+
+    Ok(Json(dto::WebhookSubscriptionsResponse {
+        subscriptions: subscriptions.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{subscription_id}",
+    tag = WEBHOOKS_API_GROUP,
+    params(
+        ("subscription_id" = u32, Path, description = "The ID of the webhook subscription to remove"),
+    ),
+    responses(
+        (status = 204, description = "The webhook subscription and its delivery log were removed"),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(webhook_port, ext_cxn))]
+/// Remove a webhook subscription
+///
+/// Requires an admin access token.
+async fn delete_subscription(
+    viewer: Viewer,
+    subscription_id: u32,
+    webhook_port: &impl domain::webhook::driving_ports::WebhookPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<StatusCode, ErrorResponse> {
+    require_admin(viewer, "manage webhook subscriptions")?;
+
+    webhook_port
+        .delete_subscription(
+            subscription_id as i64,
+            &persistence::webhook::DbSubscriptionStore,
+            ext_cxn,
+        )
+        .await
+        .map_err(webhook_error_response)?;
+
+    info!("Webhook subscription removed.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{subscription_id}/deliveries",
+    tag = WEBHOOKS_API_GROUP,
+    params(
+        ("subscription_id" = u32, Path, description = "The ID of the webhook subscription"),
+        DeliveryQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Delivery log successfully retrieved", body = WebhookDeliveriesResponse),
+        (status = 400, response = dto::err_resps::BasicError400Validation),
+        (status = 401, response = dto::err_resps::BasicError401),
+        (status = 403, response = dto::err_resps::BasicError403),
+        (status = 404, response = dto::err_resps::BasicError404),
+        (status = 500, response = dto::err_resps::BasicError500),
+    ),
+)]
+#[instrument(skip(webhook_port, ext_cxn))]
+/// List the most recent deliveries made to a webhook subscription, including retry counts and
+/// the outcome of the latest attempt
+///
+/// Requires an admin access token.
+async fn list_deliveries(
+    viewer: Viewer,
+    subscription_id: u32,
+    params: &DeliveryQueryParams,
+    webhook_port: &impl domain::webhook::driving_ports::WebhookPort,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<Json<dto::WebhookDeliveriesResponse>, ErrorResponse> {
+    require_admin(viewer, "manage webhook subscriptions")?;
+
+    params.validate().map_err(ValidationErrorResponse)?;
+
+    let deliveries = webhook_port
+        .list_deliveries(
+            subscription_id as i64,
+            params.limit.unwrap_or(DEFAULT_DELIVERY_LIMIT),
+            &persistence::webhook::DbSubscriptionStore,
+            &persistence::webhook::DbDeliveryLog,
+            ext_cxn,
+        )
+        .await
+        .map_err(webhook_error_response)?;
+
+    Ok(Json(dto::WebhookDeliveriesResponse {
+        deliveries: deliveries.into_iter().map(Into::into).collect(),
+    }))
+}
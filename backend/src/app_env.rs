@@ -9,6 +9,22 @@ pub const OTEL_SPAN_EXPORT_URL: &str = "OTEL_SPAN_EXPORT_URL";
 /// OpenTelemetry metrics export URL. Should be http://localhost:4317 by default, as the service should
 /// have an OpenTelemetry collector sidecar which directs metrics to the correct place
 pub const OTEL_METRIC_EXPORT_URL: &str = "OTEL_METRIC_EXPORT_URL";
+/// URL which watchlist notifications are POSTed to after imports. If unset, notifications are only
+/// written to the application log.
+pub const WATCHLIST_WEBHOOK_URL: &str = "WATCHLIST_WEBHOOK_URL";
+/// Comma separated bearer tokens identifying signed-in members, who may see organizer contact
+/// emails
+pub const MEMBER_ACCESS_TOKENS: &str = "MEMBER_ACCESS_TOKENS";
+/// Comma separated bearer tokens identifying admins, who may manage webhook subscriptions and
+/// other application data
+pub const ADMIN_ACCESS_TOKENS: &str = "ADMIN_ACCESS_TOKENS";
+/// Secret shared with the sign-in service, which signs the user tokens it issues with it. User
+/// tokens are needed to manage watchlists and saved searches, so if this is unset, no one can use
+/// them.
+pub const USER_TOKEN_SECRET: &str = "USER_TOKEN_SECRET";
+/// Email domain of a relay which forwards messages to organizers. If set, anonymous callers see
+/// relay addresses in place of organizer contact emails. Otherwise contacts are hidden from them.
+pub const CONTACT_RELAY_DOMAIN: &str = "CONTACT_RELAY_DOMAIN";
 
 #[cfg(test)]
 pub mod test {
@@ -1,11 +1,25 @@
+pub mod access;
+pub mod accessibility;
+pub mod contact;
 pub mod event;
+pub mod event_type;
 pub mod game_master;
+pub mod game_system;
+pub mod live_update;
 pub mod location;
 pub mod metadata;
+pub mod organizer;
+pub mod saved_search;
+pub mod schedule;
+pub mod search;
+pub mod session;
 #[cfg(test)]
 mod test_util;
 pub mod tournament;
 pub mod unique;
+pub mod venue;
+pub mod watchlist;
+pub mod webhook;
 
 /// Alias for the result of a "bulk read" operation
 pub type BulkLookupResult<T, E> = Result<Vec<Option<T>>, E>;
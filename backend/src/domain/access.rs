@@ -0,0 +1,84 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Who is making a request, from least to most trusted
+pub enum Viewer {
+    /// Callers who didn't present an access token
+    Anonymous,
+    /// Callers with a member access token or a user token, who may see organizer contact emails
+    Member,
+    /// Callers with an admin access token, who may manage the application's data
+    Admin,
+}
+
+/// Verifies user tokens, which the sign-in service issues as `<user ID>.<signature>`. The
+/// signature is the hex encoded HMAC-SHA256 of the user ID, keyed with a secret shared with this
+/// server, so a user token can't be forged or changed to name a different user.
+pub struct UserTokenVerifier {
+    secret: Vec<u8>,
+}
+
+impl UserTokenVerifier {
+    /// Creates a verifier for tokens signed with the passed secret
+    pub fn new(secret: &str) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the ID of the user a token was issued to, or None if the token wasn't signed with
+    /// the shared secret
+    pub fn user_id_for<'token>(&self, token: &'token str) -> Option<&'token str> {
+        let (user_id, signature) = token.rsplit_once('.')?;
+        if user_id.is_empty() {
+            return None;
+        }
+        let signature = hex::decode(signature).ok()?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .expect("HMAC should accept keys of any length");
+        mac.update(user_id.as_bytes());
+        // Compared in constant time so failed attempts don't reveal how much of a signature matched
+        mac.verify_slice(&signature).ok().map(|_| user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod user_id_for {
+        use super::*;
+
+        /// Signs a user ID the same way the sign-in service does
+        fn issue_token(secret: &str, user_id: &str) -> String {
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+            mac.update(user_id.as_bytes());
+            format!("{user_id}.{}", hex::encode(mac.finalize().into_bytes()))
+        }
+
+        #[test]
+        fn accepts_tokens_signed_with_the_secret() {
+            let verifier = UserTokenVerifier::new("shared-secret");
+            let token = issue_token("shared-secret", "user.with.dots");
+
+            assert_eq!(Some("user.with.dots"), verifier.user_id_for(&token));
+        }
+
+        #[test]
+        fn refuses_forged_or_altered_tokens() {
+            let verifier = UserTokenVerifier::new("shared-secret");
+            let other_secret_token = issue_token("other-secret", "alice");
+            let renamed_token = issue_token("shared-secret", "alice").replacen("alice", "bob", 1);
+            let unsigned_token = "alice";
+            let blank_user_token = issue_token("shared-secret", "");
+
+            assert_eq!(None, verifier.user_id_for(&other_secret_token));
+            assert_eq!(None, verifier.user_id_for(&renamed_token));
+            assert_eq!(None, verifier.user_id_for(unsigned_token));
+            assert_eq!(None, verifier.user_id_for(&blank_user_token));
+            assert_eq!(None, verifier.user_id_for("alice.not-hex"));
+        }
+    }
+}
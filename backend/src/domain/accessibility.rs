@@ -0,0 +1,425 @@
+use crate::domain::location::{Ref, RefType};
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use derive_more::{Display, Error};
+use driven_ports::AccessibilityWriter;
+
+/// Content tag marking events run in a quiet, low-sensory format, even outside low-sensory rooms
+pub const LOW_SENSORY_TAG: &str = "low-sensory";
+
+/// Longest content tag which can be stored
+const MAX_CONTENT_TAG_LENGTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Accessibility of a room or room section. Unset attributes haven't been checked, and unset
+/// attributes of a section fall back to those of its room.
+pub struct Accessibility {
+    pub wheelchair_accessible: Option<bool>,
+    /// Whether the space is quiet and low-sensory
+    pub low_sensory: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Content tags read from an imported list of tags
+pub struct ParsedContentTags {
+    /// Normalized tags in the order they were listed, without duplicates
+    pub tags: Vec<String>,
+    /// Listed values which couldn't be made into tags
+    pub unparseable: Vec<String>,
+}
+
+/// Normalizes a single content tag, so "Graphic Violence" and "graphic_violence" both become
+/// "graphic-violence". Returns None if the value has no letters or digits or is too long to store.
+pub fn normalize_content_tag(raw_tag: &str) -> Option<String> {
+    let tag = raw_tag
+        .to_lowercase()
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+
+    if tag.is_empty() || tag.chars().count() > MAX_CONTENT_TAG_LENGTH {
+        return None;
+    }
+    Some(tag)
+}
+
+/// Splits a comma or semicolon separated list of content tags into normalized tags. Blank entries
+/// are skipped, and entries which can't be made into tags are set aside.
+pub fn parse_content_tags(raw_tags: &str) -> ParsedContentTags {
+    let mut parsed = ParsedContentTags::default();
+    for entry in raw_tags
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        match normalize_content_tag(entry) {
+            Some(tag) if parsed.tags.contains(&tag) => {}
+            Some(tag) => parsed.tags.push(tag),
+            None => parsed.unparseable.push(entry.to_owned()),
+        }
+    }
+
+    parsed
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while maintaining accessibility and content metadata
+pub enum AccessibilityError {
+    #[display("{} with ID {} does not exist", _0.ref_type, _0.id)]
+    NotFound(#[error(not(source))] Ref),
+    #[display("Event with ID {} does not exist", _0)]
+    EventNotFound(#[error(not(source))] i64),
+    #[display("\"{}\" can't be used as a content tag", _0)]
+    InvalidTag(#[error(not(source))] String),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Records the accessibility of rooms and sections along with event content tags
+    pub trait AccessibilityWriter: Sync {
+        /// Replaces the accessibility of a room. Returns false if the room doesn't exist.
+        async fn set_room_accessibility(
+            &self,
+            room_id: i32,
+            accessibility: &Accessibility,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Replaces the accessibility of a room section. Returns false if the section doesn't
+        /// exist.
+        async fn set_section_accessibility(
+            &self,
+            section_id: i32,
+            accessibility: &Accessibility,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Replaces the content tags of an event. Returns false if the event doesn't exist.
+        async fn set_content_tags(
+            &self,
+            event_id: i64,
+            tags: &[String],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for maintaining the accessibility of rooms and the content tags of events
+    pub trait AccessibilityPort: Sync {
+        /// Replaces the accessibility of a room or section
+        async fn set_accessibility(
+            &self,
+            place: &Ref,
+            accessibility: &Accessibility,
+            writer: &impl AccessibilityWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), AccessibilityError>;
+
+        /// Replaces the content tags of an event with the normalized form of the passed tags,
+        /// returning the tags which were saved
+        async fn set_content_tags(
+            &self,
+            event_id: i64,
+            tags: &[String],
+            writer: &impl AccessibilityWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<String>, AccessibilityError>;
+    }
+}
+
+/// Service implementation of the AccessibilityPort
+pub struct AccessibilityService;
+
+impl driving_ports::AccessibilityPort for AccessibilityService {
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn set_accessibility(
+        &self,
+        place: &Ref,
+        accessibility: &Accessibility,
+        writer: &impl AccessibilityWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), AccessibilityError> {
+        let place_exists = match place.ref_type {
+            // Accessibility is recorded for the rooms of a building rather than the building
+            RefType::Location => Ok(false),
+            RefType::Room => writer
+                .set_room_accessibility(place.id, accessibility, ext_cxn)
+                .await
+                .context("Saving room accessibility"),
+            RefType::Section => writer
+                .set_section_accessibility(place.id, accessibility, ext_cxn)
+                .await
+                .context("Saving section accessibility"),
+        }
+        .map_err(AccessibilityError::PortError)?;
+        if !place_exists {
+            return Err(AccessibilityError::NotFound(place.clone()));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn set_content_tags(
+        &self,
+        event_id: i64,
+        tags: &[String],
+        writer: &impl AccessibilityWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<String>, AccessibilityError> {
+        let mut normalized_tags: Vec<String> = Vec::new();
+        for tag in tags {
+            let Some(normalized_tag) = normalize_content_tag(tag) else {
+                return Err(AccessibilityError::InvalidTag(tag.clone()));
+            };
+            if !normalized_tags.contains(&normalized_tag) {
+                normalized_tags.push(normalized_tag);
+            }
+        }
+
+        let event_exists = writer
+            .set_content_tags(event_id, &normalized_tags, ext_cxn)
+            .await
+            .context("Saving event content tags")
+            .map_err(AccessibilityError::PortError)?;
+        if !event_exists {
+            return Err(AccessibilityError::EventNotFound(event_id));
+        }
+
+        Ok(normalized_tags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::accessibility::driving_ports::AccessibilityPort;
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    mod parse_content_tags {
+        use super::*;
+
+        #[test]
+        fn normalizes_and_deduplicates_tags() {
+            let parsed = parse_content_tags("Horror, graphic_violence; Graphic Violence,, LGBTQ+ ");
+
+            assert_eq!(
+                ParsedContentTags {
+                    tags: vec![
+                        "horror".to_owned(),
+                        "graphic-violence".to_owned(),
+                        "lgbtq".to_owned(),
+                    ],
+                    unparseable: vec![],
+                },
+                parsed
+            );
+        }
+
+        #[test]
+        fn sets_aside_values_which_arent_tags() {
+            let too_long = "a".repeat(MAX_CONTENT_TAG_LENGTH + 1);
+            let parsed = parse_content_tags(&format!("Low Sensory; ???, {too_long}"));
+
+            assert_eq!(
+                ParsedContentTags {
+                    tags: vec![LOW_SENSORY_TAG.to_owned()],
+                    unparseable: vec!["???".to_owned(), too_long],
+                },
+                parsed
+            );
+        }
+    }
+
+    mod set_accessibility {
+        use super::*;
+
+        const ACCESSIBLE: Accessibility = Accessibility {
+            wheelchair_accessible: Some(true),
+            low_sensory: None,
+        };
+
+        #[tokio::test]
+        async fn saves_room_accessibility() {
+            let storage = Mutex::new(test_util::FakeAccessibilityStorage::new());
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let room = Ref {
+                id: 3,
+                ref_type: RefType::Room,
+            };
+
+            AccessibilityService
+                .set_accessibility(&room, &ACCESSIBLE, &storage, &mut ext_cxn)
+                .await
+                .expect("room accessibility should be saved");
+
+            assert_eq!(
+                Some(&ACCESSIBLE),
+                storage
+                    .lock()
+                    .expect("Failed to lock accessibility storage during assertions")
+                    .rooms
+                    .get(&3)
+            );
+        }
+
+        #[tokio::test]
+        async fn rejects_missing_places_and_buildings() {
+            let storage = Mutex::new(test_util::FakeAccessibilityStorage::new());
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            for ref_type in [RefType::Location, RefType::Section] {
+                let place = Ref { id: 99, ref_type };
+                let result = AccessibilityService
+                    .set_accessibility(&place, &ACCESSIBLE, &storage, &mut ext_cxn)
+                    .await;
+
+                assert!(
+                    matches!(result, Err(AccessibilityError::NotFound(missing)) if missing == place)
+                );
+            }
+        }
+    }
+
+    mod set_content_tags {
+        use super::*;
+
+        #[tokio::test]
+        async fn saves_normalized_tags() {
+            let storage = Mutex::new(test_util::FakeAccessibilityStorage::new());
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let tags = vec![
+                "Jump Scares".to_owned(),
+                "jump-scares".to_owned(),
+                "Horror".to_owned(),
+            ];
+
+            let saved_tags = AccessibilityService
+                .set_content_tags(10, &tags, &storage, &mut ext_cxn)
+                .await
+                .expect("tags should be saved");
+
+            let expected_tags = vec!["jump-scares".to_owned(), "horror".to_owned()];
+            assert_eq!(expected_tags, saved_tags);
+            assert_eq!(
+                Some(&expected_tags),
+                storage
+                    .lock()
+                    .expect("Failed to lock accessibility storage during assertions")
+                    .event_tags
+                    .get(&10)
+            );
+        }
+
+        #[tokio::test]
+        async fn rejects_invalid_tags_and_missing_events() {
+            let storage = Mutex::new(test_util::FakeAccessibilityStorage::new());
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let invalid_result = AccessibilityService
+                .set_content_tags(10, &["!!".to_owned()], &storage, &mut ext_cxn)
+                .await;
+            let missing_result = AccessibilityService
+                .set_content_tags(99, &[], &storage, &mut ext_cxn)
+                .await;
+
+            assert!(
+                matches!(invalid_result, Err(AccessibilityError::InvalidTag(tag)) if tag == "!!")
+            );
+            assert!(matches!(
+                missing_result,
+                Err(AccessibilityError::EventNotFound(99))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// In-memory fake of accessibility storage holding room 3, section 30, and event 10
+    pub struct FakeAccessibilityStorage {
+        pub connectivity: Connectivity,
+        pub rooms: HashMap<i32, Accessibility>,
+        pub sections: HashMap<i32, Accessibility>,
+        pub event_tags: HashMap<i64, Vec<String>>,
+    }
+
+    impl FakeAccessibilityStorage {
+        pub fn new() -> Self {
+            Self {
+                connectivity: Connectivity::Connected,
+                rooms: HashMap::from([(3, Accessibility::default())]),
+                sections: HashMap::from([(30, Accessibility::default())]),
+                event_tags: HashMap::from([(10, Vec::new())]),
+            }
+        }
+    }
+
+    impl AccessibilityWriter for Mutex<FakeAccessibilityStorage> {
+        async fn set_room_accessibility(
+            &self,
+            room_id: i32,
+            accessibility: &Accessibility,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock accessibility storage for saving rooms");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .rooms
+                .get_mut(&room_id)
+                .map(|room| *room = *accessibility)
+                .is_some())
+        }
+
+        async fn set_section_accessibility(
+            &self,
+            section_id: i32,
+            accessibility: &Accessibility,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock accessibility storage for saving sections");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .sections
+                .get_mut(&section_id)
+                .map(|section| *section = *accessibility)
+                .is_some())
+        }
+
+        async fn set_content_tags(
+            &self,
+            event_id: i64,
+            tags: &[String],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock accessibility storage for saving content tags");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .event_tags
+                .get_mut(&event_id)
+                .map(|event_tags| *event_tags = tags.to_vec())
+                .is_some())
+        }
+    }
+}
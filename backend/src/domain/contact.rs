@@ -0,0 +1,203 @@
+use crate::domain::access::Viewer;
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use derive_more::{Display, Error};
+use driven_ports::ContactPurger;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a contact email belongs to, which decides the relay address standing in for it
+pub enum ContactOwner {
+    Event(i64),
+    Group(i64),
+}
+
+#[derive(Debug, Clone, Default)]
+/// Decides which callers may see organizer contact emails, which may be PII
+pub struct ContactPolicy {
+    /// Email domain of a relay which forwards messages to organizers. When set, anonymous callers
+    /// are given relay addresses instead of nothing.
+    pub relay_domain: Option<String>,
+}
+
+impl ContactPolicy {
+    /// Returns the contact email the viewer may see. Members and admins see the email itself,
+    /// while anonymous callers see the owner's relay address if a relay is configured.
+    pub fn visible_contact(
+        &self,
+        contact: Option<String>,
+        owner: ContactOwner,
+        viewer: Viewer,
+    ) -> Option<String> {
+        let contact = contact?;
+        if viewer >= Viewer::Member {
+            return Some(contact);
+        }
+
+        self.relay_domain.as_ref().map(|relay_domain| match owner {
+            ContactOwner::Event(event_id) => format!("event-{event_id}@{relay_domain}"),
+            ContactOwner::Group(group_id) => format!("group-{group_id}@{relay_domain}"),
+        })
+    }
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while managing contact data
+pub enum ContactError {
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Driven port for deleting stored contact data
+    pub trait ContactPurger: Sync {
+        /// Removes every contact email along with the references events hold to them. Returns
+        /// the number of emails deleted.
+        async fn purge_contacts(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<u64, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for managing organizer contact data
+    pub trait ContactPort: Sync {
+        /// Deletes all contact emails, such as once the convention is over. Importing events
+        /// again brings back the contacts they list.
+        async fn purge_contacts(
+            &self,
+            purger: &impl ContactPurger,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<u64, ContactError>;
+    }
+}
+
+/// Service implementation of the ContactPort
+pub struct ContactService;
+
+impl driving_ports::ContactPort for ContactService {
+    #[tracing::instrument(skip(self, purger, ext_cxn))]
+    async fn purge_contacts(
+        &self,
+        purger: &impl ContactPurger,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<u64, ContactError> {
+        purger
+            .purge_contacts(ext_cxn)
+            .await
+            .context("Deleting contacts")
+            .map_err(ContactError::PortError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    mod visible_contact {
+        use super::*;
+
+        #[test]
+        fn hides_contacts_from_anonymous_viewers() {
+            let policy = ContactPolicy::default();
+            let contact = || Some("organizer@example.com".to_owned());
+
+            assert_eq!(
+                None,
+                policy.visible_contact(contact(), ContactOwner::Event(4), Viewer::Anonymous)
+            );
+            assert_eq!(
+                contact(),
+                policy.visible_contact(contact(), ContactOwner::Event(4), Viewer::Member)
+            );
+            assert_eq!(
+                contact(),
+                policy.visible_contact(contact(), ContactOwner::Group(2), Viewer::Admin)
+            );
+        }
+
+        #[test]
+        fn gives_anonymous_viewers_relay_addresses() {
+            let policy = ContactPolicy {
+                relay_domain: Some("relay.example.com".to_owned()),
+            };
+            let contact = || Some("organizer@example.com".to_owned());
+
+            assert_eq!(
+                Some("event-4@relay.example.com".to_owned()),
+                policy.visible_contact(contact(), ContactOwner::Event(4), Viewer::Anonymous)
+            );
+            assert_eq!(
+                Some("group-2@relay.example.com".to_owned()),
+                policy.visible_contact(contact(), ContactOwner::Group(2), Viewer::Anonymous)
+            );
+            assert_eq!(
+                None,
+                policy.visible_contact(None, ContactOwner::Group(2), Viewer::Anonymous)
+            );
+        }
+    }
+
+    mod purge_contacts {
+        use super::*;
+        use crate::domain::contact::driving_ports::ContactPort;
+
+        #[tokio::test]
+        async fn deletes_every_contact() {
+            let storage = Mutex::new(test_util::FakeContactStorage::with_contacts(3));
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let purge_result = ContactService.purge_contacts(&storage, &mut ext_cxn).await;
+
+            assert_eq!(3, purge_result.expect("contacts should be purged"));
+            assert_eq!(
+                0,
+                storage
+                    .lock()
+                    .expect("Failed to lock contact storage during assertions")
+                    .total_contacts
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake of contact storage which only tracks how many contacts it holds
+    pub struct FakeContactStorage {
+        pub connectivity: Connectivity,
+        pub total_contacts: u64,
+    }
+
+    impl FakeContactStorage {
+        pub fn with_contacts(total_contacts: u64) -> Self {
+            Self {
+                connectivity: Connectivity::Connected,
+                total_contacts,
+            }
+        }
+    }
+
+    impl ContactPurger for Mutex<FakeContactStorage> {
+        async fn purge_contacts(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<u64, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock contact storage for purging");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(std::mem::take(&mut locked_self.total_contacts))
+        }
+    }
+}
@@ -2,15 +2,18 @@
 
 use crate::domain::game_master::driven_ports::GMAssociator;
 use crate::domain::location::driven_ports::{LocationReader, LocationWriter};
-use crate::domain::location::{Location, LocationIngest, Room, Section};
+use crate::domain::location::{Location, LocationIngest};
 use crate::domain::metadata::{Metadata, UniqueMetadataToSave};
 use crate::domain::tournament::RoundInfoIngest;
 use crate::domain::unique::driven_ports::UniqueStringSaver;
-use crate::domain::{game_master, location, metadata};
+use crate::domain::{
+    accessibility, game_master, location, metadata, saved_search, search, watchlist,
+};
 use crate::external_connections::ExternalConnectivity;
 use anyhow::{Context, anyhow};
 use chrono::{DateTime, Datelike};
 use chrono_tz::Tz;
+use derive_more::{Display, Error};
 #[cfg(test)]
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
@@ -72,7 +75,52 @@ pub struct IngestEvent {
     pub group: Option<String>,
 
     pub tournament: Option<RoundInfoIngest>,
-    pub game_masters: Vec<String>,
+    pub game_masters: game_master::ParsedGameMasters,
+    pub cancelled: bool,
+    /// Content tags listed on the event, or None if the import doesn't list tags, which leaves
+    /// any tags already on the event in place
+    pub content_tags: Option<accessibility::ParsedContentTags>,
+}
+
+#[derive(Debug, Default)]
+/// Summary of the changes an import made to stored events
+pub struct ImportOutcome {
+    /// IDs of every imported event, in the order they were passed in
+    pub event_ids: Vec<i64>,
+    pub created_ids: Vec<i64>,
+    /// IDs of existing events with any changed detail, such as their time, cost, description, or
+    /// location
+    pub changed_ids: Vec<i64>,
+    /// IDs of existing events which were cancelled by this import
+    pub newly_cancelled_ids: Vec<i64>,
+    /// Changes watchers should hear about, sent once the import has been committed
+    pub watched_changes: Vec<watchlist::ChangedEvent>,
+    /// Values which couldn't be understood and were left out of the imported events
+    pub unparseable_values: Vec<UnparseableValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A value on an imported event which couldn't be understood, so it was left out
+pub struct UnparseableValue {
+    /// Event ID assigned by GenCon
+    pub game_id: String,
+    pub field: ImportedField,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Fields of imported events which are parsed into structured values
+pub enum ImportedField {
+    GameMasters,
+    ContentTags,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while deleting an event
+pub enum EventDeleteError {
+    #[display("Event with ID {_0} does not exist")]
+    NotFound(#[error(not(source))] i64),
+    PortError(anyhow::Error),
 }
 
 #[derive(PartialEq, Eq, Ord, PartialOrd, Debug, Clone, Copy)]
@@ -115,6 +163,8 @@ pub struct CreateParams<'items> {
     pub contact: Option<i64>,
     pub website: Option<i64>,
     pub group: Option<i64>,
+    pub cancelled: bool,
+    pub content_tags: &'items [String],
 }
 
 #[derive(Debug)]
@@ -138,6 +188,24 @@ pub struct UpdateParams<'items> {
     pub contact: Option<i64>,
     pub website: Option<i64>,
     pub group: Option<i64>,
+    pub cancelled: bool,
+    /// Replacement content tags, or None to keep the event's current tags
+    pub content_tags: Option<&'items [String]>,
+}
+
+impl UpdateParams<'_> {
+    /// Builds the watchlist view of the event as it will be after the update
+    fn to_snapshot(&self, event_id: i64) -> watchlist::EventSnapshot {
+        watchlist::EventSnapshot {
+            event_id,
+            title: self.title.to_owned(),
+            start: self.start,
+            end: self.end,
+            tickets_available: self.tickets_available,
+            location: self.location.clone(),
+            cancelled: self.cancelled,
+        }
+    }
 }
 
 pub mod driven_ports {
@@ -163,12 +231,25 @@ pub mod driven_ports {
             create_params: &[CreateParams<'_>],
             ext_cxn: &mut impl ExternalConnectivity,
         ) -> Result<Vec<i64>, anyhow::Error>;
-        /// Updates the provided events (id, parameters)
+        /// Updates the provided events (id, parameters) and returns the IDs of events whose
+        /// stored details differed from the parameters. Locations are stored apart from the
+        /// details, so changing only an event's location isn't reported.
         async fn bulk_update_events(
             &self,
             update_params: &[(i64, UpdateParams<'_>)],
             ext_cxn: &mut impl ExternalConnectivity,
-        ) -> Result<(), anyhow::Error>;
+        ) -> Result<Vec<i64>, anyhow::Error>;
+    }
+
+    /// Removes events from the persistence layer
+    pub trait EventRemover {
+        /// Deletes an event along with its tournament rounds, location, game masters, and other
+        /// associations. Returns false if the event didn't exist.
+        async fn delete_event(
+            &self,
+            event_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
     }
 }
 
@@ -177,27 +258,43 @@ pub mod driving_ports {
     use crate::domain::game_master;
     use crate::domain::game_master::driven_ports::GMAssociator;
 
-    /// Primary domain port for event ingestion operations
+    /// Primary domain port for event ingestion and removal operations
     pub trait EventPort: Sync {
         #[allow(clippy::too_many_arguments)]
         async fn import_events(
             &self,
             events_to_import: &[IngestEvent],
 
+            metadata_alias_reader: &impl metadata::driven_ports::MetadataAliasReader,
             evt_type_saver: &impl UniqueStringSaver<i32, metadata::EventType>,
             gamesys_saver: &impl UniqueStringSaver<i64, metadata::GameSystem>,
             contact_saver: &impl UniqueStringSaver<i64, metadata::Contact>,
             group_saver: &impl UniqueStringSaver<i64, metadata::Group>,
             websites_saver: &impl UniqueStringSaver<i64, metadata::Website>,
             materials_saver: &impl UniqueStringSaver<i64, metadata::Materials>,
+            material_item_saver: &impl UniqueStringSaver<i64, metadata::MaterialItem>,
+            material_item_linker: &impl metadata::driven_ports::MaterialItemLinker,
             gm_saver: &impl UniqueStringSaver<i64, game_master::GameMaster>,
             location_reader: &impl LocationReader,
             location_writer: &impl LocationWriter,
             gm_assoc: &impl GMAssociator,
             event_detector: &impl driven_ports::EventDetector,
             event_writer: &impl driven_ports::EventWriter,
+            snapshot_reader: &impl watchlist::driven_ports::EventSnapshotReader,
+            search_indexer: &impl search::driven_ports::SearchIndexer,
+            saved_search_reader: &impl saved_search::driven_ports::SavedSearchReader,
+            match_recorder: &impl saved_search::driven_ports::SearchMatchRecorder,
             ext_cxn: &mut impl ExternalConnectivity,
-        ) -> Result<Vec<i64>, anyhow::Error>;
+        ) -> Result<ImportOutcome, anyhow::Error>;
+
+        /// Permanently removes an event, such as one imported by mistake. Users watching the
+        /// event or with saved searches matching it lose track of it.
+        async fn delete_event(
+            &self,
+            event_id: i64,
+            event_remover: &impl driven_ports::EventRemover,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), EventDeleteError>;
     }
 }
 
@@ -211,58 +308,53 @@ impl driving_ports::EventPort for EventService {
         &self,
         events_to_import: &[IngestEvent],
 
+        metadata_alias_reader: &impl metadata::driven_ports::MetadataAliasReader,
         evt_type_saver: &impl UniqueStringSaver<i32, metadata::EventType>,
         gamesys_saver: &impl UniqueStringSaver<i64, metadata::GameSystem>,
         contact_saver: &impl UniqueStringSaver<i64, metadata::Contact>,
         group_saver: &impl UniqueStringSaver<i64, metadata::Group>,
         websites_saver: &impl UniqueStringSaver<i64, metadata::Website>,
         materials_saver: &impl UniqueStringSaver<i64, metadata::Materials>,
+        material_item_saver: &impl UniqueStringSaver<i64, metadata::MaterialItem>,
+        material_item_linker: &impl metadata::driven_ports::MaterialItemLinker,
         gm_saver: &impl UniqueStringSaver<i64, game_master::GameMaster>,
         location_reader: &impl LocationReader,
         location_writer: &impl LocationWriter,
         gm_assoc: &impl GMAssociator,
         event_detector: &impl driven_ports::EventDetector,
         event_writer: &impl driven_ports::EventWriter,
+        snapshot_reader: &impl watchlist::driven_ports::EventSnapshotReader,
+        search_indexer: &impl search::driven_ports::SearchIndexer,
+        saved_search_reader: &impl saved_search::driven_ports::SavedSearchReader,
+        match_recorder: &impl saved_search::driven_ports::SearchMatchRecorder,
         ext_cxn: &mut impl ExternalConnectivity,
-    ) -> Result<Vec<i64>, anyhow::Error> {
+    ) -> Result<ImportOutcome, anyhow::Error> {
         if events_to_import.is_empty() {
-            return Ok(Vec::new());
+            return Ok(ImportOutcome::default());
         }
 
         let unique_metadata = UniqueMetadataToSave::from(events_to_import);
         let saved_metadata = metadata::save_metadata(
             unique_metadata,
+            metadata_alias_reader,
             evt_type_saver,
             gamesys_saver,
             contact_saver,
             group_saver,
             websites_saver,
             materials_saver,
+            material_item_saver,
+            material_item_linker,
             &mut *ext_cxn,
         )
         .await
         .context("Saving metadata for incoming events")?;
 
-        let type_ids_by_name: HashMap<&str, i32> = saved_metadata
-            .event_types
-            .iter()
-            .map(|evt_type| (evt_type.name.as_str(), evt_type.id))
-            .collect();
-        let game_system_ids_by_name: HashMap<&str, i64> = saved_metadata
-            .game_systems
-            .iter()
-            .map(|system| (system.system_name.as_str(), system.id))
-            .collect();
         let contact_ids_by_name: HashMap<&str, i64> = saved_metadata
             .contacts
             .iter()
             .map(|contact| (contact.email.as_str(), contact.id))
             .collect();
-        let group_ids_by_name: HashMap<&str, i64> = saved_metadata
-            .groups
-            .iter()
-            .map(|group| (group.name.as_str(), group.id))
-            .collect();
         let website_ids_by_name: HashMap<&str, i64> = saved_metadata
             .websites
             .iter()
@@ -297,8 +389,13 @@ impl driving_ports::EventPort for EventService {
         )
         .await
         .context("Saving location for incoming events")?;
-        let location_ingest_to_ref: HashMap<LocationIngest, location::Ref> =
-            incoming_locations_to_refs(saved_unique_locations);
+        // Saved locations carry canonical names, so they're paired back up with the incoming
+        // locations by position
+        let location_ingest_to_ref: HashMap<&LocationIngest, location::Ref> =
+            unique_locations_to_import
+                .iter()
+                .zip(saved_unique_locations.iter().map(Location::as_location_ref))
+                .collect();
         let event_ids: Vec<&str> = events_to_import
             .iter()
             .map(|event| event.game_id.as_str())
@@ -318,7 +415,9 @@ impl driving_ports::EventPort for EventService {
             for (found_event_id, event_ingest) in
                 event_existence.iter().cloned().zip(events_to_import.iter())
             {
-                let Some(&event_type_id) = type_ids_by_name.get(event_ingest.event_type.as_str())
+                let Some(&event_type_id) = saved_metadata
+                    .event_type_ids
+                    .get(event_ingest.event_type.as_str())
                 else {
                     return Err(anyhow!(
                         "Unexpected error: did not receive ID for event type {} during ingest",
@@ -327,7 +426,7 @@ impl driving_ports::EventPort for EventService {
                 };
                 let game_system_id = find_id_for_optional_str(
                     event_ingest.game_system.as_deref(),
-                    &game_system_ids_by_name,
+                    &saved_metadata.game_system_ids,
                     "game system",
                 )?;
                 let contact_id = find_id_for_optional_str(
@@ -337,7 +436,7 @@ impl driving_ports::EventPort for EventService {
                 )?;
                 let group_id = find_id_for_optional_str(
                     event_ingest.group.as_deref(),
-                    &group_ids_by_name,
+                    &saved_metadata.group_ids,
                     "group",
                 )?;
                 let website_id = find_id_for_optional_str(
@@ -382,6 +481,11 @@ impl driving_ports::EventPort for EventService {
                         contact: contact_id,
                         website: website_id,
                         group: group_id,
+                        cancelled: event_ingest.cancelled,
+                        content_tags: event_ingest
+                            .content_tags
+                            .as_ref()
+                            .map(|content_tags| content_tags.tags.as_slice()),
                     };
                     event_updates.push((id, event_update_data));
                 } else {
@@ -405,6 +509,11 @@ impl driving_ports::EventPort for EventService {
                         contact: contact_id,
                         website: website_id,
                         group: group_id,
+                        cancelled: event_ingest.cancelled,
+                        content_tags: event_ingest
+                            .content_tags
+                            .as_ref()
+                            .map_or(&[], |content_tags| content_tags.tags.as_slice()),
                     };
                     event_creates.push(event_create_data);
                 }
@@ -415,10 +524,41 @@ impl driving_ports::EventPort for EventService {
             .bulk_save_events(&event_creates, &mut *ext_cxn)
             .await
             .context("Creating events")?;
-        event_writer
+        let updated_ids: Vec<i64> = event_updates.iter().map(|(id, _)| *id).collect();
+        let previous_snapshots = snapshot_reader
+            .read_snapshots(&updated_ids, &mut *ext_cxn)
+            .await
+            .context("Reading events before update")?;
+        let details_changed_ids: HashSet<i64> = event_writer
             .bulk_update_events(&event_updates, &mut *ext_cxn)
             .await
-            .context("Updating events")?;
+            .context("Updating events")?
+            .into_iter()
+            .collect();
+        let updated_snapshots: Vec<watchlist::EventSnapshot> = event_updates
+            .iter()
+            .map(|(id, update_params)| update_params.to_snapshot(*id))
+            .collect();
+        let previous_by_id: HashMap<i64, &watchlist::EventSnapshot> = previous_snapshots
+            .iter()
+            .map(|snapshot| (snapshot.event_id, snapshot))
+            .collect();
+        let mut changed_ids: Vec<i64> = Vec::new();
+        let mut newly_cancelled_ids: Vec<i64> = Vec::new();
+        for updated in updated_snapshots.iter() {
+            let Some(previous) = previous_by_id.get(&updated.event_id) else {
+                continue;
+            };
+            // Snapshots also cover the location, which the writer doesn't compare
+            if details_changed_ids.contains(&updated.event_id) || *previous != updated {
+                changed_ids.push(updated.event_id);
+            }
+            if !previous.cancelled && updated.cancelled {
+                newly_cancelled_ids.push(updated.event_id);
+            }
+        }
+        let watched_changes =
+            watchlist::detect_event_changes(&previous_snapshots, &updated_snapshots);
 
         let mut all_event_ids: Vec<i64> = Vec::new();
         let mut create_idx = 0;
@@ -439,68 +579,80 @@ impl driving_ports::EventPort for EventService {
             .zip(events_to_import.iter())
             .map(|(event_id, event_data)| game_master::GameMastersForEvent {
                 event_id,
-                game_masters: event_data.game_masters.clone(),
+                game_masters: event_data.game_masters.names.clone(),
             })
             .collect();
 
-        game_master::save_game_masters(&gm_associations, gm_saver, gm_assoc, ext_cxn)
+        game_master::save_game_masters(&gm_associations, gm_saver, gm_assoc, &mut *ext_cxn)
             .await
             .context("Saving game masters")?;
 
-        Ok(all_event_ids)
-    }
-}
+        // Search documents include game master names, so they're rebuilt after GMs are saved
+        search_indexer
+            .refresh_search_documents(&all_event_ids, &mut *ext_cxn)
+            .await
+            .context("Refreshing event search documents")?;
+        saved_search::flag_new_matches(
+            &all_event_ids,
+            saved_search_reader,
+            match_recorder,
+            ext_cxn,
+        )
+        .await
+        .context("Flagging new saved search matches")?;
 
-/// Builds a map from the original ingest location specification to the persisted location reference.
-/// Uses the most specific part (location/room/section) of each saved Location to construct the Ref.
-fn incoming_locations_to_refs(
-    saved_unique_locations: Vec<Location>,
-) -> HashMap<LocationIngest, location::Ref> {
-    saved_unique_locations
-        .into_iter()
-        .map(|loc| {
-            let location_ref = loc.as_location_ref();
-            let location_ingest = match loc {
-                Location {
-                    name: location_name,
-                    room:
-                        Some(Room {
-                            name: room_name,
-                            section:
-                                Some(Section {
-                                    name: section_name, ..
-                                }),
-                            ..
-                        }),
-                    ..
-                } => LocationIngest::Section {
-                    location_name,
-                    room_name,
-                    section_name,
-                },
-
-                Location {
-                    name: location_name,
-                    room:
-                        Some(Room {
-                            name: room_name,
-                            section: None,
-                            ..
-                        }),
-                    ..
-                } => LocationIngest::Room {
-                    location_name,
-                    room_name,
-                },
-
-                Location {
-                    name, room: None, ..
-                } => LocationIngest::Location { name },
-            };
+        let unparseable_values = events_to_import
+            .iter()
+            .flat_map(|event_data| {
+                let unparseable_gms = event_data
+                    .game_masters
+                    .unparseable
+                    .iter()
+                    .map(|value| (ImportedField::GameMasters, value));
+                let unparseable_tags = event_data
+                    .content_tags
+                    .iter()
+                    .flat_map(|content_tags| content_tags.unparseable.iter())
+                    .map(|value| (ImportedField::ContentTags, value));
+
+                unparseable_gms
+                    .chain(unparseable_tags)
+                    .map(|(field, value)| UnparseableValue {
+                        game_id: event_data.game_id.clone(),
+                        field,
+                        value: value.clone(),
+                    })
+            })
+            .collect();
 
-            (location_ingest, location_ref)
+        Ok(ImportOutcome {
+            event_ids: all_event_ids,
+            created_ids,
+            changed_ids,
+            newly_cancelled_ids,
+            watched_changes,
+            unparseable_values,
         })
-        .collect()
+    }
+
+    #[tracing::instrument(skip(self, event_remover, ext_cxn))]
+    async fn delete_event(
+        &self,
+        event_id: i64,
+        event_remover: &impl driven_ports::EventRemover,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), EventDeleteError> {
+        let deleted = event_remover
+            .delete_event(event_id, ext_cxn)
+            .await
+            .context("Deleting event")
+            .map_err(EventDeleteError::PortError)?;
+        if !deleted {
+            return Err(EventDeleteError::NotFound(event_id));
+        }
+
+        Ok(())
+    }
 }
 
 /// Helper to resolve an optional string to its ID using the provided map.
@@ -0,0 +1,247 @@
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use derive_more::{Display, Error};
+use driven_ports::{EventTypeReader, EventTypeWriter};
+
+/// A GenCon event type along with how many of the current year's uncancelled events it covers
+pub struct EventTypeDetails {
+    pub id: i32,
+    /// Short code GenCon identifies the type with, such as BGM
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Name or URL of the icon shown beside the type
+    pub icon: Option<String>,
+    pub total_events: u64,
+}
+
+/// Changes to the parts of an event type which aren't set by imports
+pub struct EventTypeUpdate {
+    pub name: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while updating event types
+pub enum EventTypeUpdateError {
+    #[display("Event type with ID {_0} does not exist")]
+    NotFound(#[error(not(source))] i32),
+    #[display("Event type names can't be blank")]
+    BlankName,
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Driven port for reading the catalog of event types
+    pub trait EventTypeReader: Sync {
+        /// Reads every event type along with its event count, ordered by name
+        async fn read_catalog(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventTypeDetails>, anyhow::Error>;
+    }
+
+    /// Driven port for maintaining the catalog of event types
+    pub trait EventTypeWriter: Sync {
+        /// Saves the type's name, description, and icon. Returns false if the type doesn't exist.
+        async fn update_event_type(
+            &self,
+            event_type_id: i32,
+            update: &EventTypeUpdate,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for browsing and maintaining event types
+    pub trait EventTypePort: Sync {
+        async fn event_type_catalog(
+            &self,
+            reader: &impl EventTypeReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventTypeDetails>, anyhow::Error>;
+
+        /// Replaces the type's name, description, and icon. Blank descriptions and icons are
+        /// cleared.
+        async fn update_event_type(
+            &self,
+            event_type_id: i32,
+            update: EventTypeUpdate,
+            writer: &impl EventTypeWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), EventTypeUpdateError>;
+    }
+}
+
+/// Service implementation of the EventTypePort
+pub struct EventTypeService;
+
+impl driving_ports::EventTypePort for EventTypeService {
+    #[tracing::instrument(skip_all)]
+    async fn event_type_catalog(
+        &self,
+        reader: &impl EventTypeReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventTypeDetails>, anyhow::Error> {
+        reader
+            .read_catalog(ext_cxn)
+            .await
+            .context("Reading event types")
+    }
+
+    #[tracing::instrument(skip(self, update, writer, ext_cxn))]
+    async fn update_event_type(
+        &self,
+        event_type_id: i32,
+        update: EventTypeUpdate,
+        writer: &impl EventTypeWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), EventTypeUpdateError> {
+        let trim_optional = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_owned())
+                .filter(|value| !value.is_empty())
+        };
+        let update = EventTypeUpdate {
+            name: update.name.trim().to_owned(),
+            description: trim_optional(update.description),
+            icon: trim_optional(update.icon),
+        };
+        if update.name.is_empty() {
+            return Err(EventTypeUpdateError::BlankName);
+        }
+
+        let type_exists = writer
+            .update_event_type(event_type_id, &update, ext_cxn)
+            .await
+            .context("Saving event type")
+            .map_err(EventTypeUpdateError::PortError)?;
+        if !type_exists {
+            return Err(EventTypeUpdateError::NotFound(event_type_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    mod update_event_type {
+        use super::*;
+        use crate::domain::event_type::driving_ports::EventTypePort;
+
+        #[tokio::test]
+        async fn trims_values_and_clears_blank_ones() {
+            let storage = Mutex::new(test_util::FakeEventTypeStorage::with_type(3));
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            EventTypeService
+                .update_event_type(
+                    3,
+                    EventTypeUpdate {
+                        name: " Board Game ".to_owned(),
+                        description: Some("  ".to_owned()),
+                        icon: Some(" dice ".to_owned()),
+                    },
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("the event type should be updated");
+
+            let locked_storage = storage
+                .lock()
+                .expect("Failed to lock event type storage during assertions");
+            let saved_update = locked_storage
+                .saved_update
+                .as_ref()
+                .expect("an update should be saved");
+            assert_eq!("Board Game", saved_update.name);
+            assert_eq!(None, saved_update.description);
+            assert_eq!(Some("dice".to_owned()), saved_update.icon);
+        }
+
+        #[tokio::test]
+        async fn rejects_blank_names_and_unknown_types() {
+            let storage = Mutex::new(test_util::FakeEventTypeStorage::with_type(3));
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let update = |name: &str| EventTypeUpdate {
+                name: name.to_owned(),
+                description: None,
+                icon: None,
+            };
+
+            let blank_result = EventTypeService
+                .update_event_type(3, update("   "), &storage, &mut ext_cxn)
+                .await;
+            let unknown_result = EventTypeService
+                .update_event_type(4, update("Board Game"), &storage, &mut ext_cxn)
+                .await;
+
+            assert!(matches!(blank_result, Err(EventTypeUpdateError::BlankName)));
+            assert!(matches!(
+                unknown_result,
+                Err(EventTypeUpdateError::NotFound(4))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake of event type storage holding a single type, which records the last update
+    /// saved to it
+    pub struct FakeEventTypeStorage {
+        pub connectivity: Connectivity,
+        pub event_type_id: i32,
+        pub saved_update: Option<EventTypeUpdate>,
+    }
+
+    impl FakeEventTypeStorage {
+        pub fn with_type(event_type_id: i32) -> Self {
+            Self {
+                connectivity: Connectivity::Connected,
+                event_type_id,
+                saved_update: None,
+            }
+        }
+    }
+
+    impl EventTypeWriter for Mutex<FakeEventTypeStorage> {
+        async fn update_event_type(
+            &self,
+            event_type_id: i32,
+            update: &EventTypeUpdate,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock event type storage for writing");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            if event_type_id != locked_self.event_type_id {
+                return Ok(false);
+            }
+            locked_self.saved_update = Some(EventTypeUpdate {
+                name: update.name.clone(),
+                description: update.description.clone(),
+                icon: update.icon.clone(),
+            });
+
+            Ok(true)
+        }
+    }
+}
@@ -1,11 +1,14 @@
-#![expect(dead_code)]
-
-use crate::domain::game_master::driven_ports::{GMAssociator, NewAssociationError};
+use crate::domain::game_master::driven_ports::{
+    GMAssociator, GameMasterReader, NewAssociationError,
+};
 use crate::domain::unique::driven_ports::UniqueStringSaver;
 use crate::domain::unique::{ConstructUniqueStr, save_or_get_unique_str};
 use crate::external_connections::ExternalConnectivity;
 use anyhow::Context;
+use chrono::DateTime;
+use chrono_tz::Tz;
 use derive_more::{Display, Error};
+use std::collections::HashSet;
 
 #[derive(Clone)]
 /// Domain model for a game master (GM) represented as a unique string value.
@@ -69,6 +72,141 @@ pub struct GameMastersForEvent {
     pub game_masters: Vec<String>,
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// Game master names read from the free-text list on an imported event
+pub struct ParsedGameMasters {
+    /// Trimmed names in the order they were listed, without duplicates
+    pub names: Vec<String>,
+    /// Listed values which aren't names, such as placeholders or punctuation
+    pub unparseable: Vec<String>,
+}
+
+/// Values GenCon lists in place of a game master who hasn't been assigned
+const PLACEHOLDER_GM_NAMES: [&str; 7] = ["tba", "tbd", "n/a", "na", "none", "various", "staff"];
+
+/// Name suffixes which belong to the name before them when listed after a comma
+const GM_NAME_SUFFIXES: [&str; 9] = ["jr", "jr.", "sr", "sr.", "ii", "iii", "iv", "phd", "ph.d."];
+
+/// Longest game master name which can be stored
+const MAX_GM_NAME_LENGTH: usize = 512;
+
+/// Splits the list of game masters on an imported event into individual names.
+///
+/// Names are separated by commas, semicolons, ampersands, or the word "and". GenCon lists game
+/// masters by full name, so a single word before a comma is read as a last name ("Smith, John"
+/// becomes "John Smith"), as are comma-separated lists made up entirely of pairs of single words.
+/// Whitespace is collapsed, names repeated with different capitalization are kept once, and
+/// values which can't be names are set aside instead.
+pub fn parse_game_master_names(raw_names: &str) -> ParsedGameMasters {
+    let mut parsed = ParsedGameMasters::default();
+    let mut seen_names: HashSet<String> = HashSet::new();
+    let separated_names = raw_names
+        .split_whitespace()
+        .map(|word| {
+            if word.eq_ignore_ascii_case("and") {
+                ";"
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    for entry in separated_names.split([';', '&']) {
+        let mut parts: Vec<String> = Vec::new();
+        for part in entry
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            match parts.last_mut() {
+                Some(name) if GM_NAME_SUFFIXES.contains(&part.to_lowercase().as_str()) => {
+                    name.push(' ');
+                    name.push_str(part);
+                }
+                _ => parts.push(part.to_owned()),
+            }
+        }
+
+        let is_single_word = |part: &String| !part.contains(' ');
+        let names: Vec<String> = if parts.len() == 2 && is_single_word(&parts[0]) {
+            vec![format!("{} {}", parts[1], parts[0])]
+        } else if parts.len() > 2
+            && parts.len().is_multiple_of(2)
+            && parts.iter().all(is_single_word)
+        {
+            parts
+                .chunks(2)
+                .map(|last_first| format!("{} {}", last_first[1], last_first[0]))
+                .collect()
+        } else {
+            parts
+        };
+
+        for name in names {
+            let is_placeholder = PLACEHOLDER_GM_NAMES.contains(&name.to_lowercase().as_str());
+            if is_placeholder
+                || !name.chars().any(char::is_alphabetic)
+                || name.chars().count() > MAX_GM_NAME_LENGTH
+            {
+                if !parsed.unparseable.contains(&name) {
+                    parsed.unparseable.push(name);
+                }
+            } else if seen_names.insert(name.to_lowercase()) {
+                parsed.names.push(name);
+            }
+        }
+    }
+
+    parsed
+}
+
+/// A game master along with how many uncancelled events they run
+pub struct GameMasterSummary {
+    pub game_master: GameMaster,
+    /// Events in the current year
+    pub events_this_year: u64,
+    /// Events across every year
+    pub total_events: u64,
+}
+
+/// A single page of game masters, in name order
+pub struct GameMasterPage {
+    pub game_masters: Vec<GameMasterSummary>,
+    /// Number of game masters matching the search across all pages
+    pub total_results: u64,
+}
+
+/// An event run by a game master in any year
+pub struct GameMasterEvent {
+    pub id: i64,
+    /// Event ID assigned by GenCon
+    pub game_id: String,
+    pub title: String,
+    pub year: i32,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub cost: Option<u32>,
+    pub tickets_available: u16,
+    pub max_players: u16,
+    pub cancelled: bool,
+}
+
+/// A game master along with every event they've run
+pub struct GameMasterProfile {
+    pub game_master: GameMaster,
+    /// Events ordered by year, newest first, and then by start time
+    pub events: Vec<GameMasterEvent>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while browsing game masters
+pub enum GameMasterError {
+    #[display("Game master with ID {_0} does not exist")]
+    NotFound(#[error(not(source))] i64),
+    PortError(anyhow::Error),
+}
+
 pub mod driven_ports {
     use super::*;
     use crate::domain::BulkLookupResult;
@@ -121,6 +259,90 @@ pub mod driven_ports {
             ext_cxn: &mut impl ExternalConnectivity,
         ) -> Result<(), anyhow::Error>;
     }
+
+    /// Port for reading game masters and the events they run
+    pub trait GameMasterReader: Sync {
+        /// Reads a page of game masters in name order. When a name is passed, only game masters
+        /// whose names contain it or closely resemble it are read.
+        async fn search_game_masters(
+            &self,
+            name: Option<&str>,
+            page: u16,
+            page_size: u16,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<GameMasterPage, anyhow::Error>;
+
+        /// Reads the game master and every event they've run. Returns None if the game master
+        /// doesn't exist.
+        async fn read_profile(
+            &self,
+            game_master_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<GameMasterProfile>, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for browsing game masters
+    pub trait GameMasterPort: Sync {
+        /// Lists a page of game masters, optionally only those whose names match the passed name
+        async fn game_masters(
+            &self,
+            name: Option<&str>,
+            page: u16,
+            page_size: u16,
+            reader: &impl GameMasterReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<GameMasterPage, anyhow::Error>;
+
+        /// Lists every event the game master has run, in this year and prior years
+        async fn game_master_profile(
+            &self,
+            game_master_id: i64,
+            reader: &impl GameMasterReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<GameMasterProfile, GameMasterError>;
+    }
+}
+
+/// Service implementation of the GameMasterPort
+pub struct GameMasterService;
+
+impl driving_ports::GameMasterPort for GameMasterService {
+    #[tracing::instrument(skip(self, reader, ext_cxn))]
+    async fn game_masters(
+        &self,
+        name: Option<&str>,
+        page: u16,
+        page_size: u16,
+        reader: &impl GameMasterReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<GameMasterPage, anyhow::Error> {
+        // Blank names would match every game master anyway
+        let name = name.map(str::trim).filter(|name| !name.is_empty());
+
+        reader
+            .search_game_masters(name, page, page_size, ext_cxn)
+            .await
+            .context("Searching game masters")
+    }
+
+    #[tracing::instrument(skip(self, reader, ext_cxn))]
+    async fn game_master_profile(
+        &self,
+        game_master_id: i64,
+        reader: &impl GameMasterReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<GameMasterProfile, GameMasterError> {
+        reader
+            .read_profile(game_master_id, ext_cxn)
+            .await
+            .context("Reading game master profile")
+            .map_err(GameMasterError::PortError)?
+            .ok_or(GameMasterError::NotFound(game_master_id))
+    }
 }
 
 #[tracing::instrument(skip_all, fields(total_game_assocs = game_masters.len(), first_5_assocs = ?game_masters.get(0..5)))]
@@ -409,6 +631,137 @@ mod tests {
             });
         }
     }
+
+    mod parse_game_master_names {
+        use super::*;
+
+        fn names(raw_names: &str) -> Vec<String> {
+            parse_game_master_names(raw_names).names
+        }
+
+        #[test]
+        fn splits_full_names_on_separators() {
+            assert_eq!(
+                vec!["Alice Smith", "Bob Jones", "Carol Lee", "Dan Brown"],
+                names("Alice Smith, Bob Jones and Carol Lee & Dan Brown")
+            );
+            assert_eq!(vec!["Alice Smith", "Bob"], names("Alice Smith, Bob"));
+            assert!(names("").is_empty());
+        }
+
+        #[test]
+        fn reverses_last_first_names() {
+            assert_eq!(vec!["John Smith"], names("Smith, John"));
+            assert_eq!(vec!["John A. Smith"], names("Smith, John A."));
+            assert_eq!(
+                vec!["John Smith", "Jane Doe"],
+                names("Smith, John; Doe, Jane")
+            );
+            assert_eq!(
+                vec!["John Smith", "Jane Doe"],
+                names("Smith, John, Doe, Jane")
+            );
+        }
+
+        #[test]
+        fn trims_and_removes_duplicates() {
+            assert_eq!(
+                vec!["Alice Smith", "Bob Jones"],
+                names("  Alice   Smith ,alice smith, Bob Jones,, Alice Smith ")
+            );
+        }
+
+        #[test]
+        fn keeps_suffixes_with_their_names() {
+            assert_eq!(
+                vec!["John Smith Jr.", "Jane Doe"],
+                names("John Smith, Jr., Jane Doe")
+            );
+        }
+
+        #[test]
+        fn sets_aside_values_which_are_not_names() {
+            let parsed = parse_game_master_names("TBD, Alice Smith, ???, ???");
+
+            assert_eq!(
+                ParsedGameMasters {
+                    names: vec!["Alice Smith".to_owned()],
+                    unparseable: vec!["TBD".to_owned(), "???".to_owned()],
+                },
+                parsed
+            );
+        }
+    }
+
+    mod game_masters {
+        use super::*;
+        use crate::domain::game_master::driving_ports::GameMasterPort;
+        use crate::domain::game_master::test_util::FakeGameMasterReader;
+        use crate::external_connections::test_util::FakeExternalConnectivity;
+        use std::sync::Mutex;
+
+        #[tokio::test]
+        async fn trims_names_and_ignores_blank_ones() {
+            let reader = Mutex::new(FakeGameMasterReader::new());
+            let mut fake_cxn = FakeExternalConnectivity::new();
+
+            GameMasterService
+                .game_masters(Some("  Smith "), 1, 50, &reader, &mut fake_cxn)
+                .await
+                .expect("searching by name should succeed");
+            GameMasterService
+                .game_masters(Some("   "), 1, 50, &reader, &mut fake_cxn)
+                .await
+                .expect("searching by a blank name should succeed");
+
+            assert_eq!(
+                vec![Some("Smith".to_owned()), None],
+                reader
+                    .lock()
+                    .expect("Failed to lock game master reader")
+                    .searched_names
+            );
+        }
+    }
+
+    mod game_master_profile {
+        use super::*;
+        use crate::domain::game_master::driving_ports::GameMasterPort;
+        use crate::domain::game_master::test_util::FakeGameMasterReader;
+        use crate::domain::test_util::Connectivity;
+        use crate::external_connections::test_util::FakeExternalConnectivity;
+        use std::sync::Mutex;
+
+        #[tokio::test]
+        async fn distinguishes_missing_game_masters_from_storage_failures() {
+            let reader = Mutex::new(FakeGameMasterReader::new());
+            let mut fake_cxn = FakeExternalConnectivity::new();
+
+            let found = GameMasterService
+                .game_master_profile(4, &reader, &mut fake_cxn)
+                .await;
+            let missing = GameMasterService
+                .game_master_profile(5, &reader, &mut fake_cxn)
+                .await;
+            reader
+                .lock()
+                .expect("Failed to lock game master reader during setup")
+                .connectivity = Connectivity::Disconnected;
+            let disconnected = GameMasterService
+                .game_master_profile(4, &reader, &mut fake_cxn)
+                .await;
+
+            assert!(matches!(
+                found,
+                Ok(GameMasterProfile {
+                    game_master: GameMaster { id: 4, .. },
+                    ..
+                })
+            ));
+            assert!(matches!(missing, Err(GameMasterError::NotFound(5))));
+            assert!(matches!(disconnected, Err(GameMasterError::PortError(_))));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -526,4 +879,60 @@ pub mod test_util {
             Ok(())
         }
     }
+
+    /// In-memory fake GameMasterReader holding a single game master with ID 4, which records the
+    /// names it was searched with
+    pub struct FakeGameMasterReader {
+        pub searched_names: Vec<Option<String>>,
+        pub connectivity: Connectivity,
+    }
+
+    impl FakeGameMasterReader {
+        pub fn new() -> Self {
+            Self {
+                searched_names: Vec::new(),
+                connectivity: Connectivity::Connected,
+            }
+        }
+    }
+
+    impl GameMasterReader for Mutex<FakeGameMasterReader> {
+        async fn search_game_masters(
+            &self,
+            name: Option<&str>,
+            _page: u16,
+            _page_size: u16,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<GameMasterPage, Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock game master reader for searching");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.searched_names.push(name.map(str::to_owned));
+
+            Ok(GameMasterPage {
+                game_masters: Vec::new(),
+                total_results: 0,
+            })
+        }
+
+        async fn read_profile(
+            &self,
+            game_master_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<GameMasterProfile>, Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock game master reader for reading");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok((game_master_id == 4).then(|| GameMasterProfile {
+                game_master: GameMaster {
+                    id: game_master_id,
+                    name: "John Smith".to_owned(),
+                },
+                events: Vec::new(),
+            }))
+        }
+    }
 }
@@ -0,0 +1,234 @@
+use crate::domain::metadata::{GameSystem, Group};
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::NaiveDate;
+use derive_more::{Display, Error};
+use driven_ports::GameSystemReader;
+
+/// A game system along with a summary of the current year's events using it. Cancelled events
+/// are left out.
+pub struct GameSystemProfile {
+    pub game_system: GameSystem,
+    pub total_events: u64,
+    /// Total number of players the events can seat
+    pub total_seats: u64,
+    /// Cheapest and most expensive event costs in dollars, with free events costing 0. None when
+    /// no events use the system.
+    pub price_range: Option<(u32, u32)>,
+    /// Days the events start on, in order
+    pub days: Vec<NaiveDate>,
+    /// Groups organizing the events, in name order
+    pub groups: Vec<Group>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while browsing game systems
+pub enum GameSystemError {
+    #[display("Game system with ID {_0} does not exist")]
+    NotFound(#[error(not(source))] i64),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Driven port for reading game systems and the events using them
+    pub trait GameSystemReader: Sync {
+        /// Reads every game system, ordered by name
+        async fn all_game_systems(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<GameSystem>, anyhow::Error>;
+
+        /// Reads the game system and a summary of its events. Returns None if the game system
+        /// doesn't exist.
+        async fn read_profile(
+            &self,
+            game_system_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<GameSystemProfile>, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for browsing game systems
+    pub trait GameSystemPort: Sync {
+        async fn game_systems(
+            &self,
+            reader: &impl GameSystemReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<GameSystem>, anyhow::Error>;
+
+        /// Summarizes the current year's events using the game system
+        async fn game_system_profile(
+            &self,
+            game_system_id: i64,
+            reader: &impl GameSystemReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<GameSystemProfile, GameSystemError>;
+    }
+}
+
+/// Service implementation of the GameSystemPort
+pub struct GameSystemService;
+
+impl driving_ports::GameSystemPort for GameSystemService {
+    #[tracing::instrument(skip_all)]
+    async fn game_systems(
+        &self,
+        reader: &impl GameSystemReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<GameSystem>, anyhow::Error> {
+        reader
+            .all_game_systems(ext_cxn)
+            .await
+            .context("Reading game systems")
+    }
+
+    #[tracing::instrument(skip(self, reader, ext_cxn))]
+    async fn game_system_profile(
+        &self,
+        game_system_id: i64,
+        reader: &impl GameSystemReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<GameSystemProfile, GameSystemError> {
+        reader
+            .read_profile(game_system_id, ext_cxn)
+            .await
+            .context("Reading game system profile")
+            .map_err(GameSystemError::PortError)?
+            .ok_or(GameSystemError::NotFound(game_system_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    mod game_systems {
+        use super::*;
+        use crate::domain::game_system::driving_ports::GameSystemPort;
+
+        #[tokio::test]
+        async fn lists_stored_systems() {
+            let storage = Mutex::new(test_util::FakeGameSystemStorage {
+                connectivity: Connectivity::Connected,
+                profile_id: 3,
+            });
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let listed = GameSystemService.game_systems(&storage, &mut ext_cxn).await;
+            storage
+                .lock()
+                .expect("Failed to lock game system storage during setup")
+                .connectivity = Connectivity::Disconnected;
+            let disconnected = GameSystemService.game_systems(&storage, &mut ext_cxn).await;
+
+            let listed_ids: Vec<i64> = listed
+                .expect("game systems should be listed")
+                .iter()
+                .map(|game_system| game_system.id)
+                .collect();
+            assert_eq!(vec![3], listed_ids);
+            assert!(disconnected.is_err());
+        }
+    }
+
+    mod game_system_profile {
+        use super::*;
+        use crate::domain::game_system::driving_ports::GameSystemPort;
+
+        #[tokio::test]
+        async fn distinguishes_missing_systems_from_storage_failures() {
+            let storage = Mutex::new(test_util::FakeGameSystemStorage {
+                connectivity: Connectivity::Connected,
+                profile_id: 3,
+            });
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let found = GameSystemService
+                .game_system_profile(3, &storage, &mut ext_cxn)
+                .await;
+            let missing = GameSystemService
+                .game_system_profile(4, &storage, &mut ext_cxn)
+                .await;
+            storage
+                .lock()
+                .expect("Failed to lock game system storage during setup")
+                .connectivity = Connectivity::Disconnected;
+            let disconnected = GameSystemService
+                .game_system_profile(3, &storage, &mut ext_cxn)
+                .await;
+
+            assert!(matches!(
+                found,
+                Ok(GameSystemProfile {
+                    game_system: GameSystem { id: 3, .. },
+                    ..
+                })
+            ));
+            assert!(matches!(missing, Err(GameSystemError::NotFound(4))));
+            assert!(matches!(disconnected, Err(GameSystemError::PortError(_))));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake of game system storage holding a single profile
+    pub struct FakeGameSystemStorage {
+        pub connectivity: Connectivity,
+        pub profile_id: i64,
+    }
+
+    impl GameSystemReader for Mutex<FakeGameSystemStorage> {
+        async fn all_game_systems(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<GameSystem>, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock game system storage for listing");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(vec![GameSystem {
+                id: locked_self.profile_id,
+                system_name: "Pathfinder Second Edition".to_owned(),
+            }])
+        }
+
+        async fn read_profile(
+            &self,
+            game_system_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<GameSystemProfile>, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock game system storage for reading");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(
+                (game_system_id == locked_self.profile_id).then(|| GameSystemProfile {
+                    game_system: GameSystem {
+                        id: game_system_id,
+                        system_name: "Pathfinder Second Edition".to_owned(),
+                    },
+                    total_events: 0,
+                    total_seats: 0,
+                    price_range: None,
+                    days: Vec::new(),
+                    groups: Vec::new(),
+                }),
+            )
+        }
+    }
+}
@@ -0,0 +1,607 @@
+use crate::domain::accessibility::LOW_SENSORY_TAG;
+use crate::domain::event::{AgeRequirement, ExperienceLevel, ImportOutcome};
+use crate::domain::search::EventFilter;
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::DateTime;
+use chrono_tz::Tz;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The current state of an event which was created or changed, pushed to live update subscribers
+pub struct EventUpdate {
+    pub event_id: i64,
+    pub game_id: String,
+    pub title: String,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub cost: Option<u32>,
+    pub tickets_available: u16,
+    pub cancelled: bool,
+
+    pub event_type_id: i32,
+    pub game_system_id: Option<i64>,
+    pub group_id: Option<i64>,
+    /// ID of the building the event takes place in
+    pub building_id: Option<i32>,
+    /// ID of the room the event takes place in, including events in a section of the room
+    pub room_id: Option<i32>,
+    pub section_id: Option<i32>,
+    pub age_requirement: AgeRequirement,
+    pub experience_requirement: ExperienceLevel,
+    pub in_tournament: bool,
+    /// Whether the event's materials list anything players need to bring
+    pub requires_materials: bool,
+    /// Whether the event's room or section is known to be wheelchair accessible
+    pub wheelchair_accessible: bool,
+    /// Whether the event's room or section is a low-sensory space
+    pub low_sensory: bool,
+    pub content_tags: Vec<String>,
+}
+
+impl EventUpdate {
+    /// Length of the event in hours
+    pub fn duration_hours(&self) -> f32 {
+        (self.end - self.start).num_minutes() as f32 / 60.0
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// Narrows down which event updates a live update subscriber receives. Unset fields match every
+/// event.
+pub struct UpdateFilter {
+    /// Only send updates for these specific events
+    pub event_ids: Option<HashSet<i64>>,
+    /// The same filters used to list events
+    pub filter: EventFilter,
+}
+
+/// Returns true if the filter list isn't set or contains the value
+fn allowed<T: PartialEq>(filter_values: &Option<Vec<T>>, value: &T) -> bool {
+    filter_values
+        .as_ref()
+        .is_none_or(|filter_values| filter_values.contains(value))
+}
+
+/// Returns true if the filter list isn't set or contains the optional value. Missing values only
+/// match when the filter isn't set.
+fn allowed_optional<T: PartialEq>(filter_values: &Option<Vec<T>>, value: &Option<T>) -> bool {
+    match (filter_values, value) {
+        (None, _) => true,
+        (Some(filter_values), Some(value)) => filter_values.contains(value),
+        (Some(_), None) => false,
+    }
+}
+
+impl UpdateFilter {
+    /// Determines whether an event update passes every part of the filter except the search
+    /// text, which only the search index can check
+    fn matches(&self, update: &EventUpdate) -> bool {
+        if let Some(event_ids) = &self.event_ids
+            && !event_ids.contains(&update.event_id)
+        {
+            return false;
+        }
+        let filter = &self.filter;
+        if let Some(min_tickets) = filter.min_available_tickets
+            && update.tickets_available < min_tickets
+        {
+            return false;
+        }
+        if !allowed(&filter.event_type_ids, &update.event_type_id)
+            || !allowed(&filter.experience_levels, &update.experience_requirement)
+            || !allowed(&filter.age_requirements, &update.age_requirement)
+            || !allowed_optional(&filter.game_system_ids, &update.game_system_id)
+            || !allowed_optional(&filter.group_ids, &update.group_id)
+            || !allowed_optional(&filter.building_ids, &update.building_id)
+            || !allowed_optional(&filter.room_ids, &update.room_id)
+            || !allowed_optional(&filter.section_ids, &update.section_id)
+        {
+            return false;
+        }
+        if filter.exclude_tournaments && update.in_tournament {
+            return false;
+        }
+        if filter.no_materials_required && update.requires_materials {
+            return false;
+        }
+        if filter.wheelchair_accessible && !update.wheelchair_accessible {
+            return false;
+        }
+        let has_tag = |tag: &String| update.content_tags.contains(tag);
+        if filter.low_sensory && !update.low_sensory && !has_tag(&LOW_SENSORY_TAG.to_owned()) {
+            return false;
+        }
+        if filter
+            .content_tags
+            .as_ref()
+            .is_some_and(|tags| !tags.iter().all(has_tag))
+            || filter
+                .exclude_content_tags
+                .as_ref()
+                .is_some_and(|tags| tags.iter().any(has_tag))
+        {
+            return false;
+        }
+
+        let start_time = update.start.time();
+        if filter
+            .earliest_start
+            .is_some_and(|earliest| start_time < earliest)
+            || filter
+                .latest_start
+                .is_some_and(|latest| start_time > latest)
+        {
+            return false;
+        }
+
+        if !filter.allows_times(update.start, update.end) {
+            return false;
+        }
+
+        let duration = update.duration_hours();
+        if filter.min_duration_hours.is_some_and(|min| duration < min)
+            || filter.max_duration_hours.is_some_and(|max| duration > max)
+        {
+            return false;
+        }
+
+        // Free events aren't excluded by cost bounds, matching the event list filters
+        if let Some(cost) = update.cost
+            && (filter.cost_min.is_some_and(|min| cost < min)
+                || filter.cost_max.is_some_and(|max| cost > max))
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Tells every running instance of the application that events have changed
+    pub trait ChangeNotifier: Sync {
+        /// Announces that the passed events were created or changed. When called inside a
+        /// transaction, the announcement is only delivered once the transaction commits.
+        async fn notify_changes(
+            &self,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+    }
+
+    /// Reads the current state of changed events
+    pub trait EventUpdateReader: Sync {
+        async fn read_updates(
+            &self,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventUpdate>, anyhow::Error>;
+    }
+
+    /// Checks changed events against event list filters the same way the event list does
+    pub trait UpdateSearcher: Sync {
+        /// Returns the IDs of the passed events which match the filter, including cancelled ones
+        async fn matching_event_ids(
+            &self,
+            event_ids: &[i64],
+            filter: &EventFilter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for publishing and reading live event updates
+    pub trait LiveUpdatePort: Sync {
+        /// Announces every event an import created or changed
+        async fn announce_import(
+            &self,
+            import_outcome: &ImportOutcome,
+            change_notifier: &impl driven_ports::ChangeNotifier,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+
+        /// Reads the current state of events which were announced as changed
+        async fn read_updates(
+            &self,
+            event_ids: &[i64],
+            update_reader: &impl driven_ports::EventUpdateReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventUpdate>, anyhow::Error>;
+
+        /// Picks out the updates a subscriber using the filter should receive. Search text is
+        /// matched with the search index, so it finds the same events as the event list.
+        async fn matching_updates(
+            &self,
+            updates: &[EventUpdate],
+            update_filter: &UpdateFilter,
+            update_searcher: &impl driven_ports::UpdateSearcher,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventUpdate>, anyhow::Error>;
+    }
+}
+
+/// Service implementation of the LiveUpdatePort
+pub struct LiveUpdateService;
+
+impl driving_ports::LiveUpdatePort for LiveUpdateService {
+    #[tracing::instrument(skip_all, fields(created = import_outcome.created_ids.len(), changed = import_outcome.changed_ids.len()))]
+    async fn announce_import(
+        &self,
+        import_outcome: &ImportOutcome,
+        change_notifier: &impl driven_ports::ChangeNotifier,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), anyhow::Error> {
+        let changed_event_ids: Vec<i64> = import_outcome
+            .created_ids
+            .iter()
+            .chain(import_outcome.changed_ids.iter())
+            .copied()
+            .collect();
+        if changed_event_ids.is_empty() {
+            return Ok(());
+        }
+
+        change_notifier
+            .notify_changes(&changed_event_ids, ext_cxn)
+            .await
+            .context("Announcing events changed by import")
+    }
+
+    #[tracing::instrument(skip_all, fields(total = event_ids.len()))]
+    async fn read_updates(
+        &self,
+        event_ids: &[i64],
+        update_reader: &impl driven_ports::EventUpdateReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventUpdate>, anyhow::Error> {
+        if event_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        update_reader
+            .read_updates(event_ids, ext_cxn)
+            .await
+            .context("Reading changed events")
+    }
+
+    #[tracing::instrument(skip_all, fields(total = updates.len()))]
+    async fn matching_updates(
+        &self,
+        updates: &[EventUpdate],
+        update_filter: &UpdateFilter,
+        update_searcher: &impl driven_ports::UpdateSearcher,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventUpdate>, anyhow::Error> {
+        let candidates: Vec<&EventUpdate> = updates
+            .iter()
+            .filter(|update| update_filter.matches(update))
+            .collect();
+        if candidates.is_empty() || update_filter.filter.search_text().is_none() {
+            return Ok(candidates.into_iter().cloned().collect());
+        }
+
+        let candidate_ids: Vec<i64> = candidates.iter().map(|update| update.event_id).collect();
+        let searched_ids: HashSet<i64> = update_searcher
+            .matching_event_ids(&candidate_ids, &update_filter.filter, ext_cxn)
+            .await
+            .context("Searching changed events")?
+            .into_iter()
+            .collect();
+
+        Ok(candidates
+            .into_iter()
+            .filter(|update| searched_ids.contains(&update.event_id))
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::live_update::driving_ports::LiveUpdatePort;
+    use crate::domain::live_update::test_util::{FakeChangeNotifier, FakeUpdateSearcher};
+    use crate::domain::test_util::Connectivity;
+    use crate::external_connections;
+    use chrono::{NaiveTime, TimeZone};
+    use std::sync::Mutex;
+
+    fn update() -> EventUpdate {
+        EventUpdate {
+            event_id: 10,
+            game_id: "RPG24ND000010".to_owned(),
+            title: "Delve into the Underdark".to_owned(),
+            start: Tz::America__Indiana__Indianapolis
+                .with_ymd_and_hms(2024, 8, 1, 10, 0, 0)
+                .unwrap(),
+            end: Tz::America__Indiana__Indianapolis
+                .with_ymd_and_hms(2024, 8, 1, 14, 0, 0)
+                .unwrap(),
+            cost: Some(4),
+            tickets_available: 3,
+            cancelled: false,
+            event_type_id: 2,
+            game_system_id: Some(7),
+            group_id: None,
+            building_id: Some(1),
+            room_id: Some(3),
+            section_id: None,
+            age_requirement: AgeRequirement::Teen,
+            experience_requirement: ExperienceLevel::None,
+            in_tournament: false,
+            requires_materials: true,
+            wheelchair_accessible: true,
+            low_sensory: false,
+            content_tags: vec!["horror".to_owned()],
+        }
+    }
+
+    mod matches {
+        use super::*;
+
+        #[test]
+        fn empty_filter_matches_everything() {
+            assert!(UpdateFilter::default().matches(&update()));
+        }
+
+        #[test]
+        fn filters_by_event_id() {
+            let filter = UpdateFilter {
+                event_ids: Some(HashSet::from([11, 12])),
+                ..Default::default()
+            };
+
+            assert!(!filter.matches(&update()));
+        }
+
+        #[test]
+        fn missing_optional_values_do_not_match_set_filters() {
+            let filter = UpdateFilter {
+                filter: EventFilter {
+                    group_ids: Some(vec![3]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            assert!(!filter.matches(&update()));
+        }
+
+        #[test]
+        fn applies_time_duration_and_cost_bounds() {
+            let too_late = UpdateFilter {
+                filter: EventFilter {
+                    latest_start: NaiveTime::from_hms_opt(9, 0, 0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let too_long = UpdateFilter {
+                filter: EventFilter {
+                    max_duration_hours: Some(3.5),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let too_expensive = UpdateFilter {
+                filter: EventFilter {
+                    cost_max: Some(2),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            assert!(!too_late.matches(&update()));
+            assert!(!too_long.matches(&update()));
+            assert!(!too_expensive.matches(&update()));
+        }
+
+        #[test]
+        fn applies_accessibility_and_content_tag_filters() {
+            let filter = |filter: EventFilter| UpdateFilter {
+                filter,
+                ..Default::default()
+            };
+            let accessible_without_horror = filter(EventFilter {
+                wheelchair_accessible: true,
+                exclude_content_tags: Some(vec!["horror".to_owned()]),
+                ..Default::default()
+            });
+            let low_sensory = filter(EventFilter {
+                low_sensory: true,
+                ..Default::default()
+            });
+            let tagged_horror = filter(EventFilter {
+                content_tags: Some(vec!["horror".to_owned()]),
+                ..Default::default()
+            });
+            let mut tagged_low_sensory = update();
+            tagged_low_sensory.content_tags = vec![LOW_SENSORY_TAG.to_owned()];
+
+            assert!(!accessible_without_horror.matches(&update()));
+            assert!(!low_sensory.matches(&update()));
+            assert!(low_sensory.matches(&tagged_low_sensory));
+            assert!(tagged_horror.matches(&update()));
+            assert!(!tagged_horror.matches(&tagged_low_sensory));
+        }
+    }
+
+    mod matching_updates {
+        use super::*;
+
+        fn updates() -> Vec<EventUpdate> {
+            let mut other_update = update();
+            other_update.event_id = 11;
+            other_update.title = "Pathfinder Society".to_owned();
+
+            vec![update(), other_update]
+        }
+
+        #[tokio::test]
+        async fn searches_for_search_text() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = Mutex::new(FakeUpdateSearcher {
+                matching_ids: vec![11],
+                searched_ids: Vec::new(),
+                connectivity: Connectivity::Connected,
+            });
+            let filter = UpdateFilter {
+                event_ids: Some(HashSet::from([10, 11])),
+                filter: EventFilter {
+                    search_text: Some("pathfnder".to_owned()),
+                    ..Default::default()
+                },
+            };
+
+            let matching = LiveUpdateService
+                .matching_updates(&updates(), &filter, &searcher, &mut ext_cxn)
+                .await
+                .expect("matching should succeed");
+
+            let matching_ids: Vec<i64> = matching.iter().map(|update| update.event_id).collect();
+            assert_eq!(vec![11], matching_ids);
+            assert_eq!(vec![vec![10, 11]], searcher.lock().unwrap().searched_ids);
+        }
+
+        #[tokio::test]
+        async fn skips_the_search_index_when_not_needed() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = Mutex::new(FakeUpdateSearcher {
+                matching_ids: Vec::new(),
+                searched_ids: Vec::new(),
+                connectivity: Connectivity::Disconnected,
+            });
+            let without_search_text = UpdateFilter {
+                event_ids: Some(HashSet::from([11])),
+                ..Default::default()
+            };
+            let excluding_everything = UpdateFilter {
+                event_ids: Some(HashSet::from([12])),
+                filter: EventFilter {
+                    search_text: Some("pathfinder".to_owned()),
+                    ..Default::default()
+                },
+            };
+
+            let matching = LiveUpdateService
+                .matching_updates(&updates(), &without_search_text, &searcher, &mut ext_cxn)
+                .await
+                .expect("matching should succeed without the search index");
+            let excluded = LiveUpdateService
+                .matching_updates(&updates(), &excluding_everything, &searcher, &mut ext_cxn)
+                .await
+                .expect("matching should succeed without the search index");
+
+            assert_eq!(1, matching.len());
+            assert!(excluded.is_empty());
+        }
+    }
+
+    mod announce_import {
+        use super::*;
+
+        #[tokio::test]
+        async fn announces_created_and_changed_events() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let notifier = Mutex::new(FakeChangeNotifier {
+                announced: Vec::new(),
+                connectivity: Connectivity::Connected,
+            });
+            let outcome = ImportOutcome {
+                event_ids: vec![1, 2, 3],
+                created_ids: vec![3],
+                changed_ids: vec![1],
+                newly_cancelled_ids: vec![1],
+                watched_changes: Vec::new(),
+                unparseable_values: Vec::new(),
+            };
+
+            LiveUpdateService
+                .announce_import(&outcome, &notifier, &mut ext_cxn)
+                .await
+                .expect("announcing should succeed");
+
+            assert_eq!(vec![vec![3, 1]], notifier.lock().unwrap().announced);
+        }
+
+        #[tokio::test]
+        async fn skips_imports_without_changes() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let notifier = Mutex::new(FakeChangeNotifier {
+                announced: Vec::new(),
+                connectivity: Connectivity::Disconnected,
+            });
+            let outcome = ImportOutcome {
+                event_ids: vec![1, 2],
+                ..Default::default()
+            };
+
+            let announce_result = LiveUpdateService
+                .announce_import(&outcome, &notifier, &mut ext_cxn)
+                .await;
+
+            assert!(announce_result.is_ok());
+            assert!(notifier.lock().unwrap().announced.is_empty());
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// Fake ChangeNotifier which records each announcement
+    pub struct FakeChangeNotifier {
+        pub announced: Vec<Vec<i64>>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::ChangeNotifier for Mutex<FakeChangeNotifier> {
+        async fn notify_changes(
+            &self,
+            event_ids: &[i64],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake change notifier");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.announced.push(event_ids.to_vec());
+
+            Ok(())
+        }
+    }
+
+    /// Fake UpdateSearcher which finds a fixed set of events and records each search
+    pub struct FakeUpdateSearcher {
+        /// IDs of the events the search finds, when they're among the searched events
+        pub matching_ids: Vec<i64>,
+        pub searched_ids: Vec<Vec<i64>>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::UpdateSearcher for Mutex<FakeUpdateSearcher> {
+        async fn matching_event_ids(
+            &self,
+            event_ids: &[i64],
+            _filter: &EventFilter,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake update searcher");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.searched_ids.push(event_ids.to_vec());
+
+            Ok(event_ids
+                .iter()
+                .filter(|event_id| locked_self.matching_ids.contains(event_id))
+                .copied()
+                .collect())
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use crate::domain::location::driven_ports::{LocationReader, LocationWriter};
+use crate::domain::location::driven_ports::{
+    HierarchyReader, LocationAliasWriter, LocationMerger, LocationReader, LocationWriter,
+};
+use crate::domain::search::EventFilter;
 use crate::external_connections::ExternalConnectivity;
 use anyhow::Context;
-use derive_more::Display;
+use derive_more::{Display, Error};
 #[cfg(test)]
 use serde::Serialize;
 use std::collections::HashMap;
@@ -63,7 +66,7 @@ pub struct Section {
     pub name: String,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 /// A compact reference to a location entity, indicating the id and the kind of entity it refers to.
 pub struct Ref {
     pub id: i32,
@@ -134,6 +137,145 @@ pub struct SectionOnlyRef<'section> {
     pub name: &'section str,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// A building along with every room and section inside it
+pub struct LocationTree {
+    pub id: i32,
+    pub name: String,
+    pub rooms: Vec<RoomTree>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// A room along with every section inside it
+pub struct RoomTree {
+    pub id: i32,
+    pub name: String,
+    pub sections: Vec<Section>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Alternate spellings of buildings, rooms, and sections along with the canonical name each one
+/// refers to. Aliases are keyed by their [alias_key], and rooms and sections are scoped to the
+/// canonical names of the places containing them.
+pub struct LocationAliases {
+    pub locations: HashMap<String, String>,
+    pub rooms: HashMap<(String, String), String>,
+    pub sections: HashMap<(String, String, String), String>,
+}
+
+impl LocationAliases {
+    /// Cleans up the spelling of a building name and swaps aliases for the canonical name
+    pub fn canonical_location(&self, location_name: &str) -> String {
+        let location_name = normalize_name(location_name);
+        self.locations
+            .get(&location_name.to_lowercase())
+            .cloned()
+            .unwrap_or(location_name)
+    }
+
+    /// Cleans up the spelling of a room name and swaps aliases for the canonical name. Rooms
+    /// which are just a number are named "Room <number>", matching numeric rooms in GenCon's
+    /// event export.
+    pub fn canonical_room(&self, canonical_location: &str, room_name: &str) -> String {
+        let mut room_name = normalize_name(room_name);
+        if let Ok(room_number) = room_name.parse::<u32>() {
+            room_name = format!("Room {room_number}");
+        }
+
+        self.rooms
+            .get(&(canonical_location.to_owned(), room_name.to_lowercase()))
+            .cloned()
+            .unwrap_or(room_name)
+    }
+
+    /// Cleans up the spelling of a section name and swaps aliases for the canonical name
+    pub fn canonical_section(
+        &self,
+        canonical_location: &str,
+        canonical_room: &str,
+        section_name: &str,
+    ) -> String {
+        let section_name = normalize_name(section_name);
+        self.sections
+            .get(&(
+                canonical_location.to_owned(),
+                canonical_room.to_owned(),
+                section_name.to_lowercase(),
+            ))
+            .cloned()
+            .unwrap_or(section_name)
+    }
+
+    /// Rewrites the names in an incoming location to their canonical forms
+    fn canonicalize(&self, ingest: &LocationIngest) -> LocationIngest {
+        match ingest {
+            LocationIngest::Location { name } => LocationIngest::Location {
+                name: self.canonical_location(name),
+            },
+            LocationIngest::Room {
+                location_name,
+                room_name,
+            } => {
+                let location_name = self.canonical_location(location_name);
+                LocationIngest::Room {
+                    room_name: self.canonical_room(&location_name, room_name),
+                    location_name,
+                }
+            }
+            LocationIngest::Section {
+                location_name,
+                room_name,
+                section_name,
+            } => {
+                let location_name = self.canonical_location(location_name);
+                let room_name = self.canonical_room(&location_name, room_name);
+                LocationIngest::Section {
+                    section_name: self.canonical_section(&location_name, &room_name, section_name),
+                    location_name,
+                    room_name,
+                }
+            }
+        }
+    }
+}
+
+/// Longest alias which can be stored, after normalization
+pub const MAX_ALIAS_LENGTH: usize = 255;
+
+/// Trims a name and collapses runs of whitespace inside it
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// The form aliases are stored and looked up in, so they match regardless of spacing or case
+pub fn alias_key(alias: &str) -> String {
+    normalize_name(alias).to_lowercase()
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while browsing locations
+pub enum LocationError {
+    #[display("{} with ID {} does not exist", _0.ref_type, _0.id)]
+    NotFound(#[error(not(source))] Ref),
+    PortError(anyhow::Error),
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while maintaining location aliases or merging duplicate locations
+pub enum LocationUpdateError {
+    #[display("{} with ID {} does not exist", _0.ref_type, _0.id)]
+    NotFound(#[error(not(source))] Ref),
+    #[display("A {_0} cannot be merged into itself")]
+    MergeIntoSelf(#[error(not(source))] RefType),
+    #[display("Rooms can only be merged within a building, and sections within a room")]
+    DifferentParents,
+    #[display("Aliases must contain between 1 and {MAX_ALIAS_LENGTH} characters")]
+    InvalidAlias,
+    #[display("{} with ID {} has no alias \"{}\"", _0.ref_type, _0.id, _1)]
+    AliasNotFound(#[error(not(source))] Ref, String),
+    PortError(anyhow::Error),
+}
+
 pub mod driven_ports {
     use super::*;
     use crate::domain::BulkLookupResult;
@@ -164,6 +306,30 @@ pub mod driven_ports {
             section_refs: &[SectionOnlyRef<'_>],
             ext_cxn: &mut impl ExternalConnectivity,
         ) -> BulkLookupResult<SectionOnly, anyhow::Error>;
+
+        /// Reads every configured alias along with the canonical name it refers to
+        async fn read_location_aliases(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<LocationAliases, anyhow::Error>;
+    }
+
+    /// Driven port for reading every known location, room, and section.
+    pub trait HierarchyReader: Sync {
+        async fn all_locations(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<LocationOnly>, anyhow::Error>;
+
+        async fn all_rooms(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<RoomOnly>, anyhow::Error>;
+
+        async fn all_sections(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SectionOnly>, anyhow::Error>;
     }
 
     /// Driven port for creating new locations, rooms, and sections.
@@ -189,17 +355,367 @@ pub mod driven_ports {
             ext_cxn: &mut impl ExternalConnectivity,
         ) -> Result<Vec<i32>, anyhow::Error>;
     }
+
+    /// Driven port for maintaining alternate names of locations, rooms, and sections.
+    pub trait LocationAliasWriter: Sync {
+        /// Points the alias (already in [alias_key] form) at the place, replacing whatever it
+        /// pointed at before. Returns false if the place doesn't exist.
+        async fn save_alias(
+            &self,
+            place: &Ref,
+            alias: &str,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Removes the alias from the place. Returns false if the place had no such alias.
+        async fn delete_alias(
+            &self,
+            place: &Ref,
+            alias: &str,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+
+    /// Driven port for folding duplicate locations, rooms, and sections into one another. Each
+    /// merge moves the duplicate's events, aliases, and contents over to the target, merging
+    /// contents which share a name, records the duplicate's name as an alias of the target, and
+    /// deletes the duplicate.
+    pub trait LocationMerger: Sync {
+        async fn merge_locations(
+            &self,
+            target_id: i32,
+            duplicate_id: i32,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+
+        async fn merge_rooms(
+            &self,
+            target_id: i32,
+            duplicate_id: i32,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+
+        async fn merge_sections(
+            &self,
+            target_id: i32,
+            duplicate_id: i32,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for browsing the buildings, rooms, and sections events take place in
+    pub trait LocationPort: Sync {
+        /// Lists every building with its rooms and their sections, each ordered by name
+        async fn location_hierarchy(
+            &self,
+            reader: &impl HierarchyReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<LocationTree>, anyhow::Error>;
+
+        /// Narrows the filter down to events taking place at the location, room, or section.
+        /// Events in a building's rooms and a room's sections take place there too.
+        async fn scope_to_place(
+            &self,
+            place: &Ref,
+            filter: EventFilter,
+            reader: &impl HierarchyReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<EventFilter, LocationError>;
+
+        /// Lists every configured alias along with the canonical name it refers to
+        async fn location_aliases(
+            &self,
+            reader: &impl LocationReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<LocationAliases, anyhow::Error>;
+
+        /// Makes imports treat the alias as another name for the location, room, or section
+        async fn set_alias(
+            &self,
+            place: &Ref,
+            alias: &str,
+            writer: &impl LocationAliasWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), LocationUpdateError>;
+
+        async fn remove_alias(
+            &self,
+            place: &Ref,
+            alias: &str,
+            writer: &impl LocationAliasWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), LocationUpdateError>;
+
+        /// Folds a duplicate of the location, room, or section into it, moving the duplicate's
+        /// events over. Rooms can only be merged within a building, and sections within a room.
+        async fn merge_places(
+            &self,
+            target: &Ref,
+            duplicate_id: i32,
+            reader: &impl HierarchyReader,
+            merger: &impl LocationMerger,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), LocationUpdateError>;
+    }
+}
+
+/// Service implementation of the LocationPort
+pub struct LocationService;
+
+impl driving_ports::LocationPort for LocationService {
+    #[tracing::instrument(skip_all)]
+    async fn location_hierarchy(
+        &self,
+        reader: &impl HierarchyReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<LocationTree>, anyhow::Error> {
+        let mut locations = reader
+            .all_locations(&mut *ext_cxn)
+            .await
+            .context("Reading locations")?;
+        let mut rooms = reader
+            .all_rooms(&mut *ext_cxn)
+            .await
+            .context("Reading rooms")?;
+        let mut sections = reader
+            .all_sections(ext_cxn)
+            .await
+            .context("Reading sections")?;
+        locations.sort_by(|loc1, loc2| loc1.name.cmp(&loc2.name).then(loc1.id.cmp(&loc2.id)));
+        rooms.sort_by(|room1, room2| room1.name.cmp(&room2.name).then(room1.id.cmp(&room2.id)));
+        sections.sort_by(|sect1, sect2| sect1.name.cmp(&sect2.name).then(sect1.id.cmp(&sect2.id)));
+
+        let mut sections_by_room: HashMap<i32, Vec<Section>> = HashMap::new();
+        for section in sections {
+            sections_by_room
+                .entry(section.room_id)
+                .or_default()
+                .push(Section {
+                    id: section.id,
+                    name: section.name,
+                });
+        }
+        let mut rooms_by_location: HashMap<i32, Vec<RoomTree>> = HashMap::new();
+        for room in rooms {
+            rooms_by_location
+                .entry(room.location_id)
+                .or_default()
+                .push(RoomTree {
+                    id: room.id,
+                    sections: sections_by_room.remove(&room.id).unwrap_or_default(),
+                    name: room.name,
+                });
+        }
+
+        Ok(locations
+            .into_iter()
+            .map(|location| LocationTree {
+                rooms: rooms_by_location.remove(&location.id).unwrap_or_default(),
+                id: location.id,
+                name: location.name,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, filter, reader, ext_cxn))]
+    async fn scope_to_place(
+        &self,
+        place: &Ref,
+        mut filter: EventFilter,
+        reader: &impl HierarchyReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<EventFilter, LocationError> {
+        let place_exists = match place.ref_type {
+            RefType::Location => reader
+                .all_locations(ext_cxn)
+                .await
+                .context("Reading locations")
+                .map(|locations| locations.iter().any(|location| location.id == place.id)),
+            RefType::Room => reader
+                .all_rooms(ext_cxn)
+                .await
+                .context("Reading rooms")
+                .map(|rooms| rooms.iter().any(|room| room.id == place.id)),
+            RefType::Section => reader
+                .all_sections(ext_cxn)
+                .await
+                .context("Reading sections")
+                .map(|sections| sections.iter().any(|section| section.id == place.id)),
+        }
+        .map_err(LocationError::PortError)?;
+        if !place_exists {
+            return Err(LocationError::NotFound(place.clone()));
+        }
+
+        match place.ref_type {
+            RefType::Location => filter.building_ids = Some(vec![place.id]),
+            RefType::Room => filter.room_ids = Some(vec![place.id]),
+            RefType::Section => filter.section_ids = Some(vec![place.id]),
+        }
+        Ok(filter)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn location_aliases(
+        &self,
+        reader: &impl LocationReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<LocationAliases, anyhow::Error> {
+        reader
+            .read_location_aliases(ext_cxn)
+            .await
+            .context("Reading location aliases")
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn set_alias(
+        &self,
+        place: &Ref,
+        alias: &str,
+        writer: &impl LocationAliasWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), LocationUpdateError> {
+        let alias = alias_key(alias);
+        if alias.is_empty() || alias.chars().count() > MAX_ALIAS_LENGTH {
+            return Err(LocationUpdateError::InvalidAlias);
+        }
+
+        let place_exists = writer
+            .save_alias(place, &alias, ext_cxn)
+            .await
+            .context("Saving location alias")
+            .map_err(LocationUpdateError::PortError)?;
+        if !place_exists {
+            return Err(LocationUpdateError::NotFound(place.clone()));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn remove_alias(
+        &self,
+        place: &Ref,
+        alias: &str,
+        writer: &impl LocationAliasWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), LocationUpdateError> {
+        let alias = alias_key(alias);
+        let deleted = writer
+            .delete_alias(place, &alias, ext_cxn)
+            .await
+            .context("Deleting location alias")
+            .map_err(LocationUpdateError::PortError)?;
+        if !deleted {
+            return Err(LocationUpdateError::AliasNotFound(place.clone(), alias));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, reader, merger, ext_cxn))]
+    async fn merge_places(
+        &self,
+        target: &Ref,
+        duplicate_id: i32,
+        reader: &impl HierarchyReader,
+        merger: &impl LocationMerger,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), LocationUpdateError> {
+        if target.id == duplicate_id {
+            return Err(LocationUpdateError::MergeIntoSelf(target.ref_type));
+        }
+        let duplicate = Ref {
+            id: duplicate_id,
+            ref_type: target.ref_type,
+        };
+
+        // Places are found by ID and paired with the ID of the place containing them, which
+        // must match for rooms and sections
+        let parent_ids: HashMap<i32, i32> = match target.ref_type {
+            RefType::Location => reader
+                .all_locations(&mut *ext_cxn)
+                .await
+                .context("Reading locations")
+                .map(|locations| {
+                    locations
+                        .into_iter()
+                        .map(|location| (location.id, 0))
+                        .collect()
+                }),
+            RefType::Room => reader
+                .all_rooms(&mut *ext_cxn)
+                .await
+                .context("Reading rooms")
+                .map(|rooms| {
+                    rooms
+                        .into_iter()
+                        .map(|room| (room.id, room.location_id))
+                        .collect()
+                }),
+            RefType::Section => reader
+                .all_sections(&mut *ext_cxn)
+                .await
+                .context("Reading sections")
+                .map(|sections| {
+                    sections
+                        .into_iter()
+                        .map(|section| (section.id, section.room_id))
+                        .collect()
+                }),
+        }
+        .map_err(LocationUpdateError::PortError)?;
+        let Some(target_parent) = parent_ids.get(&target.id) else {
+            return Err(LocationUpdateError::NotFound(target.clone()));
+        };
+        let Some(duplicate_parent) = parent_ids.get(&duplicate_id) else {
+            return Err(LocationUpdateError::NotFound(duplicate));
+        };
+        if target_parent != duplicate_parent {
+            return Err(LocationUpdateError::DifferentParents);
+        }
+
+        match target.ref_type {
+            RefType::Location => merger
+                .merge_locations(target.id, duplicate_id, ext_cxn)
+                .await
+                .context("Merging locations"),
+            RefType::Room => merger
+                .merge_rooms(target.id, duplicate_id, ext_cxn)
+                .await
+                .context("Merging rooms"),
+            RefType::Section => merger
+                .merge_sections(target.id, duplicate_id, ext_cxn)
+                .await
+                .context("Merging sections"),
+        }
+        .map_err(LocationUpdateError::PortError)
+    }
 }
 
 #[tracing::instrument(skip_all, fields(first_10 = ?incoming_locations.get(0..10), total = incoming_locations.len()))]
 /// Ensures locations/rooms/sections referenced by ingest exist; creates missing ones and returns synthesized Locations
-/// in the same order as [incoming_locations].
+/// in the same order as [incoming_locations]. Names are normalized and aliases are swapped for their canonical names
+/// first, so the returned Locations carry canonical names.
 pub(super) async fn save_locations(
     incoming_locations: &[LocationIngest],
     reader: &impl LocationReader,
     writer: &impl LocationWriter,
     ext_cxn: &mut impl ExternalConnectivity,
 ) -> Result<Vec<Location>, anyhow::Error> {
+    let aliases = reader
+        .read_location_aliases(&mut *ext_cxn)
+        .await
+        .context("reading location aliases")?;
+    let incoming_locations: Vec<LocationIngest> = incoming_locations
+        .iter()
+        .map(|location| aliases.canonicalize(location))
+        .collect();
+
     // Assemble the list of locations
     let locations: Vec<&str> = incoming_locations
         .iter()
@@ -490,6 +1006,8 @@ mod tests {
                 rooms: existing_rooms,
                 sections: existing_sections,
                 location_storage_failures: Default::default(),
+                aliases: Default::default(),
+                merges: Vec::new(),
             });
             let mut fake_connectivity =
                 external_connections::test_util::FakeExternalConnectivity::new();
@@ -723,6 +1241,298 @@ mod tests {
             assert_eq!(expected_rooms, locked_storage.rooms.as_slice());
             assert_eq!(expected_sections, locked_storage.sections.as_slice());
         }
+
+        #[tokio::test]
+        async fn collapses_spelling_variants_and_aliases() {
+            let location_storage = Mutex::new(test_util::FakeLocationStorage {
+                locations: vec![LocationOnly {
+                    id: 1,
+                    name: "ICC".to_owned(),
+                }],
+                rooms: vec![RoomOnly {
+                    id: 3,
+                    location_id: 1,
+                    name: "Room 213".to_owned(),
+                }],
+                sections: Vec::new(),
+                location_storage_failures: Default::default(),
+                aliases: LocationAliases {
+                    locations: HashMap::from([(
+                        "indiana convention center".to_owned(),
+                        "ICC".to_owned(),
+                    )]),
+                    ..Default::default()
+                },
+                merges: Vec::new(),
+            });
+            let mut fake_connectivity =
+                external_connections::test_util::FakeExternalConnectivity::new();
+
+            let ingested_locations = [
+                LocationIngest::Location {
+                    name: "ICC ".to_owned(),
+                },
+                LocationIngest::Room {
+                    location_name: "Indiana  Convention Center".to_owned(),
+                    room_name: "213".to_owned(),
+                },
+                LocationIngest::Room {
+                    location_name: "ICC".to_owned(),
+                    room_name: "Room 213".to_owned(),
+                },
+            ];
+
+            let saved_locations = save_locations(
+                &ingested_locations,
+                &location_storage,
+                &location_storage,
+                &mut fake_connectivity,
+            )
+            .await
+            .expect("saving locations should succeed");
+
+            let room_213 = Location {
+                id: 1,
+                name: "ICC".to_owned(),
+                room: Some(Room {
+                    id: 3,
+                    name: "Room 213".to_owned(),
+                    section: None,
+                }),
+            };
+            assert_eq!(
+                vec![
+                    Location {
+                        id: 1,
+                        name: "ICC".to_owned(),
+                        room: None,
+                    },
+                    room_213.clone(),
+                    room_213,
+                ],
+                saved_locations
+            );
+            let locked_storage = location_storage
+                .lock()
+                .expect("Failed to lock location storage during assertions");
+            assert_eq!(1, locked_storage.locations.len());
+            assert_eq!(1, locked_storage.rooms.len());
+        }
+    }
+
+    fn browsable_storage() -> std::sync::Mutex<test_util::FakeLocationStorage> {
+        std::sync::Mutex::new(test_util::FakeLocationStorage {
+            locations: vec![
+                LocationOnly {
+                    id: 2,
+                    name: "Westin".to_owned(),
+                },
+                LocationOnly {
+                    id: 1,
+                    name: "ICC".to_owned(),
+                },
+            ],
+            rooms: vec![
+                RoomOnly {
+                    id: 5,
+                    location_id: 1,
+                    name: "Hall D".to_owned(),
+                },
+                RoomOnly {
+                    id: 4,
+                    location_id: 1,
+                    name: "Hall B".to_owned(),
+                },
+            ],
+            sections: vec![SectionOnly {
+                id: 9,
+                room_id: 5,
+                name: "Front".to_owned(),
+            }],
+            location_storage_failures: Default::default(),
+            aliases: Default::default(),
+            merges: Vec::new(),
+        })
+    }
+
+    mod location_hierarchy {
+        use super::*;
+        use crate::domain::location::driving_ports::LocationPort;
+        use crate::external_connections;
+
+        #[tokio::test]
+        async fn nests_rooms_and_sections_by_name() {
+            let storage = browsable_storage();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let hierarchy = LocationService
+                .location_hierarchy(&storage, &mut ext_cxn)
+                .await
+                .expect("reading the hierarchy should succeed");
+
+            assert_eq!(
+                vec![
+                    LocationTree {
+                        id: 1,
+                        name: "ICC".to_owned(),
+                        rooms: vec![
+                            RoomTree {
+                                id: 4,
+                                name: "Hall B".to_owned(),
+                                sections: Vec::new(),
+                            },
+                            RoomTree {
+                                id: 5,
+                                name: "Hall D".to_owned(),
+                                sections: vec![Section {
+                                    id: 9,
+                                    name: "Front".to_owned(),
+                                }],
+                            },
+                        ],
+                    },
+                    LocationTree {
+                        id: 2,
+                        name: "Westin".to_owned(),
+                        rooms: Vec::new(),
+                    },
+                ],
+                hierarchy
+            );
+        }
+    }
+
+    mod scope_to_place {
+        use super::*;
+        use crate::domain::location::driving_ports::LocationPort;
+        use crate::external_connections;
+
+        #[tokio::test]
+        async fn scopes_filter_to_the_place() {
+            let storage = browsable_storage();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let base_filter = EventFilter {
+                min_available_tickets: Some(1),
+                ..Default::default()
+            };
+
+            let filter = LocationService
+                .scope_to_place(
+                    &Ref {
+                        id: 5,
+                        ref_type: RefType::Room,
+                    },
+                    base_filter,
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("the room should exist");
+
+            assert_eq!(Some(vec![5]), filter.room_ids);
+            assert_eq!(None, filter.building_ids);
+            assert_eq!(Some(1), filter.min_available_tickets);
+        }
+
+        #[tokio::test]
+        async fn rejects_unknown_places() {
+            let storage = browsable_storage();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let scope_result = LocationService
+                .scope_to_place(
+                    &Ref {
+                        id: 5,
+                        ref_type: RefType::Section,
+                    },
+                    EventFilter::default(),
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await;
+
+            assert!(matches!(scope_result, Err(LocationError::NotFound(_))));
+        }
+    }
+
+    mod merge_places {
+        use super::*;
+        use crate::domain::location::driving_ports::LocationPort;
+        use crate::external_connections;
+
+        #[tokio::test]
+        async fn merges_rooms_in_the_same_building() {
+            let storage = browsable_storage();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            LocationService
+                .merge_places(
+                    &Ref {
+                        id: 5,
+                        ref_type: RefType::Room,
+                    },
+                    4,
+                    &storage,
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("the rooms should merge");
+
+            let locked_storage = storage
+                .lock()
+                .expect("Failed to lock location storage during assertions");
+            assert_eq!(vec![(RefType::Room, 5, 4)], locked_storage.merges);
+        }
+
+        #[tokio::test]
+        async fn rejects_invalid_merges() {
+            let storage = browsable_storage();
+            storage
+                .lock()
+                .expect("Failed to lock location storage during setup")
+                .rooms
+                .push(RoomOnly {
+                    id: 6,
+                    location_id: 2,
+                    name: "Ballroom".to_owned(),
+                });
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let room = |id| Ref {
+                id,
+                ref_type: RefType::Room,
+            };
+
+            let into_self = LocationService
+                .merge_places(&room(5), 5, &storage, &storage, &mut ext_cxn)
+                .await;
+            let across_buildings = LocationService
+                .merge_places(&room(5), 6, &storage, &storage, &mut ext_cxn)
+                .await;
+            let missing_duplicate = LocationService
+                .merge_places(&room(5), 99, &storage, &storage, &mut ext_cxn)
+                .await;
+
+            assert!(matches!(
+                into_self,
+                Err(LocationUpdateError::MergeIntoSelf(RefType::Room))
+            ));
+            assert!(matches!(
+                across_buildings,
+                Err(LocationUpdateError::DifferentParents)
+            ));
+            assert!(matches!(
+                missing_duplicate,
+                Err(LocationUpdateError::NotFound(Ref { id: 99, .. }))
+            ));
+            assert!(
+                storage
+                    .lock()
+                    .expect("Failed to lock location storage during assertions")
+                    .merges
+                    .is_empty()
+            );
+        }
     }
 }
 
@@ -774,6 +1584,9 @@ mod test_util {
         pub rooms: Vec<RoomOnly>,
         pub sections: Vec<SectionOnly>,
         pub location_storage_failures: LocationStorageFuncs,
+        pub aliases: LocationAliases,
+        /// Merges requested through the LocationMerger, as (type, target ID, duplicate ID)
+        pub merges: Vec<(RefType, i32, i32)>,
     }
 
     impl LocationReader for Mutex<FakeLocationStorage> {
@@ -927,6 +1740,93 @@ mod test_util {
 
             Ok(detected_sections)
         }
+
+        async fn read_location_aliases(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<LocationAliases, anyhow::Error> {
+            Ok(self
+                .lock()
+                .expect("could not lock location storage for reading aliases")
+                .aliases
+                .clone())
+        }
+    }
+
+    impl LocationMerger for Mutex<FakeLocationStorage> {
+        async fn merge_locations(
+            &self,
+            target_id: i32,
+            duplicate_id: i32,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            self.lock()
+                .expect("could not lock location storage for merging locations")
+                .merges
+                .push((RefType::Location, target_id, duplicate_id));
+            Ok(())
+        }
+
+        async fn merge_rooms(
+            &self,
+            target_id: i32,
+            duplicate_id: i32,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            self.lock()
+                .expect("could not lock location storage for merging rooms")
+                .merges
+                .push((RefType::Room, target_id, duplicate_id));
+            Ok(())
+        }
+
+        async fn merge_sections(
+            &self,
+            target_id: i32,
+            duplicate_id: i32,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            self.lock()
+                .expect("could not lock location storage for merging sections")
+                .merges
+                .push((RefType::Section, target_id, duplicate_id));
+            Ok(())
+        }
+    }
+
+    impl HierarchyReader for Mutex<FakeLocationStorage> {
+        async fn all_locations(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<LocationOnly>, anyhow::Error> {
+            Ok(self
+                .lock()
+                .expect("could not lock location storage for reading locations")
+                .locations
+                .clone())
+        }
+
+        async fn all_rooms(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<RoomOnly>, anyhow::Error> {
+            Ok(self
+                .lock()
+                .expect("could not lock location storage for reading rooms")
+                .rooms
+                .clone())
+        }
+
+        async fn all_sections(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SectionOnly>, anyhow::Error> {
+            Ok(self
+                .lock()
+                .expect("could not lock location storage for reading sections")
+                .sections
+                .clone())
+        }
     }
 
     impl LocationWriter for Mutex<FakeLocationStorage> {
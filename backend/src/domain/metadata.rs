@@ -1,19 +1,50 @@
 #![expect(dead_code)]
 
 use crate::domain::event::IngestEvent;
+use crate::domain::location::{MAX_ALIAS_LENGTH, alias_key, normalize_name};
+use crate::domain::search::driven_ports::SearchIndexer;
 use crate::domain::unique;
 use crate::domain::unique::ConstructUniqueStr;
 use crate::domain::unique::driven_ports::UniqueStringSaver;
 use crate::external_connections::ExternalConnectivity;
 use anyhow::Context;
-use std::collections::HashSet;
+use derive_more::{Display, Error};
+use driven_ports::{MaterialItemLinker, MetadataAliasReader, MetadataAliasWriter, MetadataMerger};
+use std::collections::{HashMap, HashSet};
 
 /// Represents a unique GenCon event type (e.g., RPG, BGM)
 pub struct EventType {
     pub id: i32,
+    /// Label the type was imported with, such as "BGM - Board Game"
     pub name: String,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+/// The parts of an event type label as GenCon lists it, such as "BGM - Board Game"
+pub struct EventTypeLabel<'label> {
+    /// Short code identifying the type, such as BGM
+    pub code: &'label str,
+    /// Human-readable name of the type, such as Board Game
+    pub name: &'label str,
+}
+
+impl<'label> EventTypeLabel<'label> {
+    /// Splits a label into its code and name. Labels without a separator are used as both.
+    pub fn parse(label: &'label str) -> Self {
+        let label = label.trim();
+        match label.split_once(" - ") {
+            Some((code, name)) if !code.trim().is_empty() && !name.trim().is_empty() => Self {
+                code: code.trim(),
+                name: name.trim(),
+            },
+            _ => Self {
+                code: label,
+                name: label,
+            },
+        }
+    }
+}
+
 impl ConstructUniqueStr<i32> for EventType {
     fn new_with_id(id: i32, value: String) -> Self {
         Self { id, name: value }
@@ -84,6 +115,92 @@ impl ConstructUniqueStr<i64> for Materials {
     }
 }
 
+/// Represents a single item listed in materials descriptions, such as "dice"
+pub struct MaterialItem {
+    pub id: i64,
+    pub item_name: String,
+}
+
+impl ConstructUniqueStr<i64> for MaterialItem {
+    fn new_with_id(id: i64, value: String) -> Self {
+        Self {
+            id,
+            item_name: value,
+        }
+    }
+}
+
+/// Descriptions meaning players don't need to bring anything, compared after normalizing
+const NO_MATERIALS_REQUIRED: [&str; 16] = [
+    "none",
+    "none required",
+    "none needed",
+    "nothing",
+    "nothing required",
+    "nothing needed",
+    "n/a",
+    "na",
+    "no",
+    "no materials",
+    "no materials required",
+    "no materials needed",
+    "all materials provided",
+    "materials provided",
+    "everything provided",
+    "everything is provided",
+];
+
+/// Words asking players to bring an item which aren't part of the item's name
+const MATERIAL_ITEM_PREFIXES: [&str; 7] = [
+    "please bring ",
+    "bring ",
+    "your own ",
+    "a ",
+    "an ",
+    "some ",
+    "the ",
+];
+
+/// Longest material item name which can be stored
+const MAX_MATERIAL_ITEM_LENGTH: usize = 512;
+
+/// Splits a materials description into the individual items it lists.
+///
+/// Items are separated by commas, semicolons, line breaks, ampersands, or the word "and". Each
+/// item is lowercased with whitespace collapsed, leading words like "bring" or "a" and trailing
+/// punctuation are dropped, and repeated items are kept once. Descriptions like "None" or "All
+/// materials provided" list no items, meaning no materials are required.
+pub fn parse_material_items(summary: &str) -> Vec<String> {
+    let normalized_summary = summary
+        .to_lowercase()
+        .split_whitespace()
+        .map(|word| if word == "and" { ";" } else { word })
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let mut items: Vec<String> = Vec::new();
+    for entry in normalized_summary.split([',', ';', '&']) {
+        let mut item = entry.trim().trim_end_matches(['.', '!']).trim();
+        while let Some(prefix) = MATERIAL_ITEM_PREFIXES
+            .iter()
+            .find(|prefix| item.starts_with(*prefix))
+        {
+            item = item[prefix.len()..].trim_start();
+        }
+
+        if !item.chars().any(char::is_alphabetic)
+            || NO_MATERIALS_REQUIRED.contains(&item)
+            || item.chars().count() > MAX_MATERIAL_ITEM_LENGTH
+            || items.iter().any(|existing| existing == item)
+        {
+            continue;
+        }
+        items.push(item.to_owned());
+    }
+
+    items
+}
+
 #[derive(Clone, Debug)]
 /// A de-duplicated collection of string metadata extracted from incoming events
 pub struct UniqueMetadataToSave<'incoming_data> {
@@ -130,12 +247,14 @@ impl<'ie> From<&'ie [IngestEvent]> for UniqueMetadataToSave<'ie> {
     }
 }
 
-/// The set of metadata records that were ensured to exist during an import
-pub struct SavedMetadata {
-    pub event_types: Vec<EventType>,
-    pub game_systems: Vec<GameSystem>,
+/// The set of metadata records that were ensured to exist during an import. Event types are saved
+/// by code, and game systems and groups under their canonical names, so their IDs are keyed by
+/// each incoming spelling.
+pub struct SavedMetadata<'incoming_data> {
+    pub event_type_ids: HashMap<&'incoming_data str, i32>,
+    pub game_system_ids: HashMap<&'incoming_data str, i64>,
     pub contacts: Vec<Contact>,
-    pub groups: Vec<Group>,
+    pub group_ids: HashMap<&'incoming_data str, i64>,
     pub websites: Vec<Website>,
     pub materials: Vec<Materials>,
 }
@@ -150,29 +269,414 @@ pub struct Metadata {
     pub group: Option<Group>,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Display, Debug)]
+/// The kinds of metadata whose spelling variants can be aliased and merged
+pub enum MetadataKind {
+    #[display("Game system")]
+    GameSystem,
+    Group,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Alternate spellings of game systems and groups along with the canonical name each one refers
+/// to. Aliases are keyed by their [alias_key].
+pub struct MetadataAliases {
+    pub game_systems: HashMap<String, String>,
+    pub groups: HashMap<String, String>,
+}
+
+impl MetadataAliases {
+    /// Cleans up the spelling of a game system or group name and swaps aliases for the canonical
+    /// name
+    pub fn canonical_name(&self, kind: MetadataKind, name: &str) -> String {
+        let name = normalize_name(name);
+        let aliases = match kind {
+            MetadataKind::GameSystem => &self.game_systems,
+            MetadataKind::Group => &self.groups,
+        };
+
+        aliases.get(&name.to_lowercase()).cloned().unwrap_or(name)
+    }
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while maintaining game system and group aliases or merging duplicates
+pub enum MetadataUpdateError {
+    #[display("{_0} with ID {_1} does not exist")]
+    NotFound(#[error(not(source))] MetadataKind, i64),
+    #[display("A {} cannot be merged into itself", _0.to_string().to_lowercase())]
+    MergeIntoSelf(#[error(not(source))] MetadataKind),
+    #[display("Aliases must contain between 1 and {MAX_ALIAS_LENGTH} characters")]
+    InvalidAlias,
+    #[display("{_0} with ID {_1} has no alias \"{_2}\"")]
+    AliasNotFound(#[error(not(source))] MetadataKind, i64, String),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Driven port for reading the aliases of game systems and groups
+    pub trait MetadataAliasReader: Sync {
+        /// Reads every configured alias along with the canonical name it refers to
+        async fn read_metadata_aliases(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<MetadataAliases, anyhow::Error>;
+    }
+
+    /// Driven port for recording which items each materials description lists
+    pub trait MaterialItemLinker: Sync {
+        /// Replaces the items linked to each materials description with the passed item IDs.
+        /// Descriptions mapped to no items are left with none.
+        async fn link_material_items(
+            &self,
+            item_ids_by_materials: &HashMap<i64, Vec<i64>>,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+    }
+
+    /// Driven port for maintaining the aliases of game systems and groups
+    pub trait MetadataAliasWriter: Sync {
+        /// Points the alias (already in [alias_key] form) at the record, replacing whatever it
+        /// pointed at before. Returns false if the record doesn't exist.
+        async fn save_alias(
+            &self,
+            kind: MetadataKind,
+            id: i64,
+            alias: &str,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Removes the alias from the record. Returns false if the record had no such alias.
+        async fn delete_alias(
+            &self,
+            kind: MetadataKind,
+            id: i64,
+            alias: &str,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+
+    /// Driven port for folding duplicate game systems and groups into a canonical record. A merge
+    /// moves the duplicate's events and aliases over to the target, records the duplicate's name
+    /// as an alias of the target, and deletes the duplicate.
+    pub trait MetadataMerger: Sync {
+        /// Returns which of the passed IDs belong to existing records
+        async fn existing_records(
+            &self,
+            kind: MetadataKind,
+            ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error>;
+
+        /// Merges the duplicate into the target, returning the IDs of the events which moved
+        async fn merge_records(
+            &self,
+            kind: MetadataKind,
+            target_id: i64,
+            duplicate_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for cleaning up duplicate game systems and groups
+    pub trait MetadataPort: Sync {
+        /// Lists every configured alias along with the canonical name it refers to
+        async fn metadata_aliases(
+            &self,
+            reader: &impl MetadataAliasReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<MetadataAliases, anyhow::Error>;
+
+        /// Makes imports treat the alias as another name for the game system or group
+        async fn set_alias(
+            &self,
+            kind: MetadataKind,
+            id: i64,
+            alias: &str,
+            writer: &impl MetadataAliasWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), MetadataUpdateError>;
+
+        /// Stops imports from treating the alias as another name for the game system or group
+        async fn remove_alias(
+            &self,
+            kind: MetadataKind,
+            id: i64,
+            alias: &str,
+            writer: &impl MetadataAliasWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), MetadataUpdateError>;
+
+        /// Folds a duplicate game system or group into the target, moving its events over and
+        /// refreshing their search documents to carry the target's name
+        async fn merge_records(
+            &self,
+            kind: MetadataKind,
+            target_id: i64,
+            duplicate_id: i64,
+            merger: &impl MetadataMerger,
+            search_indexer: &impl SearchIndexer,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), MetadataUpdateError>;
+    }
+}
+
+/// Service implementation of the MetadataPort
+pub struct MetadataService;
+
+impl driving_ports::MetadataPort for MetadataService {
+    #[tracing::instrument(skip_all)]
+    async fn metadata_aliases(
+        &self,
+        reader: &impl MetadataAliasReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<MetadataAliases, anyhow::Error> {
+        reader
+            .read_metadata_aliases(ext_cxn)
+            .await
+            .context("Reading metadata aliases")
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn set_alias(
+        &self,
+        kind: MetadataKind,
+        id: i64,
+        alias: &str,
+        writer: &impl MetadataAliasWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), MetadataUpdateError> {
+        let alias = alias_key(alias);
+        if alias.is_empty() || alias.chars().count() > MAX_ALIAS_LENGTH {
+            return Err(MetadataUpdateError::InvalidAlias);
+        }
+
+        let record_exists = writer
+            .save_alias(kind, id, &alias, ext_cxn)
+            .await
+            .context("Saving metadata alias")
+            .map_err(MetadataUpdateError::PortError)?;
+        if !record_exists {
+            return Err(MetadataUpdateError::NotFound(kind, id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn remove_alias(
+        &self,
+        kind: MetadataKind,
+        id: i64,
+        alias: &str,
+        writer: &impl MetadataAliasWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), MetadataUpdateError> {
+        let alias = alias_key(alias);
+        let deleted = writer
+            .delete_alias(kind, id, &alias, ext_cxn)
+            .await
+            .context("Deleting metadata alias")
+            .map_err(MetadataUpdateError::PortError)?;
+        if !deleted {
+            return Err(MetadataUpdateError::AliasNotFound(kind, id, alias));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, merger, search_indexer, ext_cxn))]
+    async fn merge_records(
+        &self,
+        kind: MetadataKind,
+        target_id: i64,
+        duplicate_id: i64,
+        merger: &impl MetadataMerger,
+        search_indexer: &impl SearchIndexer,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), MetadataUpdateError> {
+        if target_id == duplicate_id {
+            return Err(MetadataUpdateError::MergeIntoSelf(kind));
+        }
+
+        let existing_ids = merger
+            .existing_records(kind, &[target_id, duplicate_id], &mut *ext_cxn)
+            .await
+            .context("Checking merged records exist")
+            .map_err(MetadataUpdateError::PortError)?;
+        if let Some(missing_id) = [target_id, duplicate_id]
+            .into_iter()
+            .find(|id| !existing_ids.contains(id))
+        {
+            return Err(MetadataUpdateError::NotFound(kind, missing_id));
+        }
+
+        let moved_event_ids = merger
+            .merge_records(kind, target_id, duplicate_id, &mut *ext_cxn)
+            .await
+            .context("Merging records")
+            .map_err(MetadataUpdateError::PortError)?;
+        if !moved_event_ids.is_empty() {
+            search_indexer
+                .refresh_search_documents(&moved_event_ids, ext_cxn)
+                .await
+                .context("Refreshing search documents of merged events")
+                .map_err(MetadataUpdateError::PortError)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Saves the canonical form of each incoming game system or group name, returning the ID of the
+/// record each incoming spelling ended up as
+async fn save_canonical_names<'incoming_data, DomainType: ConstructUniqueStr<i64>>(
+    incoming_names: &[&'incoming_data str],
+    kind: MetadataKind,
+    aliases: &MetadataAliases,
+    saver: &impl UniqueStringSaver<i64, DomainType>,
+    id_of: impl Fn(&DomainType) -> i64,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<HashMap<&'incoming_data str, i64>, anyhow::Error> {
+    let canonical_names: Vec<String> = incoming_names
+        .iter()
+        .map(|name| aliases.canonical_name(kind, name))
+        .collect();
+    // Several spellings can share a canonical name, which must only be saved once
+    let mut seen_names: HashSet<&str> = HashSet::new();
+    let unique_names: Vec<&str> = canonical_names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| seen_names.insert(name))
+        .collect();
+
+    let saved_records = unique::save_or_get_unique_str(&unique_names, saver, ext_cxn).await?;
+    let ids_by_canonical_name: HashMap<&str, i64> = unique_names
+        .into_iter()
+        .zip(saved_records.iter().map(id_of))
+        .collect();
+
+    Ok(incoming_names
+        .iter()
+        .zip(canonical_names.iter())
+        .map(|(incoming_name, canonical_name)| {
+            (
+                *incoming_name,
+                ids_by_canonical_name[canonical_name.as_str()],
+            )
+        })
+        .collect())
+}
+
+/// Saves the event types the incoming labels refer to. Labels sharing a code share a record, so
+/// only the first label listing a new code is saved. Returns the ID of each incoming label.
+async fn save_event_types<'incoming_data>(
+    labels: &[&'incoming_data str],
+    saver: &impl UniqueStringSaver<i32, EventType>,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<HashMap<&'incoming_data str, i32>, anyhow::Error> {
+    let mut labels_by_code: HashMap<&str, &str> = HashMap::new();
+    for label in labels {
+        labels_by_code
+            .entry(EventTypeLabel::parse(label).code)
+            .or_insert(label);
+    }
+    let unique_labels: Vec<&str> = labels_by_code.into_values().collect();
+    let saved_types = unique::save_or_get_unique_str(&unique_labels, saver, ext_cxn).await?;
+    let ids_by_code: HashMap<&str, i32> = unique_labels
+        .iter()
+        .zip(saved_types.iter())
+        .map(|(label, saved_type)| (EventTypeLabel::parse(label).code, saved_type.id))
+        .collect();
+
+    Ok(labels
+        .iter()
+        .map(|label| (*label, ids_by_code[EventTypeLabel::parse(label).code]))
+        .collect())
+}
+
+#[tracing::instrument(skip_all)]
+/// Splits each materials description into its items, saves any new items, and links every
+/// description to the items it lists
+async fn save_material_items(
+    materials: &[Materials],
+    item_saver: &impl UniqueStringSaver<i64, MaterialItem>,
+    item_linker: &impl MaterialItemLinker,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<(), anyhow::Error> {
+    let items_by_materials: Vec<(i64, Vec<String>)> = materials
+        .iter()
+        .map(|materials| (materials.id, parse_material_items(&materials.summary)))
+        .collect();
+    let unique_items: Vec<&str> = items_by_materials
+        .iter()
+        .flat_map(|(_, items)| items.iter().map(String::as_str))
+        .collect::<HashSet<&str>>()
+        .into_iter()
+        .collect();
+
+    let saved_items = unique::save_or_get_unique_str(&unique_items, item_saver, &mut *ext_cxn)
+        .await
+        .context("saving items")?;
+    let item_ids_by_name: HashMap<&str, i64> = saved_items
+        .iter()
+        .map(|item| (item.item_name.as_str(), item.id))
+        .collect();
+    let item_ids_by_materials: HashMap<i64, Vec<i64>> = items_by_materials
+        .iter()
+        .map(|(materials_id, items)| {
+            let item_ids = items
+                .iter()
+                .map(|item| item_ids_by_name[item.as_str()])
+                .collect();
+            (*materials_id, item_ids)
+        })
+        .collect();
+
+    item_linker
+        .link_material_items(&item_ids_by_materials, ext_cxn)
+        .await
+        .context("linking materials to items")
+}
+
 #[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip_all)]
-/// Ensures referenced metadata strings exist (creating as needed) and returns their records
-pub(super) async fn save_metadata(
-    metadata: UniqueMetadataToSave<'_>,
+/// Ensures referenced metadata strings exist (creating as needed) and returns their records.
+/// Game system and group names are normalized and aliases are swapped for their canonical names
+/// first, so spelling variants share a record.
+pub(super) async fn save_metadata<'incoming_data>(
+    metadata: UniqueMetadataToSave<'incoming_data>,
+    alias_reader: &impl MetadataAliasReader,
     evt_type_saver: &impl UniqueStringSaver<i32, EventType>,
     gamesys_saver: &impl UniqueStringSaver<i64, GameSystem>,
     contact_saver: &impl UniqueStringSaver<i64, Contact>,
     group_saver: &impl UniqueStringSaver<i64, Group>,
     websites_saver: &impl UniqueStringSaver<i64, Website>,
     materials_saver: &impl UniqueStringSaver<i64, Materials>,
+    material_item_saver: &impl UniqueStringSaver<i64, MaterialItem>,
+    material_item_linker: &impl MaterialItemLinker,
     ext_cxn: &mut impl ExternalConnectivity,
-) -> Result<SavedMetadata, anyhow::Error> {
-    let event_types = unique::save_or_get_unique_str(
-        metadata.event_types.as_ref(),
-        evt_type_saver,
-        &mut *ext_cxn,
-    )
-    .await
-    .context("saving event types")?;
-    let game_systems = unique::save_or_get_unique_str(
+) -> Result<SavedMetadata<'incoming_data>, anyhow::Error> {
+    let aliases = alias_reader
+        .read_metadata_aliases(&mut *ext_cxn)
+        .await
+        .context("reading metadata aliases")?;
+    let event_type_ids =
+        save_event_types(metadata.event_types.as_ref(), evt_type_saver, &mut *ext_cxn)
+            .await
+            .context("saving event types")?;
+    let game_system_ids = save_canonical_names(
         metadata.game_systems.as_ref(),
+        MetadataKind::GameSystem,
+        &aliases,
         gamesys_saver,
+        |system: &GameSystem| system.id,
         &mut *ext_cxn,
     )
     .await
@@ -181,10 +685,16 @@ pub(super) async fn save_metadata(
         unique::save_or_get_unique_str(metadata.contacts.as_ref(), contact_saver, &mut *ext_cxn)
             .await
             .context("saving contacts")?;
-    let groups =
-        unique::save_or_get_unique_str(metadata.groups.as_ref(), group_saver, &mut *ext_cxn)
-            .await
-            .context("saving groups")?;
+    let group_ids = save_canonical_names(
+        metadata.groups.as_ref(),
+        MetadataKind::Group,
+        &aliases,
+        group_saver,
+        |group: &Group| group.id,
+        &mut *ext_cxn,
+    )
+    .await
+    .context("saving groups")?;
     let websites =
         unique::save_or_get_unique_str(metadata.websites.as_ref(), websites_saver, &mut *ext_cxn)
             .await
@@ -193,13 +703,470 @@ pub(super) async fn save_metadata(
         unique::save_or_get_unique_str(metadata.materials.as_ref(), materials_saver, &mut *ext_cxn)
             .await
             .context("saving materials")?;
+    save_material_items(
+        &materials,
+        material_item_saver,
+        material_item_linker,
+        &mut *ext_cxn,
+    )
+    .await
+    .context("saving material items")?;
 
     Ok(SavedMetadata {
-        event_types,
-        game_systems,
+        event_type_ids,
+        game_system_ids,
         contacts,
-        groups,
+        group_ids,
         websites,
         materials,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::unique::test_util::FakeStringSaver;
+    use crate::external_connections;
+    use speculoos::prelude::*;
+    use std::sync::Mutex;
+
+    mod save_metadata {
+        use super::*;
+
+        #[tokio::test]
+        async fn collapses_spelling_variants_and_aliases() {
+            let alias_storage = Mutex::new(test_util::FakeMetadataStorage {
+                aliases: MetadataAliases {
+                    game_systems: HashMap::from([("pf2".to_owned(), "Pathfinder 2e".to_owned())]),
+                    groups: HashMap::from([("ttg".to_owned(), "Tabletop Guild".to_owned())]),
+                },
+                ..Default::default()
+            });
+            let evt_type_saver: Mutex<FakeStringSaver<i32>> =
+                FakeStringSaver::new_locked(|saver| {
+                    saver.saved_strings = vec![(1, "RPG".to_owned())];
+                });
+            let gamesys_saver: Mutex<FakeStringSaver<i64>> = FakeStringSaver::new_locked(|saver| {
+                saver.saved_strings = vec![(1, "Pathfinder 2e".to_owned())];
+            });
+            let group_saver: Mutex<FakeStringSaver<i64>> = FakeStringSaver::new_locked(|saver| {
+                saver.saved_strings = vec![(4, "Dice Tower".to_owned())];
+            });
+            let other_saver: Mutex<FakeStringSaver<i64>> = FakeStringSaver::new_locked(|saver| {
+                saver.saved_strings = vec![(1, "unused".to_owned())];
+            });
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let saved_metadata = save_metadata(
+                UniqueMetadataToSave {
+                    event_types: vec![],
+                    game_systems: vec!["Pathfinder 2e", " Pathfinder  2e ", "PF2", "1830"],
+                    contacts: vec![],
+                    groups: vec!["Tabletop Guild", "TTG"],
+                    websites: vec![],
+                    materials: vec![],
+                },
+                &alias_storage,
+                &evt_type_saver,
+                &gamesys_saver,
+                &other_saver,
+                &group_saver,
+                &other_saver,
+                &other_saver,
+                &other_saver,
+                &alias_storage,
+                &mut ext_cxn,
+            )
+            .await
+            .expect("saving metadata should succeed");
+
+            assert_that!(saved_metadata.game_system_ids).is_equal_to(HashMap::from([
+                ("Pathfinder 2e", 1),
+                (" Pathfinder  2e ", 1),
+                ("PF2", 1),
+                ("1830", 2),
+            ]));
+            assert_that!(saved_metadata.group_ids)
+                .is_equal_to(HashMap::from([("Tabletop Guild", 5), ("TTG", 5)]));
+            assert_eq!(
+                vec![(1, "Pathfinder 2e".to_owned()), (2, "1830".to_owned())],
+                gamesys_saver
+                    .lock()
+                    .expect("Failed to lock game system saver during assertions")
+                    .saved_strings
+            );
+        }
+    }
+
+    mod save_material_items {
+        use super::*;
+
+        #[tokio::test]
+        async fn links_descriptions_to_shared_items() {
+            let storage = Mutex::new(test_util::FakeMetadataStorage::default());
+            let item_saver: Mutex<FakeStringSaver<i64>> = FakeStringSaver::new_locked(|saver| {
+                saver.saved_strings = vec![(1, "dice".to_owned())];
+            });
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            save_material_items(
+                &[
+                    Materials {
+                        id: 10,
+                        summary: "Dice, pencil".to_owned(),
+                    },
+                    Materials {
+                        id: 11,
+                        summary: "Bring your own dice.".to_owned(),
+                    },
+                    Materials {
+                        id: 12,
+                        summary: "None".to_owned(),
+                    },
+                ],
+                &item_saver,
+                &storage,
+                &mut ext_cxn,
+            )
+            .await
+            .expect("saving material items should succeed");
+
+            let locked_storage = storage
+                .lock()
+                .expect("Failed to lock metadata storage during assertions");
+            assert_that!(locked_storage.material_items).is_equal_to(HashMap::from([
+                (10, vec![1, 2]),
+                (11, vec![1]),
+                (12, vec![]),
+            ]));
+        }
+    }
+
+    mod parse_material_items {
+        use super::*;
+
+        #[test]
+        fn splits_and_normalizes_items() {
+            assert_eq!(
+                vec![
+                    "dice".to_owned(),
+                    "pencil".to_owned(),
+                    "character sheet provided".to_owned(),
+                ],
+                parse_material_items("Dice,  Pencil and a character sheet provided.")
+            );
+            assert_eq!(
+                vec!["paper".to_owned(), "dice".to_owned()],
+                parse_material_items("Please bring paper; DICE & dice\n")
+            );
+        }
+
+        #[test]
+        fn lists_nothing_when_no_materials_are_required() {
+            for summary in [
+                "None",
+                " none required. ",
+                "N/A",
+                "All materials provided!",
+                "-",
+            ] {
+                assert_that!(parse_material_items(summary)).is_empty();
+            }
+        }
+    }
+
+    mod save_event_types {
+        use super::*;
+
+        #[tokio::test]
+        async fn labels_sharing_a_code_share_a_record() {
+            let evt_type_saver: Mutex<FakeStringSaver<i32>> =
+                FakeStringSaver::new_locked(|saver| {
+                    saver.saved_strings = vec![(1, "RPG - Role Playing Game".to_owned())];
+                });
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let type_ids = save_event_types(
+                &[
+                    "RPG - Role Playing Game",
+                    "BGM - Board Game",
+                    "BGM - Board Games",
+                ],
+                &evt_type_saver,
+                &mut ext_cxn,
+            )
+            .await
+            .expect("saving event types should succeed");
+
+            assert_that!(type_ids).is_equal_to(HashMap::from([
+                ("RPG - Role Playing Game", 1),
+                ("BGM - Board Game", 2),
+                ("BGM - Board Games", 2),
+            ]));
+            assert_eq!(
+                2,
+                evt_type_saver
+                    .lock()
+                    .expect("Failed to lock event type saver during assertions")
+                    .saved_strings
+                    .len()
+            );
+        }
+    }
+
+    mod event_type_label {
+        use super::*;
+
+        #[test]
+        fn splits_codes_from_names() {
+            assert_eq!(
+                EventTypeLabel {
+                    code: "BGM",
+                    name: "Board Game",
+                },
+                EventTypeLabel::parse(" BGM - Board Game ")
+            );
+            assert_eq!(
+                EventTypeLabel {
+                    code: "Seminar",
+                    name: "Seminar",
+                },
+                EventTypeLabel::parse("Seminar")
+            );
+            assert_eq!(
+                EventTypeLabel {
+                    code: "- Oddity",
+                    name: "- Oddity",
+                },
+                EventTypeLabel::parse("- Oddity")
+            );
+        }
+    }
+
+    mod merge_records {
+        use super::*;
+        use crate::domain::metadata::driving_ports::MetadataPort;
+
+        fn storage_with_duplicates() -> Mutex<test_util::FakeMetadataStorage> {
+            Mutex::new(test_util::FakeMetadataStorage {
+                records: vec![
+                    (MetadataKind::GameSystem, 1),
+                    (MetadataKind::GameSystem, 2),
+                    (MetadataKind::Group, 3),
+                ],
+                events: vec![
+                    (10, MetadataKind::GameSystem, 1),
+                    (11, MetadataKind::GameSystem, 2),
+                    (12, MetadataKind::GameSystem, 2),
+                    (13, MetadataKind::Group, 3),
+                ],
+                ..Default::default()
+            })
+        }
+
+        #[tokio::test]
+        async fn moves_events_and_refreshes_their_search_documents() {
+            let storage = storage_with_duplicates();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            MetadataService
+                .merge_records(
+                    MetadataKind::GameSystem,
+                    1,
+                    2,
+                    &storage,
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("the game systems should merge");
+
+            let locked_storage = storage
+                .lock()
+                .expect("Failed to lock metadata storage during assertions");
+            assert_eq!(
+                vec![(MetadataKind::GameSystem, 1, 2)],
+                locked_storage.merges
+            );
+            assert_eq!(vec![11, 12], locked_storage.refreshed_event_ids);
+        }
+
+        #[tokio::test]
+        async fn rejects_invalid_merges() {
+            let storage = storage_with_duplicates();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let into_self = MetadataService
+                .merge_records(
+                    MetadataKind::GameSystem,
+                    1,
+                    1,
+                    &storage,
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await;
+            let across_kinds = MetadataService
+                .merge_records(
+                    MetadataKind::GameSystem,
+                    1,
+                    3,
+                    &storage,
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await;
+            let missing_target = MetadataService
+                .merge_records(MetadataKind::Group, 99, 3, &storage, &storage, &mut ext_cxn)
+                .await;
+
+            assert!(matches!(
+                into_self,
+                Err(MetadataUpdateError::MergeIntoSelf(MetadataKind::GameSystem))
+            ));
+            assert!(matches!(
+                across_kinds,
+                Err(MetadataUpdateError::NotFound(MetadataKind::GameSystem, 3))
+            ));
+            assert!(matches!(
+                missing_target,
+                Err(MetadataUpdateError::NotFound(MetadataKind::Group, 99))
+            ));
+            let locked_storage = storage
+                .lock()
+                .expect("Failed to lock metadata storage during assertions");
+            assert!(locked_storage.merges.is_empty());
+            assert!(locked_storage.refreshed_event_ids.is_empty());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake of game system and group storage, along with the events referencing them
+    pub struct FakeMetadataStorage {
+        pub connectivity: Connectivity,
+        pub records: Vec<(MetadataKind, i64)>,
+        pub aliases: MetadataAliases,
+        /// Events as (event ID, kind, ID of the referenced game system or group)
+        pub events: Vec<(i64, MetadataKind, i64)>,
+        /// Merges requested through the MetadataMerger, as (kind, target ID, duplicate ID)
+        pub merges: Vec<(MetadataKind, i64, i64)>,
+        pub refreshed_event_ids: Vec<i64>,
+        /// IDs of the items linked to each materials description
+        pub material_items: HashMap<i64, Vec<i64>>,
+    }
+
+    impl Default for FakeMetadataStorage {
+        fn default() -> Self {
+            Self {
+                connectivity: Connectivity::Connected,
+                records: Vec::new(),
+                aliases: Default::default(),
+                events: Vec::new(),
+                merges: Vec::new(),
+                refreshed_event_ids: Vec::new(),
+                material_items: HashMap::new(),
+            }
+        }
+    }
+
+    impl MaterialItemLinker for Mutex<FakeMetadataStorage> {
+        async fn link_material_items(
+            &self,
+            item_ids_by_materials: &HashMap<i64, Vec<i64>>,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock metadata storage for linking material items");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self
+                .material_items
+                .extend(item_ids_by_materials.clone());
+
+            Ok(())
+        }
+    }
+
+    impl MetadataAliasReader for Mutex<FakeMetadataStorage> {
+        async fn read_metadata_aliases(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<MetadataAliases, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock metadata storage for reading aliases");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self.aliases.clone())
+        }
+    }
+
+    impl MetadataMerger for Mutex<FakeMetadataStorage> {
+        async fn existing_records(
+            &self,
+            kind: MetadataKind,
+            ids: &[i64],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock metadata storage for reading records");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(ids
+                .iter()
+                .copied()
+                .filter(|id| locked_self.records.contains(&(kind, *id)))
+                .collect())
+        }
+
+        async fn merge_records(
+            &self,
+            kind: MetadataKind,
+            target_id: i64,
+            duplicate_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock metadata storage for merging");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.merges.push((kind, target_id, duplicate_id));
+            locked_self
+                .records
+                .retain(|record| *record != (kind, duplicate_id));
+
+            let mut moved_event_ids: Vec<i64> = Vec::new();
+            for (event_id, event_kind, record_id) in locked_self.events.iter_mut() {
+                if *event_kind == kind && *record_id == duplicate_id {
+                    *record_id = target_id;
+                    moved_event_ids.push(*event_id);
+                }
+            }
+
+            Ok(moved_event_ids)
+        }
+    }
+
+    impl SearchIndexer for Mutex<FakeMetadataStorage> {
+        async fn refresh_search_documents(
+            &self,
+            event_ids: &[i64],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock metadata storage for refreshing search documents");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.refreshed_event_ids.extend_from_slice(event_ids);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use crate::domain::access::Viewer;
+use crate::domain::contact::{ContactOwner, ContactPolicy};
+use crate::domain::metadata::{GameSystem, Group};
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use derive_more::{Display, Error};
+use driven_ports::{GroupReader, GroupWriter};
+
+/// An organizing group along with how many of the current year's uncancelled events it runs
+pub struct GroupSummary {
+    pub group: Group,
+    pub total_events: u64,
+}
+
+/// An organizing group along with what its current year's uncancelled events have in common
+pub struct GroupProfile {
+    pub group: Group,
+    pub total_events: u64,
+    /// The website listed most often on the group's events
+    pub website: Option<String>,
+    /// The contact email listed most often on the group's events. Shown to everyone when the
+    /// group has agreed to share it, and otherwise only to callers the contact policy allows.
+    pub contact: Option<String>,
+    /// Whether the group has agreed to have its contact email shown on its profile
+    pub shares_contact: bool,
+    /// Game systems the group's events use, in name order
+    pub game_systems: Vec<GameSystem>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while browsing or updating organizing groups
+pub enum OrganizerError {
+    #[display("Group with ID {_0} does not exist")]
+    NotFound(#[error(not(source))] i64),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Driven port for reading organizing groups and the events they run
+    pub trait GroupReader: Sync {
+        /// Reads every group along with its event count, ordered by name
+        async fn all_groups(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<GroupSummary>, anyhow::Error>;
+
+        /// Reads the group and a summary of its events, including its contact email whether or
+        /// not the group shares it. Returns None if the group doesn't exist.
+        async fn read_profile(
+            &self,
+            group_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<GroupProfile>, anyhow::Error>;
+    }
+
+    /// Driven port for updating organizing groups
+    pub trait GroupWriter: Sync {
+        /// Records whether the group shares its contact email. Returns false if the group
+        /// doesn't exist.
+        async fn set_contact_sharing(
+            &self,
+            group_id: i64,
+            shares_contact: bool,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for browsing organizing groups
+    pub trait OrganizerPort: Sync {
+        async fn groups(
+            &self,
+            reader: &impl GroupReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<GroupSummary>, anyhow::Error>;
+
+        /// Summarizes the group's events. The contact email is left as is if the group shares
+        /// it, and otherwise shown only as the contact policy allows for the viewer.
+        async fn group_profile(
+            &self,
+            group_id: i64,
+            viewer: Viewer,
+            contact_policy: &ContactPolicy,
+            reader: &impl GroupReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<GroupProfile, OrganizerError>;
+
+        /// Records whether the group agrees to have its contact email shown on its profile
+        async fn set_contact_sharing(
+            &self,
+            group_id: i64,
+            shares_contact: bool,
+            writer: &impl GroupWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), OrganizerError>;
+    }
+}
+
+/// Service implementation of the OrganizerPort
+pub struct OrganizerService;
+
+impl driving_ports::OrganizerPort for OrganizerService {
+    #[tracing::instrument(skip_all)]
+    async fn groups(
+        &self,
+        reader: &impl GroupReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<GroupSummary>, anyhow::Error> {
+        reader.all_groups(ext_cxn).await.context("Reading groups")
+    }
+
+    #[tracing::instrument(skip(self, contact_policy, reader, ext_cxn))]
+    async fn group_profile(
+        &self,
+        group_id: i64,
+        viewer: Viewer,
+        contact_policy: &ContactPolicy,
+        reader: &impl GroupReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<GroupProfile, OrganizerError> {
+        let mut profile = reader
+            .read_profile(group_id, ext_cxn)
+            .await
+            .context("Reading group profile")
+            .map_err(OrganizerError::PortError)?
+            .ok_or(OrganizerError::NotFound(group_id))?;
+        if !profile.shares_contact {
+            profile.contact = contact_policy.visible_contact(
+                profile.contact.take(),
+                ContactOwner::Group(group_id),
+                viewer,
+            );
+        }
+
+        Ok(profile)
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn set_contact_sharing(
+        &self,
+        group_id: i64,
+        shares_contact: bool,
+        writer: &impl GroupWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), OrganizerError> {
+        let group_exists = writer
+            .set_contact_sharing(group_id, shares_contact, ext_cxn)
+            .await
+            .context("Saving group contact sharing")
+            .map_err(OrganizerError::PortError)?;
+        if !group_exists {
+            return Err(OrganizerError::NotFound(group_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    mod groups {
+        use super::*;
+        use crate::domain::organizer::driving_ports::OrganizerPort;
+        use crate::domain::test_util::Connectivity;
+
+        #[tokio::test]
+        async fn lists_stored_groups() {
+            let storage = Mutex::new(test_util::FakeGroupStorage::with_group(7));
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let listed = OrganizerService.groups(&storage, &mut ext_cxn).await;
+            storage
+                .lock()
+                .expect("Failed to lock group storage during setup")
+                .connectivity = Connectivity::Disconnected;
+            let disconnected = OrganizerService.groups(&storage, &mut ext_cxn).await;
+
+            let listed_ids: Vec<i64> = listed
+                .expect("groups should be listed")
+                .iter()
+                .map(|summary| summary.group.id)
+                .collect();
+            assert_eq!(vec![7], listed_ids);
+            assert!(disconnected.is_err());
+        }
+    }
+
+    mod group_profile {
+        use super::*;
+        use crate::domain::organizer::driving_ports::OrganizerPort;
+
+        #[tokio::test]
+        async fn only_shows_anonymous_viewers_contacts_groups_agreed_to_share() {
+            let storage = Mutex::new(test_util::FakeGroupStorage::with_group(7));
+            let policy = ContactPolicy::default();
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let private_profile = OrganizerService
+                .group_profile(7, Viewer::Anonymous, &policy, &storage, &mut ext_cxn)
+                .await
+                .expect("the group should be found");
+            let member_profile = OrganizerService
+                .group_profile(7, Viewer::Member, &policy, &storage, &mut ext_cxn)
+                .await
+                .expect("the group should be found");
+            OrganizerService
+                .set_contact_sharing(7, true, &storage, &mut ext_cxn)
+                .await
+                .expect("the group should agree to share its contact");
+            let shared_profile = OrganizerService
+                .group_profile(7, Viewer::Anonymous, &policy, &storage, &mut ext_cxn)
+                .await
+                .expect("the group should be found");
+
+            assert_eq!(None, private_profile.contact);
+            assert_eq!(
+                Some("organizer@example.com".to_owned()),
+                member_profile.contact
+            );
+            assert_eq!(
+                Some("organizer@example.com".to_owned()),
+                shared_profile.contact
+            );
+            assert_eq!(
+                Some("https://example.com".to_owned()),
+                private_profile.website
+            );
+        }
+
+        #[tokio::test]
+        async fn rejects_unknown_groups() {
+            let storage = Mutex::new(test_util::FakeGroupStorage::with_group(7));
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let profile = OrganizerService
+                .group_profile(
+                    8,
+                    Viewer::Anonymous,
+                    &ContactPolicy::default(),
+                    &storage,
+                    &mut ext_cxn,
+                )
+                .await;
+            let sharing = OrganizerService
+                .set_contact_sharing(8, true, &storage, &mut ext_cxn)
+                .await;
+
+            assert!(matches!(profile, Err(OrganizerError::NotFound(8))));
+            assert!(matches!(sharing, Err(OrganizerError::NotFound(8))));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake of group storage holding a single group with a website and contact
+    pub struct FakeGroupStorage {
+        pub connectivity: Connectivity,
+        pub group_id: i64,
+        pub shares_contact: bool,
+    }
+
+    impl FakeGroupStorage {
+        pub fn with_group(group_id: i64) -> Self {
+            Self {
+                connectivity: Connectivity::Connected,
+                group_id,
+                shares_contact: false,
+            }
+        }
+    }
+
+    impl GroupReader for Mutex<FakeGroupStorage> {
+        async fn all_groups(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<GroupSummary>, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock group storage for listing");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(vec![GroupSummary {
+                group: Group {
+                    id: locked_self.group_id,
+                    name: "Tabletop Guild".to_owned(),
+                },
+                total_events: 3,
+            }])
+        }
+
+        async fn read_profile(
+            &self,
+            group_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<GroupProfile>, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock group storage for reading");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok((group_id == locked_self.group_id).then(|| GroupProfile {
+                group: Group {
+                    id: group_id,
+                    name: "Tabletop Guild".to_owned(),
+                },
+                total_events: 3,
+                website: Some("https://example.com".to_owned()),
+                contact: Some("organizer@example.com".to_owned()),
+                shares_contact: locked_self.shares_contact,
+                game_systems: Vec::new(),
+            }))
+        }
+    }
+
+    impl GroupWriter for Mutex<FakeGroupStorage> {
+        async fn set_contact_sharing(
+            &self,
+            group_id: i64,
+            shares_contact: bool,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock group storage for writing");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            if group_id != locked_self.group_id {
+                return Ok(false);
+            }
+            locked_self.shares_contact = shares_contact;
+
+            Ok(true)
+        }
+    }
+}
@@ -0,0 +1,587 @@
+use crate::domain::search::EventFilter;
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use derive_more::{Display, Error};
+
+/// The most searches a single user can save
+pub const MAX_SAVED_SEARCHES_PER_USER: usize = 50;
+
+#[derive(Debug, Clone)]
+/// Event filters a user saved so they can run them again and hear about new matches
+pub struct SavedSearch {
+    pub id: i64,
+    pub user_id: String,
+    pub name: String,
+    /// The event list query string the search was saved from
+    pub query: String,
+    pub filter: EventFilter,
+    pub created_at: DateTime<Utc>,
+    /// When the user last checked for new matches
+    pub last_checked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+/// A search a user wants to save
+pub struct NewSavedSearch {
+    pub name: String,
+    pub query: String,
+    pub filter: EventFilter,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An event which started matching a saved search
+pub struct SearchMatch {
+    pub event_id: i64,
+    pub title: String,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub cost: Option<u32>,
+    pub tickets_available: u16,
+    pub max_players: u16,
+    /// When an import found the event matching the search
+    pub flagged_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while managing saved searches
+pub enum SavedSearchError {
+    #[display("Saved search with ID {} does not exist", _0)]
+    NotFound(#[error(not(source))] i64),
+    #[display("Users can save at most {} searches", MAX_SAVED_SEARCHES_PER_USER)]
+    TooManySearches,
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Reads saved searches from storage
+    pub trait SavedSearchReader: Sync {
+        /// Lists a user's saved searches, oldest first
+        async fn saved_searches(
+            &self,
+            user_id: &str,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SavedSearch>, anyhow::Error>;
+
+        /// Retrieves one of a user's saved searches, returning None if the user has no search
+        /// with the ID
+        async fn saved_search(
+            &self,
+            user_id: &str,
+            search_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<SavedSearch>, anyhow::Error>;
+
+        /// Lists every user's saved searches
+        async fn all_saved_searches(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SavedSearch>, anyhow::Error>;
+    }
+
+    /// Creates, removes, and checks saved searches in storage
+    pub trait SavedSearchWriter: Sync {
+        async fn create_saved_search(
+            &self,
+            user_id: &str,
+            new_search: &NewSavedSearch,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<SavedSearch, anyhow::Error>;
+
+        /// Deletes one of a user's saved searches. Returns false if the user has no search with
+        /// the ID.
+        async fn delete_saved_search(
+            &self,
+            user_id: &str,
+            search_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Returns the uncancelled matches of the search the user hasn't seen yet, in start time
+        /// order, and marks every match as seen
+        async fn take_new_matches(
+            &self,
+            search_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SearchMatch>, anyhow::Error>;
+    }
+
+    /// Records which events match saved searches
+    pub trait SearchMatchRecorder: Sync {
+        /// Records every event of the latest convention currently matching the search as
+        /// already seen, so only events matching later count as new
+        async fn record_existing_matches(
+            &self,
+            search: &SavedSearch,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+
+        /// Flags the passed events which match the search as new matches. Events already
+        /// recorded as matching are left alone. Returns the number of events flagged.
+        async fn flag_new_matches(
+            &self,
+            search: &SavedSearch,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<u64, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for managing saved searches
+    pub trait SavedSearchPort: Sync {
+        async fn saved_searches(
+            &self,
+            user_id: &str,
+            reader: &impl driven_ports::SavedSearchReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SavedSearch>, anyhow::Error>;
+
+        /// Saves a search for the user. Events matching the search when it's saved aren't
+        /// reported as new matches.
+        async fn save_search(
+            &self,
+            user_id: &str,
+            new_search: &NewSavedSearch,
+            reader: &impl driven_ports::SavedSearchReader,
+            writer: &impl driven_ports::SavedSearchWriter,
+            match_recorder: &impl driven_ports::SearchMatchRecorder,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<SavedSearch, SavedSearchError>;
+
+        async fn delete_saved_search(
+            &self,
+            user_id: &str,
+            search_id: i64,
+            writer: &impl driven_ports::SavedSearchWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), SavedSearchError>;
+
+        /// Returns the saved search along with the events which started matching it since the
+        /// user last checked
+        async fn check_new_matches(
+            &self,
+            user_id: &str,
+            search_id: i64,
+            reader: &impl driven_ports::SavedSearchReader,
+            writer: &impl driven_ports::SavedSearchWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(SavedSearch, Vec<SearchMatch>), SavedSearchError>;
+    }
+}
+
+/// Service implementation of the SavedSearchPort
+pub struct SavedSearchService;
+
+impl driving_ports::SavedSearchPort for SavedSearchService {
+    #[tracing::instrument(skip(self, reader, ext_cxn))]
+    async fn saved_searches(
+        &self,
+        user_id: &str,
+        reader: &impl driven_ports::SavedSearchReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<SavedSearch>, anyhow::Error> {
+        reader
+            .saved_searches(user_id, ext_cxn)
+            .await
+            .context("Reading saved searches")
+    }
+
+    #[tracing::instrument(skip(self, new_search, reader, writer, match_recorder, ext_cxn))]
+    async fn save_search(
+        &self,
+        user_id: &str,
+        new_search: &NewSavedSearch,
+        reader: &impl driven_ports::SavedSearchReader,
+        writer: &impl driven_ports::SavedSearchWriter,
+        match_recorder: &impl driven_ports::SearchMatchRecorder,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<SavedSearch, SavedSearchError> {
+        let existing_searches = reader
+            .saved_searches(user_id, &mut *ext_cxn)
+            .await
+            .context("Counting the user's saved searches")
+            .map_err(SavedSearchError::PortError)?;
+        if existing_searches.len() >= MAX_SAVED_SEARCHES_PER_USER {
+            return Err(SavedSearchError::TooManySearches);
+        }
+
+        let saved_search = writer
+            .create_saved_search(user_id, new_search, &mut *ext_cxn)
+            .await
+            .context("Creating saved search")
+            .map_err(SavedSearchError::PortError)?;
+        match_recorder
+            .record_existing_matches(&saved_search, ext_cxn)
+            .await
+            .context("Recording events already matching the saved search")
+            .map_err(SavedSearchError::PortError)?;
+
+        Ok(saved_search)
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn delete_saved_search(
+        &self,
+        user_id: &str,
+        search_id: i64,
+        writer: &impl driven_ports::SavedSearchWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), SavedSearchError> {
+        let deleted = writer
+            .delete_saved_search(user_id, search_id, ext_cxn)
+            .await
+            .context("Deleting saved search")
+            .map_err(SavedSearchError::PortError)?;
+        if !deleted {
+            return Err(SavedSearchError::NotFound(search_id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, reader, writer, ext_cxn))]
+    async fn check_new_matches(
+        &self,
+        user_id: &str,
+        search_id: i64,
+        reader: &impl driven_ports::SavedSearchReader,
+        writer: &impl driven_ports::SavedSearchWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(SavedSearch, Vec<SearchMatch>), SavedSearchError> {
+        let saved_search = reader
+            .saved_search(user_id, search_id, &mut *ext_cxn)
+            .await
+            .context("Reading saved search")
+            .map_err(SavedSearchError::PortError)?
+            .ok_or(SavedSearchError::NotFound(search_id))?;
+        let new_matches = writer
+            .take_new_matches(search_id, ext_cxn)
+            .await
+            .context("Taking new saved search matches")
+            .map_err(SavedSearchError::PortError)?;
+
+        Ok((saved_search, new_matches))
+    }
+}
+
+#[tracing::instrument(skip_all, fields(total_events = event_ids.len()))]
+/// Flags the imported events matching each saved search so users can be told about them
+pub(super) async fn flag_new_matches(
+    event_ids: &[i64],
+    reader: &impl driven_ports::SavedSearchReader,
+    match_recorder: &impl driven_ports::SearchMatchRecorder,
+    ext_cxn: &mut impl ExternalConnectivity,
+) -> Result<(), anyhow::Error> {
+    if event_ids.is_empty() {
+        return Ok(());
+    }
+
+    let saved_searches = reader
+        .all_saved_searches(&mut *ext_cxn)
+        .await
+        .context("Reading saved searches")?;
+    let mut total_flagged = 0;
+    for saved_search in &saved_searches {
+        total_flagged += match_recorder
+            .flag_new_matches(saved_search, event_ids, &mut *ext_cxn)
+            .await
+            .with_context(|| {
+                format!(
+                    "Flagging new matches of saved search {} for user {}",
+                    saved_search.id, saved_search.user_id
+                )
+            })?;
+    }
+    tracing::info!(
+        total_searches = saved_searches.len(),
+        total_flagged,
+        "Flagged new saved search matches."
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::saved_search::driving_ports::SavedSearchPort;
+    use crate::domain::saved_search::test_util::FakeSavedSearchStore;
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    fn new_search() -> NewSavedSearch {
+        NewSavedSearch {
+            name: "Pathfinder".to_owned(),
+            query: "search-text=pathfinder".to_owned(),
+            filter: EventFilter {
+                search_text: Some("pathfinder".to_owned()),
+                ..Default::default()
+            },
+        }
+    }
+
+    mod save_search {
+        use super::*;
+
+        #[tokio::test]
+        async fn records_existing_matches() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = Mutex::new(FakeSavedSearchStore::new());
+
+            let saved_search = SavedSearchService
+                .save_search("user1", &new_search(), &store, &store, &store, &mut ext_cxn)
+                .await
+                .expect("saving should succeed");
+
+            let locked_store = store.lock().unwrap();
+            assert_eq!(1, locked_store.searches.len());
+            assert_eq!(vec![saved_search.id], locked_store.existing_match_searches);
+        }
+
+        #[tokio::test]
+        async fn limits_searches_per_user() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = Mutex::new(FakeSavedSearchStore::new());
+            for _ in 0..MAX_SAVED_SEARCHES_PER_USER {
+                SavedSearchService
+                    .save_search("user1", &new_search(), &store, &store, &store, &mut ext_cxn)
+                    .await
+                    .expect("saving under the limit should succeed");
+            }
+
+            let save_result = SavedSearchService
+                .save_search("user1", &new_search(), &store, &store, &store, &mut ext_cxn)
+                .await;
+            SavedSearchService
+                .save_search("user2", &new_search(), &store, &store, &store, &mut ext_cxn)
+                .await
+                .expect("other users should have their own limit");
+
+            assert!(matches!(
+                save_result,
+                Err(SavedSearchError::TooManySearches)
+            ));
+        }
+    }
+
+    mod check_new_matches {
+        use super::*;
+
+        #[tokio::test]
+        async fn rejects_other_users_searches() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = Mutex::new(FakeSavedSearchStore::new());
+            let saved_search = SavedSearchService
+                .save_search("user1", &new_search(), &store, &store, &store, &mut ext_cxn)
+                .await
+                .expect("saving should succeed");
+
+            let check_result = SavedSearchService
+                .check_new_matches("user2", saved_search.id, &store, &store, &mut ext_cxn)
+                .await;
+
+            assert!(matches!(check_result, Err(SavedSearchError::NotFound(_))));
+            assert!(store.lock().unwrap().taken_searches.is_empty());
+        }
+    }
+
+    mod flag_new_matches {
+        use super::*;
+
+        #[tokio::test]
+        async fn checks_every_saved_search() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = Mutex::new(FakeSavedSearchStore::new());
+            for user_id in ["user1", "user2"] {
+                SavedSearchService
+                    .save_search(user_id, &new_search(), &store, &store, &store, &mut ext_cxn)
+                    .await
+                    .expect("saving should succeed");
+            }
+
+            super::super::flag_new_matches(&[4, 5], &store, &store, &mut ext_cxn)
+                .await
+                .expect("flagging should succeed");
+
+            assert_eq!(
+                vec![(1, vec![4, 5]), (2, vec![4, 5])],
+                store.lock().unwrap().flagged
+            );
+        }
+
+        #[tokio::test]
+        async fn skips_empty_imports() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = Mutex::new(FakeSavedSearchStore::new());
+            store.lock().unwrap().connectivity =
+                crate::domain::test_util::Connectivity::Disconnected;
+
+            super::super::flag_new_matches(&[], &store, &store, &mut ext_cxn)
+                .await
+                .expect("nothing should be looked up");
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// Fake saved search storage which records the matches it's asked to flag
+    pub struct FakeSavedSearchStore {
+        pub searches: Vec<SavedSearch>,
+        /// IDs of searches whose existing matches were recorded
+        pub existing_match_searches: Vec<i64>,
+        /// IDs of searches whose new matches were taken
+        pub taken_searches: Vec<i64>,
+        /// Search IDs along with the event IDs checked for new matches
+        pub flagged: Vec<(i64, Vec<i64>)>,
+        pub connectivity: Connectivity,
+    }
+
+    impl FakeSavedSearchStore {
+        pub fn new() -> Self {
+            Self {
+                searches: Vec::new(),
+                existing_match_searches: Vec::new(),
+                taken_searches: Vec::new(),
+                flagged: Vec::new(),
+                connectivity: Connectivity::Connected,
+            }
+        }
+    }
+
+    impl driven_ports::SavedSearchReader for Mutex<FakeSavedSearchStore> {
+        async fn saved_searches(
+            &self,
+            user_id: &str,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SavedSearch>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .searches
+                .iter()
+                .filter(|search| search.user_id == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn saved_search(
+            &self,
+            user_id: &str,
+            search_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<SavedSearch>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .searches
+                .iter()
+                .find(|search| search.user_id == user_id && search.id == search_id)
+                .cloned())
+        }
+
+        async fn all_saved_searches(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SavedSearch>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self.searches.clone())
+        }
+    }
+
+    impl driven_ports::SavedSearchWriter for Mutex<FakeSavedSearchStore> {
+        async fn create_saved_search(
+            &self,
+            user_id: &str,
+            new_search: &NewSavedSearch,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<SavedSearch, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let now = Utc::now();
+            let saved_search = SavedSearch {
+                id: locked_self.searches.len() as i64 + 1,
+                user_id: user_id.to_owned(),
+                name: new_search.name.clone(),
+                query: new_search.query.clone(),
+                filter: new_search.filter.clone(),
+                created_at: now,
+                last_checked_at: now,
+            };
+            locked_self.searches.push(saved_search.clone());
+
+            Ok(saved_search)
+        }
+
+        async fn delete_saved_search(
+            &self,
+            user_id: &str,
+            search_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let total_before = locked_self.searches.len();
+            locked_self
+                .searches
+                .retain(|search| search.user_id != user_id || search.id != search_id);
+
+            Ok(locked_self.searches.len() < total_before)
+        }
+
+        async fn take_new_matches(
+            &self,
+            search_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<SearchMatch>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.taken_searches.push(search_id);
+
+            Ok(Vec::new())
+        }
+    }
+
+    impl driven_ports::SearchMatchRecorder for Mutex<FakeSavedSearchStore> {
+        async fn record_existing_matches(
+            &self,
+            search: &SavedSearch,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.existing_match_searches.push(search.id);
+
+            Ok(())
+        }
+
+        async fn flag_new_matches(
+            &self,
+            search: &SavedSearch,
+            event_ids: &[i64],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<u64, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake saved search store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.flagged.push((search.id, event_ids.to_vec()));
+
+            Ok(event_ids.len() as u64)
+        }
+    }
+}
@@ -0,0 +1,841 @@
+use crate::domain::location::Ref;
+use crate::domain::venue::VenueMap;
+use crate::domain::venue::driven_ports::VenueMapReader;
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone};
+use chrono_tz::Tz;
+use derive_more::Display;
+use std::collections::{HashMap, HashSet};
+
+/// Upper bound on the number of search nodes explored by the schedule solver before it settles
+/// for the best schedule found so far.
+const SOLVER_NODE_BUDGET: usize = 250_000;
+
+#[derive(Debug, Clone)]
+/// An event the user wants to attend, in order of preference
+pub struct Wish {
+    pub event_id: i64,
+    /// Explicit value of attending the event. When absent, the value is derived from the wish's rank.
+    pub weight: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Event data required to place a wished-for event on a schedule
+pub struct ScheduleCandidate {
+    pub id: i64,
+    pub title: String,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub cost: Option<u32>,
+    /// ID of the building (location) the event takes place in, if known
+    pub building_id: Option<i32>,
+    /// The most specific place the event takes place in, if known
+    pub place: Option<Ref>,
+}
+
+#[derive(Debug, Clone)]
+/// A window of the day during which the user needs a block of free time (e.g. lunch)
+pub struct MealBreak {
+    pub window_start: NaiveTime,
+    pub window_end: NaiveTime,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+/// Limits the suggested schedule must respect
+pub struct ScheduleConstraints {
+    pub max_hours_per_day: Option<Duration>,
+    pub meal_breaks: Vec<MealBreak>,
+    pub earliest_start: Option<NaiveTime>,
+    pub budget: Option<u32>,
+    /// Free time required between two events held in different buildings when the walking time
+    /// between them isn't known
+    pub travel_time: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+/// The reason a wished-for event did not make it into the suggested schedule
+pub enum DropReason {
+    #[display("The event could not be found")]
+    NotFound,
+    #[display("The event was already requested earlier in the wish list")]
+    DuplicateWish,
+    #[display("The event starts before the requested earliest start time")]
+    StartsTooEarly,
+    #[display("The event would exceed the maximum hours per day")]
+    ExceedsDailyHours,
+    #[display("The event would not leave enough time for a meal break")]
+    BlocksMealBreak,
+    #[display("The event would exceed the budget")]
+    ExceedsBudget,
+    #[display("The event overlaps with event {_0}")]
+    OverlapsEvent(i64),
+    #[display("There is not enough time to travel to or from event {_0}")]
+    InsufficientTravelTime(i64),
+    #[display("The schedule search was cut short before the event could be considered")]
+    SearchLimitReached,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A wish that was left off the suggested schedule
+pub struct DroppedWish {
+    pub event_id: i64,
+    /// Zero-based position of the wish in the submitted list
+    pub rank: usize,
+    pub reason: DropReason,
+}
+
+#[derive(Debug)]
+/// The best schedule that could be built out of a list of wishes
+pub struct SuggestedSchedule {
+    /// Scheduled events, ordered by start time
+    pub events: Vec<ScheduleCandidate>,
+    pub dropped: Vec<DroppedWish>,
+    pub total_cost: u32,
+    pub total_value: u64,
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Reads the event data needed to build a schedule
+    pub trait CandidateReader: Sync {
+        /// Retrieves schedule candidates for the requested event IDs. Events that don't exist
+        /// are omitted from the result.
+        async fn read_candidates(
+            &self,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<ScheduleCandidate>, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for building schedules out of a user's wish list
+    pub trait SchedulePort: Sync {
+        /// Produces the highest-value schedule of non-overlapping events from the wishes that
+        /// satisfies the passed constraints, reporting which wishes were dropped and why. The
+        /// venue map is used to estimate the time needed to walk between events.
+        async fn suggest_schedule(
+            &self,
+            wishes: &[Wish],
+            constraints: &ScheduleConstraints,
+            candidate_reader: &impl driven_ports::CandidateReader,
+            venue_reader: &impl VenueMapReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<SuggestedSchedule, anyhow::Error>;
+    }
+}
+
+/// Service implementation of the SchedulePort
+pub struct ScheduleService;
+
+impl driving_ports::SchedulePort for ScheduleService {
+    #[tracing::instrument(skip_all, fields(total_wishes = wishes.len()))]
+    async fn suggest_schedule(
+        &self,
+        wishes: &[Wish],
+        constraints: &ScheduleConstraints,
+        candidate_reader: &impl driven_ports::CandidateReader,
+        venue_reader: &impl VenueMapReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<SuggestedSchedule, anyhow::Error> {
+        let event_ids: Vec<i64> = wishes.iter().map(|wish| wish.event_id).collect();
+        let candidates = candidate_reader
+            .read_candidates(&event_ids, &mut *ext_cxn)
+            .await
+            .context("Reading events for schedule suggestion")?;
+        let venue_map = venue_reader
+            .read_venue_map(&mut *ext_cxn)
+            .await
+            .context("Reading venue map for schedule suggestion")?;
+
+        Ok(build_schedule(wishes, candidates, constraints, &venue_map))
+    }
+}
+
+/// A wish paired with the event data behind it and the value of attending
+struct RankedCandidate {
+    rank: usize,
+    value: u64,
+    candidate: ScheduleCandidate,
+}
+
+/// Working state of the schedule while the solver searches for the best combination of events
+#[derive(Clone, Default)]
+struct ScheduleState {
+    chosen: Vec<usize>,
+    total_cost: u32,
+    total_value: u64,
+    hours_by_day: HashMap<NaiveDate, Duration>,
+}
+
+#[tracing::instrument(skip_all, fields(total_wishes = wishes.len(), total_candidates = candidates.len()))]
+/// Selects the highest-value set of wished-for events which can all be attended under the given
+/// constraints. Wishes without an explicit weight are valued by rank, so the first wish in a list
+/// of N is worth N, the second N - 1, and so on.
+pub fn build_schedule(
+    wishes: &[Wish],
+    candidates: Vec<ScheduleCandidate>,
+    constraints: &ScheduleConstraints,
+    venue_map: &VenueMap,
+) -> SuggestedSchedule {
+    build_schedule_within(
+        wishes,
+        candidates,
+        constraints,
+        venue_map,
+        SOLVER_NODE_BUDGET,
+    )
+}
+
+/// Builds a schedule as build_schedule does, exploring at most `node_budget` search nodes
+fn build_schedule_within(
+    wishes: &[Wish],
+    candidates: Vec<ScheduleCandidate>,
+    constraints: &ScheduleConstraints,
+    venue_map: &VenueMap,
+    node_budget: usize,
+) -> SuggestedSchedule {
+    let mut candidates_by_id: HashMap<i64, ScheduleCandidate> = candidates
+        .into_iter()
+        .map(|candidate| (candidate.id, candidate))
+        .collect();
+    let mut seen_ids: HashSet<i64> = HashSet::new();
+    let mut dropped: Vec<DroppedWish> = Vec::new();
+    let mut ranked: Vec<RankedCandidate> = Vec::with_capacity(wishes.len());
+
+    for (rank, wish) in wishes.iter().enumerate() {
+        let drop = |reason| DroppedWish {
+            event_id: wish.event_id,
+            rank,
+            reason,
+        };
+
+        if !seen_ids.insert(wish.event_id) {
+            dropped.push(drop(DropReason::DuplicateWish));
+            continue;
+        }
+        let Some(candidate) = candidates_by_id.remove(&wish.event_id) else {
+            dropped.push(drop(DropReason::NotFound));
+            continue;
+        };
+        if let Some(reason) = standalone_violation(&candidate, constraints) {
+            dropped.push(drop(reason));
+            continue;
+        }
+
+        ranked.push(RankedCandidate {
+            rank,
+            value: wish
+                .weight
+                .map(u64::from)
+                .unwrap_or((wishes.len() - rank) as u64),
+            candidate,
+        });
+    }
+
+    let best = solve(&ranked, constraints, venue_map, node_budget);
+    let chosen_set: HashSet<usize> = best.chosen.iter().copied().collect();
+    let chosen_candidates: Vec<&ScheduleCandidate> = best
+        .chosen
+        .iter()
+        .map(|idx| &ranked[*idx].candidate)
+        .collect();
+
+    for (idx, ranked_candidate) in ranked.iter().enumerate() {
+        if chosen_set.contains(&idx) {
+            continue;
+        }
+
+        dropped.push(DroppedWish {
+            event_id: ranked_candidate.candidate.id,
+            rank: ranked_candidate.rank,
+            reason: conflict_with_schedule(
+                &ranked_candidate.candidate,
+                &chosen_candidates,
+                &best,
+                constraints,
+                venue_map,
+            )
+            // The solver only leaves an event out if adding it breaks a constraint, unless the
+            // search was cut short by the node budget before finding a schedule with the event
+            .unwrap_or(DropReason::SearchLimitReached),
+        });
+    }
+    dropped.sort_by_key(|dropped_wish| dropped_wish.rank);
+
+    let mut events: Vec<ScheduleCandidate> = best
+        .chosen
+        .iter()
+        .map(|idx| ranked[*idx].candidate.clone())
+        .collect();
+    events.sort_by_key(|event| (event.start, event.id));
+
+    SuggestedSchedule {
+        events,
+        dropped,
+        total_cost: best.total_cost,
+        total_value: best.total_value,
+    }
+}
+
+/// Runs a depth-first branch and bound search over the candidates (in rank order) to find the
+/// highest-value feasible schedule. The greedy schedule is used as the initial best guess so the
+/// search can prune aggressively and still return something sensible if the node budget runs out.
+fn solve(
+    ranked: &[RankedCandidate],
+    constraints: &ScheduleConstraints,
+    venue_map: &VenueMap,
+    node_budget: usize,
+) -> ScheduleState {
+    let mut greedy = ScheduleState::default();
+    for idx in 0..ranked.len() {
+        if let Some(next_state) = try_add(&greedy, idx, ranked, constraints, venue_map) {
+            greedy = next_state;
+        }
+    }
+
+    // remaining_value[i] holds the total value of all candidates from index i onward
+    let mut remaining_value: Vec<u64> = vec![0; ranked.len() + 1];
+    for idx in (0..ranked.len()).rev() {
+        remaining_value[idx] = remaining_value[idx + 1] + ranked[idx].value;
+    }
+
+    let mut best = greedy;
+    let mut nodes_visited: usize = 0;
+    search(
+        0,
+        &ScheduleState::default(),
+        ranked,
+        constraints,
+        venue_map,
+        &remaining_value,
+        &mut best,
+        &mut nodes_visited,
+        node_budget,
+    );
+
+    best
+}
+
+/// Recursive step of the branch and bound search
+#[allow(clippy::too_many_arguments)]
+fn search(
+    idx: usize,
+    state: &ScheduleState,
+    ranked: &[RankedCandidate],
+    constraints: &ScheduleConstraints,
+    venue_map: &VenueMap,
+    remaining_value: &[u64],
+    best: &mut ScheduleState,
+    nodes_visited: &mut usize,
+    node_budget: usize,
+) {
+    *nodes_visited += 1;
+    if *nodes_visited > node_budget {
+        return;
+    }
+    if state.total_value > best.total_value {
+        *best = state.clone();
+    }
+    if idx >= ranked.len() || state.total_value + remaining_value[idx] <= best.total_value {
+        return;
+    }
+
+    if let Some(with_candidate) = try_add(state, idx, ranked, constraints, venue_map) {
+        search(
+            idx + 1,
+            &with_candidate,
+            ranked,
+            constraints,
+            venue_map,
+            remaining_value,
+            best,
+            nodes_visited,
+            node_budget,
+        );
+    }
+    search(
+        idx + 1,
+        state,
+        ranked,
+        constraints,
+        venue_map,
+        remaining_value,
+        best,
+        nodes_visited,
+        node_budget,
+    );
+}
+
+/// Returns the state of the schedule with the candidate at `idx` added, or None if adding it
+/// would violate a constraint.
+fn try_add(
+    state: &ScheduleState,
+    idx: usize,
+    ranked: &[RankedCandidate],
+    constraints: &ScheduleConstraints,
+    venue_map: &VenueMap,
+) -> Option<ScheduleState> {
+    let chosen: Vec<&ScheduleCandidate> = state
+        .chosen
+        .iter()
+        .map(|chosen_idx| &ranked[*chosen_idx].candidate)
+        .collect();
+    let candidate = &ranked[idx].candidate;
+    if conflict_with_schedule(candidate, &chosen, state, constraints, venue_map).is_some() {
+        return None;
+    }
+
+    let mut next_state = state.clone();
+    next_state.chosen.push(idx);
+    next_state.total_cost += candidate.cost.unwrap_or(0);
+    next_state.total_value += ranked[idx].value;
+    *next_state
+        .hours_by_day
+        .entry(candidate.start.date_naive())
+        .or_insert_with(Duration::zero) += candidate.end - candidate.start;
+
+    Some(next_state)
+}
+
+/// Checks constraints which rule out an event regardless of what else is on the schedule
+fn standalone_violation(
+    candidate: &ScheduleCandidate,
+    constraints: &ScheduleConstraints,
+) -> Option<DropReason> {
+    if let Some(earliest_start) = constraints.earliest_start
+        && candidate.start.time() < earliest_start
+    {
+        return Some(DropReason::StartsTooEarly);
+    }
+    if let Some(max_hours) = constraints.max_hours_per_day
+        && candidate.end - candidate.start > max_hours
+    {
+        return Some(DropReason::ExceedsDailyHours);
+    }
+    if let Some(budget) = constraints.budget
+        && candidate.cost.unwrap_or(0) > budget
+    {
+        return Some(DropReason::ExceedsBudget);
+    }
+    if !meal_breaks_satisfied(&[candidate], candidate.start.date_naive(), constraints) {
+        return Some(DropReason::BlocksMealBreak);
+    }
+
+    None
+}
+
+/// Determines which constraint (if any) would be broken by adding the candidate to a schedule
+/// already containing the `chosen` events
+fn conflict_with_schedule(
+    candidate: &ScheduleCandidate,
+    chosen: &[&ScheduleCandidate],
+    state: &ScheduleState,
+    constraints: &ScheduleConstraints,
+    venue_map: &VenueMap,
+) -> Option<DropReason> {
+    for scheduled in chosen.iter() {
+        if candidate.start < scheduled.end && scheduled.start < candidate.end {
+            return Some(DropReason::OverlapsEvent(scheduled.id));
+        }
+
+        let gap = if candidate.start >= scheduled.end {
+            candidate.start - scheduled.end
+        } else {
+            scheduled.start - candidate.end
+        };
+        let different_buildings = matches!(
+            (candidate.building_id, scheduled.building_id),
+            (Some(candidate_building), Some(scheduled_building)) if candidate_building != scheduled_building
+        );
+        let estimated_transit = match (&candidate.place, &scheduled.place) {
+            (Some(candidate_place), Some(scheduled_place)) => {
+                venue_map.transit_time(candidate_place, scheduled_place)
+            }
+            _ => None,
+        };
+        let required_gap = estimated_transit.unwrap_or(if different_buildings {
+            constraints.travel_time
+        } else {
+            Duration::zero()
+        });
+        if gap < required_gap {
+            return Some(DropReason::InsufficientTravelTime(scheduled.id));
+        }
+    }
+
+    if let Some(budget) = constraints.budget
+        && state.total_cost + candidate.cost.unwrap_or(0) > budget
+    {
+        return Some(DropReason::ExceedsBudget);
+    }
+
+    let day = candidate.start.date_naive();
+    if let Some(max_hours) = constraints.max_hours_per_day {
+        let hours_on_day = state
+            .hours_by_day
+            .get(&day)
+            .copied()
+            .unwrap_or_else(Duration::zero);
+        if hours_on_day + (candidate.end - candidate.start) > max_hours {
+            return Some(DropReason::ExceedsDailyHours);
+        }
+    }
+
+    let mut events_on_day: Vec<&ScheduleCandidate> = chosen
+        .iter()
+        .copied()
+        .filter(|scheduled| scheduled.start.date_naive() == day)
+        .collect();
+    events_on_day.push(candidate);
+    if !meal_breaks_satisfied(&events_on_day, day, constraints) {
+        return Some(DropReason::BlocksMealBreak);
+    }
+
+    None
+}
+
+/// Returns true if every meal break window on `day` still contains a free block of time long
+/// enough for the meal, given the events scheduled that day
+fn meal_breaks_satisfied(
+    events_on_day: &[&ScheduleCandidate],
+    day: NaiveDate,
+    constraints: &ScheduleConstraints,
+) -> bool {
+    let Some(timezone) = events_on_day.first().map(|event| event.start.timezone()) else {
+        return true;
+    };
+
+    constraints.meal_breaks.iter().all(|meal_break| {
+        let (Some(window_start), Some(window_end)) = (
+            timezone
+                .from_local_datetime(&day.and_time(meal_break.window_start))
+                .earliest(),
+            timezone
+                .from_local_datetime(&day.and_time(meal_break.window_end))
+                .latest(),
+        ) else {
+            return true;
+        };
+
+        let mut busy: Vec<(DateTime<Tz>, DateTime<Tz>)> = events_on_day
+            .iter()
+            .filter(|event| event.start < window_end && window_start < event.end)
+            .map(|event| (event.start.max(window_start), event.end.min(window_end)))
+            .collect();
+        busy.sort();
+
+        let mut free_from = window_start;
+        for (busy_start, busy_end) in busy {
+            if busy_start - free_from >= meal_break.duration {
+                return true;
+            }
+            free_from = free_from.max(busy_end);
+        }
+
+        window_end - free_from >= meal_break.duration
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod build_schedule {
+        use super::*;
+        use crate::domain::location::RefType;
+        use speculoos::prelude::*;
+
+        fn at(day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+            Tz::America__Indiana__Indianapolis
+                .with_ymd_and_hms(2024, 8, day, hour, minute, 0)
+                .unwrap()
+        }
+
+        fn candidate(
+            id: i64,
+            start: DateTime<Tz>,
+            end: DateTime<Tz>,
+            building_id: Option<i32>,
+        ) -> ScheduleCandidate {
+            ScheduleCandidate {
+                id,
+                title: format!("Event {id}"),
+                start,
+                end,
+                cost: None,
+                building_id,
+                place: building_id.map(|id| Ref {
+                    id,
+                    ref_type: RefType::Location,
+                }),
+            }
+        }
+
+        fn wishes(ids: &[i64]) -> Vec<Wish> {
+            ids.iter()
+                .map(|id| Wish {
+                    event_id: *id,
+                    weight: None,
+                })
+                .collect()
+        }
+
+        fn no_constraints() -> ScheduleConstraints {
+            ScheduleConstraints {
+                max_hours_per_day: None,
+                meal_breaks: Vec::new(),
+                earliest_start: None,
+                budget: None,
+                travel_time: Duration::zero(),
+            }
+        }
+
+        fn scheduled_ids(schedule: &SuggestedSchedule) -> Vec<i64> {
+            schedule.events.iter().map(|event| event.id).collect()
+        }
+
+        #[test]
+        fn prefers_higher_total_value_over_greedy_choice() {
+            // Event 1 blocks both 2 and 3, but 2 and 3 together are worth more than 1
+            let candidates = vec![
+                candidate(1, at(1, 10, 0), at(1, 14, 0), None),
+                candidate(2, at(1, 10, 0), at(1, 12, 0), None),
+                candidate(3, at(1, 12, 0), at(1, 14, 0), None),
+                candidate(4, at(1, 16, 0), at(1, 18, 0), None),
+            ];
+
+            let schedule = build_schedule(
+                &wishes(&[1, 2, 3, 4]),
+                candidates,
+                &no_constraints(),
+                &VenueMap::default(),
+            );
+
+            assert_eq!(vec![2, 3, 4], scheduled_ids(&schedule));
+            assert_eq!(
+                vec![DroppedWish {
+                    event_id: 1,
+                    rank: 0,
+                    reason: DropReason::OverlapsEvent(2),
+                }],
+                schedule.dropped
+            );
+        }
+
+        #[test]
+        fn reports_wishes_left_out_when_search_is_cut_short() {
+            // The greedy schedule holds only event 1. The search swaps it for event 2 and runs
+            // out of nodes before adding event 3, which fits alongside event 2.
+            let candidates = vec![
+                candidate(1, at(1, 10, 0), at(1, 14, 0), None),
+                candidate(2, at(1, 10, 0), at(1, 12, 0), None),
+                candidate(3, at(1, 12, 0), at(1, 14, 0), None),
+            ];
+            let mut weighted_wishes = wishes(&[1, 2, 3]);
+            weighted_wishes[0].weight = Some(1);
+            weighted_wishes[1].weight = Some(5);
+            weighted_wishes[2].weight = Some(5);
+
+            let schedule = build_schedule_within(
+                &weighted_wishes,
+                candidates,
+                &no_constraints(),
+                &VenueMap::default(),
+                6,
+            );
+
+            assert_eq!(vec![2], scheduled_ids(&schedule));
+            let reasons: Vec<(i64, DropReason)> = schedule
+                .dropped
+                .iter()
+                .map(|dropped| (dropped.event_id, dropped.reason))
+                .collect();
+            assert_eq!(
+                vec![
+                    (1, DropReason::OverlapsEvent(2)),
+                    (3, DropReason::SearchLimitReached)
+                ],
+                reasons
+            );
+        }
+
+        #[test]
+        fn respects_explicit_weights() {
+            let candidates = vec![
+                candidate(1, at(1, 10, 0), at(1, 14, 0), None),
+                candidate(2, at(1, 10, 0), at(1, 12, 0), None),
+                candidate(3, at(1, 12, 0), at(1, 14, 0), None),
+            ];
+            let mut weighted_wishes = wishes(&[1, 2, 3]);
+            weighted_wishes[0].weight = Some(10);
+
+            let schedule = build_schedule(
+                &weighted_wishes,
+                candidates,
+                &no_constraints(),
+                &VenueMap::default(),
+            );
+
+            assert_eq!(vec![1], scheduled_ids(&schedule));
+            assert_eq!(10, schedule.total_value);
+        }
+
+        #[test]
+        fn accounts_for_travel_between_buildings() {
+            let candidates = vec![
+                candidate(1, at(1, 10, 0), at(1, 12, 0), Some(1)),
+                candidate(2, at(1, 12, 5), at(1, 13, 0), Some(2)),
+                candidate(3, at(1, 12, 0), at(1, 13, 0), Some(1)),
+            ];
+            let constraints = ScheduleConstraints {
+                travel_time: Duration::minutes(15),
+                ..no_constraints()
+            };
+
+            let schedule = build_schedule(
+                &wishes(&[1, 2, 3]),
+                candidates,
+                &constraints,
+                &VenueMap::default(),
+            );
+
+            assert_eq!(vec![1, 3], scheduled_ids(&schedule));
+            assert_that!(schedule.dropped).contains(DroppedWish {
+                event_id: 2,
+                rank: 1,
+                reason: DropReason::InsufficientTravelTime(1),
+            });
+        }
+
+        #[test]
+        fn uses_known_walking_times_between_buildings() {
+            let candidates = vec![
+                candidate(1, at(1, 10, 0), at(1, 12, 0), Some(1)),
+                candidate(2, at(1, 12, 5), at(1, 13, 0), Some(2)),
+                candidate(3, at(1, 13, 10), at(1, 14, 0), Some(3)),
+            ];
+            let constraints = ScheduleConstraints {
+                travel_time: Duration::minutes(15),
+                ..no_constraints()
+            };
+            let venue_map = VenueMap {
+                walking_minutes: HashMap::from([((1, 2), 5), ((2, 3), 20)]),
+                ..VenueMap::default()
+            };
+
+            let schedule =
+                build_schedule(&wishes(&[1, 2, 3]), candidates, &constraints, &venue_map);
+
+            assert_eq!(vec![1, 2], scheduled_ids(&schedule));
+            assert_that!(schedule.dropped).contains(DroppedWish {
+                event_id: 3,
+                rank: 2,
+                reason: DropReason::InsufficientTravelTime(2),
+            });
+        }
+
+        #[test]
+        fn enforces_budget_daily_hours_and_start_time() {
+            let mut expensive = candidate(1, at(1, 10, 0), at(1, 11, 0), None);
+            expensive.cost = Some(40);
+            let mut affordable = candidate(2, at(1, 11, 0), at(1, 12, 0), None);
+            affordable.cost = Some(20);
+            let candidates = vec![
+                expensive,
+                affordable,
+                candidate(3, at(1, 7, 0), at(1, 8, 0), None),
+                candidate(4, at(1, 13, 0), at(1, 15, 0), None),
+                candidate(5, at(2, 13, 0), at(2, 15, 0), None),
+            ];
+            let constraints = ScheduleConstraints {
+                budget: Some(50),
+                max_hours_per_day: Some(Duration::hours(3)),
+                earliest_start: NaiveTime::from_hms_opt(9, 0, 0),
+                ..no_constraints()
+            };
+
+            let schedule = build_schedule(
+                &wishes(&[1, 2, 3, 4, 5]),
+                candidates,
+                &constraints,
+                &VenueMap::default(),
+            );
+
+            assert_eq!(vec![1, 4, 5], scheduled_ids(&schedule));
+            assert_eq!(40, schedule.total_cost);
+            let reasons: Vec<(i64, DropReason)> = schedule
+                .dropped
+                .iter()
+                .map(|dropped| (dropped.event_id, dropped.reason))
+                .collect();
+            assert_eq!(
+                vec![
+                    (2, DropReason::ExceedsBudget),
+                    (3, DropReason::StartsTooEarly)
+                ],
+                reasons
+            );
+        }
+
+        #[test]
+        fn keeps_meal_breaks_free() {
+            let candidates = vec![
+                candidate(1, at(1, 11, 0), at(1, 12, 30), None),
+                candidate(2, at(1, 12, 30), at(1, 14, 0), None),
+            ];
+            let constraints = ScheduleConstraints {
+                meal_breaks: vec![MealBreak {
+                    window_start: NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+                    window_end: NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+                    duration: Duration::minutes(45),
+                }],
+                ..no_constraints()
+            };
+
+            let schedule = build_schedule(
+                &wishes(&[1, 2]),
+                candidates,
+                &constraints,
+                &VenueMap::default(),
+            );
+
+            assert_eq!(vec![1], scheduled_ids(&schedule));
+            assert_eq!(DropReason::BlocksMealBreak, schedule.dropped[0].reason);
+        }
+
+        #[test]
+        fn reports_missing_and_duplicate_wishes() {
+            let candidates = vec![candidate(1, at(1, 10, 0), at(1, 11, 0), None)];
+
+            let schedule = build_schedule(
+                &wishes(&[1, 1, 99]),
+                candidates,
+                &no_constraints(),
+                &VenueMap::default(),
+            );
+
+            assert_eq!(vec![1], scheduled_ids(&schedule));
+            assert_eq!(
+                vec![
+                    DroppedWish {
+                        event_id: 1,
+                        rank: 1,
+                        reason: DropReason::DuplicateWish,
+                    },
+                    DroppedWish {
+                        event_id: 99,
+                        rank: 2,
+                        reason: DropReason::NotFound,
+                    },
+                ],
+                schedule.dropped
+            );
+        }
+    }
+}
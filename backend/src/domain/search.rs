@@ -0,0 +1,1549 @@
+use crate::domain::event::{AgeRequirement, ExperienceLevel};
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use derive_more::{Display, Error};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default)]
+/// Criteria for narrowing down a list of events. Unset fields match every event.
+pub struct EventFilter {
+    pub min_available_tickets: Option<u16>,
+    pub event_type_ids: Option<Vec<i32>>,
+    pub experience_levels: Option<Vec<ExperienceLevel>>,
+    pub age_requirements: Option<Vec<AgeRequirement>>,
+    pub game_system_ids: Option<Vec<i64>>,
+    pub group_ids: Option<Vec<i64>>,
+    /// IDs of the buildings events must take place in
+    pub building_ids: Option<Vec<i32>>,
+    /// IDs of the rooms events must take place in, including events in sections of the rooms
+    pub room_ids: Option<Vec<i32>>,
+    /// IDs of the room sections events must take place in
+    pub section_ids: Option<Vec<i32>>,
+    pub exclude_tournaments: bool,
+    /// Only events whose materials list nothing players need to bring
+    pub no_materials_required: bool,
+    /// Only events in rooms or sections known to be wheelchair accessible
+    pub wheelchair_accessible: bool,
+    /// Only events in low-sensory rooms or sections, or tagged low-sensory
+    pub low_sensory: bool,
+    /// Normalized content tags events must all have
+    pub content_tags: Option<Vec<String>>,
+    /// Normalized content tags events must not have any of, such as content warnings
+    pub exclude_content_tags: Option<Vec<String>>,
+    pub earliest_start: Option<NaiveTime>,
+    pub latest_start: Option<NaiveTime>,
+    /// End times are measured from the day the event starts, so an event running past midnight
+    /// ends after every time of day
+    pub earliest_end: Option<NaiveTime>,
+    pub latest_end: Option<NaiveTime>,
+    /// Only events which both start and end inside this span
+    pub fits_within: Option<TimeSpan>,
+    /// Leaves out events overlapping any of these spans, such as times the user is already busy
+    pub busy: Vec<TimeSpan>,
+    pub min_duration_hours: Option<f32>,
+    pub max_duration_hours: Option<f32>,
+    /// Full-text query matched against event titles, descriptions, game systems, groups, and
+    /// game masters. Supports quoted phrases, `or`, and `-` to exclude words. Titles and game
+    /// systems which are spelled similarly to the query also match.
+    pub search_text: Option<String>,
+    /// Free events are never excluded by cost bounds
+    pub cost_min: Option<u32>,
+    pub cost_max: Option<u32>,
+}
+
+impl EventFilter {
+    /// Returns true if an event with the passed start and end times satisfies the end time,
+    /// window, and busy time filters
+    pub fn allows_times(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> bool {
+        let local_end = end.naive_local();
+        let start_day = start.date_naive();
+        if self
+            .earliest_end
+            .is_some_and(|earliest| local_end < start_day.and_time(earliest))
+            || self
+                .latest_end
+                .is_some_and(|latest| local_end > start_day.and_time(latest))
+        {
+            return false;
+        }
+        if self
+            .fits_within
+            .is_some_and(|window| start < window.start || end > window.end)
+        {
+            return false;
+        }
+
+        !self.busy.iter().any(|busy| busy.overlaps(start, end))
+    }
+
+    /// Returns the search text if it contains anything other than whitespace
+    pub fn search_text(&self) -> Option<&str> {
+        self.search_text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A span of time, including its start but not its end
+pub struct TimeSpan {
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+}
+
+impl TimeSpan {
+    /// Returns true if any part of the span falls between the passed start and end
+    pub fn overlaps(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> bool {
+        start < self.end && end > self.start
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The days a search covers, including both ends. Searches without a first day begin at the
+/// start of the latest convention, and searches without a last day run to its end.
+pub struct DateRange {
+    pub first: Option<NaiveDate>,
+    pub last: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// Creates a range covering only the passed day
+    pub fn day(day: NaiveDate) -> Self {
+        Self {
+            first: Some(day),
+            last: Some(day),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// Orderings available for event search results. Events which sort the same are ordered by
+/// start time and then ID, so results stay in the same order from page to page.
+pub enum EventSort {
+    #[default]
+    StartTime,
+    Title,
+    /// Free events sort as costing nothing
+    Cost,
+    Duration,
+    TicketsAvailable,
+    /// Full-text and fuzzy match quality. Only meaningful alongside search text.
+    Relevance,
+}
+
+impl EventSort {
+    /// The direction results are sorted in when none is requested. Relevance lists the best
+    /// matches first, everything else starts from the smallest value.
+    pub fn default_direction(self) -> SortDirection {
+        match self {
+            EventSort::Relevance => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        }
+    }
+}
+
+impl EventSort {
+    /// Returns true if events sorted this way are keyed by a value of the passed kind. Start
+    /// times are part of every key, so sorting by start time needs no other value.
+    fn is_keyed_by(self, value: Option<&SortValue>) -> bool {
+        matches!(
+            (self, value),
+            (EventSort::StartTime, None)
+                | (EventSort::Title, Some(SortValue::Text(_)))
+                | (
+                    EventSort::Cost
+                        | EventSort::Duration
+                        | EventSort::TicketsAvailable
+                        | EventSort::Relevance,
+                    Some(SortValue::Number(_))
+                )
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// The value of the field an event was sorted by
+pub enum SortValue {
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Where an event falls in a sort order. Start time and ID break ties between events with the
+/// same sort value, so every event has a distinct key.
+pub struct SortKey {
+    /// The sorted value, or None when sorting by start time
+    pub value: Option<SortValue>,
+    pub start: DateTime<Utc>,
+    pub id: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Marks a place in a sorted list of search results to continue paging from. Since cursors hold
+/// the key of an event instead of a count of results, events added or removed while paging don't
+/// shift the results of later pages.
+pub struct PageCursor {
+    pub sort: EventSort,
+    pub direction: SortDirection,
+    pub key: SortKey,
+    /// Pages backwards, returning the events just before the key rather than just after it
+    pub backwards: bool,
+}
+
+impl PageCursor {
+    /// Encodes the cursor as URL-safe text. Clients should treat the text as opaque.
+    pub fn encode(&self) -> String {
+        hex::encode(serde_json::to_vec(self).expect("page cursors should always serialize"))
+    }
+
+    /// Decodes a cursor produced by [PageCursor::encode], returning None if the text isn't a
+    /// valid cursor
+    pub fn decode(encoded: &str) -> Option<Self> {
+        let json = hex::decode(encoded).ok()?;
+
+        serde_json::from_slice(&json).ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The page of search results a client asked for
+pub enum PageSelection {
+    /// Page number, starting at 1
+    Number(u16),
+    Cursor(PageCursor),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Where in the sorted search results a page begins
+pub enum ResultPosition {
+    /// Skips the given number of results
+    Offset(u64),
+    /// Starts just after the event with the given key
+    After(SortKey),
+    /// Ends just before the event with the given key
+    Before(SortKey),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An event matching a search
+pub struct EventSearchResult {
+    pub id: i64,
+    pub title: String,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub cost: Option<u32>,
+    pub tickets_available: u16,
+    pub max_players: u16,
+    pub sort_key: SortKey,
+    /// Where the event falls in all search results, starting at 1
+    pub position: u64,
+}
+
+#[derive(Debug)]
+/// A single page of search results, in sort order
+pub struct SearchPage {
+    pub events: Vec<EventSearchResult>,
+    /// Number of events matching the search across all pages
+    pub total_results: u64,
+}
+
+#[derive(Debug)]
+/// A single page of search results along with cursors for the pages around it
+pub struct EventPage {
+    pub events: Vec<EventSearchResult>,
+    /// Number of events matching the search across all pages
+    pub total_results: u64,
+    /// The page number the first result falls on if results were paged by number, starting at 1
+    pub page_number: u16,
+    pub next: Option<PageCursor>,
+    pub prev: Option<PageCursor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The number of events matching a filter on a single day of the convention
+pub struct DayCount {
+    pub date: NaiveDate,
+    pub total_events: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Facets which count events by something with an ID and a name
+pub enum NamedFacet {
+    EventType,
+    GameSystem,
+    Group,
+    Building,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Facets which count events falling into ranges of a number
+pub enum RangeFacet {
+    /// Event cost in dollars, counting free events as costing nothing
+    Cost,
+    /// Event length in hours
+    Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A range of values including `min` but not `max`. Ranges without a max are unbounded.
+pub struct FacetRange {
+    pub min: f32,
+    pub max: Option<f32>,
+}
+
+/// Cost ranges events are counted in, starting with free events
+pub const COST_RANGES: [FacetRange; 6] = [
+    FacetRange {
+        min: 0.0,
+        max: Some(1.0),
+    },
+    FacetRange {
+        min: 1.0,
+        max: Some(5.0),
+    },
+    FacetRange {
+        min: 5.0,
+        max: Some(10.0),
+    },
+    FacetRange {
+        min: 10.0,
+        max: Some(20.0),
+    },
+    FacetRange {
+        min: 20.0,
+        max: Some(50.0),
+    },
+    FacetRange {
+        min: 50.0,
+        max: None,
+    },
+];
+
+/// Duration ranges in hours events are counted in
+pub const DURATION_RANGES: [FacetRange; 5] = [
+    FacetRange {
+        min: 0.0,
+        max: Some(1.0),
+    },
+    FacetRange {
+        min: 1.0,
+        max: Some(2.0),
+    },
+    FacetRange {
+        min: 2.0,
+        max: Some(4.0),
+    },
+    FacetRange {
+        min: 4.0,
+        max: Some(8.0),
+    },
+    FacetRange {
+        min: 8.0,
+        max: None,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq)]
+/// The number of events associated with a named item, such as a game system
+pub struct NamedCount {
+    pub id: i64,
+    pub name: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The number of events with a particular value
+pub struct ValueCount<T> {
+    pub value: T,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The number of events falling into a range
+pub struct RangeCount {
+    pub range: FacetRange,
+    pub count: u64,
+}
+
+#[derive(Debug, Default)]
+/// Counts of the events each filter value would produce. Every facet applies all filters except
+/// its own, so the counts show what choosing a different value would return rather than only
+/// repeating the current selection.
+pub struct EventFacets {
+    pub event_types: Vec<NamedCount>,
+    pub age_requirements: Vec<ValueCount<AgeRequirement>>,
+    pub experience_levels: Vec<ValueCount<ExperienceLevel>>,
+    pub game_systems: Vec<NamedCount>,
+    pub groups: Vec<NamedCount>,
+    pub buildings: Vec<NamedCount>,
+    pub cost_ranges: Vec<RangeCount>,
+    pub duration_ranges: Vec<RangeCount>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The kinds of things search suggestions can refer to
+pub enum SuggestionKind {
+    EventTitle,
+    GameSystem,
+    Group,
+    GameMaster,
+    Location,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A possible completion of partially typed search text
+pub struct Suggestion {
+    pub kind: SuggestionKind,
+    /// ID of the suggested game system, group, game master, or location. Event titles have no ID
+    /// since many events can share a title.
+    pub id: Option<i64>,
+    pub text: String,
+    /// How closely the suggestion matches the typed text, from 0 to 1
+    pub score: f32,
+}
+
+/// Shortest text worth suggesting completions for, since one letter matches nearly everything
+pub const MIN_SUGGESTION_TEXT_LEN: usize = 2;
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while searching for events
+pub enum SearchError {
+    #[display("No events take place on {}", _0)]
+    DayNotFound(#[error(not(source))] NaiveDate),
+    #[display("The page cursor doesn't match the requested sort")]
+    InvalidCursor,
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Finds events matching search criteria
+    pub trait EventSearcher: Sync {
+        /// Returns true if any events take place on the given day
+        async fn day_has_events(
+            &self,
+            day: NaiveDate,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Searches for events starting within the date range, returning at most `limit` results
+        /// from the passed position. Total results count every matching event regardless of
+        /// position.
+        #[allow(clippy::too_many_arguments)]
+        async fn search(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            sort: EventSort,
+            direction: SortDirection,
+            position: &ResultPosition,
+            limit: u16,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<SearchPage, anyhow::Error>;
+
+        /// Counts events matching the filter on each day of the latest convention year
+        async fn count_by_day(
+            &self,
+            filter: &EventFilter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<DayCount>, anyhow::Error>;
+    }
+
+    /// Counts the events within a date range matching a filter, grouped by facet
+    pub trait FacetCounter: Sync {
+        /// Counts events per named item, most common first. Items without events are left out.
+        async fn count_named(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            facet: NamedFacet,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<NamedCount>, anyhow::Error>;
+
+        async fn count_age_requirements(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<ValueCount<AgeRequirement>>, anyhow::Error>;
+
+        async fn count_experience_levels(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<ValueCount<ExperienceLevel>>, anyhow::Error>;
+
+        /// Counts events falling into each range, returning one count per passed range
+        async fn count_ranges(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            facet: RangeFacet,
+            ranges: &[FacetRange],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<u64>, anyhow::Error>;
+    }
+
+    /// Finds names and titles resembling partially typed search text
+    pub trait SuggestionReader: Sync {
+        /// Returns at most `limit` suggestions, best matches first. Matching tolerates typos.
+        async fn suggest(
+            &self,
+            text: &str,
+            limit: u16,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<Suggestion>, anyhow::Error>;
+    }
+
+    /// Keeps the data used for full-text search up to date
+    pub trait SearchIndexer: Sync {
+        /// Rebuilds the searchable text of the passed events from their current details
+        async fn refresh_search_documents(
+            &self,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for searching events
+    pub trait SearchPort: Sync {
+        /// Retrieves one page of the events on a day matching the filter, along with cursors for
+        /// the next and previous pages. The sort's default direction is used when no direction
+        /// is passed. Cursors must come from a search with the same sort and direction.
+        #[allow(clippy::too_many_arguments)]
+        async fn search_day(
+            &self,
+            day: NaiveDate,
+            filter: &EventFilter,
+            sort: EventSort,
+            direction: Option<SortDirection>,
+            page: &PageSelection,
+            page_size: u16,
+            searcher: &impl driven_ports::EventSearcher,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<EventPage, SearchError>;
+
+        /// Retrieves one page of the events within the date range matching the filter, paged
+        /// and sorted the same way as [SearchPort::search_day]
+        #[allow(clippy::too_many_arguments)]
+        async fn search_events(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            sort: EventSort,
+            direction: Option<SortDirection>,
+            page: &PageSelection,
+            page_size: u16,
+            searcher: &impl driven_ports::EventSearcher,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<EventPage, SearchError>;
+
+        /// Counts the events matching the filter on each day of the convention
+        async fn count_by_day(
+            &self,
+            filter: &EventFilter,
+            searcher: &impl driven_ports::EventSearcher,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<DayCount>, anyhow::Error>;
+
+        /// Counts the events within the date range each filter value would produce, given the
+        /// rest of the filter
+        async fn facets(
+            &self,
+            dates: &DateRange,
+            filter: &EventFilter,
+            facet_counter: &impl driven_ports::FacetCounter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<EventFacets, anyhow::Error>;
+
+        /// Suggests event titles, game systems, groups, game masters, and locations which
+        /// complete the typed text
+        async fn suggest(
+            &self,
+            text: &str,
+            limit: u16,
+            suggestion_reader: &impl driven_ports::SuggestionReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<Suggestion>, anyhow::Error>;
+    }
+}
+
+/// Service implementation of the SearchPort
+pub struct SearchService;
+
+impl driving_ports::SearchPort for SearchService {
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(self, filter, searcher, ext_cxn))]
+    async fn search_day(
+        &self,
+        day: NaiveDate,
+        filter: &EventFilter,
+        sort: EventSort,
+        direction: Option<SortDirection>,
+        page: &PageSelection,
+        page_size: u16,
+        searcher: &impl driven_ports::EventSearcher,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<EventPage, SearchError> {
+        let day_exists = searcher
+            .day_has_events(day, &mut *ext_cxn)
+            .await
+            .context("Checking the requested day has events")
+            .map_err(SearchError::PortError)?;
+        if !day_exists {
+            return Err(SearchError::DayNotFound(day));
+        }
+
+        self.search_events(
+            &DateRange::day(day),
+            filter,
+            sort,
+            direction,
+            page,
+            page_size,
+            searcher,
+            ext_cxn,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(self, filter, searcher, ext_cxn))]
+    async fn search_events(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        sort: EventSort,
+        direction: Option<SortDirection>,
+        page: &PageSelection,
+        page_size: u16,
+        searcher: &impl driven_ports::EventSearcher,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<EventPage, SearchError> {
+        // Without search text every event is equally relevant
+        let sort = if sort == EventSort::Relevance && filter.search_text().is_none() {
+            EventSort::StartTime
+        } else {
+            sort
+        };
+        let direction = direction.unwrap_or(sort.default_direction());
+        let position = match page {
+            PageSelection::Number(number) => {
+                ResultPosition::Offset(number.saturating_sub(1) as u64 * page_size as u64)
+            }
+            PageSelection::Cursor(cursor) => {
+                if cursor.sort != sort
+                    || cursor.direction != direction
+                    || !sort.is_keyed_by(cursor.key.value.as_ref())
+                {
+                    return Err(SearchError::InvalidCursor);
+                }
+                if cursor.backwards {
+                    ResultPosition::Before(cursor.key.clone())
+                } else {
+                    ResultPosition::After(cursor.key.clone())
+                }
+            }
+        };
+
+        let search_page = searcher
+            .search(
+                dates,
+                filter,
+                sort,
+                direction,
+                &position,
+                page_size,
+                &mut *ext_cxn,
+            )
+            .await
+            .context("Searching for events")
+            .map_err(SearchError::PortError)?;
+
+        let cursor = |event: &EventSearchResult, backwards: bool| PageCursor {
+            sort,
+            direction,
+            key: event.sort_key.clone(),
+            backwards,
+        };
+        let next = search_page
+            .events
+            .last()
+            .filter(|last| last.position < search_page.total_results)
+            .map(|last| cursor(last, false));
+        let prev = search_page
+            .events
+            .first()
+            .filter(|first| first.position > 1)
+            .map(|first| cursor(first, true));
+        let page_number = match (search_page.events.first(), page) {
+            (Some(first), _) => ((first.position - 1) / page_size as u64 + 1) as u16,
+            (None, PageSelection::Number(number)) => *number,
+            (None, PageSelection::Cursor(_)) => 1,
+        };
+
+        Ok(EventPage {
+            events: search_page.events,
+            total_results: search_page.total_results,
+            page_number,
+            next,
+            prev,
+        })
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn count_by_day(
+        &self,
+        filter: &EventFilter,
+        searcher: &impl driven_ports::EventSearcher,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<DayCount>, anyhow::Error> {
+        searcher
+            .count_by_day(filter, ext_cxn)
+            .await
+            .context("Counting events by day")
+    }
+
+    #[tracing::instrument(skip(self, filter, facet_counter, ext_cxn))]
+    async fn facets(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        facet_counter: &impl driven_ports::FacetCounter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<EventFacets, anyhow::Error> {
+        let without = |clear: fn(&mut EventFilter)| {
+            let mut facet_filter = filter.clone();
+            clear(&mut facet_filter);
+            facet_filter
+        };
+        let count_ranges = async |facet: RangeFacet,
+                                  ranges: &[FacetRange],
+                                  facet_filter: EventFilter,
+                                  ext_cxn: &mut _|
+               -> Result<Vec<RangeCount>, anyhow::Error> {
+            let counts = facet_counter
+                .count_ranges(dates, &facet_filter, facet, ranges, ext_cxn)
+                .await
+                .with_context(|| format!("Counting events by {facet:?} range"))?;
+
+            Ok(ranges
+                .iter()
+                .zip(counts)
+                .map(|(range, count)| RangeCount {
+                    range: *range,
+                    count,
+                })
+                .collect())
+        };
+
+        let mut facets = EventFacets::default();
+        for facet in [
+            NamedFacet::EventType,
+            NamedFacet::GameSystem,
+            NamedFacet::Group,
+            NamedFacet::Building,
+        ] {
+            let facet_filter = match facet {
+                NamedFacet::EventType => without(|filter| filter.event_type_ids = None),
+                NamedFacet::GameSystem => without(|filter| filter.game_system_ids = None),
+                NamedFacet::Group => without(|filter| filter.group_ids = None),
+                NamedFacet::Building => without(|filter| filter.building_ids = None),
+            };
+            let counts = facet_counter
+                .count_named(dates, &facet_filter, facet, &mut *ext_cxn)
+                .await
+                .with_context(|| format!("Counting events by {facet:?}"))?;
+            match facet {
+                NamedFacet::EventType => facets.event_types = counts,
+                NamedFacet::GameSystem => facets.game_systems = counts,
+                NamedFacet::Group => facets.groups = counts,
+                NamedFacet::Building => facets.buildings = counts,
+            }
+        }
+
+        facets.age_requirements = facet_counter
+            .count_age_requirements(
+                dates,
+                &without(|filter| filter.age_requirements = None),
+                &mut *ext_cxn,
+            )
+            .await
+            .context("Counting events by age requirement")?;
+        facets.experience_levels = facet_counter
+            .count_experience_levels(
+                dates,
+                &without(|filter| filter.experience_levels = None),
+                &mut *ext_cxn,
+            )
+            .await
+            .context("Counting events by experience level")?;
+        facets.cost_ranges = count_ranges(
+            RangeFacet::Cost,
+            &COST_RANGES,
+            without(|filter| {
+                filter.cost_min = None;
+                filter.cost_max = None;
+            }),
+            &mut *ext_cxn,
+        )
+        .await?;
+        facets.duration_ranges = count_ranges(
+            RangeFacet::Duration,
+            &DURATION_RANGES,
+            without(|filter| {
+                filter.min_duration_hours = None;
+                filter.max_duration_hours = None;
+            }),
+            &mut *ext_cxn,
+        )
+        .await?;
+
+        Ok(facets)
+    }
+
+    #[tracing::instrument(skip(self, suggestion_reader, ext_cxn))]
+    async fn suggest(
+        &self,
+        text: &str,
+        limit: u16,
+        suggestion_reader: &impl driven_ports::SuggestionReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<Suggestion>, anyhow::Error> {
+        let text = text.trim();
+        if text.chars().count() < MIN_SUGGESTION_TEXT_LEN {
+            return Ok(Vec::new());
+        }
+
+        suggestion_reader
+            .suggest(text, limit, ext_cxn)
+            .await
+            .context("Looking up search suggestions")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::search::driving_ports::SearchPort;
+    use crate::domain::search::test_util::{
+        FakeEventSearcher, FakeFacetCounter, FakeSuggestionReader,
+    };
+    use crate::domain::test_util::Connectivity;
+    use crate::external_connections;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    fn searcher() -> Mutex<FakeEventSearcher> {
+        Mutex::new(FakeEventSearcher {
+            days_with_events: vec![NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()],
+            found_events: Vec::new(),
+            total_results: 0,
+            searches: Vec::new(),
+            connectivity: Connectivity::Connected,
+        })
+    }
+
+    fn sort_key(id: i64) -> SortKey {
+        SortKey {
+            value: Some(SortValue::Number(15.0)),
+            start: DateTime::parse_from_rfc3339("2024-08-01T14:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            id,
+        }
+    }
+
+    fn found_event(id: i64, position: u64) -> EventSearchResult {
+        let start = DateTime::parse_from_rfc3339("2024-08-01T10:00:00-04:00")
+            .unwrap()
+            .with_timezone(&Tz::America__Indiana__Indianapolis);
+        EventSearchResult {
+            id,
+            title: format!("Event {id}"),
+            start,
+            end: start + chrono::Duration::hours(2),
+            cost: Some(15),
+            tickets_available: 4,
+            max_players: 6,
+            sort_key: sort_key(id),
+            position,
+        }
+    }
+
+    fn cost_cursor(backwards: bool) -> PageCursor {
+        PageCursor {
+            sort: EventSort::Cost,
+            direction: SortDirection::Ascending,
+            key: sort_key(12),
+            backwards,
+        }
+    }
+
+    mod page_cursor {
+        use super::*;
+
+        #[test]
+        fn decodes_encoded_cursor() {
+            let cursor = cost_cursor(true);
+
+            assert_eq!(Some(cursor.clone()), PageCursor::decode(&cursor.encode()));
+        }
+
+        #[test]
+        fn rejects_garbage() {
+            assert_eq!(None, PageCursor::decode("not a cursor"));
+            assert_eq!(None, PageCursor::decode(&hex::encode("{}")));
+        }
+    }
+
+    mod search_text {
+        use super::*;
+
+        #[test]
+        fn ignores_blank_text() {
+            let filter = EventFilter {
+                search_text: Some("   ".to_owned()),
+                ..Default::default()
+            };
+
+            assert_eq!(None, filter.search_text());
+        }
+
+        #[test]
+        fn trims_text() {
+            let filter = EventFilter {
+                search_text: Some(" pathfinder ".to_owned()),
+                ..Default::default()
+            };
+
+            assert_eq!(Some("pathfinder"), filter.search_text());
+        }
+    }
+
+    mod allows_times {
+        use super::*;
+
+        fn at(time: &str) -> DateTime<Tz> {
+            DateTime::parse_from_rfc3339(time)
+                .unwrap()
+                .with_timezone(&Tz::America__Indiana__Indianapolis)
+        }
+
+        #[test]
+        fn measures_end_times_from_the_start_day() {
+            let filter = EventFilter {
+                latest_end: NaiveTime::from_hms_opt(23, 0, 0),
+                ..Default::default()
+            };
+
+            assert!(filter.allows_times(
+                at("2024-08-01T20:00:00-04:00"),
+                at("2024-08-01T22:00:00-04:00")
+            ));
+            assert!(!filter.allows_times(
+                at("2024-08-01T22:00:00-04:00"),
+                at("2024-08-02T01:00:00-04:00")
+            ));
+        }
+
+        #[test]
+        fn requires_events_to_fit_in_window() {
+            let filter = EventFilter {
+                fits_within: Some(TimeSpan {
+                    start: at("2024-08-01T13:00:00-04:00"),
+                    end: at("2024-08-01T17:00:00-04:00"),
+                }),
+                ..Default::default()
+            };
+
+            assert!(filter.allows_times(
+                at("2024-08-01T13:00:00-04:00"),
+                at("2024-08-01T17:00:00-04:00")
+            ));
+            assert!(!filter.allows_times(
+                at("2024-08-01T12:00:00-04:00"),
+                at("2024-08-01T14:00:00-04:00")
+            ));
+            assert!(!filter.allows_times(
+                at("2024-08-01T16:00:00-04:00"),
+                at("2024-08-01T18:00:00-04:00")
+            ));
+        }
+
+        #[test]
+        fn excludes_events_overlapping_busy_times() {
+            let filter = EventFilter {
+                busy: vec![TimeSpan {
+                    start: at("2024-08-01T12:00:00-04:00"),
+                    end: at("2024-08-01T13:00:00-04:00"),
+                }],
+                ..Default::default()
+            };
+
+            assert!(filter.allows_times(
+                at("2024-08-01T10:00:00-04:00"),
+                at("2024-08-01T12:00:00-04:00")
+            ));
+            assert!(filter.allows_times(
+                at("2024-08-01T13:00:00-04:00"),
+                at("2024-08-01T14:00:00-04:00")
+            ));
+            assert!(!filter.allows_times(
+                at("2024-08-01T11:00:00-04:00"),
+                at("2024-08-01T12:30:00-04:00")
+            ));
+        }
+    }
+
+    mod search_day {
+        use super::*;
+
+        #[tokio::test]
+        async fn converts_page_to_offset() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+            let filter = EventFilter {
+                search_text: Some("dragons".to_owned()),
+                ..Default::default()
+            };
+
+            SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &filter,
+                    EventSort::Relevance,
+                    None,
+                    &PageSelection::Number(3),
+                    50,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(
+                vec![(
+                    EventSort::Relevance,
+                    SortDirection::Descending,
+                    ResultPosition::Offset(100),
+                    50
+                )],
+                searcher.lock().unwrap().searches
+            );
+        }
+
+        #[tokio::test]
+        async fn relevance_without_search_text_sorts_by_start() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+
+            SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::Relevance,
+                    None,
+                    &PageSelection::Number(1),
+                    50,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(
+                vec![(
+                    EventSort::StartTime,
+                    SortDirection::Ascending,
+                    ResultPosition::Offset(0),
+                    50
+                )],
+                searcher.lock().unwrap().searches
+            );
+        }
+
+        #[tokio::test]
+        async fn keeps_requested_direction() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+
+            SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::Cost,
+                    Some(SortDirection::Descending),
+                    &PageSelection::Number(1),
+                    50,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(
+                vec![(
+                    EventSort::Cost,
+                    SortDirection::Descending,
+                    ResultPosition::Offset(0),
+                    50
+                )],
+                searcher.lock().unwrap().searches
+            );
+        }
+
+        #[tokio::test]
+        async fn rejects_days_without_events() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+
+            let search_result = SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::StartTime,
+                    None,
+                    &PageSelection::Number(1),
+                    50,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await;
+
+            assert!(matches!(search_result, Err(SearchError::DayNotFound(_))));
+            assert!(searcher.lock().unwrap().searches.is_empty());
+        }
+
+        #[tokio::test]
+        async fn pages_backwards_from_cursor() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+
+            SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::Cost,
+                    None,
+                    &PageSelection::Cursor(cost_cursor(true)),
+                    20,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(
+                vec![(
+                    EventSort::Cost,
+                    SortDirection::Ascending,
+                    ResultPosition::Before(sort_key(12)),
+                    20
+                )],
+                searcher.lock().unwrap().searches
+            );
+        }
+
+        #[tokio::test]
+        async fn rejects_cursors_from_other_sorts() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+
+            let search_result = SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::Cost,
+                    Some(SortDirection::Descending),
+                    &PageSelection::Cursor(cost_cursor(false)),
+                    20,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await;
+
+            assert!(matches!(search_result, Err(SearchError::InvalidCursor)));
+            assert!(searcher.lock().unwrap().searches.is_empty());
+        }
+
+        #[tokio::test]
+        async fn links_surrounding_pages() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+            {
+                let mut locked_searcher = searcher.lock().unwrap();
+                locked_searcher.found_events = vec![found_event(7, 21), found_event(12, 22)];
+                locked_searcher.total_results = 30;
+            }
+
+            let page = SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::Cost,
+                    None,
+                    &PageSelection::Cursor(cost_cursor(false)),
+                    20,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(2, page.page_number);
+            assert_eq!(Some(sort_key(12)), page.next.map(|cursor| cursor.key));
+            let prev = page.prev.expect("a previous page should exist");
+            assert_eq!(sort_key(7), prev.key);
+            assert!(prev.backwards);
+        }
+
+        #[tokio::test]
+        async fn omits_cursors_past_the_ends() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+            {
+                let mut locked_searcher = searcher.lock().unwrap();
+                locked_searcher.found_events = vec![found_event(7, 1), found_event(12, 2)];
+                locked_searcher.total_results = 2;
+            }
+
+            let page = SearchService
+                .search_day(
+                    NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    &EventFilter::default(),
+                    EventSort::Cost,
+                    None,
+                    &PageSelection::Number(1),
+                    20,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(None, page.next);
+            assert_eq!(None, page.prev);
+        }
+    }
+
+    mod search_events {
+        use super::*;
+
+        #[tokio::test]
+        async fn searches_ranges_spanning_days_without_events() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let searcher = searcher();
+            searcher.lock().unwrap().days_with_events.clear();
+
+            SearchService
+                .search_events(
+                    &DateRange {
+                        first: NaiveDate::from_ymd_opt(2024, 7, 31),
+                        last: None,
+                    },
+                    &EventFilter::default(),
+                    EventSort::StartTime,
+                    None,
+                    &PageSelection::Number(2),
+                    25,
+                    &searcher,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("search should succeed");
+
+            assert_eq!(
+                vec![(
+                    EventSort::StartTime,
+                    SortDirection::Ascending,
+                    ResultPosition::Offset(25),
+                    25
+                )],
+                searcher.lock().unwrap().searches
+            );
+        }
+    }
+
+    mod facets {
+        use super::*;
+
+        #[tokio::test]
+        async fn facets_ignore_their_own_filter() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let counter = Mutex::new(FakeFacetCounter {
+                counted_filters: HashMap::new(),
+                connectivity: Connectivity::Connected,
+            });
+            let filter = EventFilter {
+                game_system_ids: Some(vec![4]),
+                cost_max: Some(10),
+                ..Default::default()
+            };
+
+            SearchService
+                .facets(
+                    &DateRange::day(NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()),
+                    &filter,
+                    &counter,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("counting facets should succeed");
+
+            let counted_filters = &counter.lock().unwrap().counted_filters;
+            let game_system_filter = &counted_filters["GameSystem"];
+            let cost_filter = &counted_filters["Cost"];
+            let group_filter = &counted_filters["Group"];
+            assert_eq!(None, game_system_filter.game_system_ids);
+            assert_eq!(Some(10), game_system_filter.cost_max);
+            assert_eq!(Some(vec![4]), cost_filter.game_system_ids);
+            assert_eq!(None, cost_filter.cost_max);
+            assert_eq!(Some(vec![4]), group_filter.game_system_ids);
+            assert_eq!(Some(10), group_filter.cost_max);
+        }
+
+        #[tokio::test]
+        async fn pairs_range_counts_with_ranges() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let counter = Mutex::new(FakeFacetCounter {
+                counted_filters: HashMap::new(),
+                connectivity: Connectivity::Connected,
+            });
+
+            let facets = SearchService
+                .facets(
+                    &DateRange::day(NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()),
+                    &EventFilter::default(),
+                    &counter,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("counting facets should succeed");
+
+            assert_eq!(COST_RANGES.len(), facets.cost_ranges.len());
+            assert_eq!(
+                RangeCount {
+                    range: DURATION_RANGES[2],
+                    count: 2,
+                },
+                facets.duration_ranges[2]
+            );
+        }
+    }
+
+    mod suggest {
+        use super::*;
+
+        #[tokio::test]
+        async fn trims_text() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let reader = Mutex::new(FakeSuggestionReader {
+                lookups: Vec::new(),
+                connectivity: Connectivity::Connected,
+            });
+
+            SearchService
+                .suggest("  pathf ", 10, &reader, &mut ext_cxn)
+                .await
+                .expect("suggesting should succeed");
+
+            assert_eq!(vec!["pathf".to_owned()], reader.lock().unwrap().lookups);
+        }
+
+        #[tokio::test]
+        async fn skips_text_too_short_to_suggest_for() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let reader = Mutex::new(FakeSuggestionReader {
+                lookups: Vec::new(),
+                connectivity: Connectivity::Disconnected,
+            });
+
+            let suggestions = SearchService
+                .suggest(" p ", 10, &reader, &mut ext_cxn)
+                .await
+                .expect("short text should not be looked up");
+
+            assert!(suggestions.is_empty());
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Fake EventSearcher which records the sort, direction, position, and limit of each search.
+    /// Every search returns the same events.
+    pub struct FakeEventSearcher {
+        pub days_with_events: Vec<NaiveDate>,
+        pub found_events: Vec<EventSearchResult>,
+        pub total_results: u64,
+        pub searches: Vec<(EventSort, SortDirection, ResultPosition, u16)>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::EventSearcher for Mutex<FakeEventSearcher> {
+        async fn day_has_events(
+            &self,
+            day: NaiveDate,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake event searcher");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self.days_with_events.contains(&day))
+        }
+
+        async fn search(
+            &self,
+            _dates: &DateRange,
+            _filter: &EventFilter,
+            sort: EventSort,
+            direction: SortDirection,
+            position: &ResultPosition,
+            limit: u16,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<SearchPage, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake event searcher");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self
+                .searches
+                .push((sort, direction, position.clone(), limit));
+
+            Ok(SearchPage {
+                events: locked_self.found_events.clone(),
+                total_results: locked_self.total_results,
+            })
+        }
+
+        async fn count_by_day(
+            &self,
+            _filter: &EventFilter,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<DayCount>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake event searcher");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .days_with_events
+                .iter()
+                .map(|day| DayCount {
+                    date: *day,
+                    total_events: 0,
+                })
+                .collect())
+        }
+    }
+
+    /// Fake SuggestionReader which records the text it was asked to complete
+    pub struct FakeSuggestionReader {
+        pub lookups: Vec<String>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::SuggestionReader for Mutex<FakeSuggestionReader> {
+        async fn suggest(
+            &self,
+            text: &str,
+            _limit: u16,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<Suggestion>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake suggestion reader");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.lookups.push(text.to_owned());
+
+            Ok(Vec::new())
+        }
+    }
+
+    /// Fake FacetCounter which records the filter used for each facet. Range counts are the
+    /// index of each range.
+    pub struct FakeFacetCounter {
+        pub counted_filters: HashMap<String, EventFilter>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::FacetCounter for Mutex<FakeFacetCounter> {
+        async fn count_named(
+            &self,
+            _dates: &DateRange,
+            filter: &EventFilter,
+            facet: NamedFacet,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<NamedCount>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake facet counter");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self
+                .counted_filters
+                .insert(format!("{facet:?}"), filter.clone());
+
+            Ok(Vec::new())
+        }
+
+        async fn count_age_requirements(
+            &self,
+            _dates: &DateRange,
+            filter: &EventFilter,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<ValueCount<AgeRequirement>>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake facet counter");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self
+                .counted_filters
+                .insert("AgeRequirement".to_owned(), filter.clone());
+
+            Ok(Vec::new())
+        }
+
+        async fn count_experience_levels(
+            &self,
+            _dates: &DateRange,
+            filter: &EventFilter,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<ValueCount<ExperienceLevel>>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake facet counter");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self
+                .counted_filters
+                .insert("ExperienceLevel".to_owned(), filter.clone());
+
+            Ok(Vec::new())
+        }
+
+        async fn count_ranges(
+            &self,
+            _dates: &DateRange,
+            filter: &EventFilter,
+            facet: RangeFacet,
+            ranges: &[FacetRange],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<u64>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake facet counter");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self
+                .counted_filters
+                .insert(format!("{facet:?}"), filter.clone());
+
+            Ok((0..ranges.len() as u64).collect())
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::external_connections::ExternalConnectivity;
+use chrono::DateTime;
+use chrono_tz::Tz;
+use derive_more::{Display, Error};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A single run of an event, used to compare sessions of events that are offered more than once
+pub struct EventSession {
+    pub id: i64,
+    pub game_id: String,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub tickets_available: u16,
+    pub cancelled: bool,
+}
+
+#[derive(Debug)]
+/// The session that was looked up, along with every other session sharing its title, game system,
+/// organizing group, and year
+pub struct SessionGroup {
+    pub requested: EventSession,
+    pub siblings: Vec<EventSession>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while looking up alternate sessions of an event
+pub enum AlternateSessionError {
+    #[display("Event with ID {} does not exist", _0)]
+    EventNotFound(#[error(not(source))] i64),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Reads sessions of events from storage
+    pub trait SessionReader: Sync {
+        /// Retrieves the requested event's session and every other event with the same title,
+        /// game system, group, and year which hasn't been cancelled. Returns None if the
+        /// requested event does not exist.
+        async fn read_session_group(
+            &self,
+            event_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<SessionGroup>, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for finding other sessions of an event
+    pub trait SessionPort: Sync {
+        /// Lists sessions of the same event held at a different time than the requested one,
+        /// ordered by start time. Cancelled sessions are never listed, and if `available_only`
+        /// is set, sold out sessions are left out too.
+        async fn alternate_sessions(
+            &self,
+            event_id: i64,
+            available_only: bool,
+            session_reader: &impl driven_ports::SessionReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventSession>, AlternateSessionError>;
+    }
+}
+
+/// Service implementation of the SessionPort
+pub struct SessionService;
+
+impl driving_ports::SessionPort for SessionService {
+    #[tracing::instrument(skip(self, session_reader, ext_cxn))]
+    async fn alternate_sessions(
+        &self,
+        event_id: i64,
+        available_only: bool,
+        session_reader: &impl driven_ports::SessionReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventSession>, AlternateSessionError> {
+        let session_group = session_reader
+            .read_session_group(event_id, &mut *ext_cxn)
+            .await
+            .map_err(AlternateSessionError::PortError)?
+            .ok_or(AlternateSessionError::EventNotFound(event_id))?;
+
+        let requested = &session_group.requested;
+        let mut alternates: Vec<EventSession> = session_group
+            .siblings
+            .into_iter()
+            .filter(|session| session.id != requested.id && !session.cancelled)
+            .filter(|session| session.start != requested.start || session.end != requested.end)
+            .filter(|session| !available_only || session.tickets_available > 0)
+            .collect();
+        alternates.sort_by_key(|session| (session.start, session.id));
+
+        Ok(alternates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod alternate_sessions {
+        use super::*;
+        use crate::domain::session::driving_ports::SessionPort;
+        use crate::domain::session::test_util::FakeSessionReader;
+        use crate::domain::test_util::Connectivity;
+        use crate::external_connections;
+        use chrono::TimeZone;
+        use speculoos::prelude::*;
+        use std::sync::Mutex;
+
+        fn session(id: i64, hour: u32, tickets_available: u16) -> EventSession {
+            let start = Tz::America__Indiana__Indianapolis
+                .with_ymd_and_hms(2024, 8, 1, hour, 0, 0)
+                .unwrap();
+            EventSession {
+                id,
+                game_id: format!("RPG24ND{id:06}"),
+                start,
+                end: start + chrono::Duration::hours(2),
+                tickets_available,
+                cancelled: false,
+            }
+        }
+
+        fn reader_with_group() -> Mutex<FakeSessionReader> {
+            Mutex::new(FakeSessionReader {
+                groups: vec![SessionGroup {
+                    requested: session(1, 10, 0),
+                    siblings: vec![
+                        session(4, 18, 0),
+                        session(2, 14, 3),
+                        session(3, 10, 6),
+                        session(1, 10, 0),
+                    ],
+                }],
+                connectivity: Connectivity::Connected,
+            })
+        }
+
+        #[tokio::test]
+        async fn lists_sessions_at_other_times_in_order() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let alternates = SessionService
+                .alternate_sessions(1, false, &reader_with_group(), &mut ext_cxn)
+                .await
+                .expect("alternate session lookup should succeed");
+
+            let ids: Vec<i64> = alternates.iter().map(|session| session.id).collect();
+            assert_eq!(vec![2, 4], ids);
+        }
+
+        #[tokio::test]
+        async fn leaves_out_sold_out_sessions_when_requested() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let alternates = SessionService
+                .alternate_sessions(1, true, &reader_with_group(), &mut ext_cxn)
+                .await
+                .expect("alternate session lookup should succeed");
+
+            let ids: Vec<i64> = alternates.iter().map(|session| session.id).collect();
+            assert_eq!(vec![2], ids);
+        }
+
+        #[tokio::test]
+        async fn leaves_out_cancelled_sessions() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let reader = reader_with_group();
+            reader.lock().unwrap().groups[0].siblings[1].cancelled = true;
+
+            let alternates = SessionService
+                .alternate_sessions(1, false, &reader, &mut ext_cxn)
+                .await
+                .expect("alternate session lookup should succeed");
+
+            let ids: Vec<i64> = alternates.iter().map(|session| session.id).collect();
+            assert_eq!(vec![4], ids);
+        }
+
+        #[tokio::test]
+        async fn fails_for_missing_event() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+
+            let lookup_result = SessionService
+                .alternate_sessions(99, false, &reader_with_group(), &mut ext_cxn)
+                .await;
+
+            assert!(matches!(
+                lookup_result,
+                Err(AlternateSessionError::EventNotFound(99))
+            ));
+        }
+
+        #[tokio::test]
+        async fn propagates_port_errors() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let reader = reader_with_group();
+            reader.lock().unwrap().connectivity = Connectivity::Disconnected;
+
+            let lookup_result = SessionService
+                .alternate_sessions(1, false, &reader, &mut ext_cxn)
+                .await;
+
+            assert_that!(lookup_result).is_err();
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake implementing SessionReader over a fixed set of session groups
+    pub struct FakeSessionReader {
+        pub groups: Vec<SessionGroup>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::SessionReader for Mutex<FakeSessionReader> {
+        async fn read_session_group(
+            &self,
+            event_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<SessionGroup>, anyhow::Error> {
+            let locked_self = self
+                .lock()
+                .expect("could not lock session reader for reading session groups");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .groups
+                .iter()
+                .find(|group| group.requested.id == event_id)
+                .map(|group| SessionGroup {
+                    requested: group.requested.clone(),
+                    siblings: group.siblings.clone(),
+                }))
+        }
+    }
+}
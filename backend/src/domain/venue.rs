@@ -0,0 +1,508 @@
+use crate::domain::location::{Ref, RefType};
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::Duration;
+use derive_more::{Display, Error};
+use std::collections::HashMap;
+
+/// Average walking speed of a convention goer, allowing for crowds
+const WALKING_METERS_PER_MINUTE: f64 = 70.0;
+/// How much longer walking routes are than a straight line between two points, accounting for
+/// skywalks, stairs, and street crossings
+const ROUTE_DETOUR_FACTOR: f64 = 1.3;
+/// Mean radius of the earth, used to measure distances between coordinates
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A point on the map in decimal degrees
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinates {
+    /// Great-circle distance to the other point in meters
+    pub fn distance_meters(&self, other: &Coordinates) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let half_lat_delta = (lat2 - lat1) / 2.0;
+        let half_lon_delta = (other.longitude - self.longitude).to_radians() / 2.0;
+
+        let haversine =
+            half_lat_delta.sin().powi(2) + lat1.cos() * lat2.cos() * half_lon_delta.sin().powi(2);
+        2.0 * EARTH_RADIUS_METERS * haversine.sqrt().asin()
+    }
+
+    /// Estimated time to walk to the other point, rounded up to the minute
+    pub fn walking_time_to(&self, other: &Coordinates) -> Duration {
+        let route_meters = self.distance_meters(other) * ROUTE_DETOUR_FACTOR;
+        Duration::minutes((route_meters / WALKING_METERS_PER_MINUTE).ceil() as i64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A walking time measured between two buildings, which applies in both directions
+pub struct WalkingTime {
+    pub location_id: i32,
+    pub other_location_id: i32,
+    pub minutes: u16,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A room and where it is
+pub struct MappedRoom {
+    pub location_id: i32,
+    pub coordinates: Option<Coordinates>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Everything known about where buildings and rooms are and how long it takes to walk between
+/// them
+pub struct VenueMap {
+    pub location_coordinates: HashMap<i32, Coordinates>,
+    pub rooms: HashMap<i32, MappedRoom>,
+    /// Room ID of each section
+    pub section_rooms: HashMap<i32, i32>,
+    /// Walking minutes keyed by the pair of building IDs, lowest ID first
+    pub walking_minutes: HashMap<(i32, i32), u16>,
+}
+
+/// Orders a pair of building IDs the way walking times are keyed
+pub fn location_pair(location_id: i32, other_location_id: i32) -> (i32, i32) {
+    (
+        location_id.min(other_location_id),
+        location_id.max(other_location_id),
+    )
+}
+
+/// A place resolved to its building and, if known, the room it's in
+struct ResolvedPlace {
+    location_id: i32,
+    room_id: Option<i32>,
+}
+
+impl VenueMap {
+    /// Finds the building and room of a location, room, or section. Returns None if the place
+    /// isn't on the map.
+    fn resolve(&self, place: &Ref) -> Option<ResolvedPlace> {
+        let room_id = match place.ref_type {
+            RefType::Location => {
+                return Some(ResolvedPlace {
+                    location_id: place.id,
+                    room_id: None,
+                });
+            }
+            RefType::Room => place.id,
+            RefType::Section => *self.section_rooms.get(&place.id)?,
+        };
+
+        self.rooms.get(&room_id).map(|room| ResolvedPlace {
+            location_id: room.location_id,
+            room_id: Some(room_id),
+        })
+    }
+
+    /// The most precise coordinates known for the place, preferring its room over its building
+    fn coordinates(&self, place: &ResolvedPlace) -> Option<Coordinates> {
+        place
+            .room_id
+            .and_then(|room_id| self.rooms.get(&room_id))
+            .and_then(|room| room.coordinates)
+            .or_else(|| self.location_coordinates.get(&place.location_id).copied())
+    }
+
+    /// Estimates how long it takes to get from one place to another. Places in the same room
+    /// take no time to travel between, and places in the same building only take time if both
+    /// rooms have coordinates. Walking times recorded between buildings are used before
+    /// estimating from coordinates. Returns None if there isn't enough information to estimate.
+    pub fn transit_time(&self, from: &Ref, to: &Ref) -> Option<Duration> {
+        let from = self.resolve(from)?;
+        let to = self.resolve(to)?;
+
+        if from.location_id == to.location_id {
+            if from.room_id.is_some() && from.room_id == to.room_id {
+                return Some(Duration::zero());
+            }
+            let room_coordinates = |place: &ResolvedPlace| {
+                place
+                    .room_id
+                    .and_then(|room_id| self.rooms.get(&room_id))
+                    .and_then(|room| room.coordinates)
+            };
+            return Some(match (room_coordinates(&from), room_coordinates(&to)) {
+                (Some(from_coords), Some(to_coords)) => from_coords.walking_time_to(&to_coords),
+                _ => Duration::zero(),
+            });
+        }
+
+        if let Some(minutes) = self
+            .walking_minutes
+            .get(&location_pair(from.location_id, to.location_id))
+        {
+            return Some(Duration::minutes(*minutes as i64));
+        }
+
+        let from_coords = self.coordinates(&from)?;
+        let to_coords = self.coordinates(&to)?;
+        Some(from_coords.walking_time_to(&to_coords))
+    }
+
+    /// Lists the recorded walking times, ordered by building IDs
+    pub fn walking_times(&self) -> Vec<WalkingTime> {
+        let mut walking_times: Vec<WalkingTime> = self
+            .walking_minutes
+            .iter()
+            .map(|((location_id, other_location_id), minutes)| WalkingTime {
+                location_id: *location_id,
+                other_location_id: *other_location_id,
+                minutes: *minutes,
+            })
+            .collect();
+        walking_times
+            .sort_by_key(|walking_time| (walking_time.location_id, walking_time.other_location_id));
+
+        walking_times
+    }
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while maintaining venue geography
+pub enum VenueError {
+    #[display("{} with ID {} does not exist", _0.ref_type, _0.id)]
+    NotFound(#[error(not(source))] Ref),
+    #[display("Walking times can only be recorded between two different buildings")]
+    SameLocation,
+    #[display("No walking time is recorded between buildings {} and {}", _0, _1)]
+    NoWalkingTime(#[error(not(source))] i32, i32),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Reads where buildings and rooms are and the walking times between them
+    pub trait VenueMapReader: Sync {
+        async fn read_venue_map(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<VenueMap, anyhow::Error>;
+    }
+
+    /// Records where buildings and rooms are and the walking times between them
+    pub trait VenueMapWriter: Sync {
+        /// Sets or clears the coordinates of a building. Returns false if the building doesn't
+        /// exist.
+        async fn set_location_coordinates(
+            &self,
+            location_id: i32,
+            coordinates: Option<Coordinates>,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Sets or clears the coordinates of a room. Returns false if the room doesn't exist.
+        async fn set_room_coordinates(
+            &self,
+            room_id: i32,
+            coordinates: Option<Coordinates>,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+
+        /// Records the walking time between two buildings, replacing any time already recorded.
+        /// Returns the ID of a building which doesn't exist, if any.
+        async fn save_walking_time(
+            &self,
+            walking_time: &WalkingTime,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<i32>, anyhow::Error>;
+
+        /// Removes the walking time recorded between two buildings. Returns false if no time was
+        /// recorded.
+        async fn delete_walking_time(
+            &self,
+            location_pair: (i32, i32),
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for maintaining where buildings and rooms are and how long it takes to walk
+    /// between them
+    pub trait VenuePort: Sync {
+        async fn venue_map(
+            &self,
+            reader: &impl driven_ports::VenueMapReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<VenueMap, anyhow::Error>;
+
+        /// Sets or clears the coordinates of a building or room
+        async fn set_coordinates(
+            &self,
+            place: &Ref,
+            coordinates: Option<Coordinates>,
+            writer: &impl driven_ports::VenueMapWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), VenueError>;
+
+        async fn save_walking_time(
+            &self,
+            walking_time: &WalkingTime,
+            writer: &impl driven_ports::VenueMapWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), VenueError>;
+
+        async fn delete_walking_time(
+            &self,
+            location_id: i32,
+            other_location_id: i32,
+            writer: &impl driven_ports::VenueMapWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), VenueError>;
+    }
+}
+
+/// Service implementation of the VenuePort
+pub struct VenueService;
+
+impl driving_ports::VenuePort for VenueService {
+    #[tracing::instrument(skip_all)]
+    async fn venue_map(
+        &self,
+        reader: &impl driven_ports::VenueMapReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<VenueMap, anyhow::Error> {
+        reader
+            .read_venue_map(ext_cxn)
+            .await
+            .context("Reading venue map")
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn set_coordinates(
+        &self,
+        place: &Ref,
+        coordinates: Option<Coordinates>,
+        writer: &impl driven_ports::VenueMapWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), VenueError> {
+        let place_exists = match place.ref_type {
+            RefType::Location => writer
+                .set_location_coordinates(place.id, coordinates, ext_cxn)
+                .await
+                .context("Saving building coordinates"),
+            RefType::Room => writer
+                .set_room_coordinates(place.id, coordinates, ext_cxn)
+                .await
+                .context("Saving room coordinates"),
+            // Sections share the coordinates of their room
+            RefType::Section => Ok(false),
+        }
+        .map_err(VenueError::PortError)?;
+        if !place_exists {
+            return Err(VenueError::NotFound(place.clone()));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn save_walking_time(
+        &self,
+        walking_time: &WalkingTime,
+        writer: &impl driven_ports::VenueMapWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), VenueError> {
+        if walking_time.location_id == walking_time.other_location_id {
+            return Err(VenueError::SameLocation);
+        }
+
+        let (location_id, other_location_id) =
+            location_pair(walking_time.location_id, walking_time.other_location_id);
+        let missing_location_id = writer
+            .save_walking_time(
+                &WalkingTime {
+                    location_id,
+                    other_location_id,
+                    minutes: walking_time.minutes,
+                },
+                ext_cxn,
+            )
+            .await
+            .context("Saving walking time")
+            .map_err(VenueError::PortError)?;
+        if let Some(missing_location_id) = missing_location_id {
+            return Err(VenueError::NotFound(Ref {
+                id: missing_location_id,
+                ref_type: RefType::Location,
+            }));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, writer, ext_cxn))]
+    async fn delete_walking_time(
+        &self,
+        location_id: i32,
+        other_location_id: i32,
+        writer: &impl driven_ports::VenueMapWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), VenueError> {
+        let deleted = writer
+            .delete_walking_time(location_pair(location_id, other_location_id), ext_cxn)
+            .await
+            .context("Deleting walking time")
+            .map_err(VenueError::PortError)?;
+        if !deleted {
+            return Err(VenueError::NoWalkingTime(location_id, other_location_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The convention center and a hotel a few blocks away, with two rooms and a section in the
+    /// convention center
+    fn venue_map() -> VenueMap {
+        VenueMap {
+            location_coordinates: HashMap::from([
+                (
+                    1,
+                    Coordinates {
+                        latitude: 39.7637,
+                        longitude: -86.1623,
+                    },
+                ),
+                (
+                    2,
+                    Coordinates {
+                        latitude: 39.7658,
+                        longitude: -86.1643,
+                    },
+                ),
+            ]),
+            rooms: HashMap::from([
+                (
+                    10,
+                    MappedRoom {
+                        location_id: 1,
+                        coordinates: Some(Coordinates {
+                            latitude: 39.7630,
+                            longitude: -86.1610,
+                        }),
+                    },
+                ),
+                (
+                    11,
+                    MappedRoom {
+                        location_id: 1,
+                        coordinates: None,
+                    },
+                ),
+                (
+                    20,
+                    MappedRoom {
+                        location_id: 3,
+                        coordinates: None,
+                    },
+                ),
+            ]),
+            section_rooms: HashMap::from([(100, 10)]),
+            walking_minutes: HashMap::new(),
+        }
+    }
+
+    fn place(id: i32, ref_type: RefType) -> Ref {
+        Ref { id, ref_type }
+    }
+
+    mod distance_meters {
+        use super::*;
+
+        #[test]
+        fn measures_great_circle_distance() {
+            let stadium = Coordinates {
+                latitude: 39.7601,
+                longitude: -86.1639,
+            };
+            let convention_center = Coordinates {
+                latitude: 39.7637,
+                longitude: -86.1623,
+            };
+
+            let distance = stadium.distance_meters(&convention_center);
+
+            assert!(
+                (400.0..440.0).contains(&distance),
+                "distance was {distance}"
+            );
+        }
+    }
+
+    mod transit_time {
+        use super::*;
+
+        #[test]
+        fn needs_no_time_within_a_room() {
+            let transit =
+                venue_map().transit_time(&place(100, RefType::Section), &place(10, RefType::Room));
+
+            assert_eq!(Some(Duration::zero()), transit);
+        }
+
+        #[test]
+        fn needs_room_coordinates_to_time_walks_within_a_building() {
+            let map = venue_map();
+
+            let with_one_room =
+                map.transit_time(&place(10, RefType::Room), &place(11, RefType::Room));
+            let with_building =
+                map.transit_time(&place(100, RefType::Section), &place(1, RefType::Location));
+
+            assert_eq!(Some(Duration::zero()), with_one_room);
+            assert_eq!(Some(Duration::zero()), with_building);
+        }
+
+        #[test]
+        fn prefers_recorded_walking_times() {
+            let mut map = venue_map();
+            map.walking_minutes.insert(location_pair(2, 1), 12);
+
+            let transit =
+                map.transit_time(&place(2, RefType::Location), &place(100, RefType::Section));
+
+            assert_eq!(Some(Duration::minutes(12)), transit);
+        }
+
+        #[test]
+        fn estimates_from_coordinates() {
+            let map = venue_map();
+
+            let to_room = map.transit_time(&place(10, RefType::Room), &place(2, RefType::Location));
+            let to_building =
+                map.transit_time(&place(11, RefType::Room), &place(2, RefType::Location));
+
+            assert_eq!(Some(Duration::minutes(8)), to_room);
+            assert_eq!(Some(Duration::minutes(6)), to_building);
+        }
+
+        #[test]
+        fn cannot_estimate_without_coordinates() {
+            let map = venue_map();
+
+            let unknown_building =
+                map.transit_time(&place(20, RefType::Room), &place(1, RefType::Location));
+            let unknown_room =
+                map.transit_time(&place(99, RefType::Room), &place(1, RefType::Location));
+
+            assert_eq!(None, unknown_building);
+            assert_eq!(None, unknown_room);
+        }
+    }
+}
@@ -0,0 +1,605 @@
+use crate::domain::location;
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::DateTime;
+use chrono_tz::Tz;
+use derive_more::{Display, Error};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The parts of an event that watchers are notified about when they change
+pub struct EventSnapshot {
+    pub event_id: i64,
+    pub title: String,
+    pub start: DateTime<Tz>,
+    pub end: DateTime<Tz>,
+    pub tickets_available: u16,
+    pub location: Option<location::Ref>,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A change to an event that watchers should hear about
+pub enum EventChange {
+    /// The event was sold out and now has tickets available again
+    TicketsAvailable {
+        tickets_available: u16,
+    },
+    StartTimeChanged {
+        previous_start: DateTime<Tz>,
+        new_start: DateTime<Tz>,
+    },
+    LocationChanged {
+        previous_location: Option<location::Ref>,
+        new_location: Option<location::Ref>,
+    },
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An event which changed in a way watchers should hear about
+pub struct ChangedEvent {
+    pub event_id: i64,
+    pub title: String,
+    pub changes: Vec<EventChange>,
+}
+
+#[derive(Debug, Clone)]
+/// All the changes to a single event that one watcher should be told about
+pub struct WatchNotification {
+    pub user_id: String,
+    pub event_id: i64,
+    pub title: String,
+    pub changes: Vec<EventChange>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while updating a user's watchlist
+pub enum WatchlistError {
+    #[display("Event with ID {} does not exist", _0)]
+    EventNotFound(#[error(not(source))] i64),
+    PortError(anyhow::Error),
+}
+
+impl From<driven_ports::AddWatchError> for WatchlistError {
+    fn from(value: driven_ports::AddWatchError) -> Self {
+        match value {
+            driven_ports::AddWatchError::EventDoesNotExist(id) => Self::EventNotFound(id),
+            driven_ports::AddWatchError::PortError(e) => Self::PortError(e),
+        }
+    }
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    #[derive(Debug, Display, Error)]
+    /// Errors when adding an event to a user's watchlist
+    pub enum AddWatchError {
+        #[display("Event with ID {} does not exist", _0)]
+        EventDoesNotExist(#[error(not(source))] i64),
+        PortError(anyhow::Error),
+    }
+
+    /// Reads the current state of events so changes can be detected
+    pub trait EventSnapshotReader: Sync {
+        /// Retrieves snapshots of the requested events. Events that don't exist are omitted.
+        async fn read_snapshots(
+            &self,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventSnapshot>, anyhow::Error>;
+    }
+
+    /// Reads watchlist entries from storage
+    pub trait WatchlistReader: Sync {
+        /// Lists the IDs of events a user is watching
+        async fn watched_event_ids(
+            &self,
+            user_id: &str,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error>;
+
+        /// Returns the IDs of users watching each of the requested events. Events nobody is
+        /// watching are omitted from the map.
+        async fn watchers_of_events(
+            &self,
+            event_ids: &[i64],
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<HashMap<i64, Vec<String>>, anyhow::Error>;
+    }
+
+    /// Adds and removes watchlist entries in storage
+    pub trait WatchlistWriter: Sync {
+        /// Adds an event to a user's watchlist. Watching an already watched event does nothing.
+        async fn add_watch(
+            &self,
+            user_id: &str,
+            event_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), AddWatchError>;
+
+        /// Removes an event from a user's watchlist. Returns false if the user wasn't watching it.
+        async fn remove_watch(
+            &self,
+            user_id: &str,
+            event_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+
+    /// Delivers watchlist notifications to users
+    pub trait NotificationSender: Sync {
+        async fn send_notifications(
+            &self,
+            notifications: &[WatchNotification],
+        ) -> Result<(), anyhow::Error>;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for managing the events a user is watching
+    pub trait WatchlistPort: Sync {
+        /// Retrieves the current state of every event the user is watching
+        async fn watched_events(
+            &self,
+            user_id: &str,
+            watchlist_reader: &impl driven_ports::WatchlistReader,
+            snapshot_reader: &impl driven_ports::EventSnapshotReader,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventSnapshot>, anyhow::Error>;
+
+        /// Starts watching an event for the user
+        async fn watch_event(
+            &self,
+            user_id: &str,
+            event_id: i64,
+            watchlist_writer: &impl driven_ports::WatchlistWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), WatchlistError>;
+
+        /// Stops watching an event for the user
+        async fn unwatch_event(
+            &self,
+            user_id: &str,
+            event_id: i64,
+            watchlist_writer: &impl driven_ports::WatchlistWriter,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), WatchlistError>;
+
+        /// Notifies everyone watching one of the changed events. Must only be called once the
+        /// changes are committed. Failing to deliver notifications is logged rather than
+        /// returned.
+        async fn notify_watchers(
+            &self,
+            changed_events: &[ChangedEvent],
+            watchlist_reader: &impl driven_ports::WatchlistReader,
+            notification_sender: &impl driven_ports::NotificationSender,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+    }
+}
+
+/// Service implementation of the WatchlistPort
+pub struct WatchlistService;
+
+impl driving_ports::WatchlistPort for WatchlistService {
+    #[tracing::instrument(skip(self, watchlist_reader, snapshot_reader, ext_cxn))]
+    async fn watched_events(
+        &self,
+        user_id: &str,
+        watchlist_reader: &impl driven_ports::WatchlistReader,
+        snapshot_reader: &impl driven_ports::EventSnapshotReader,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventSnapshot>, anyhow::Error> {
+        let event_ids = watchlist_reader
+            .watched_event_ids(user_id, &mut *ext_cxn)
+            .await
+            .context("Reading watched event IDs")?;
+        let mut snapshots = snapshot_reader
+            .read_snapshots(&event_ids, &mut *ext_cxn)
+            .await
+            .context("Reading watched events")?;
+        snapshots.sort_by_key(|snapshot| (snapshot.start, snapshot.event_id));
+
+        Ok(snapshots)
+    }
+
+    #[tracing::instrument(skip(self, watchlist_writer, ext_cxn))]
+    async fn watch_event(
+        &self,
+        user_id: &str,
+        event_id: i64,
+        watchlist_writer: &impl driven_ports::WatchlistWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), WatchlistError> {
+        Ok(watchlist_writer
+            .add_watch(user_id, event_id, ext_cxn)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(self, watchlist_writer, ext_cxn))]
+    async fn unwatch_event(
+        &self,
+        user_id: &str,
+        event_id: i64,
+        watchlist_writer: &impl driven_ports::WatchlistWriter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), WatchlistError> {
+        let removed = watchlist_writer
+            .remove_watch(user_id, event_id, ext_cxn)
+            .await
+            .map_err(WatchlistError::PortError)?;
+        if !removed {
+            return Err(WatchlistError::EventNotFound(event_id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(total_changed = changed_events.len()))]
+    async fn notify_watchers(
+        &self,
+        changed_events: &[ChangedEvent],
+        watchlist_reader: &impl driven_ports::WatchlistReader,
+        notification_sender: &impl driven_ports::NotificationSender,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), anyhow::Error> {
+        if changed_events.is_empty() {
+            return Ok(());
+        }
+
+        let changed_ids: Vec<i64> = changed_events
+            .iter()
+            .map(|changed_event| changed_event.event_id)
+            .collect();
+        let watchers_by_event = watchlist_reader
+            .watchers_of_events(&changed_ids, ext_cxn)
+            .await
+            .context("Reading watchers of changed events")?;
+
+        let notifications: Vec<WatchNotification> = changed_events
+            .iter()
+            .flat_map(|changed_event| {
+                watchers_by_event
+                    .get(&changed_event.event_id)
+                    .into_iter()
+                    .flatten()
+                    .map(move |user_id| WatchNotification {
+                        user_id: user_id.clone(),
+                        event_id: changed_event.event_id,
+                        title: changed_event.title.clone(),
+                        changes: changed_event.changes.clone(),
+                    })
+            })
+            .collect();
+        if notifications.is_empty() {
+            return Ok(());
+        }
+
+        if let Err(send_err) = notification_sender.send_notifications(&notifications).await {
+            tracing::warn!(
+                total_notifications = notifications.len(),
+                "Failed to deliver watchlist notifications: {send_err:#}"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Lists what watchers need to hear about between two snapshots of the same event
+pub fn detect_changes(before: &EventSnapshot, after: &EventSnapshot) -> Vec<EventChange> {
+    let mut changes: Vec<EventChange> = Vec::new();
+
+    if after.cancelled {
+        if !before.cancelled {
+            changes.push(EventChange::Cancelled);
+        }
+        // Nothing else about a cancelled event matters to a watcher
+        return changes;
+    }
+
+    if before.tickets_available == 0 && after.tickets_available > 0 {
+        changes.push(EventChange::TicketsAvailable {
+            tickets_available: after.tickets_available,
+        });
+    }
+    if before.start != after.start {
+        changes.push(EventChange::StartTimeChanged {
+            previous_start: before.start,
+            new_start: after.start,
+        });
+    }
+    if before.location != after.location {
+        changes.push(EventChange::LocationChanged {
+            previous_location: before.location.clone(),
+            new_location: after.location.clone(),
+        });
+    }
+
+    changes
+}
+
+/// Lists the events which changed between two sets of snapshots in a way watchers should hear
+/// about
+pub fn detect_event_changes(
+    previous: &[EventSnapshot],
+    updated: &[EventSnapshot],
+) -> Vec<ChangedEvent> {
+    let previous_by_id: HashMap<i64, &EventSnapshot> = previous
+        .iter()
+        .map(|snapshot| (snapshot.event_id, snapshot))
+        .collect();
+
+    updated
+        .iter()
+        .filter_map(|after| {
+            let before = previous_by_id.get(&after.event_id)?;
+            let changes = detect_changes(before, after);
+            (!changes.is_empty()).then(|| ChangedEvent {
+                event_id: after.event_id,
+                title: after.title.clone(),
+                changes,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn snapshot(event_id: i64, tickets_available: u16) -> EventSnapshot {
+        let start = Tz::America__Indiana__Indianapolis
+            .with_ymd_and_hms(2024, 8, 1, 10, 0, 0)
+            .unwrap();
+        EventSnapshot {
+            event_id,
+            title: format!("Event {event_id}"),
+            start,
+            end: start + chrono::Duration::hours(2),
+            tickets_available,
+            location: Some(location::Ref {
+                id: 1,
+                ref_type: location::RefType::Location,
+            }),
+            cancelled: false,
+        }
+    }
+
+    mod detect_changes {
+        use super::*;
+
+        #[test]
+        fn reports_tickets_freeing_up() {
+            let changes = detect_changes(&snapshot(1, 0), &snapshot(1, 4));
+
+            assert_eq!(
+                vec![EventChange::TicketsAvailable {
+                    tickets_available: 4
+                }],
+                changes
+            );
+        }
+
+        #[test]
+        fn ignores_ticket_count_changes_while_available() {
+            let changes = detect_changes(&snapshot(1, 6), &snapshot(1, 4));
+
+            assert!(changes.is_empty());
+        }
+
+        #[test]
+        fn reports_start_time_and_location_changes() {
+            let before = snapshot(1, 2);
+            let mut after = snapshot(1, 2);
+            after.start += chrono::Duration::hours(1);
+            after.location = None;
+
+            let changes = detect_changes(&before, &after);
+
+            assert_eq!(
+                vec![
+                    EventChange::StartTimeChanged {
+                        previous_start: before.start,
+                        new_start: after.start,
+                    },
+                    EventChange::LocationChanged {
+                        previous_location: before.location.clone(),
+                        new_location: None,
+                    },
+                ],
+                changes
+            );
+        }
+
+        #[test]
+        fn only_reports_cancellation_once() {
+            let mut cancelled = snapshot(1, 4);
+            cancelled.cancelled = true;
+
+            assert_eq!(
+                vec![EventChange::Cancelled],
+                detect_changes(&snapshot(1, 0), &cancelled)
+            );
+            assert!(detect_changes(&cancelled, &cancelled).is_empty());
+        }
+    }
+
+    mod notify_watchers {
+        use super::*;
+        use crate::domain::test_util::Connectivity;
+        use crate::domain::watchlist::driving_ports::WatchlistPort;
+        use crate::domain::watchlist::test_util::{FakeNotificationSender, FakeWatchlist};
+        use crate::external_connections;
+        use speculoos::prelude::*;
+        use std::sync::Mutex;
+
+        fn watchlist() -> Mutex<FakeWatchlist> {
+            Mutex::new(FakeWatchlist {
+                entries: vec![
+                    ("alice".to_owned(), 1),
+                    ("bob".to_owned(), 1),
+                    ("bob".to_owned(), 2),
+                ],
+                snapshots: Vec::new(),
+                connectivity: Connectivity::Connected,
+            })
+        }
+
+        #[tokio::test]
+        async fn notifies_each_watcher_of_changed_events() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let sender = Mutex::new(FakeNotificationSender::new());
+
+            let changed_events = detect_event_changes(
+                &[snapshot(1, 0), snapshot(2, 3), snapshot(3, 0)],
+                &[snapshot(1, 2), snapshot(2, 1), snapshot(3, 5)],
+            );
+            WatchlistService
+                .notify_watchers(&changed_events, &watchlist(), &sender, &mut ext_cxn)
+                .await
+                .expect("notifying watchers should succeed");
+
+            let sent = &sender.lock().unwrap().sent;
+            let recipients: Vec<(&str, i64)> = sent
+                .iter()
+                .map(|notification| (notification.user_id.as_str(), notification.event_id))
+                .collect();
+            assert_eq!(vec![("alice", 1), ("bob", 1)], recipients);
+        }
+
+        #[tokio::test]
+        async fn tolerates_delivery_failures() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let sender = Mutex::new(FakeNotificationSender::new());
+            sender.lock().unwrap().connectivity = Connectivity::Disconnected;
+
+            let changed_events = detect_event_changes(&[snapshot(1, 0)], &[snapshot(1, 2)]);
+            let notify_result = WatchlistService
+                .notify_watchers(&changed_events, &watchlist(), &sender, &mut ext_cxn)
+                .await;
+
+            assert_that!(notify_result).is_ok();
+        }
+
+        #[tokio::test]
+        async fn fails_if_watchers_cannot_be_read() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let sender = Mutex::new(FakeNotificationSender::new());
+            let watchlist = watchlist();
+            watchlist.lock().unwrap().connectivity = Connectivity::Disconnected;
+
+            let changed_events = detect_event_changes(&[snapshot(1, 0)], &[snapshot(1, 2)]);
+            let notify_result = WatchlistService
+                .notify_watchers(&changed_events, &watchlist, &sender, &mut ext_cxn)
+                .await;
+
+            assert_that!(notify_result).is_err();
+            assert!(sender.lock().unwrap().sent.is_empty());
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake implementing watchlist storage and event snapshot reads
+    pub struct FakeWatchlist {
+        /// (user ID, event ID) pairs
+        pub entries: Vec<(String, i64)>,
+        pub snapshots: Vec<EventSnapshot>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::WatchlistReader for Mutex<FakeWatchlist> {
+        async fn watched_event_ids(
+            &self,
+            user_id: &str,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<i64>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake watchlist");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .entries
+                .iter()
+                .filter(|(watcher, _)| watcher == user_id)
+                .map(|(_, event_id)| *event_id)
+                .collect())
+        }
+
+        async fn watchers_of_events(
+            &self,
+            event_ids: &[i64],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<HashMap<i64, Vec<String>>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake watchlist");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let mut watchers: HashMap<i64, Vec<String>> = HashMap::new();
+            for (user_id, event_id) in locked_self.entries.iter() {
+                if event_ids.contains(event_id) {
+                    watchers.entry(*event_id).or_default().push(user_id.clone());
+                }
+            }
+
+            Ok(watchers)
+        }
+    }
+
+    impl driven_ports::EventSnapshotReader for Mutex<FakeWatchlist> {
+        async fn read_snapshots(
+            &self,
+            event_ids: &[i64],
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<EventSnapshot>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake watchlist");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .snapshots
+                .iter()
+                .filter(|snapshot| event_ids.contains(&snapshot.event_id))
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// Fake NotificationSender which records every notification it's asked to send
+    pub struct FakeNotificationSender {
+        pub sent: Vec<WatchNotification>,
+        pub connectivity: Connectivity,
+    }
+
+    impl FakeNotificationSender {
+        pub fn new() -> Self {
+            Self {
+                sent: Vec::new(),
+                connectivity: Connectivity::Connected,
+            }
+        }
+    }
+
+    impl driven_ports::NotificationSender for Mutex<FakeNotificationSender> {
+        async fn send_notifications(
+            &self,
+            notifications: &[WatchNotification],
+        ) -> Result<(), anyhow::Error> {
+            let mut locked_self = self
+                .lock()
+                .expect("could not lock fake notification sender");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+            locked_self.sent.extend(notifications.iter().cloned());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,1108 @@
+use crate::domain::event::ImportOutcome;
+use crate::external_connections::ExternalConnectivity;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use derive_more::{Display, Error};
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use std::time::Duration;
+use url::{Host, Url};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Serialize, Deserialize)]
+/// The kinds of activity webhook subscribers can be notified about
+pub enum Topic {
+    #[display("import.completed")]
+    #[serde(rename = "import.completed")]
+    ImportCompleted,
+    #[display("event.created")]
+    #[serde(rename = "event.created")]
+    EventCreated,
+    #[display("event.updated")]
+    #[serde(rename = "event.updated")]
+    EventUpdated,
+    #[display("event.cancelled")]
+    #[serde(rename = "event.cancelled")]
+    EventCancelled,
+    #[display("event.deleted")]
+    #[serde(rename = "event.deleted")]
+    EventDeleted,
+}
+
+impl Topic {
+    pub const ALL: [Topic; 5] = [
+        Topic::ImportCompleted,
+        Topic::EventCreated,
+        Topic::EventUpdated,
+        Topic::EventCancelled,
+        Topic::EventDeleted,
+    ];
+}
+
+#[derive(Debug, Display, Error)]
+#[display("Unrecognized webhook topic: {_0}")]
+pub struct UnknownTopic(#[error(not(source))] String);
+
+impl FromStr for Topic {
+    type Err = UnknownTopic;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Topic::ALL
+            .into_iter()
+            .find(|topic| topic.to_string() == s)
+            .ok_or_else(|| UnknownTopic(s.to_owned()))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A downstream system registered to receive webhook calls
+pub struct Subscription {
+    pub id: i64,
+    pub target_url: String,
+    /// Shared secret used to sign payloads so the receiver can verify them
+    pub secret: String,
+    pub topics: Vec<Topic>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+/// Parameters for registering a new webhook subscription
+pub struct NewSubscription {
+    pub target_url: String,
+    pub secret: String,
+    pub topics: Vec<Topic>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Totals describing a completed import
+pub struct ImportSummary {
+    pub total_events: usize,
+    pub created: usize,
+    pub changed: usize,
+    pub cancelled: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// The JSON body sent to webhook subscribers
+pub struct WebhookMessage {
+    pub topic: Topic,
+    pub occurred_at: DateTime<Utc>,
+    /// Events the message is about. Empty for import completion messages.
+    pub event_ids: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_summary: Option<ImportSummary>,
+}
+
+impl WebhookMessage {
+    /// Builds the messages describing an import: one for the import itself, plus one per
+    /// event-level topic that had any affected events
+    pub fn from_import(outcome: &ImportOutcome, occurred_at: DateTime<Utc>) -> Vec<Self> {
+        let mut messages = vec![Self {
+            topic: Topic::ImportCompleted,
+            occurred_at,
+            event_ids: Vec::new(),
+            import_summary: Some(ImportSummary {
+                total_events: outcome.event_ids.len(),
+                created: outcome.created_ids.len(),
+                changed: outcome.changed_ids.len(),
+                cancelled: outcome.newly_cancelled_ids.len(),
+            }),
+        }];
+
+        for (topic, event_ids) in [
+            (Topic::EventCreated, &outcome.created_ids),
+            (Topic::EventUpdated, &outcome.changed_ids),
+            (Topic::EventCancelled, &outcome.newly_cancelled_ids),
+        ] {
+            if !event_ids.is_empty() {
+                messages.push(Self {
+                    topic,
+                    occurred_at,
+                    event_ids: event_ids.clone(),
+                    import_summary: None,
+                });
+            }
+        }
+
+        messages
+    }
+
+    /// Builds the message announcing that an event was deleted
+    pub fn from_deletion(event_id: i64, occurred_at: DateTime<Utc>) -> Self {
+        Self {
+            topic: Topic::EventDeleted,
+            occurred_at,
+            event_ids: vec![event_id],
+            import_summary: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+/// Where a webhook delivery is in its lifecycle
+pub enum DeliveryStatus {
+    #[display("pending")]
+    Pending,
+    #[display("succeeded")]
+    Succeeded,
+    #[display("failed")]
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+/// A delivery that has been logged and is waiting to be sent
+pub struct PendingDelivery {
+    pub delivery_id: i64,
+    pub subscription: Subscription,
+    pub message: WebhookMessage,
+    /// Attempts already made to send the delivery
+    pub attempts: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Result of a single attempt to call a subscriber
+pub enum AttemptOutcome {
+    /// The subscriber responded with the contained HTTP status code
+    Responded(u16),
+    /// The subscriber could not be reached
+    Unreachable(String),
+}
+
+impl AttemptOutcome {
+    fn is_success(&self) -> bool {
+        matches!(self, Self::Responded(status) if (200..300).contains(status))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An entry in the webhook delivery log
+pub struct DeliveryRecord {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub topic: Topic,
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: u16,
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// When the delivery will next be attempted, or None once it has succeeded or failed
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+/// Controls how failed deliveries are retried. The wait before each retry doubles, starting at
+/// `initial_backoff`.
+pub struct RetryPolicy {
+    pub max_attempts: u16,
+    pub initial_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// How long to wait before retrying a delivery which has failed the passed number of times
+    fn backoff_after(&self, failed_attempts: u16) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(u32::from(failed_attempts.saturating_sub(1))))
+    }
+}
+
+/// Most deliveries claimed from the log at once
+const CLAIM_BATCH_SIZE: u16 = 10;
+
+/// How long claimed deliveries are held for the claiming server before another may claim them.
+/// Long enough to attempt a full batch even when none of the subscribers respond.
+const CLAIM_LEASE: Duration = Duration::from_secs(300);
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+#[derive(Debug, Display, Error)]
+/// Errors that can occur while managing webhook subscriptions
+pub enum WebhookError {
+    #[display("Webhook subscription with ID {} does not exist", _0)]
+    SubscriptionNotFound(#[error(not(source))] i64),
+    #[display("Webhook target {} is not a publicly reachable URL", _0)]
+    NonPublicTarget(#[error(not(source))] String),
+    PortError(anyhow::Error),
+}
+
+pub mod driven_ports {
+    use super::*;
+
+    /// Stores webhook subscriptions
+    pub trait SubscriptionStore: Sync {
+        async fn create_subscription(
+            &self,
+            subscription: &NewSubscription,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Subscription, anyhow::Error>;
+
+        async fn list_subscriptions(
+            &self,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<Subscription>, anyhow::Error>;
+
+        /// Retrieves a single subscription, or None if it doesn't exist
+        async fn read_subscription(
+            &self,
+            subscription_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<Subscription>, anyhow::Error>;
+
+        /// Removes a subscription. Returns false if it didn't exist.
+        async fn delete_subscription(
+            &self,
+            subscription_id: i64,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error>;
+    }
+
+    /// Records webhook deliveries and the result of each attempt to send them
+    pub trait DeliveryLog: Sync {
+        /// Logs a new pending delivery of the message to a subscription, due to be sent right
+        /// away, returning its ID
+        async fn record_pending(
+            &self,
+            subscription_id: i64,
+            message: &WebhookMessage,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<i64, anyhow::Error>;
+
+        /// Claims up to `limit` pending deliveries whose next attempt is due, oldest first. The
+        /// claimed deliveries' next attempts are pushed back by `lease` so other servers leave
+        /// them alone while they're being sent.
+        async fn claim_due_deliveries(
+            &self,
+            limit: u16,
+            lease: Duration,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<PendingDelivery>, anyhow::Error>;
+
+        /// Records the outcome of an attempt to send a delivery along with its resulting status
+        /// and when it should next be attempted, if it should be retried
+        async fn record_attempt(
+            &self,
+            delivery_id: i64,
+            outcome: &AttemptOutcome,
+            status: DeliveryStatus,
+            next_attempt_at: Option<DateTime<Utc>>,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error>;
+
+        /// Lists the most recent deliveries for a subscription, newest first
+        async fn list_deliveries(
+            &self,
+            subscription_id: i64,
+            limit: u16,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<DeliveryRecord>, anyhow::Error>;
+    }
+
+    /// Sends signed webhook messages to subscribers
+    pub trait WebhookDeliverer: Sync {
+        /// Makes a single attempt to send a delivery
+        async fn attempt_delivery(&self, delivery: &PendingDelivery) -> AttemptOutcome;
+    }
+}
+
+pub mod driving_ports {
+    use super::*;
+
+    /// Domain port for managing webhook subscriptions and sending webhooks
+    pub trait WebhookPort: Sync {
+        /// Registers a subscription. Targets on loopback, private, or link-local addresses are
+        /// refused so subscribers can't point deliveries at internal services.
+        async fn create_subscription(
+            &self,
+            subscription: &NewSubscription,
+            subscription_store: &impl driven_ports::SubscriptionStore,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Subscription, WebhookError>;
+
+        async fn list_subscriptions(
+            &self,
+            subscription_store: &impl driven_ports::SubscriptionStore,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<Subscription>, anyhow::Error>;
+
+        async fn delete_subscription(
+            &self,
+            subscription_id: i64,
+            subscription_store: &impl driven_ports::SubscriptionStore,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), WebhookError>;
+
+        /// Lists the most recent deliveries made to a subscription
+        async fn list_deliveries(
+            &self,
+            subscription_id: i64,
+            limit: u16,
+            subscription_store: &impl driven_ports::SubscriptionStore,
+            delivery_log: &impl driven_ports::DeliveryLog,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<DeliveryRecord>, WebhookError>;
+
+        /// Logs a pending delivery of each message to every subscription interested in its topic.
+        /// The deliveries are sent later by [deliver_due](Self::deliver_due).
+        async fn publish(
+            &self,
+            messages: &[WebhookMessage],
+            subscription_store: &impl driven_ports::SubscriptionStore,
+            delivery_log: &impl driven_ports::DeliveryLog,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<PendingDelivery>, anyhow::Error>;
+
+        /// Makes one attempt at a batch of logged deliveries which are due. Failed deliveries are
+        /// scheduled for a retry with exponential backoff until the retry policy's attempts run
+        /// out. Returns how many deliveries were attempted, which is 0 once none are due.
+        async fn deliver_due(
+            &self,
+            retry_policy: &RetryPolicy,
+            deliverer: &impl driven_ports::WebhookDeliverer,
+            delivery_log: &impl driven_ports::DeliveryLog,
+            ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<usize, anyhow::Error>;
+    }
+}
+
+/// Service implementation of the WebhookPort
+pub struct WebhookService;
+
+impl driving_ports::WebhookPort for WebhookService {
+    #[tracing::instrument(skip_all, fields(target_url = subscription.target_url))]
+    async fn create_subscription(
+        &self,
+        subscription: &NewSubscription,
+        subscription_store: &impl driven_ports::SubscriptionStore,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Subscription, WebhookError> {
+        if !is_public_target(&subscription.target_url) {
+            return Err(WebhookError::NonPublicTarget(
+                subscription.target_url.clone(),
+            ));
+        }
+
+        subscription_store
+            .create_subscription(subscription, ext_cxn)
+            .await
+            .context("Creating webhook subscription")
+            .map_err(WebhookError::PortError)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn list_subscriptions(
+        &self,
+        subscription_store: &impl driven_ports::SubscriptionStore,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<Subscription>, anyhow::Error> {
+        subscription_store
+            .list_subscriptions(ext_cxn)
+            .await
+            .context("Listing webhook subscriptions")
+    }
+
+    #[tracing::instrument(skip(self, subscription_store, ext_cxn))]
+    async fn delete_subscription(
+        &self,
+        subscription_id: i64,
+        subscription_store: &impl driven_ports::SubscriptionStore,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), WebhookError> {
+        let deleted = subscription_store
+            .delete_subscription(subscription_id, ext_cxn)
+            .await
+            .map_err(WebhookError::PortError)?;
+        if !deleted {
+            return Err(WebhookError::SubscriptionNotFound(subscription_id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, subscription_store, delivery_log, ext_cxn))]
+    async fn list_deliveries(
+        &self,
+        subscription_id: i64,
+        limit: u16,
+        subscription_store: &impl driven_ports::SubscriptionStore,
+        delivery_log: &impl driven_ports::DeliveryLog,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<DeliveryRecord>, WebhookError> {
+        let subscription = subscription_store
+            .read_subscription(subscription_id, &mut *ext_cxn)
+            .await
+            .map_err(WebhookError::PortError)?;
+        if subscription.is_none() {
+            return Err(WebhookError::SubscriptionNotFound(subscription_id));
+        }
+
+        delivery_log
+            .list_deliveries(subscription_id, limit, &mut *ext_cxn)
+            .await
+            .map_err(WebhookError::PortError)
+    }
+
+    #[tracing::instrument(skip_all, fields(total_messages = messages.len()))]
+    async fn publish(
+        &self,
+        messages: &[WebhookMessage],
+        subscription_store: &impl driven_ports::SubscriptionStore,
+        delivery_log: &impl driven_ports::DeliveryLog,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<PendingDelivery>, anyhow::Error> {
+        let subscriptions = subscription_store
+            .list_subscriptions(&mut *ext_cxn)
+            .await
+            .context("Reading webhook subscriptions to publish to")?;
+
+        let mut pending_deliveries: Vec<PendingDelivery> = Vec::new();
+        for message in messages {
+            for subscription in subscriptions
+                .iter()
+                .filter(|subscription| subscription.topics.contains(&message.topic))
+            {
+                let delivery_id = delivery_log
+                    .record_pending(subscription.id, message, &mut *ext_cxn)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Logging {} delivery to subscription {}",
+                            message.topic, subscription.id
+                        )
+                    })?;
+                pending_deliveries.push(PendingDelivery {
+                    delivery_id,
+                    subscription: subscription.clone(),
+                    message: message.clone(),
+                    attempts: 0,
+                });
+            }
+        }
+
+        Ok(pending_deliveries)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn deliver_due(
+        &self,
+        retry_policy: &RetryPolicy,
+        deliverer: &impl driven_ports::WebhookDeliverer,
+        delivery_log: &impl driven_ports::DeliveryLog,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<usize, anyhow::Error> {
+        let due_deliveries = delivery_log
+            .claim_due_deliveries(CLAIM_BATCH_SIZE, CLAIM_LEASE, &mut *ext_cxn)
+            .await
+            .context("Claiming due webhook deliveries")?;
+
+        for delivery in due_deliveries.iter() {
+            let outcome = deliverer.attempt_delivery(delivery).await;
+            let attempts = delivery.attempts + 1;
+            let (status, next_attempt_at) = if outcome.is_success() {
+                (DeliveryStatus::Succeeded, None)
+            } else if attempts >= retry_policy.max_attempts {
+                (DeliveryStatus::Failed, None)
+            } else {
+                let backoff = retry_policy.backoff_after(attempts);
+                (DeliveryStatus::Pending, Some(Utc::now() + backoff))
+            };
+
+            delivery_log
+                .record_attempt(
+                    delivery.delivery_id,
+                    &outcome,
+                    status,
+                    next_attempt_at,
+                    &mut *ext_cxn,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Recording attempt to send webhook delivery {}",
+                        delivery.delivery_id
+                    )
+                })?;
+            match status {
+                DeliveryStatus::Pending => {
+                    tracing::warn!(
+                        delivery_id = delivery.delivery_id,
+                        attempts,
+                        ?outcome,
+                        ?next_attempt_at,
+                        "Webhook delivery failed, will retry."
+                    )
+                }
+                DeliveryStatus::Failed => {
+                    tracing::error!(
+                        delivery_id = delivery.delivery_id,
+                        attempts,
+                        ?outcome,
+                        "Webhook delivery failed, giving up."
+                    )
+                }
+                DeliveryStatus::Succeeded => {}
+            }
+        }
+
+        Ok(due_deliveries.len())
+    }
+}
+
+/// Checks whether a webhook target URL points somewhere outside the server's own network. Only
+/// the host as written is checked, since a name's DNS records can change after the subscription
+/// is created.
+fn is_public_target(target_url: &str) -> bool {
+    let Ok(url) = Url::parse(target_url) else {
+        return false;
+    };
+
+    match url.host() {
+        None => false,
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(addr)) => is_public_ipv4(addr),
+        Some(Host::Ipv6(addr)) => match addr.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(addr),
+        },
+    }
+}
+
+fn is_public_ipv4(addr: Ipv4Addr) -> bool {
+    !(addr.is_loopback()
+        || addr.is_private()
+        || addr.is_link_local()
+        || addr.is_unspecified()
+        || addr.is_broadcast())
+}
+
+fn is_public_ipv6(addr: Ipv6Addr) -> bool {
+    !(addr.is_loopback()
+        || addr.is_unspecified()
+        || addr.is_unique_local()
+        || addr.is_unicast_link_local())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use crate::domain::webhook::driving_ports::WebhookPort;
+    use crate::domain::webhook::test_util::{FakeDeliverer, FakeWebhookStore};
+    use crate::external_connections;
+    use std::sync::Mutex;
+
+    fn subscription(id: i64, topics: Vec<Topic>) -> Subscription {
+        Subscription {
+            id,
+            target_url: format!("https://example.com/hooks/{id}"),
+            secret: "super-secret-value".to_owned(),
+            topics,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn store_with_subscriptions() -> Mutex<FakeWebhookStore> {
+        Mutex::new(FakeWebhookStore {
+            subscriptions: vec![
+                subscription(1, vec![Topic::ImportCompleted]),
+                subscription(2, vec![Topic::EventCreated, Topic::EventCancelled]),
+            ],
+            deliveries: Vec::new(),
+            connectivity: Connectivity::Connected,
+        })
+    }
+
+    mod from_import {
+        use super::*;
+
+        #[test]
+        fn only_includes_topics_with_affected_events() {
+            let outcome = ImportOutcome {
+                event_ids: vec![1, 2, 3],
+                created_ids: vec![3],
+                changed_ids: Vec::new(),
+                newly_cancelled_ids: Vec::new(),
+                watched_changes: Vec::new(),
+                unparseable_values: Vec::new(),
+            };
+
+            let messages = WebhookMessage::from_import(&outcome, Utc::now());
+
+            let topics: Vec<Topic> = messages.iter().map(|message| message.topic).collect();
+            assert_eq!(vec![Topic::ImportCompleted, Topic::EventCreated], topics);
+            assert_eq!(vec![3], messages[1].event_ids);
+            assert_eq!(3, messages[0].import_summary.as_ref().unwrap().total_events);
+        }
+    }
+
+    mod publish {
+        use super::*;
+
+        #[tokio::test]
+        async fn logs_deliveries_for_interested_subscriptions() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = store_with_subscriptions();
+            let outcome = ImportOutcome {
+                event_ids: vec![1, 2],
+                created_ids: vec![2],
+                changed_ids: vec![1],
+                newly_cancelled_ids: Vec::new(),
+                watched_changes: Vec::new(),
+                unparseable_values: Vec::new(),
+            };
+
+            let pending = WebhookService
+                .publish(
+                    &WebhookMessage::from_import(&outcome, Utc::now()),
+                    &store,
+                    &store,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("publishing should succeed");
+
+            let targets: Vec<(i64, Topic)> = pending
+                .iter()
+                .map(|delivery| (delivery.subscription.id, delivery.message.topic))
+                .collect();
+            assert_eq!(
+                vec![(1, Topic::ImportCompleted), (2, Topic::EventCreated)],
+                targets
+            );
+            assert_eq!(2, store.lock().unwrap().deliveries.len());
+        }
+
+        #[tokio::test]
+        async fn only_sends_deletions_to_delete_subscribers() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = store_with_subscriptions();
+            store
+                .lock()
+                .unwrap()
+                .subscriptions
+                .push(subscription(3, vec![Topic::EventDeleted]));
+
+            let pending = WebhookService
+                .publish(
+                    &[WebhookMessage::from_deletion(7, Utc::now())],
+                    &store,
+                    &store,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("publishing should succeed");
+
+            assert_eq!(1, pending.len());
+            assert_eq!(3, pending[0].subscription.id);
+            assert_eq!(vec![7], pending[0].message.event_ids);
+        }
+    }
+
+    mod create_subscription {
+        use super::*;
+
+        fn new_subscription(target_url: &str) -> NewSubscription {
+            NewSubscription {
+                target_url: target_url.to_owned(),
+                secret: "super-secret-value".to_owned(),
+                topics: vec![Topic::ImportCompleted],
+            }
+        }
+
+        #[tokio::test]
+        async fn saves_public_targets() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = store_with_subscriptions();
+
+            let created = WebhookService
+                .create_subscription(
+                    &new_subscription("https://hooks.example.com/genconcal"),
+                    &store,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("public targets should be accepted");
+
+            assert_eq!("https://hooks.example.com/genconcal", created.target_url);
+            assert_eq!(3, store.lock().unwrap().subscriptions.len());
+        }
+
+        #[tokio::test]
+        async fn refuses_internal_targets() {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let store = store_with_subscriptions();
+            let internal_targets = [
+                "http://localhost:8080/hooks",
+                "http://api.localhost/hooks",
+                "http://127.0.0.1/hooks",
+                "http://10.1.2.3/hooks",
+                "http://172.16.0.5/hooks",
+                "http://192.168.1.10/hooks",
+                "http://169.254.169.254/latest/meta-data",
+                "http://0.0.0.0/hooks",
+                "http://[::1]/hooks",
+                "http://[fd00::1]/hooks",
+                "http://[fe80::1]/hooks",
+                "http://[::ffff:127.0.0.1]/hooks",
+            ];
+
+            for target_url in internal_targets {
+                let create_result = WebhookService
+                    .create_subscription(&new_subscription(target_url), &store, &mut ext_cxn)
+                    .await;
+
+                assert!(
+                    matches!(create_result, Err(WebhookError::NonPublicTarget(_))),
+                    "{target_url} should be refused"
+                );
+            }
+            assert_eq!(2, store.lock().unwrap().subscriptions.len());
+        }
+    }
+
+    mod deliver_due {
+        use super::*;
+
+        fn no_wait_policy() -> RetryPolicy {
+            RetryPolicy {
+                max_attempts: 3,
+                initial_backoff: Duration::ZERO,
+            }
+        }
+
+        async fn publish_import_completed(store: &Mutex<FakeWebhookStore>) {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let outcome = ImportOutcome::default();
+            WebhookService
+                .publish(
+                    &WebhookMessage::from_import(&outcome, Utc::now()),
+                    store,
+                    store,
+                    &mut ext_cxn,
+                )
+                .await
+                .expect("publishing should succeed");
+        }
+
+        /// Sends due deliveries until none are left, returning how many times the log was checked
+        async fn deliver_until_idle(
+            retry_policy: &RetryPolicy,
+            deliverer: &Mutex<FakeDeliverer>,
+            store: &Mutex<FakeWebhookStore>,
+        ) -> usize {
+            let mut ext_cxn = external_connections::test_util::FakeExternalConnectivity::new();
+            let mut rounds = 0;
+            while WebhookService
+                .deliver_due(retry_policy, deliverer, store, &mut ext_cxn)
+                .await
+                .expect("delivery should be recorded")
+                > 0
+            {
+                rounds += 1;
+            }
+
+            rounds
+        }
+
+        #[tokio::test]
+        async fn retries_until_success() {
+            let store = store_with_subscriptions();
+            publish_import_completed(&store).await;
+            let deliverer = Mutex::new(FakeDeliverer {
+                outcomes: vec![
+                    AttemptOutcome::Unreachable("connection refused".to_owned()),
+                    AttemptOutcome::Responded(503),
+                    AttemptOutcome::Responded(204),
+                ],
+            });
+
+            let rounds = deliver_until_idle(&no_wait_policy(), &deliverer, &store).await;
+
+            assert_eq!(3, rounds);
+            let locked_store = store.lock().unwrap();
+            let record = &locked_store.deliveries[0];
+            assert_eq!(DeliveryStatus::Succeeded, record.status);
+            assert_eq!(3, record.attempts);
+            assert_eq!(Some(204), record.last_status_code);
+            assert_eq!(None, record.next_attempt_at);
+        }
+
+        #[tokio::test]
+        async fn gives_up_after_max_attempts() {
+            let store = store_with_subscriptions();
+            publish_import_completed(&store).await;
+            let deliverer = Mutex::new(FakeDeliverer {
+                outcomes: vec![AttemptOutcome::Responded(500); 5],
+            });
+
+            deliver_until_idle(&no_wait_policy(), &deliverer, &store).await;
+
+            let locked_store = store.lock().unwrap();
+            assert_eq!(DeliveryStatus::Failed, locked_store.deliveries[0].status);
+            assert_eq!(3, locked_store.deliveries[0].attempts);
+            assert_eq!(2, deliverer.lock().unwrap().outcomes.len());
+        }
+
+        #[tokio::test]
+        async fn waits_for_backoff_before_retrying() {
+            let store = store_with_subscriptions();
+            publish_import_completed(&store).await;
+            let deliverer = Mutex::new(FakeDeliverer {
+                outcomes: vec![AttemptOutcome::Responded(500); 2],
+            });
+            let retry_policy = RetryPolicy {
+                max_attempts: 3,
+                initial_backoff: Duration::from_secs(60),
+            };
+
+            let rounds = deliver_until_idle(&retry_policy, &deliverer, &store).await;
+
+            assert_eq!(1, rounds);
+            let locked_store = store.lock().unwrap();
+            let record = &locked_store.deliveries[0];
+            assert_eq!(DeliveryStatus::Pending, record.status);
+            assert!(record.next_attempt_at.unwrap() > Utc::now() + Duration::from_secs(50));
+        }
+    }
+
+    mod backoff_after {
+        use super::*;
+
+        #[test]
+        fn doubles_after_each_failure() {
+            let retry_policy = RetryPolicy::default();
+
+            assert_eq!(Duration::from_secs(2), retry_policy.backoff_after(1));
+            assert_eq!(Duration::from_secs(4), retry_policy.backoff_after(2));
+            assert_eq!(Duration::from_secs(16), retry_policy.backoff_after(4));
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::*;
+    use crate::domain::test_util::Connectivity;
+    use std::sync::Mutex;
+
+    /// In-memory fake implementing subscription and delivery log storage
+    pub struct FakeWebhookStore {
+        pub subscriptions: Vec<Subscription>,
+        pub deliveries: Vec<DeliveryRecord>,
+        pub connectivity: Connectivity,
+    }
+
+    impl driven_ports::SubscriptionStore for Mutex<FakeWebhookStore> {
+        async fn create_subscription(
+            &self,
+            subscription: &NewSubscription,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Subscription, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let created = Subscription {
+                id: locked_self.subscriptions.len() as i64 + 1,
+                target_url: subscription.target_url.clone(),
+                secret: subscription.secret.clone(),
+                topics: subscription.topics.clone(),
+                created_at: Utc::now(),
+            };
+            locked_self.subscriptions.push(created.clone());
+
+            Ok(created)
+        }
+
+        async fn list_subscriptions(
+            &self,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<Subscription>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self.subscriptions.clone())
+        }
+
+        async fn read_subscription(
+            &self,
+            subscription_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Option<Subscription>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .subscriptions
+                .iter()
+                .find(|subscription| subscription.id == subscription_id)
+                .cloned())
+        }
+
+        async fn delete_subscription(
+            &self,
+            subscription_id: i64,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<bool, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let starting_len = locked_self.subscriptions.len();
+            locked_self
+                .subscriptions
+                .retain(|subscription| subscription.id != subscription_id);
+
+            Ok(locked_self.subscriptions.len() < starting_len)
+        }
+    }
+
+    impl driven_ports::DeliveryLog for Mutex<FakeWebhookStore> {
+        async fn record_pending(
+            &self,
+            subscription_id: i64,
+            message: &WebhookMessage,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<i64, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let id = locked_self.deliveries.len() as i64 + 1;
+            locked_self.deliveries.push(DeliveryRecord {
+                id,
+                subscription_id,
+                topic: message.topic,
+                payload: serde_json::to_value(message)?,
+                status: DeliveryStatus::Pending,
+                attempts: 0,
+                last_status_code: None,
+                last_error: None,
+                created_at: Utc::now(),
+                last_attempt_at: None,
+                next_attempt_at: Some(Utc::now()),
+            });
+
+            Ok(id)
+        }
+
+        async fn claim_due_deliveries(
+            &self,
+            limit: u16,
+            lease: Duration,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<PendingDelivery>, anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let now = Utc::now();
+            let subscriptions = locked_self.subscriptions.clone();
+            let mut claimed: Vec<PendingDelivery> = Vec::new();
+            for record in locked_self.deliveries.iter_mut().filter(|record| {
+                record.status == DeliveryStatus::Pending
+                    && record.next_attempt_at.is_some_and(|due_at| due_at <= now)
+            }) {
+                if claimed.len() >= limit as usize {
+                    break;
+                }
+                let Some(subscription) = subscriptions
+                    .iter()
+                    .find(|subscription| subscription.id == record.subscription_id)
+                else {
+                    continue;
+                };
+
+                record.next_attempt_at = Some(now + lease);
+                claimed.push(PendingDelivery {
+                    delivery_id: record.id,
+                    subscription: subscription.clone(),
+                    message: serde_json::from_value(record.payload.clone())?,
+                    attempts: record.attempts,
+                });
+            }
+
+            Ok(claimed)
+        }
+
+        async fn record_attempt(
+            &self,
+            delivery_id: i64,
+            outcome: &AttemptOutcome,
+            status: DeliveryStatus,
+            next_attempt_at: Option<DateTime<Utc>>,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<(), anyhow::Error> {
+            let mut locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            let Some(record) = locked_self
+                .deliveries
+                .iter_mut()
+                .find(|record| record.id == delivery_id)
+            else {
+                return Err(anyhow::anyhow!("Delivery {delivery_id} does not exist"));
+            };
+            record.attempts += 1;
+            record.status = status;
+            record.last_attempt_at = Some(Utc::now());
+            record.next_attempt_at = next_attempt_at;
+            match outcome {
+                AttemptOutcome::Responded(status_code) => {
+                    record.last_status_code = Some(*status_code);
+                    record.last_error = None;
+                }
+                AttemptOutcome::Unreachable(reason) => {
+                    record.last_status_code = None;
+                    record.last_error = Some(reason.clone());
+                }
+            }
+
+            Ok(())
+        }
+
+        async fn list_deliveries(
+            &self,
+            subscription_id: i64,
+            limit: u16,
+            _ext_cxn: &mut impl ExternalConnectivity,
+        ) -> Result<Vec<DeliveryRecord>, anyhow::Error> {
+            let locked_self = self.lock().expect("could not lock fake webhook store");
+            locked_self.connectivity.blow_up_if_disconnected()?;
+
+            Ok(locked_self
+                .deliveries
+                .iter()
+                .rev()
+                .filter(|record| record.subscription_id == subscription_id)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// Fake WebhookDeliverer which returns a scripted sequence of attempt outcomes
+    pub struct FakeDeliverer {
+        /// Outcomes returned by each attempt, in order
+        pub outcomes: Vec<AttemptOutcome>,
+    }
+
+    impl driven_ports::WebhookDeliverer for Mutex<FakeDeliverer> {
+        async fn attempt_delivery(&self, _delivery: &PendingDelivery) -> AttemptOutcome {
+            let mut locked_self = self.lock().expect("could not lock fake deliverer");
+            if locked_self.outcomes.is_empty() {
+                return AttemptOutcome::Unreachable("no outcomes left".to_owned());
+            }
+
+            locked_self.outcomes.remove(0)
+        }
+    }
+}
@@ -1,8 +1,11 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, ParseError, TimeZone};
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, ParseError, TimeZone, Utc,
+};
 use chrono_tz::Tz;
 use derive_more::{Display, Error};
 use fake::faker::boolean::en::Boolean;
@@ -14,7 +17,7 @@ use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use utoipa::openapi::{RefOr, Schema};
 use utoipa::{OpenApi, ToSchema, openapi};
-use validator::ValidationErrors;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::domain;
 use crate::domain::event::{AgeRequirement, ExperienceLevel};
@@ -25,14 +28,22 @@ use crate::dto::IngestEventConvertErr::{UnrecognizedAgeRequirement, Unrecognized
     schemas(
         EventImportRequest,
         ImportedEvent,
+        ImportReport,
+        UnparseableValue,
+        ImportedField,
         NumberOrString,
         DaysResponse,
         EventDay,
         TimeInfoResponse,
         TimeBlockedEventsResponse,
         PaginationInfo,
+        EventFacets,
+        FacetValueCount,
+        FacetRangeCount,
         EventBlock,
         EventSummary,
+        EventSortOption,
+        SortDirectionOption,
         TicketAvailability,
         DateDto,
         TimeDto,
@@ -43,6 +54,9 @@ use crate::dto::IngestEventConvertErr::{UnrecognizedAgeRequirement, Unrecognized
         CommaSeparated<i32>,
         CommaSeparated<String>,
         EventType,
+        EventTypeUpdateRequest,
+        ContentTagsRequest,
+        ContentTagsResponse,
         EventDetailResponse,
         GameSystem,
         Location,
@@ -50,11 +64,68 @@ use crate::dto::IngestEventConvertErr::{UnrecognizedAgeRequirement, Unrecognized
         Group,
         TournamentInfo,
         LocationPart,
+        LocationsResponse,
+        LocationTree,
+        RoomTree,
+        VenueGeographyResponse,
+        LocationCoordinates,
+        RoomCoordinates,
+        WalkingTime,
+        CoordinatesRequest,
+        AccessibilityRequest,
+        WalkingTimeRequest,
+        LocationAliasesResponse,
+        LocationAlias,
+        RoomAlias,
+        SectionAlias,
+        MetadataAlias,
+        MergeRequest,
+        GameSystemDetailResponse,
+        PriceRange,
+        GroupSummary,
+        GroupProfileResponse,
+        ContactSharingRequest,
+        ContactPurgeResponse,
+        GameMastersResponse,
+        GameMasterSummary,
+        GameMasterDetailResponse,
+        GameMasterYear,
+        GameMasterEvent,
         TournamentSegment,
         RelatedEvent,
+        ScheduleSuggestionRequest,
+        ScheduleWish,
+        MealBreakRequest,
+        ScheduleSuggestionResponse,
+        ScheduledEvent,
+        DroppedWish,
+        AlternateSessionsResponse,
+        AlternateSession,
+        WatchlistResponse,
+        WatchedEvent,
+        SavedSearchRequest,
+        SavedSearch,
+        SavedSearchesResponse,
+        SavedSearchMatchesResponse,
+        SavedSearchMatch,
+        EventUpdateMessage,
+        WebhookSubscriptionRequest,
+        WebhookTopic,
+        WebhookSubscription,
+        WebhookSubscriptionsResponse,
+        WebhookDeliveriesResponse,
+        WebhookDelivery,
+        SearchSuggestionsResponse,
+        SearchSuggestion,
+        SearchSuggestionKind,
+        EventListResponse,
+        DatedEventSummary,
+        DayEventBlocks,
     ),
     responses(
         err_resps::BasicError400Validation,
+        err_resps::BasicError401,
+        err_resps::BasicError403,
         err_resps::BasicError404,
         err_resps::BasicError500,
     ),
@@ -136,6 +207,161 @@ pub struct DailyTimeBlockedEventsResponse {
 pub struct TimeBlockedEventsResponse {
     pub pagination_info: PaginationInfo,
     pub events_by_time: Vec<EventBlock>,
+    /// Counts of the events each filter value would produce, present when facets are requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<EventFacets>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventListResponse {
+    pub pagination_info: PaginationInfo,
+    /// Events in sort order, present unless grouping by day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<DatedEventSummary>>,
+    /// Events grouped by the day and then the hour they start in, present when grouping by day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_by_day: Option<Vec<DayEventBlocks>>,
+    /// Counts of the events each filter value would produce, present when facets are requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<EventFacets>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// An event summary along with the day the event starts on
+pub struct DatedEventSummary {
+    #[schema(example = 20240801)]
+    pub day_id: u32,
+    #[serde(flatten)]
+    pub event: EventSummary,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Events starting on one day, grouped by the hour they start in
+pub struct DayEventBlocks {
+    #[schema(example = 20240801)]
+    pub day_id: u32,
+    #[schema(example = "8/1/2024")]
+    pub date: DateDto,
+    pub events_by_time: Vec<EventBlock>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Counts of the events each filter value would produce. Each facet applies every filter except
+/// its own, so choosing a value from a facet returns the listed number of events.
+pub struct EventFacets {
+    pub event_types: Vec<FacetValueCount>,
+    pub age_requirements: Vec<FacetValueCount>,
+    pub experience_levels: Vec<FacetValueCount>,
+    pub game_systems: Vec<FacetValueCount>,
+    pub groups: Vec<FacetValueCount>,
+    pub buildings: Vec<FacetValueCount>,
+    /// Cost ranges in dollars. Free events fall into the range starting at 0.
+    pub cost_ranges: Vec<FacetRangeCount>,
+    /// Duration ranges in hours
+    pub duration_ranges: Vec<FacetRangeCount>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// The number of events with a filter value
+pub struct FacetValueCount {
+    /// The value to pass in the matching filter query parameter
+    #[schema(example = "12")]
+    pub value: String,
+    #[schema(example = "Pathfinder Second Edition")]
+    pub label: String,
+    #[schema(example = 143)]
+    pub count: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// The number of events falling into a range, including the minimum but not the maximum
+pub struct FacetRangeCount {
+    #[schema(example = 1.0)]
+    pub min: f32,
+    /// Missing for the last, unbounded range
+    #[schema(example = 5.0)]
+    pub max: Option<f32>,
+    #[schema(example = 87)]
+    pub count: u64,
+}
+
+impl From<domain::search::NamedCount> for FacetValueCount {
+    fn from(value: domain::search::NamedCount) -> Self {
+        Self {
+            value: value.id.to_string(),
+            label: value.name,
+            count: value.count,
+        }
+    }
+}
+
+impl From<domain::search::ValueCount<AgeRequirement>> for FacetValueCount {
+    fn from(value: domain::search::ValueCount<AgeRequirement>) -> Self {
+        let (filter_value, label) = match value.value {
+            AgeRequirement::Everyone => ("everyone", "Everyone"),
+            AgeRequirement::KidsOnly => ("kidsonly", "Kids Only"),
+            AgeRequirement::Teen => ("teen", "Teen"),
+            AgeRequirement::Mature => ("mature", "Mature"),
+            AgeRequirement::Adult => ("adult", "Adult"),
+        };
+
+        Self {
+            value: filter_value.to_owned(),
+            label: label.to_owned(),
+            count: value.count,
+        }
+    }
+}
+
+impl From<domain::search::ValueCount<ExperienceLevel>> for FacetValueCount {
+    fn from(value: domain::search::ValueCount<ExperienceLevel>) -> Self {
+        let (filter_value, label) = match value.value {
+            ExperienceLevel::None => ("none", "None"),
+            ExperienceLevel::Some => ("some", "Some"),
+            ExperienceLevel::Expert => ("expert", "Expert"),
+        };
+
+        Self {
+            value: filter_value.to_owned(),
+            label: label.to_owned(),
+            count: value.count,
+        }
+    }
+}
+
+impl From<domain::search::RangeCount> for FacetRangeCount {
+    fn from(value: domain::search::RangeCount) -> Self {
+        Self {
+            min: value.range.min,
+            max: value.range.max,
+            count: value.count,
+        }
+    }
+}
+
+impl From<domain::search::EventFacets> for EventFacets {
+    fn from(value: domain::search::EventFacets) -> Self {
+        fn convert<T, U: From<T>>(counts: Vec<T>) -> Vec<U> {
+            counts.into_iter().map(U::from).collect()
+        }
+
+        Self {
+            event_types: convert(value.event_types),
+            age_requirements: convert(value.age_requirements),
+            experience_levels: convert(value.experience_levels),
+            game_systems: convert(value.game_systems),
+            groups: convert(value.groups),
+            buildings: convert(value.buildings),
+            cost_ranges: convert(value.cost_ranges),
+            duration_ranges: convert(value.duration_ranges),
+        }
+    }
 }
 
 #[derive(Serialize, ToSchema)]
@@ -145,6 +371,11 @@ pub struct PaginationInfo {
     pub page: u16,
     #[schema(example = 15)]
     pub total_pages: u16,
+    /// Link to the next page of results, if there is one
+    #[schema(example = "/api/days/20240801/events?limit=50&cursor=7b22736f7274223a2253746172")]
+    pub next: Option<String>,
+    /// Link to the previous page of results, if there is one
+    pub prev: Option<String>,
 }
 
 #[derive(Clone, Serialize, ToSchema)]
@@ -155,6 +386,47 @@ pub struct EventBlock {
     pub events: Vec<EventSummary>,
 }
 
+#[derive(Deserialize, ToSchema, Clone, Copy, Debug)]
+#[serde(rename_all = "kebab-case")]
+/// Orderings available for lists of events
+pub enum EventSortOption {
+    StartTime,
+    Title,
+    Cost,
+    Duration,
+    TicketsAvailable,
+    Relevance,
+}
+
+impl From<EventSortOption> for domain::search::EventSort {
+    fn from(value: EventSortOption) -> Self {
+        match value {
+            EventSortOption::StartTime => Self::StartTime,
+            EventSortOption::Title => Self::Title,
+            EventSortOption::Cost => Self::Cost,
+            EventSortOption::Duration => Self::Duration,
+            EventSortOption::TicketsAvailable => Self::TicketsAvailable,
+            EventSortOption::Relevance => Self::Relevance,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Clone, Copy, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortDirectionOption {
+    Asc,
+    Desc,
+}
+
+impl From<SortDirectionOption> for domain::search::SortDirection {
+    fn from(value: SortDirectionOption) -> Self {
+        match value {
+            SortDirectionOption::Asc => Self::Ascending,
+            SortDirectionOption::Desc => Self::Descending,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EventSummary {
@@ -172,6 +444,22 @@ pub fn event_in_time_slot(time: u32) -> EventInTimeSlot {
     EventInTimeSlot(time)
 }
 
+impl From<domain::search::EventSearchResult> for EventSummary {
+    fn from(value: domain::search::EventSearchResult) -> Self {
+        Self {
+            id: value.id as u32,
+            event_time: TimeDto(value.start.time()),
+            duration: (value.end - value.start).num_minutes() as f32 / 60.0,
+            title: value.title,
+            tickets: TicketAvailability {
+                available: value.tickets_available,
+                total: value.max_players,
+            },
+            cost: value.cost.map(|cost| cost as u16),
+        }
+    }
+}
+
 impl Dummy<EventInTimeSlot> for EventSummary {
     fn dummy_with_rng<R: Rng + ?Sized>(config: &EventInTimeSlot, rng: &mut R) -> Self {
         let id: u32 = (100..10_000).fake_with_rng(rng);
@@ -233,11 +521,83 @@ impl Dummy<DiscreteF32> for f32 {
 }
 
 #[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EventType {
     #[schema(example = 10)]
     pub id: u32,
-    #[schema(example = "BGM - Board Game")]
-    pub type_name: String,
+    #[schema(example = "BGM")]
+    /// Short code GenCon identifies the type with
+    pub code: String,
+    #[schema(example = "Board Game")]
+    pub name: String,
+    #[schema(example = "Tabletop games played with a board and pieces")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[schema(example = "board-game")]
+    /// Name or URL of the icon shown beside the type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[schema(example = 1400)]
+    /// Number of the current year's uncancelled events of this type
+    pub total_events: u64,
+}
+
+impl From<domain::event_type::EventTypeDetails> for EventType {
+    fn from(value: domain::event_type::EventTypeDetails) -> Self {
+        Self {
+            id: value.id as u32,
+            code: value.code,
+            name: value.name,
+            description: value.description,
+            icon: value.icon,
+            total_events: value.total_events,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EventTypeUpdateRequest {
+    #[schema(example = "Board Game")]
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+
+    #[schema(example = "Tabletop games played with a board and pieces")]
+    #[validate(length(max = 2000))]
+    /// Leave out to clear the description
+    pub description: Option<String>,
+
+    #[schema(example = "board-game")]
+    #[validate(length(max = 255))]
+    /// Name or URL of the icon shown beside the type. Leave out to clear the icon.
+    pub icon: Option<String>,
+}
+
+impl From<EventTypeUpdateRequest> for domain::event_type::EventTypeUpdate {
+    fn from(value: EventTypeUpdateRequest) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            icon: value.icon,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentTagsRequest {
+    #[schema(example = json!(["Horror", "jump scares"]))]
+    #[validate(length(max = 50))]
+    /// Tags replacing the event's current tags. Leave empty to clear them.
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentTagsResponse {
+    #[schema(example = json!(["horror", "jump-scares"]))]
+    /// Tags saved on the event, lowercased with words joined by hyphens
+    pub tags: Vec<String>,
 }
 
 #[derive(Serialize, ToSchema, Clone)]
@@ -397,6 +757,120 @@ pub struct GameSystem {
     pub name: String,
 }
 
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// A game system along with a summary of the current year's uncancelled events using it
+pub struct GameSystemDetailResponse {
+    #[schema(example = 58)]
+    pub id: u32,
+    #[schema(example = "Twilight Imperium 4th Edition")]
+    pub name: String,
+    #[schema(example = 12)]
+    pub total_events: u64,
+    #[schema(example = 72)]
+    /// Total number of players the events can seat
+    pub total_seats: u64,
+    /// Cheapest and most expensive events, absent when no events use the system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_range: Option<PriceRange>,
+    #[schema(example = json!([20240801, 20240803]))]
+    /// IDs of the days events start on (YYYYMMDD format), in order
+    pub days_offered: Vec<u32>,
+    /// Groups organizing the events
+    pub groups: Vec<Group>,
+    pub events: EventListResponse,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Costs in dollars. Free events cost 0.
+pub struct PriceRange {
+    #[schema(example = 0)]
+    pub min_cost: u32,
+    #[schema(example = 24)]
+    pub max_cost: u32,
+}
+
+impl From<domain::metadata::GameSystem> for GameSystem {
+    fn from(value: domain::metadata::GameSystem) -> Self {
+        Self {
+            id: value.id as u32,
+            name: value.system_name,
+        }
+    }
+}
+
+impl From<domain::metadata::Group> for Group {
+    fn from(value: domain::metadata::Group) -> Self {
+        Self {
+            id: value.id as u32,
+            name: value.name,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupSummary {
+    #[schema(example = 30)]
+    pub id: u32,
+    #[schema(example = "Super Group Ltd.")]
+    pub name: String,
+    #[schema(example = 14)]
+    /// Number of the current year's uncancelled events the group runs
+    pub total_events: u64,
+}
+
+impl From<domain::organizer::GroupSummary> for GroupSummary {
+    fn from(value: domain::organizer::GroupSummary) -> Self {
+        Self {
+            id: value.group.id as u32,
+            name: value.group.name,
+            total_events: value.total_events,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupProfileResponse {
+    #[schema(example = 30)]
+    pub id: u32,
+    #[schema(example = "Super Group Ltd.")]
+    pub name: String,
+    #[schema(example = 14)]
+    pub total_events: u64,
+    #[schema(example = "https://supergroup.example.com")]
+    /// Website listed most often on the group's events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[schema(example = "events@supergroup.example.com")]
+    /// Contact email listed most often on the group's events. Only shown for groups which agreed
+    /// to share it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<String>,
+    /// Game systems the group's events use
+    pub game_systems: Vec<GameSystem>,
+    /// Every one of the group's events, grouped by day and then by hour
+    pub events_by_day: Vec<DayEventBlocks>,
+}
+
+#[derive(Deserialize, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactSharingRequest {
+    #[schema(example = true)]
+    /// Whether the group's contact email may be shown on its profile
+    pub share_contact: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactPurgeResponse {
+    #[schema(example = 214)]
+    /// Number of contact emails deleted
+    pub purged_contacts: u64,
+}
+
 #[derive(Serialize, Dummy, ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GameMaster {
@@ -408,6 +882,106 @@ pub struct GameMaster {
     pub name: String,
 }
 
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GameMastersResponse {
+    #[schema(example = 1)]
+    pub page: u16,
+    #[schema(example = 12)]
+    pub total_pages: u16,
+    #[schema(example = 580)]
+    /// Number of game masters matching the search across all pages
+    pub total_results: u64,
+    /// Game masters in name order
+    pub game_masters: Vec<GameMasterSummary>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GameMasterSummary {
+    #[schema(example = 40)]
+    pub id: u32,
+    #[schema(example = "John Smith")]
+    pub name: String,
+    #[schema(example = 6)]
+    /// Number of the current year's uncancelled events the game master runs
+    pub events_this_year: u64,
+    #[schema(example = 21)]
+    /// Number of uncancelled events the game master has run across every year
+    pub total_events: u64,
+}
+
+impl From<domain::game_master::GameMasterSummary> for GameMasterSummary {
+    fn from(value: domain::game_master::GameMasterSummary) -> Self {
+        Self {
+            id: value.game_master.id as u32,
+            name: value.game_master.name,
+            events_this_year: value.events_this_year,
+            total_events: value.total_events,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GameMasterDetailResponse {
+    #[schema(example = 40)]
+    pub id: u32,
+    #[schema(example = "John Smith")]
+    pub name: String,
+    /// Years the game master ran events in, newest first
+    pub years: Vec<GameMasterYear>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GameMasterYear {
+    #[schema(example = 2024)]
+    pub year: i32,
+    /// Events ordered by start time
+    pub events: Vec<GameMasterEvent>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GameMasterEvent {
+    #[schema(example = 1234)]
+    pub id: u32,
+    #[schema(example = "RPG24ND286543")]
+    /// Event ID assigned by GenCon
+    pub game_id: String,
+    #[schema(example = "Delve into the Underdark")]
+    pub title: String,
+    pub date: DateDto,
+    #[schema(example = "10:00")]
+    pub start_time: TimeDto,
+    #[schema(example = "12:00")]
+    pub end_time: TimeDto,
+    #[schema(example = 4)]
+    pub cost: Option<u32>,
+    pub tickets: TicketAvailability,
+    pub cancelled: bool,
+}
+
+impl From<domain::game_master::GameMasterEvent> for GameMasterEvent {
+    fn from(value: domain::game_master::GameMasterEvent) -> Self {
+        Self {
+            id: value.id as u32,
+            game_id: value.game_id,
+            title: value.title,
+            date: DateDto(value.start.date_naive()),
+            start_time: TimeDto(value.start.time()),
+            end_time: TimeDto(value.end.time()),
+            cost: value.cost,
+            tickets: TicketAvailability {
+                available: value.tickets_available,
+                total: value.max_players,
+            },
+            cancelled: value.cancelled,
+        }
+    }
+}
+
 #[derive(Serialize, Dummy, ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Group {
@@ -512,40 +1086,454 @@ pub struct LocationPart {
     pub name: String,
 }
 
-#[derive(Deserialize, Debug, ToSchema)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct EventImportRequest {
-    /// List of GenCon events to import
-    pub event_data: Vec<ImportedEvent>,
+pub struct LocationsResponse {
+    /// Buildings ordered by name
+    pub locations: Vec<LocationTree>,
 }
 
-#[derive(Deserialize, Debug, ToSchema)]
+#[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct ImportedEvent {
-    #[schema(example = "everyone (6+)")]
-    /// Event age requirement
-    ///
-    /// Valid values:
-    /// * "Everyone (6+)"
-    /// * "Kids Only (12 and under)"
-    /// * "Teen (13+)"
-    /// * "Mature (18+)"
-    /// * "21+"
-    pub age_requirement: String,
-
-    #[schema(example = "jdoe22@somewhere.com")]
-    /// The contact e-mail for the event organizer. Empty string for no contact.
-    pub contact: String,
+/// A building along with every room and section inside it
+pub struct LocationTree {
+    #[schema(example = 5)]
+    pub id: u32,
+    #[schema(example = "JW Marriott")]
+    pub name: String,
+    /// Rooms ordered by name
+    pub rooms: Vec<RoomTree>,
+}
 
-    #[schema(example = 10)]
-    /// The cost of the event (in dollars). 0 if the event is free.
-    pub cost: u32,
+impl From<domain::location::LocationTree> for LocationTree {
+    fn from(value: domain::location::LocationTree) -> Self {
+        Self {
+            id: value.id as u32,
+            name: value.name,
+            rooms: value.rooms.into_iter().map(Into::into).collect(),
+        }
+    }
+}
 
-    #[schema(
-        example = "This is a description of the event. It can be as long as you want. It can even contain multiple paragraphs."
-    )]
-    /// Description of the event
-    pub description_short: String,
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// A room along with every section inside it
+pub struct RoomTree {
+    #[schema(example = 18)]
+    pub id: u32,
+    #[schema(example = "3rd Floor Ballroom")]
+    pub name: String,
+    /// Sections ordered by name
+    pub sections: Vec<LocationPart>,
+}
+
+impl From<domain::location::RoomTree> for RoomTree {
+    fn from(value: domain::location::RoomTree) -> Self {
+        Self {
+            id: value.id as u32,
+            name: value.name,
+            sections: value
+                .sections
+                .into_iter()
+                .map(|section| LocationPart {
+                    id: section.id as u32,
+                    name: section.name,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Where buildings and rooms are and how long it takes to walk between buildings
+pub struct VenueGeographyResponse {
+    /// Buildings with coordinates, ordered by ID
+    pub locations: Vec<LocationCoordinates>,
+    /// Rooms with coordinates, ordered by ID
+    pub rooms: Vec<RoomCoordinates>,
+    /// Recorded walking times, ordered by building IDs
+    pub walking_times: Vec<WalkingTime>,
+}
+
+impl From<domain::venue::VenueMap> for VenueGeographyResponse {
+    fn from(value: domain::venue::VenueMap) -> Self {
+        let walking_times = value.walking_times().into_iter().map(Into::into).collect();
+
+        let mut locations: Vec<LocationCoordinates> = value
+            .location_coordinates
+            .into_iter()
+            .map(|(location_id, coordinates)| LocationCoordinates {
+                location_id: location_id as u32,
+                latitude: coordinates.latitude,
+                longitude: coordinates.longitude,
+            })
+            .collect();
+        locations.sort_by_key(|location| location.location_id);
+
+        let mut rooms: Vec<RoomCoordinates> = value
+            .rooms
+            .into_iter()
+            .filter_map(|(room_id, room)| {
+                room.coordinates.map(|coordinates| RoomCoordinates {
+                    room_id: room_id as u32,
+                    location_id: room.location_id as u32,
+                    latitude: coordinates.latitude,
+                    longitude: coordinates.longitude,
+                })
+            })
+            .collect();
+        rooms.sort_by_key(|room| room.room_id);
+
+        Self {
+            locations,
+            rooms,
+            walking_times,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationCoordinates {
+    #[schema(example = 5)]
+    pub location_id: u32,
+    #[schema(example = 39.7637)]
+    pub latitude: f64,
+    #[schema(example = -86.1623)]
+    pub longitude: f64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomCoordinates {
+    #[schema(example = 18)]
+    pub room_id: u32,
+    #[schema(example = 5)]
+    /// The building the room is in
+    pub location_id: u32,
+    #[schema(example = 39.7631)]
+    pub latitude: f64,
+    #[schema(example = -86.1612)]
+    pub longitude: f64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Time measured to walk between two buildings, in either direction
+pub struct WalkingTime {
+    #[schema(example = 5)]
+    pub location_id: u32,
+    #[schema(example = 7)]
+    pub other_location_id: u32,
+    #[schema(example = 8)]
+    pub minutes: u16,
+}
+
+impl From<domain::venue::WalkingTime> for WalkingTime {
+    fn from(value: domain::venue::WalkingTime) -> Self {
+        Self {
+            location_id: value.location_id as u32,
+            other_location_id: value.other_location_id as u32,
+            minutes: value.minutes,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CoordinatesRequest {
+    #[schema(example = 39.7637)]
+    #[validate(range(min = -90.0, max = 90.0))]
+    /// Latitude in decimal degrees
+    pub latitude: f64,
+
+    #[schema(example = -86.1623)]
+    #[validate(range(min = -180.0, max = 180.0))]
+    /// Longitude in decimal degrees
+    pub longitude: f64,
+}
+
+impl From<&CoordinatesRequest> for domain::venue::Coordinates {
+    fn from(value: &CoordinatesRequest) -> Self {
+        Self {
+            latitude: value.latitude,
+            longitude: value.longitude,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityRequest {
+    #[schema(example = true)]
+    /// Whether the space can be reached and used from a wheelchair. Leave out if unknown.
+    pub wheelchair_accessible: Option<bool>,
+
+    #[schema(example = false)]
+    /// Whether the space is quiet and low-sensory. Leave out if unknown.
+    pub low_sensory: Option<bool>,
+}
+
+impl From<&AccessibilityRequest> for domain::accessibility::Accessibility {
+    fn from(value: &AccessibilityRequest) -> Self {
+        Self {
+            wheelchair_accessible: value.wheelchair_accessible,
+            low_sensory: value.low_sensory,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Alternate names imports treat as existing buildings, rooms, and sections. Aliases are listed
+/// in the trimmed, lowercase form they're matched in.
+pub struct LocationAliasesResponse {
+    pub locations: Vec<LocationAlias>,
+    pub rooms: Vec<RoomAlias>,
+    pub sections: Vec<SectionAlias>,
+}
+
+impl From<domain::location::LocationAliases> for LocationAliasesResponse {
+    fn from(value: domain::location::LocationAliases) -> Self {
+        let mut locations: Vec<LocationAlias> = value
+            .locations
+            .into_iter()
+            .map(|(alias, location)| LocationAlias { alias, location })
+            .collect();
+        locations.sort_by(|alias1, alias2| {
+            (&alias1.location, &alias1.alias).cmp(&(&alias2.location, &alias2.alias))
+        });
+
+        let mut rooms: Vec<RoomAlias> = value
+            .rooms
+            .into_iter()
+            .map(|((location, alias), room)| RoomAlias {
+                alias,
+                location,
+                room,
+            })
+            .collect();
+        rooms.sort_by(|alias1, alias2| {
+            (&alias1.location, &alias1.room, &alias1.alias).cmp(&(
+                &alias2.location,
+                &alias2.room,
+                &alias2.alias,
+            ))
+        });
+
+        let mut sections: Vec<SectionAlias> = value
+            .sections
+            .into_iter()
+            .map(|((location, room, alias), section)| SectionAlias {
+                alias,
+                location,
+                room,
+                section,
+            })
+            .collect();
+        sections.sort_by(|alias1, alias2| {
+            (
+                &alias1.location,
+                &alias1.room,
+                &alias1.section,
+                &alias1.alias,
+            )
+                .cmp(&(
+                    &alias2.location,
+                    &alias2.room,
+                    &alias2.section,
+                    &alias2.alias,
+                ))
+        });
+
+        Self {
+            locations,
+            rooms,
+            sections,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationAlias {
+    #[schema(example = "indiana convention center")]
+    pub alias: String,
+    #[schema(example = "ICC")]
+    /// Canonical name of the building
+    pub location: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomAlias {
+    #[schema(example = "hall d")]
+    pub alias: String,
+    #[schema(example = "ICC")]
+    pub location: String,
+    #[schema(example = "Exhibit Hall D")]
+    /// Canonical name of the room
+    pub room: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionAlias {
+    #[schema(example = "front half")]
+    pub alias: String,
+    #[schema(example = "ICC")]
+    pub location: String,
+    #[schema(example = "Exhibit Hall D")]
+    pub room: String,
+    #[schema(example = "Front")]
+    /// Canonical name of the section
+    pub section: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// An alternate name imports treat as an existing game system or group. Aliases are listed in the
+/// trimmed, lowercase form they're matched in.
+pub struct MetadataAlias {
+    #[schema(example = "twilight imperium 4e")]
+    pub alias: String,
+    #[schema(example = "Twilight Imperium 4th Edition")]
+    /// Canonical name of the game system or group
+    pub name: String,
+}
+
+impl MetadataAlias {
+    /// Lists aliases keyed by alias, ordered by canonical name and then alias
+    pub fn sorted(aliases: HashMap<String, String>) -> Vec<Self> {
+        let mut aliases: Vec<Self> = aliases
+            .into_iter()
+            .map(|(alias, name)| Self { alias, name })
+            .collect();
+        aliases.sort_by(|alias1, alias2| {
+            (&alias1.name, &alias1.alias).cmp(&(&alias2.name, &alias2.alias))
+        });
+
+        aliases
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeRequest {
+    #[schema(example = 12)]
+    /// ID of the duplicate to fold into the target. The duplicate is deleted once its events
+    /// have moved over.
+    pub duplicate_id: u32,
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WalkingTimeRequest {
+    #[schema(example = 8)]
+    #[validate(range(max = 240))]
+    /// Minutes it takes to walk between the two buildings
+    pub minutes: u16,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventImportRequest {
+    /// List of GenCon events to import
+    pub event_data: Vec<ImportedEvent>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Summary of the changes an import made
+pub struct ImportReport {
+    #[schema(example = 1200)]
+    pub imported_events: usize,
+    #[schema(example = 40)]
+    pub created_events: usize,
+    #[schema(example = 12)]
+    /// Existing events with any changed detail, such as their time, cost, or location
+    pub changed_events: usize,
+    #[schema(example = 2)]
+    pub cancelled_events: usize,
+    /// Values which couldn't be understood and were left out of the imported events
+    pub unparseable_values: Vec<UnparseableValue>,
+}
+
+impl From<&domain::event::ImportOutcome> for ImportReport {
+    fn from(value: &domain::event::ImportOutcome) -> Self {
+        Self {
+            imported_events: value.event_ids.len(),
+            created_events: value.created_ids.len(),
+            changed_events: value.changed_ids.len(),
+            cancelled_events: value.newly_cancelled_ids.len(),
+            unparseable_values: value
+                .unparseable_values
+                .iter()
+                .map(|unparseable| UnparseableValue {
+                    game_id: unparseable.game_id.clone(),
+                    field: unparseable.field.into(),
+                    value: unparseable.value.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UnparseableValue {
+    #[schema(example = "RPG24ND286543")]
+    pub game_id: String,
+    pub field: ImportedField,
+    #[schema(example = "TBD")]
+    pub value: String,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+/// Name of the imported event field a value was listed in
+pub enum ImportedField {
+    GmNames,
+    ContentTags,
+}
+
+impl From<domain::event::ImportedField> for ImportedField {
+    fn from(value: domain::event::ImportedField) -> Self {
+        match value {
+            domain::event::ImportedField::GameMasters => Self::GmNames,
+            domain::event::ImportedField::ContentTags => Self::ContentTags,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedEvent {
+    #[schema(example = "everyone (6+)")]
+    /// Event age requirement
+    ///
+    /// Valid values:
+    /// * "Everyone (6+)"
+    /// * "Kids Only (12 and under)"
+    /// * "Teen (13+)"
+    /// * "Mature (18+)"
+    /// * "21+"
+    pub age_requirement: String,
+
+    #[schema(example = "jdoe22@somewhere.com")]
+    /// The contact e-mail for the event organizer. Empty string for no contact.
+    pub contact: String,
+
+    #[schema(example = 10)]
+    /// The cost of the event (in dollars). 0 if the event is free.
+    pub cost: u32,
+
+    #[schema(
+        example = "This is a description of the event. It can be as long as you want. It can even contain multiple paragraphs."
+    )]
+    /// Description of the event
+    pub description_short: String,
 
     /// The end date of the event.
     pub end_date: DateDto,
@@ -576,7 +1564,8 @@ pub struct ImportedEvent {
     pub game_system: NumberOrString,
 
     #[schema(example = "John Doe, Jane Smith")]
-    /// The names of the game masters for the event, comma-separated.
+    /// The names of the game masters for the event, separated by commas, semicolons, "&", or
+    /// "and". Names may be listed as "Last, First".
     pub gm_names: String,
 
     #[schema(example = "Super Cool Group Ltd.")]
@@ -613,6 +1602,622 @@ pub struct ImportedEvent {
     pub round_total: u8,
     #[schema(example = "www.supercoolgroup.com")]
     pub website: String,
+    #[serde(default)]
+    #[schema(example = false)]
+    /// Whether the event has been cancelled (default false)
+    pub cancelled: bool,
+    #[serde(default)]
+    #[schema(example = "Horror, Low Sensory")]
+    /// Comma or semicolon separated content tags, such as content warnings. Tags are lowercased
+    /// with words joined by hyphens. Leave out to keep the tags already set on the event.
+    pub content_tags: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleSuggestionRequest {
+    #[validate(length(min = 1, max = 200), nested)]
+    /// Events the user wants to attend, most wanted first
+    pub wishes: Vec<ScheduleWish>,
+
+    #[schema(example = 8.0)]
+    #[validate(range(min = 0.5, max = 24.0))]
+    /// The most hours of events the user wants to attend on a single day
+    pub max_hours_per_day: Option<f32>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    /// Windows of each day which need to keep some free time for a meal
+    pub meal_breaks: Vec<MealBreakRequest>,
+
+    #[schema(example = "09:00")]
+    /// Time in HH:MM 24-hour format, events starting before this are dropped
+    pub earliest_start: Option<TimeDto>,
+
+    #[schema(example = 40)]
+    /// The most the user wants to spend on tickets (in dollars)
+    pub budget: Option<u32>,
+
+    #[schema(example = 15)]
+    #[validate(range(max = 240))]
+    /// Minutes needed to get between events in different buildings when the walking time
+    /// between them isn't known (default 15)
+    pub travel_minutes: Option<u16>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleWish {
+    #[schema(example = 1234)]
+    pub event_id: u32,
+
+    #[schema(example = 10)]
+    #[validate(range(min = 1, max = 1000))]
+    /// How much the user values this event. Defaults to a value based on the wish's position in the list.
+    pub weight: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_meal_break"))]
+pub struct MealBreakRequest {
+    #[schema(example = "11:00")]
+    pub window_start: TimeDto,
+    #[schema(example = "14:00")]
+    pub window_end: TimeDto,
+    #[schema(example = 45)]
+    #[validate(range(min = 1, max = 240))]
+    pub duration_minutes: u16,
+}
+
+/// Ensures a meal break's window is in order and long enough to fit the meal
+fn validate_meal_break(meal_break: &MealBreakRequest) -> Result<(), ValidationError> {
+    let window_minutes = (meal_break.window_end.0 - meal_break.window_start.0).num_minutes();
+    if window_minutes < meal_break.duration_minutes as i64 {
+        return Err(
+            ValidationError::new("bad_meal_window").with_message(Cow::Borrowed(
+                "Meal break window must end after it starts and be at least as long as the meal.",
+            )),
+        );
+    }
+
+    Ok(())
+}
+
+impl From<&ScheduleSuggestionRequest> for domain::schedule::ScheduleConstraints {
+    fn from(value: &ScheduleSuggestionRequest) -> Self {
+        Self {
+            max_hours_per_day: value
+                .max_hours_per_day
+                .map(|hours| Duration::minutes((hours * 60.0) as i64)),
+            meal_breaks: value
+                .meal_breaks
+                .iter()
+                .map(|meal_break| domain::schedule::MealBreak {
+                    window_start: meal_break.window_start.0,
+                    window_end: meal_break.window_end.0,
+                    duration: Duration::minutes(meal_break.duration_minutes as i64),
+                })
+                .collect(),
+            earliest_start: value.earliest_start.as_ref().map(|time| time.0),
+            budget: value.budget,
+            travel_time: Duration::minutes(value.travel_minutes.unwrap_or(15) as i64),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleSuggestionResponse {
+    /// Events on the suggested schedule, ordered by start time
+    pub scheduled_events: Vec<ScheduledEvent>,
+    /// Wishes which could not fit on the schedule
+    pub dropped_wishes: Vec<DroppedWish>,
+    #[schema(example = 24)]
+    pub total_cost: u32,
+    #[schema(example = 42)]
+    pub total_value: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledEvent {
+    #[schema(example = 1234)]
+    pub id: i64,
+    #[schema(example = "Delve into the Underdark")]
+    pub title: String,
+    pub date: DateDto,
+    #[schema(example = "10:00")]
+    pub start_time: TimeDto,
+    #[schema(example = "12:00")]
+    pub end_time: TimeDto,
+    #[schema(example = 4)]
+    pub cost: Option<u32>,
+}
+
+impl From<domain::schedule::ScheduleCandidate> for ScheduledEvent {
+    fn from(value: domain::schedule::ScheduleCandidate) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            date: DateDto(value.start.date_naive()),
+            start_time: TimeDto(value.start.time()),
+            end_time: TimeDto(value.end.time()),
+            cost: value.cost,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedWish {
+    #[schema(example = 1234)]
+    pub event_id: i64,
+    #[schema(example = 2)]
+    /// Zero-based position of the wish in the request
+    pub rank: usize,
+    #[schema(example = "overlaps_event")]
+    /// Machine-readable reason the wish was dropped
+    pub reason_code: String,
+    #[schema(example = "The event overlaps with event 1233")]
+    pub reason: String,
+    #[schema(example = 1233)]
+    /// The scheduled event responsible for the wish being dropped, if any
+    pub conflicting_event_id: Option<i64>,
+}
+
+impl From<domain::schedule::DroppedWish> for DroppedWish {
+    fn from(value: domain::schedule::DroppedWish) -> Self {
+        use domain::schedule::DropReason;
+
+        let (reason_code, conflicting_event_id) = match value.reason {
+            DropReason::NotFound => ("not_found", None),
+            DropReason::DuplicateWish => ("duplicate_wish", None),
+            DropReason::StartsTooEarly => ("starts_too_early", None),
+            DropReason::ExceedsDailyHours => ("exceeds_daily_hours", None),
+            DropReason::BlocksMealBreak => ("blocks_meal_break", None),
+            DropReason::ExceedsBudget => ("exceeds_budget", None),
+            DropReason::OverlapsEvent(event_id) => ("overlaps_event", Some(event_id)),
+            DropReason::InsufficientTravelTime(event_id) => {
+                ("insufficient_travel_time", Some(event_id))
+            }
+            DropReason::SearchLimitReached => ("search_limit_reached", None),
+        };
+
+        Self {
+            event_id: value.event_id,
+            rank: value.rank,
+            reason_code: reason_code.to_owned(),
+            reason: value.reason.to_string(),
+            conflicting_event_id,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AlternateSessionsResponse {
+    #[schema(example = 1234)]
+    /// The event other sessions were requested for
+    pub event_id: i64,
+    /// Other sessions of the event, ordered by start time
+    pub sessions: Vec<AlternateSession>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AlternateSession {
+    #[schema(example = 1240)]
+    pub id: i64,
+    #[schema(example = "RPG24ND192830")]
+    pub game_id: String,
+    pub date: DateDto,
+    #[schema(example = "14:00")]
+    pub start_time: TimeDto,
+    #[schema(example = "16:00")]
+    pub end_time: TimeDto,
+    #[schema(example = 3)]
+    pub tickets_available: u16,
+}
+
+impl From<domain::session::EventSession> for AlternateSession {
+    fn from(value: domain::session::EventSession) -> Self {
+        Self {
+            id: value.id,
+            game_id: value.game_id,
+            date: DateDto(value.start.date_naive()),
+            start_time: TimeDto(value.start.time()),
+            end_time: TimeDto(value.end.time()),
+            tickets_available: value.tickets_available,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistResponse {
+    #[schema(example = "user-1234")]
+    pub user_id: String,
+    /// Watched events, ordered by start time
+    pub events: Vec<WatchedEvent>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchedEvent {
+    #[schema(example = 1234)]
+    pub id: i64,
+    #[schema(example = "Delve into the Underdark")]
+    pub title: String,
+    pub date: DateDto,
+    #[schema(example = "10:00")]
+    pub start_time: TimeDto,
+    #[schema(example = "12:00")]
+    pub end_time: TimeDto,
+    #[schema(example = 0)]
+    pub tickets_available: u16,
+    #[schema(example = false)]
+    pub cancelled: bool,
+}
+
+impl From<domain::watchlist::EventSnapshot> for WatchedEvent {
+    fn from(value: domain::watchlist::EventSnapshot) -> Self {
+        Self {
+            id: value.event_id,
+            title: value.title,
+            date: DateDto(value.start.date_naive()),
+            start_time: TimeDto(value.start.time()),
+            end_time: TimeDto(value.end.time()),
+            tickets_available: value.tickets_available,
+            cancelled: value.cancelled,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchRequest {
+    #[schema(example = "Evening Pathfinder")]
+    #[validate(length(min = 1, max = 128))]
+    /// Name the user knows the search by
+    pub name: String,
+
+    #[schema(example = "search-text=pathfinder&start-time=18:00")]
+    #[validate(length(max = 4096))]
+    /// Event list query string holding the filters to save, in the same format accepted by
+    /// `/api/events`. Dates, sorting, and paging parameters aren't part of a saved search.
+    pub query: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Event list filters a user saved to run again
+pub struct SavedSearch {
+    #[schema(example = 12)]
+    pub id: i64,
+    #[schema(example = "Evening Pathfinder")]
+    pub name: String,
+    #[schema(example = "search-text=pathfinder&start-time=18:00")]
+    pub query: String,
+    pub created_at: DateTime<Utc>,
+    /// When new matches were last checked for
+    pub last_checked_at: DateTime<Utc>,
+}
+
+impl From<domain::saved_search::SavedSearch> for SavedSearch {
+    fn from(value: domain::saved_search::SavedSearch) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            query: value.query,
+            created_at: value.created_at,
+            last_checked_at: value.last_checked_at,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchesResponse {
+    #[schema(example = "user-1234")]
+    pub user_id: String,
+    /// Saved searches, oldest first
+    pub searches: Vec<SavedSearch>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchMatchesResponse {
+    pub search: SavedSearch,
+    /// Events which started matching the search since it was last checked, ordered by start time
+    pub new_matches: Vec<SavedSearchMatch>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// An event which started matching a saved search
+pub struct SavedSearchMatch {
+    #[schema(example = 1234)]
+    pub id: i64,
+    #[schema(example = "Delve into the Underdark")]
+    pub title: String,
+    pub date: DateDto,
+    #[schema(example = "10:00")]
+    pub start_time: TimeDto,
+    #[schema(example = "12:00")]
+    pub end_time: TimeDto,
+    #[schema(example = 4)]
+    pub cost: Option<u32>,
+    pub tickets: TicketAvailability,
+    /// When an import found the event matching the search
+    pub flagged_at: DateTime<Utc>,
+}
+
+impl From<domain::saved_search::SearchMatch> for SavedSearchMatch {
+    fn from(value: domain::saved_search::SearchMatch) -> Self {
+        Self {
+            id: value.event_id,
+            title: value.title,
+            date: DateDto(value.start.date_naive()),
+            start_time: TimeDto(value.start.time()),
+            end_time: TimeDto(value.end.time()),
+            cost: value.cost,
+            tickets: TicketAvailability {
+                available: value.tickets_available,
+                total: value.max_players,
+            },
+            flagged_at: value.flagged_at,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// The latest details of an event which was created or changed by an import
+pub struct EventUpdateMessage {
+    #[schema(example = 1234)]
+    pub id: i64,
+    #[schema(example = "RPG24ND123456")]
+    pub game_id: String,
+    #[schema(example = "Delve into the Underdark")]
+    pub title: String,
+    pub date: DateDto,
+    #[schema(example = "10:00")]
+    pub start_time: TimeDto,
+    #[schema(example = "12:00")]
+    pub end_time: TimeDto,
+    #[schema(example = 4)]
+    pub cost: Option<u32>,
+    #[schema(example = 3)]
+    pub tickets_available: u16,
+    #[schema(example = false)]
+    pub cancelled: bool,
+}
+
+impl From<&domain::live_update::EventUpdate> for EventUpdateMessage {
+    fn from(value: &domain::live_update::EventUpdate) -> Self {
+        Self {
+            id: value.event_id,
+            game_id: value.game_id.clone(),
+            title: value.title.clone(),
+            date: DateDto(value.start.date_naive()),
+            start_time: TimeDto(value.start.time()),
+            end_time: TimeDto(value.end.time()),
+            cost: value.cost,
+            tickets_available: value.tickets_available,
+            cancelled: value.cancelled,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSubscriptionRequest {
+    #[schema(example = "https://example.com/hooks/genconcal")]
+    #[validate(url, length(max = 2048))]
+    /// URL which will receive a JSON POST for each webhook message
+    pub target_url: String,
+
+    #[schema(example = "a-long-random-shared-secret")]
+    #[validate(length(min = 16, max = 256))]
+    /// Shared secret used to sign each payload. The signature is sent in the
+    /// X-GenConCal-Signature header as `sha256=<hex HMAC-SHA256 of the body>`.
+    pub secret: String,
+
+    #[validate(length(min = 1))]
+    /// The kinds of activity the subscriber wants to be notified about
+    pub topics: Vec<WebhookTopic>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug)]
+/// The kinds of activity a webhook can be sent for
+pub enum WebhookTopic {
+    #[serde(rename = "import.completed")]
+    ImportCompleted,
+    #[serde(rename = "event.created")]
+    EventCreated,
+    #[serde(rename = "event.updated")]
+    EventUpdated,
+    #[serde(rename = "event.cancelled")]
+    EventCancelled,
+    #[serde(rename = "event.deleted")]
+    EventDeleted,
+}
+
+impl From<WebhookTopic> for domain::webhook::Topic {
+    fn from(value: WebhookTopic) -> Self {
+        match value {
+            WebhookTopic::ImportCompleted => Self::ImportCompleted,
+            WebhookTopic::EventCreated => Self::EventCreated,
+            WebhookTopic::EventUpdated => Self::EventUpdated,
+            WebhookTopic::EventCancelled => Self::EventCancelled,
+            WebhookTopic::EventDeleted => Self::EventDeleted,
+        }
+    }
+}
+
+impl From<domain::webhook::Topic> for WebhookTopic {
+    fn from(value: domain::webhook::Topic) -> Self {
+        match value {
+            domain::webhook::Topic::ImportCompleted => Self::ImportCompleted,
+            domain::webhook::Topic::EventCreated => Self::EventCreated,
+            domain::webhook::Topic::EventUpdated => Self::EventUpdated,
+            domain::webhook::Topic::EventCancelled => Self::EventCancelled,
+            domain::webhook::Topic::EventDeleted => Self::EventDeleted,
+        }
+    }
+}
+
+impl From<WebhookSubscriptionRequest> for domain::webhook::NewSubscription {
+    fn from(value: WebhookSubscriptionRequest) -> Self {
+        let mut topics: Vec<domain::webhook::Topic> =
+            value.topics.into_iter().map(Into::into).collect();
+        topics.sort_by_key(|topic| topic.to_string());
+        topics.dedup();
+
+        Self {
+            target_url: value.target_url,
+            secret: value.secret,
+            topics,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// A registered webhook subscription. The secret is never returned.
+pub struct WebhookSubscription {
+    #[schema(example = 12)]
+    pub id: i64,
+    #[schema(example = "https://example.com/hooks/genconcal")]
+    pub target_url: String,
+    pub topics: Vec<WebhookTopic>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<domain::webhook::Subscription> for WebhookSubscription {
+    fn from(value: domain::webhook::Subscription) -> Self {
+        Self {
+            id: value.id,
+            target_url: value.target_url,
+            topics: value.topics.into_iter().map(Into::into).collect(),
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSubscriptionsResponse {
+    pub subscriptions: Vec<WebhookSubscription>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveriesResponse {
+    /// Deliveries made to the subscription, newest first
+    pub deliveries: Vec<WebhookDelivery>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDelivery {
+    #[schema(example = 345)]
+    pub id: i64,
+    #[schema(example = 12)]
+    pub subscription_id: i64,
+    pub topic: WebhookTopic,
+    #[schema(value_type = Object)]
+    /// The JSON body sent to the subscriber
+    pub payload: serde_json::Value,
+    #[schema(example = "succeeded")]
+    /// One of "pending", "succeeded", or "failed"
+    pub status: String,
+    #[schema(example = 1)]
+    pub attempts: u16,
+    #[schema(example = 200)]
+    /// HTTP status code returned by the subscriber on the latest attempt
+    pub last_status_code: Option<u16>,
+    /// Why the latest attempt couldn't reach the subscriber, if it couldn't
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// When the next attempt is scheduled, while the delivery is pending
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+impl From<domain::webhook::DeliveryRecord> for WebhookDelivery {
+    fn from(value: domain::webhook::DeliveryRecord) -> Self {
+        Self {
+            id: value.id,
+            subscription_id: value.subscription_id,
+            topic: value.topic.into(),
+            payload: value.payload,
+            status: value.status.to_string(),
+            attempts: value.attempts,
+            last_status_code: value.last_status_code,
+            last_error: value.last_error,
+            created_at: value.created_at,
+            last_attempt_at: value.last_attempt_at,
+            next_attempt_at: value.next_attempt_at,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSuggestionsResponse {
+    pub suggestions: Vec<SearchSuggestion>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// A completion of partially typed search text
+pub struct SearchSuggestion {
+    pub kind: SearchSuggestionKind,
+    /// ID to filter events by. Only set for game systems, groups, game masters, and locations.
+    #[schema(example = 12)]
+    pub id: Option<i64>,
+    #[schema(example = "Pathfinder Second Edition")]
+    pub text: String,
+    /// How closely the suggestion matches the typed text, from 0 to 1
+    #[schema(example = 0.8)]
+    pub score: f32,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug)]
+#[serde(rename_all = "kebab-case")]
+/// What a search suggestion refers to. Event titles and game masters fill in search-text, while
+/// game systems, groups, and locations map to the game-systems, groups, and locations filters.
+pub enum SearchSuggestionKind {
+    EventTitle,
+    GameSystem,
+    Group,
+    GameMaster,
+    Location,
+}
+
+impl From<domain::search::SuggestionKind> for SearchSuggestionKind {
+    fn from(value: domain::search::SuggestionKind) -> Self {
+        match value {
+            domain::search::SuggestionKind::EventTitle => Self::EventTitle,
+            domain::search::SuggestionKind::GameSystem => Self::GameSystem,
+            domain::search::SuggestionKind::Group => Self::Group,
+            domain::search::SuggestionKind::GameMaster => Self::GameMaster,
+            domain::search::SuggestionKind::Location => Self::Location,
+        }
+    }
+}
+
+impl From<domain::search::Suggestion> for SearchSuggestion {
+    fn from(value: domain::search::Suggestion) -> Self {
+        Self {
+            kind: value.kind.into(),
+            id: value.id,
+            text: value.text,
+            score: value.score,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, ToSchema, Debug)]
@@ -636,11 +2241,21 @@ impl From<DateDto> for String {
 
 impl DateDto {
     pub fn from_date_id(day_id: u32) -> Self {
+        Self::try_from_date_id(day_id).unwrap()
+    }
+
+    /// Parses a day ID in YYYYMMDD format, returning None if it isn't a real date
+    pub fn try_from_date_id(day_id: u32) -> Option<Self> {
         let day = day_id % 100;
         let month = ((day_id - day) % 10000) / 100;
         let year = (day_id - (month * 100) - day) / 10000;
 
-        Self(NaiveDate::from_ymd_opt(year as i32, month, day).unwrap())
+        NaiveDate::from_ymd_opt(year as i32, month, day).map(Self)
+    }
+
+    /// Formats the date as a day ID in YYYYMMDD format
+    pub fn date_id(&self) -> u32 {
+        self.0.year() as u32 * 10000 + self.0.month() * 100 + self.0.day()
     }
 }
 
@@ -663,6 +2278,78 @@ impl From<TimeDto> for String {
     }
 }
 
+#[derive(Debug, Display, Error)]
+#[display("Time spans must look like 2024-08-01T13:00/2024-08-01T17:00")]
+pub struct TimeSpanParseErr;
+
+#[derive(Serialize, Debug, Deserialize, Clone, Copy)]
+#[serde(try_from = "String", into = "String")]
+/// A span of convention local time written as two date-times separated by a slash
+pub struct TimeSpanDto {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+impl FromStr for TimeSpanDto {
+    type Err = TimeSpanParseErr;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (start, end) = value.split_once('/').ok_or(TimeSpanParseErr)?;
+        let parse = |date_time: &str| {
+            NaiveDateTime::parse_from_str(date_time.trim(), "%Y-%m-%dT%H:%M")
+                .map_err(|_| TimeSpanParseErr)
+        };
+
+        Ok(Self {
+            start: parse(start)?,
+            end: parse(end)?,
+        })
+    }
+}
+
+impl std::fmt::Display for TimeSpanDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            self.start.format("%Y-%m-%dT%H:%M"),
+            self.end.format("%Y-%m-%dT%H:%M")
+        )
+    }
+}
+
+impl TryFrom<String> for TimeSpanDto {
+    type Error = TimeSpanParseErr;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<TimeSpanDto> for String {
+    fn from(value: TimeSpanDto) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<TimeSpanDto> for domain::search::TimeSpan {
+    fn from(value: TimeSpanDto) -> Self {
+        // Local times skipped by a daylight saving change are read as UTC, which only shifts the
+        // span by the offset
+        let localize = |date_time: NaiveDateTime| {
+            Tz::America__Indiana__Indianapolis
+                .from_local_datetime(&date_time)
+                .earliest()
+                .unwrap_or_else(|| Tz::America__Indiana__Indianapolis.from_utc_datetime(&date_time))
+        };
+
+        Self {
+            start: localize(value.start),
+            end: localize(value.end),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum IngestEventConvertErr {
     BadStartTime,
@@ -787,18 +2474,12 @@ impl TryFrom<ImportedEvent> for domain::event::IngestEvent {
             } else {
                 None
             },
-            game_masters: {
-                if value.gm_names.is_empty() {
-                    Vec::new()
-                } else {
-                    value
-                        .gm_names
-                        .as_str()
-                        .split(", ")
-                        .map(ToOwned::to_owned)
-                        .collect()
-                }
-            },
+            game_masters: domain::game_master::parse_game_master_names(&value.gm_names),
+            cancelled: value.cancelled,
+            content_tags: value
+                .content_tags
+                .as_deref()
+                .map(domain::accessibility::parse_content_tags),
         })
     }
 }
@@ -857,6 +2538,28 @@ pub mod err_resps {
     )]
     pub struct BasicError400Validation(BasicError);
 
+    #[derive(ToResponse)]
+    #[response(
+        description = "No access token was passed, or the token wasn't recognized",
+        example = json!({
+            "errorCode": "invalid_access_token",
+            "errorDescription": "The Authorization header must contain a recognized bearer token.",
+            "extraInfo": null
+        })
+    )]
+    pub struct BasicError401(BasicError);
+
+    #[derive(ToResponse)]
+    #[response(
+        description = "The access token doesn't allow this request",
+        example = json!({
+            "errorCode": "admin_only",
+            "errorDescription": "Only admins can manage webhook subscriptions.",
+            "extraInfo": null
+        })
+    )]
+    pub struct BasicError403(BasicError);
+
     #[derive(ToResponse)]
     #[response(
         description = "Entity could not be found",
@@ -1,5 +1,5 @@
 use crate::persistence::ExternalConnectivity;
-use crate::{SharedData, app_env, db, logging};
+use crate::{SharedData, app_env, db, logging, outbound};
 use axum::Router;
 use dotenv::dotenv;
 use lazy_static::lazy_static;
@@ -131,6 +131,12 @@ pub async fn prepare_application(routes: Router<Arc<SharedData>>) -> (Router, sq
     let db = prepare_db(pg_connection_base_url.as_str()).await;
     let app = routes.with_state(Arc::new(SharedData {
         ext_cxn: ExternalConnectivity::new(db.clone()),
+        notification_sender: outbound::watchlist::ConfiguredNotificationSender::Log(
+            outbound::watchlist::LogNotificationSender,
+        ),
+        event_updates: tokio::sync::broadcast::channel(16).0,
+        access_tokens: Default::default(),
+        contact_policy: Default::default(),
     }));
 
     (app, db)
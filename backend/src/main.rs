@@ -25,10 +25,15 @@ mod external_connections;
 #[cfg(test)]
 mod integration_test;
 mod logging;
+mod outbound;
 
 /// Global data store which is shared among HTTP routes
 pub struct SharedData {
     pub ext_cxn: persistence::ExternalConnectivity,
+    pub notification_sender: outbound::watchlist::ConfiguredNotificationSender,
+    pub event_updates: api::event_stream::EventUpdateSender,
+    pub access_tokens: api::access::AccessTokens,
+    pub contact_policy: domain::contact::ContactPolicy,
 }
 
 /// Type alias for the extractor used to get access to the global app state
@@ -50,12 +55,48 @@ async fn main() {
     let db_url = env::var(app_env::DB_URL).expect("Could not get database URL from environment");
 
     let sqlx_db_connection = db::connect_sqlx(&db_url).await;
-    let ext_cxn = persistence::ExternalConnectivity::new(sqlx_db_connection);
+    let ext_cxn = persistence::ExternalConnectivity::new(sqlx_db_connection.clone());
+    let event_updates = api::event_stream::start_update_relay(sqlx_db_connection, ext_cxn.clone());
+    let http_client = reqwest::Client::new();
+    let notification_sender = match env::var(app_env::WATCHLIST_WEBHOOK_URL) {
+        Ok(webhook_url) => outbound::watchlist::ConfiguredNotificationSender::Webhook(
+            outbound::watchlist::WebhookNotificationSender::new(http_client.clone(), webhook_url),
+        ),
+        Err(_) => outbound::watchlist::ConfiguredNotificationSender::Log(
+            outbound::watchlist::LogNotificationSender,
+        ),
+    };
+    api::webhooks::start_delivery_worker(
+        ext_cxn.clone(),
+        outbound::webhook::HttpWebhookDeliverer::new(http_client),
+    );
+    let access_tokens = api::access::AccessTokens::new(
+        &env::var(app_env::MEMBER_ACCESS_TOKENS).unwrap_or_default(),
+        &env::var(app_env::ADMIN_ACCESS_TOKENS).unwrap_or_default(),
+        env::var(app_env::USER_TOKEN_SECRET).ok().as_deref(),
+    );
+    let contact_policy = domain::contact::ContactPolicy {
+        relay_domain: env::var(app_env::CONTACT_RELAY_DOMAIN).ok(),
+    };
 
     let router = Router::new()
+        .nest("/api/contacts", api::contacts::contacts_routes())
         .nest("/api/days", api::days::day_routes())
         .nest("/api/events", api::events::events_routes())
+        .nest(
+            "/api/game-masters",
+            api::game_masters::game_masters_routes(),
+        )
+        .nest(
+            "/api/game-systems",
+            api::game_systems::game_systems_routes(),
+        )
+        .nest("/api/locations", api::locations::locations_routes())
         .nest("/api/organizers", api::organizers::organizers_routes())
+        .nest("/api/schedules", api::schedules::schedules_routes())
+        .nest("/api/search", api::search::search_routes())
+        .nest("/api/users", api::users::users_routes())
+        .nest("/api/webhooks", api::webhooks::webhooks_routes())
         .layer(ServiceBuilder::new().layer(api::cors::cors_config()))
         .nest(
             "/api/data-ingests",
@@ -86,7 +127,13 @@ async fn main() {
                     ),
             ),
         )
-        .with_state(Arc::new(SharedData { ext_cxn }));
+        .with_state(Arc::new(SharedData {
+            ext_cxn,
+            notification_sender,
+            event_updates,
+            access_tokens,
+            contact_policy,
+        }));
 
     info!("Starting server.");
     let network_listener = match TcpListener::bind(&"0.0.0.0:8080").await {
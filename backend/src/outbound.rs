@@ -0,0 +1,2 @@
+pub mod watchlist;
+pub mod webhook;
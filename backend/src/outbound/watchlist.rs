@@ -0,0 +1,184 @@
+use crate::domain;
+use crate::domain::location::Ref;
+use crate::domain::watchlist::{EventChange, WatchNotification};
+use anyhow::Context;
+use chrono::DateTime;
+use chrono_tz::Tz;
+use serde::Serialize;
+use std::time::Duration;
+use tracing::*;
+
+/// How long to wait for the notification webhook to respond before giving up on the batch
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Sends watchlist notifications as a JSON POST to a configured webhook URL
+pub struct WebhookNotificationSender {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotificationSender {
+    /// Creates a sender which posts notifications to the passed URL
+    pub fn new(client: reqwest::Client, url: String) -> Self {
+        Self { client, url }
+    }
+}
+
+impl domain::watchlist::driven_ports::NotificationSender for WebhookNotificationSender {
+    #[instrument(skip_all, fields(total_notifications = notifications.len()))]
+    async fn send_notifications(
+        &self,
+        notifications: &[WatchNotification],
+    ) -> Result<(), anyhow::Error> {
+        let payload = NotificationBatch {
+            notifications: notifications
+                .iter()
+                .map(NotificationPayload::from)
+                .collect(),
+        };
+
+        self.client
+            .post(&self.url)
+            .timeout(REQUEST_TIMEOUT)
+            .json(&payload)
+            .send()
+            .await
+            .context("Posting watchlist notifications to webhook")?
+            .error_for_status()
+            .context("Webhook rejected watchlist notifications")?;
+
+        Ok(())
+    }
+}
+
+/// Writes watchlist notifications to the application log instead of delivering them. Used when no
+/// webhook is configured, such as in local development and tests.
+pub struct LogNotificationSender;
+
+impl domain::watchlist::driven_ports::NotificationSender for LogNotificationSender {
+    async fn send_notifications(
+        &self,
+        notifications: &[WatchNotification],
+    ) -> Result<(), anyhow::Error> {
+        for notification in notifications {
+            info!(
+                user_id = notification.user_id,
+                event_id = notification.event_id,
+                changes = ?notification.changes,
+                "Watchlist notification."
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Notification sender chosen at startup based on the application's configuration
+pub enum ConfiguredNotificationSender {
+    Webhook(WebhookNotificationSender),
+    Log(LogNotificationSender),
+}
+
+impl domain::watchlist::driven_ports::NotificationSender for ConfiguredNotificationSender {
+    async fn send_notifications(
+        &self,
+        notifications: &[WatchNotification],
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Self::Webhook(sender) => sender.send_notifications(notifications).await,
+            Self::Log(sender) => sender.send_notifications(notifications).await,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+/// Body of a webhook call containing watchlist notifications
+struct NotificationBatch {
+    notifications: Vec<NotificationPayload>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+/// JSON representation of a single watchlist notification
+struct NotificationPayload {
+    user_id: String,
+    event_id: i64,
+    title: String,
+    changes: Vec<ChangePayload>,
+}
+
+impl From<&WatchNotification> for NotificationPayload {
+    fn from(value: &WatchNotification) -> Self {
+        Self {
+            user_id: value.user_id.clone(),
+            event_id: value.event_id,
+            title: value.title.clone(),
+            changes: value.changes.iter().map(ChangePayload::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+/// JSON representation of a change to a watched event
+enum ChangePayload {
+    TicketsAvailable {
+        tickets_available: u16,
+    },
+    StartTimeChanged {
+        previous_start: DateTime<Tz>,
+        new_start: DateTime<Tz>,
+    },
+    LocationChanged {
+        previous_location: Option<LocationPayload>,
+        new_location: Option<LocationPayload>,
+    },
+    Cancelled,
+}
+
+impl From<&EventChange> for ChangePayload {
+    fn from(value: &EventChange) -> Self {
+        match value {
+            EventChange::TicketsAvailable { tickets_available } => Self::TicketsAvailable {
+                tickets_available: *tickets_available,
+            },
+            EventChange::StartTimeChanged {
+                previous_start,
+                new_start,
+            } => Self::StartTimeChanged {
+                previous_start: *previous_start,
+                new_start: *new_start,
+            },
+            EventChange::LocationChanged {
+                previous_location,
+                new_location,
+            } => Self::LocationChanged {
+                previous_location: previous_location.as_ref().map(LocationPayload::from),
+                new_location: new_location.as_ref().map(LocationPayload::from),
+            },
+            EventChange::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+/// JSON representation of a location reference
+struct LocationPayload {
+    id: i32,
+    ref_type: String,
+}
+
+impl From<&Ref> for LocationPayload {
+    fn from(value: &Ref) -> Self {
+        Self {
+            id: value.id,
+            ref_type: value.ref_type.to_string(),
+        }
+    }
+}
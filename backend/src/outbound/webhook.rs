@@ -0,0 +1,71 @@
+use crate::domain;
+use crate::domain::webhook::{AttemptOutcome, PendingDelivery};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+use tracing::*;
+
+/// Header containing the HMAC-SHA256 signature of the request body, formatted as `sha256=<hex>`
+pub const SIGNATURE_HEADER: &str = "X-GenConCal-Signature";
+/// Header containing the topic of the webhook message
+pub const TOPIC_HEADER: &str = "X-GenConCal-Topic";
+/// Header containing the delivery ID, which stays the same across retries of a delivery
+pub const DELIVERY_HEADER: &str = "X-GenConCal-Delivery";
+
+/// How long to wait for a subscriber to respond before treating the attempt as failed
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Computes the value of the signature header for a request body signed with the passed secret
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC should accept keys of any length");
+    mac.update(body);
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[derive(Clone)]
+/// Delivers webhook messages to subscribers as signed JSON POST requests
+pub struct HttpWebhookDeliverer {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookDeliverer {
+    /// Creates a deliverer which sends requests with the passed client
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl domain::webhook::driven_ports::WebhookDeliverer for HttpWebhookDeliverer {
+    #[instrument(skip_all, fields(delivery_id = delivery.delivery_id, target_url = delivery.subscription.target_url))]
+    async fn attempt_delivery(&self, delivery: &PendingDelivery) -> AttemptOutcome {
+        let body = match serde_json::to_vec(&delivery.message) {
+            Ok(body) => body,
+            Err(serialize_err) => {
+                error!("Could not serialize webhook message: {serialize_err}");
+                return AttemptOutcome::Unreachable(format!(
+                    "Could not serialize message: {serialize_err}"
+                ));
+            }
+        };
+        let signature = sign_payload(&delivery.subscription.secret, &body);
+
+        let send_result = self
+            .client
+            .post(&delivery.subscription.target_url)
+            .timeout(REQUEST_TIMEOUT)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, signature)
+            .header(TOPIC_HEADER, delivery.message.topic.to_string())
+            .header(DELIVERY_HEADER, delivery.delivery_id.to_string())
+            .body(body)
+            .send()
+            .await;
+
+        match send_result {
+            Ok(response) => AttemptOutcome::Responded(response.status().as_u16()),
+            Err(send_err) => AttemptOutcome::Unreachable(send_err.to_string()),
+        }
+    }
+}
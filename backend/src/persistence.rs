@@ -1,7 +1,20 @@
+pub mod accessibility;
+pub mod contact;
 pub mod event;
+pub mod event_type;
 pub mod game_master;
+pub mod game_system;
+pub mod live_update;
 pub mod location;
 pub mod metadata;
+pub mod organizer;
+pub mod saved_search;
+pub mod schedule;
+pub mod search;
+pub mod session;
+pub mod venue;
+pub mod watchlist;
+pub mod webhook;
 
 use crate::external_connections;
 use crate::external_connections::ConnectionHandle;
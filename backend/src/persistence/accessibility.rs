@@ -0,0 +1,83 @@
+use crate::domain;
+use crate::domain::accessibility::Accessibility;
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+
+/// Saves the accessibility of rooms and sections along with event content tags to the database
+pub struct DbAccessibilityWriter;
+
+impl domain::accessibility::driven_ports::AccessibilityWriter for DbAccessibilityWriter {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn set_room_accessibility(
+        &self,
+        room_id: i32,
+        accessibility: &Accessibility,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save room accessibility.")?;
+
+        let result = sqlx::query!(
+            "UPDATE rooms SET wheelchair_accessible = $2, low_sensory = $3 WHERE id = $1",
+            room_id,
+            accessibility.wheelchair_accessible,
+            accessibility.low_sensory,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating room accessibility")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn set_section_accessibility(
+        &self,
+        section_id: i32,
+        accessibility: &Accessibility,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save section accessibility.")?;
+
+        let result = sqlx::query!(
+            "UPDATE sections SET wheelchair_accessible = $2, low_sensory = $3 WHERE id = $1",
+            section_id,
+            accessibility.wheelchair_accessible,
+            accessibility.low_sensory,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating section accessibility")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn set_content_tags(
+        &self,
+        event_id: i64,
+        tags: &[String],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save event content tags.")?;
+
+        let result = sqlx::query!(
+            "UPDATE events SET content_tags = $2 WHERE id = $1",
+            event_id,
+            tags,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating event content tags")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::domain;
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+
+/// Deletes contact emails from the database
+pub struct DbContactPurger;
+
+impl domain::contact::driven_ports::ContactPurger for DbContactPurger {
+    #[tracing::instrument(skip_all)]
+    async fn purge_contacts(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<u64, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete contacts.")?;
+
+        sqlx::query!("UPDATE events SET contact_id = NULL WHERE contact_id IS NOT NULL")
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Removing contacts from events")?;
+        let result = sqlx::query!("DELETE FROM contacts")
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Deleting contacts")?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -5,6 +5,7 @@ use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
 use crate::persistence::{u16_as_i16, u32_as_i32};
 use anyhow::Context;
 use chrono::Datelike;
+use serde::{Deserialize, Serialize};
 use sqlx::{Postgres, Row};
 use std::collections::HashMap;
 
@@ -57,11 +58,11 @@ impl domain::event::driven_ports::EventDetector for DbEventDetector {
     }
 }
 
-#[derive(sqlx::Type)]
+#[derive(sqlx::Type, Debug, Serialize, Deserialize)]
 #[sqlx(type_name = "agerequirement")]
 #[sqlx(rename_all = "PascalCase")]
 /// Database enum mapping for AgeRequirement domain values.
-enum AgeRequirementDTO {
+pub(super) enum AgeRequirementDTO {
     Everyone,
     KidsOnly,
     Teen,
@@ -81,11 +82,23 @@ impl From<AgeRequirement> for AgeRequirementDTO {
     }
 }
 
-#[derive(sqlx::Type)]
+impl From<AgeRequirementDTO> for AgeRequirement {
+    fn from(age_req: AgeRequirementDTO) -> Self {
+        match age_req {
+            AgeRequirementDTO::Everyone => AgeRequirement::Everyone,
+            AgeRequirementDTO::KidsOnly => AgeRequirement::KidsOnly,
+            AgeRequirementDTO::Teen => AgeRequirement::Teen,
+            AgeRequirementDTO::Mature => AgeRequirement::Mature,
+            AgeRequirementDTO::Adult => AgeRequirement::Adult,
+        }
+    }
+}
+
+#[derive(sqlx::Type, Debug, Serialize, Deserialize)]
 #[sqlx(type_name = "experiencerequirement")]
 #[sqlx(rename_all = "PascalCase")]
 /// Database enum mapping for ExperienceLevel domain values.
-enum ExperienceLevelDTO {
+pub(super) enum ExperienceLevelDTO {
     Some,
     None,
     Expert,
@@ -101,11 +114,21 @@ impl From<ExperienceLevel> for ExperienceLevelDTO {
     }
 }
 
+impl From<ExperienceLevelDTO> for ExperienceLevel {
+    fn from(exp_req: ExperienceLevelDTO) -> Self {
+        match exp_req {
+            ExperienceLevelDTO::None => ExperienceLevel::None,
+            ExperienceLevelDTO::Some => ExperienceLevel::Some,
+            ExperienceLevelDTO::Expert => ExperienceLevel::Expert,
+        }
+    }
+}
+
 /// Writes and updates event records in the database
 pub struct DbEventWriter;
 
 /// Number of SQL bind parameters required to insert a single event row.
-const SINGLE_EVENT_INSERT_PARAMS_LEN: usize = 19;
+const SINGLE_EVENT_INSERT_PARAMS_LEN: usize = 21;
 /// Maximum number of events per insert batch without exceeding PostgreSQL's parameter limit.
 const EVENT_INSERT_CHUNK_SIZE: usize = super::PG_PARAM_LIMIT / SINGLE_EVENT_INSERT_PARAMS_LEN;
 
@@ -136,7 +159,8 @@ impl domain::event::driven_ports::EventWriter for DbEventWriter {
                     game_id, event_type_id, game_system_id, title,
                     description, start_dt, end_dt, year, cost, tickets_available,
                     min_players, max_players, required_experience, age_requirement,
-                    table_number, materials_id, contact_id, website_id, group_id
+                    table_number, materials_id, contact_id, website_id, group_id,
+                    cancelled, content_tags
                 )
             "#,
             );
@@ -161,7 +185,9 @@ impl domain::event::driven_ports::EventWriter for DbEventWriter {
                     .push_bind(event_create.materials)
                     .push_bind(event_create.contact)
                     .push_bind(event_create.website)
-                    .push_bind(event_create.group);
+                    .push_bind(event_create.group)
+                    .push_bind(event_create.cancelled)
+                    .push_bind(event_create.content_tags);
             });
 
             insert_query_builder.push(" RETURNING events.id");
@@ -220,18 +246,21 @@ impl domain::event::driven_ports::EventWriter for DbEventWriter {
         &self,
         update_params: &[(i64, UpdateParams<'_>)],
         ext_cxn: &mut impl ExternalConnectivity,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<Vec<i64>, anyhow::Error> {
         let mut cxn = ext_cxn
             .database_cxn()
             .await
             .context("Acquiring connection to bulk update events")?;
+        let mut changed_ids: Vec<i64> = Vec::new();
         let mut all_buildings: Vec<GameLocation> = Vec::new();
         let mut all_rooms: Vec<GameLocation> = Vec::new();
         let mut all_sections: Vec<GameLocation> = Vec::new();
         let mut event_ids_to_remove_location: Vec<i64> = Vec::new();
 
         for (id, update_params) in update_params.iter() {
-            sqlx::query!(
+            // Rows already holding the imported values are left alone, so the number of affected
+            // rows tells whether anything about the event changed
+            let update_result = sqlx::query!(
                 r#"
                 UPDATE events
                 SET event_type_id = $1
@@ -252,7 +281,15 @@ impl domain::event::driven_ports::EventWriter for DbEventWriter {
                     , contact_id = $16
                     , website_id = $17
                     , group_id = $18
-                WHERE id = $19
+                    , cancelled = $19
+                    , content_tags = COALESCE($20, content_tags)
+                WHERE id = $21
+                    AND (event_type_id, game_system_id, title, description, start_dt, end_dt, year,
+                        cost, tickets_available, min_players, max_players, age_requirement,
+                        required_experience, table_number, materials_id, contact_id, website_id,
+                        group_id, cancelled, content_tags)
+                    IS DISTINCT FROM ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
+                        $15, $16, $17, $18, $19, COALESCE($20, content_tags))
             "#,
                 update_params.event_type_id,
                 update_params.game_system_id,
@@ -272,11 +309,16 @@ impl domain::event::driven_ports::EventWriter for DbEventWriter {
                 update_params.contact,
                 update_params.website,
                 update_params.group,
+                update_params.cancelled,
+                update_params.content_tags,
                 id,
             )
             .execute(cxn.borrow_connection())
             .await
             .with_context(|| format!("Updating event ID {id}"))?;
+            if update_result.rows_affected() > 0 {
+                changed_ids.push(*id);
+            }
 
             if let Some(ref location) = update_params.location {
                 let location_to_save = GameLocation {
@@ -306,7 +348,7 @@ impl domain::event::driven_ports::EventWriter for DbEventWriter {
             .await
             .context("Event update")?;
 
-        Ok(())
+        Ok(changed_ids)
     }
 }
 
@@ -344,8 +386,23 @@ async fn upsert_location_refs(
         return Ok(());
     }
 
-    // TODO add code to resolve the situation where an event moves between locations of the same type.
-    //   this will still result in a conflict but the data won't get updated.
+    // Clear out existing joins of the same type first so events which move between two locations
+    // of the same type (e.g. from one room to another) don't end up joined to both
+    let clear_query = match ref_type {
+        RefType::Location => "DELETE FROM event_location WHERE event_id = ANY($1)",
+        RefType::Room => "DELETE FROM event_room WHERE event_id = ANY($1)",
+        RefType::Section => "DELETE FROM event_section WHERE event_id = ANY($1)",
+    };
+    let event_ids: Vec<i64> = locations
+        .iter()
+        .map(|game_location| game_location.id)
+        .collect();
+    sqlx::query(clear_query)
+        .bind(&event_ids)
+        .execute(ext_cxn_handle.borrow_connection())
+        .await
+        .with_context(|| format!("Clearing existing {ref_type} connections"))?;
+
     let insert_query = match ref_type {
         RefType::Location => "INSERT INTO event_location(event_id, location_id)",
         RefType::Room => "INSERT INTO event_room(event_id, room_id)",
@@ -421,3 +478,36 @@ async fn remove_location_refs(
 
     Ok(())
 }
+
+/// Removes events from the database
+pub struct DbEventRemover;
+
+impl domain::event::driven_ports::EventRemover for DbEventRemover {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn delete_event(
+        &self,
+        event_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete an event.")?;
+
+        // Tournament rounds don't cascade with their events, so they're removed first. The
+        // event's other associations are removed along with it.
+        sqlx::query!(
+            "DELETE FROM tournament_segment WHERE event_id = $1",
+            event_id
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Deleting the event's tournament rounds")?;
+        let delete_result = sqlx::query!("DELETE FROM events WHERE id = $1", event_id)
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Deleting event")?;
+
+        Ok(delete_result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::domain;
+use crate::domain::event_type::{EventTypeDetails, EventTypeUpdate};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+
+/// Reads the catalog of event types from the database
+pub struct DbEventTypeReader;
+
+impl domain::event_type::driven_ports::EventTypeReader for DbEventTypeReader {
+    #[tracing::instrument(skip_all)]
+    async fn read_catalog(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventTypeDetails>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read event types.")?;
+
+        let event_types = sqlx::query!(
+            r#"SELECT et.id, et.code, et.display_name, et.description, et.icon,
+                COUNT(e.id) FILTER (
+                    WHERE NOT e.cancelled AND e.year = (SELECT MAX(year) FROM events)
+                ) AS "total_events!"
+            FROM event_types et
+            LEFT JOIN events e ON e.event_type_id = et.id
+            GROUP BY et.id
+            ORDER BY et.display_name, et.code"#
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting event types")?;
+
+        Ok(event_types
+            .into_iter()
+            .map(|record| EventTypeDetails {
+                id: record.id,
+                code: record.code,
+                name: record.display_name,
+                description: record.description,
+                icon: record.icon,
+                total_events: record.total_events as u64,
+            })
+            .collect())
+    }
+}
+
+/// Updates event types in the database
+pub struct DbEventTypeWriter;
+
+impl domain::event_type::driven_ports::EventTypeWriter for DbEventTypeWriter {
+    #[tracing::instrument(skip(self, update, ext_cxn))]
+    async fn update_event_type(
+        &self,
+        event_type_id: i32,
+        update: &EventTypeUpdate,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to update an event type.")?;
+
+        let result = sqlx::query!(
+            "UPDATE event_types SET display_name = $2, description = $3, icon = $4 WHERE id = $1",
+            event_type_id,
+            update.name,
+            update.description,
+            update.icon,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating event type")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
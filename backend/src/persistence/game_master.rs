@@ -1,11 +1,14 @@
 use crate::domain::BulkLookupResult;
-use crate::domain::game_master::GameMaster;
 use crate::domain::game_master::driven_ports::{
-    ExistingAssociationError, GMAssociator, NewAssociationError,
+    ExistingAssociationError, GMAssociator, GameMasterReader, NewAssociationError,
+};
+use crate::domain::game_master::{
+    GameMaster, GameMasterEvent, GameMasterPage, GameMasterProfile, GameMasterSummary,
 };
 use crate::domain::unique::driven_ports::UniqueStringSaver;
 use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
 use anyhow::{Context, Error};
+use chrono_tz::Tz;
 use sqlx::{Postgres, Row};
 use std::collections::{HashMap, HashSet};
 
@@ -222,3 +225,133 @@ impl GMAssociator for GameMasterDbAssociator {
         Ok(())
     }
 }
+
+/// Reads game masters and the events they run from the database
+pub struct DbGameMasterReader;
+
+impl GameMasterReader for DbGameMasterReader {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn search_game_masters(
+        &self,
+        name: Option<&str>,
+        page: u16,
+        page_size: u16,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<GameMasterPage, Error> {
+        let mut db_cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Retrieving DB connection to search game masters")?;
+        let contains_pattern = name.map(|name| format!("%{}%", super::search::escape_like(name)));
+
+        // Names containing the search are matched even when too short for trigrams to be similar
+        let total_results = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "total_results!"
+            FROM game_masters
+            WHERE $1::TEXT IS NULL OR gm_name ILIKE $2 OR $1 <% gm_name"#,
+            name,
+            contains_pattern,
+        )
+        .fetch_one(db_cxn.borrow_connection())
+        .await
+        .context("Counting matching game masters")?;
+        let game_masters = sqlx::query!(
+            r#"SELECT gm.id, gm.gm_name,
+                COUNT(e.id) FILTER (
+                    WHERE e.year = (SELECT MAX(year) FROM events)
+                ) AS "events_this_year!",
+                COUNT(e.id) AS "total_events!"
+            FROM game_masters gm
+            LEFT JOIN event_game_masters egm ON egm.gm_id = gm.id
+            LEFT JOIN events e ON e.id = egm.event_id AND NOT e.cancelled
+            WHERE $1::TEXT IS NULL OR gm.gm_name ILIKE $2 OR $1 <% gm.gm_name
+            GROUP BY gm.id
+            ORDER BY gm.gm_name, gm.id
+            LIMIT $3 OFFSET $4"#,
+            name,
+            contains_pattern,
+            page_size as i64,
+            (page.saturating_sub(1) as i64) * page_size as i64,
+        )
+        .fetch_all(db_cxn.borrow_connection())
+        .await
+        .context("Selecting page of game masters")?;
+
+        Ok(GameMasterPage {
+            game_masters: game_masters
+                .into_iter()
+                .map(|record| GameMasterSummary {
+                    game_master: GameMaster {
+                        id: record.id,
+                        name: record.gm_name,
+                    },
+                    events_this_year: record.events_this_year as u64,
+                    total_events: record.total_events as u64,
+                })
+                .collect(),
+            total_results: total_results as u64,
+        })
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn read_profile(
+        &self,
+        game_master_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<GameMasterProfile>, Error> {
+        let mut db_cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Retrieving DB connection to read a game master profile")?;
+
+        let Some(game_master) = sqlx::query!(
+            "SELECT id, gm_name FROM game_masters WHERE id = $1",
+            game_master_id,
+        )
+        .fetch_optional(db_cxn.borrow_connection())
+        .await
+        .context("Selecting game master")?
+        else {
+            return Ok(None);
+        };
+
+        let events = sqlx::query!(
+            "SELECT e.id, e.game_id, e.title, e.year, e.start_dt, e.end_dt, e.cost,
+                e.tickets_available, e.max_players, e.cancelled
+            FROM event_game_masters egm
+            JOIN events e ON e.id = egm.event_id
+            WHERE egm.gm_id = $1
+            ORDER BY e.year DESC, e.start_dt, e.id",
+            game_master_id,
+        )
+        .fetch_all(db_cxn.borrow_connection())
+        .await
+        .context("Selecting game master events")?;
+
+        Ok(Some(GameMasterProfile {
+            game_master: GameMaster {
+                id: game_master.id,
+                name: game_master.gm_name,
+            },
+            events: events
+                .into_iter()
+                .map(|record| GameMasterEvent {
+                    id: record.id,
+                    game_id: record.game_id,
+                    title: record.title,
+                    year: record.year as i32,
+                    start: record
+                        .start_dt
+                        .with_timezone(&Tz::America__Indiana__Indianapolis),
+                    end: record
+                        .end_dt
+                        .with_timezone(&Tz::America__Indiana__Indianapolis),
+                    cost: record.cost.map(|cost| cost as u32),
+                    tickets_available: record.tickets_available as u16,
+                    max_players: record.max_players as u16,
+                    cancelled: record.cancelled,
+                })
+                .collect(),
+        }))
+    }
+}
@@ -0,0 +1,117 @@
+use crate::domain;
+use crate::domain::game_system::GameSystemProfile;
+use crate::domain::metadata::{GameSystem, Group};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+
+/// Reads game systems and summaries of their events from the database
+pub struct DbGameSystemReader;
+
+impl domain::game_system::driven_ports::GameSystemReader for DbGameSystemReader {
+    #[tracing::instrument(skip_all)]
+    async fn all_game_systems(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<GameSystem>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read game systems.")?;
+
+        let game_systems =
+            sqlx::query!("SELECT id, system_name FROM game_systems ORDER BY system_name")
+                .fetch_all(cxn.borrow_connection())
+                .await
+                .context("Selecting game systems")?;
+
+        Ok(game_systems
+            .into_iter()
+            .map(|record| GameSystem {
+                id: record.id,
+                system_name: record.system_name,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn read_profile(
+        &self,
+        game_system_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<GameSystemProfile>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read a game system profile.")?;
+
+        let Some(game_system) = sqlx::query!(
+            "SELECT id, system_name FROM game_systems WHERE id = $1",
+            game_system_id,
+        )
+        .fetch_optional(cxn.borrow_connection())
+        .await
+        .context("Selecting game system")?
+        else {
+            return Ok(None);
+        };
+
+        // Events without a cost are free
+        let totals = sqlx::query!(
+            r#"SELECT COUNT(*) AS "total_events!",
+                COALESCE(SUM(max_players), 0) AS "total_seats!",
+                MIN(COALESCE(cost, 0)) AS min_cost,
+                MAX(COALESCE(cost, 0)) AS max_cost
+            FROM events
+            WHERE game_system_id = $1 AND NOT cancelled
+                AND year = (SELECT MAX(year) FROM events)"#,
+            game_system_id,
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Totaling game system events")?;
+        let days = sqlx::query_scalar!(
+            r#"SELECT DISTINCT (start_dt AT TIME ZONE 'America/Indiana/Indianapolis')::DATE AS "day!"
+            FROM events
+            WHERE game_system_id = $1 AND NOT cancelled
+                AND year = (SELECT MAX(year) FROM events)
+            ORDER BY 1"#,
+            game_system_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting game system event days")?;
+        let groups = sqlx::query!(
+            "SELECT DISTINCT g.id, g.group_name
+            FROM events e
+            JOIN groups g ON g.id = e.group_id
+            WHERE e.game_system_id = $1 AND NOT e.cancelled
+                AND e.year = (SELECT MAX(year) FROM events)
+            ORDER BY g.group_name, g.id",
+            game_system_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting game system organizing groups")?;
+
+        Ok(Some(GameSystemProfile {
+            game_system: GameSystem {
+                id: game_system.id,
+                system_name: game_system.system_name,
+            },
+            total_events: totals.total_events as u64,
+            total_seats: totals.total_seats as u64,
+            price_range: totals
+                .min_cost
+                .zip(totals.max_cost)
+                .map(|(min_cost, max_cost)| (min_cost as u32, max_cost as u32)),
+            days,
+            groups: groups
+                .into_iter()
+                .map(|record| Group {
+                    id: record.id,
+                    name: record.group_name,
+                })
+                .collect(),
+        }))
+    }
+}
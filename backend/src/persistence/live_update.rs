@@ -0,0 +1,200 @@
+use crate::domain;
+use crate::domain::live_update::EventUpdate;
+use crate::domain::search::EventFilter;
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use crate::persistence::event::{AgeRequirementDTO, ExperienceLevelDTO};
+use crate::persistence::search::{EVENT_BUILDING_JOINS, push_filter_conditions};
+use anyhow::Context;
+use chrono_tz::Tz;
+use sqlx::postgres::PgListener;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+/// Postgres notification channel used to announce changed events
+const EVENT_CHANGES_CHANNEL: &str = "event_changes";
+
+/// Number of event IDs sent in a single notification, keeping payloads well under Postgres'
+/// 8000 byte notification limit
+const IDS_PER_NOTIFICATION: usize = 500;
+
+/// Announces changed events to every application instance using Postgres NOTIFY
+pub struct DbChangeNotifier;
+
+impl domain::live_update::driven_ports::ChangeNotifier for DbChangeNotifier {
+    #[tracing::instrument(skip_all, fields(total = event_ids.len()))]
+    async fn notify_changes(
+        &self,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to announce event changes.")?;
+
+        for id_chunk in event_ids.chunks(IDS_PER_NOTIFICATION) {
+            let payload = id_chunk
+                .iter()
+                .map(i64::to_string)
+                .collect::<Vec<String>>()
+                .join(",");
+            sqlx::query!("SELECT pg_notify($1, $2)", EVENT_CHANGES_CHANNEL, payload)
+                .execute(cxn.borrow_connection())
+                .await
+                .context("Sending event change notification")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the current state of changed events from the database
+pub struct DbEventUpdateReader;
+
+impl domain::live_update::driven_ports::EventUpdateReader for DbEventUpdateReader {
+    #[tracing::instrument(skip_all, fields(first_3 = ?event_ids.get(0..3), total = event_ids.len()))]
+    async fn read_updates(
+        &self,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventUpdate>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read event updates.")?;
+
+        let updates = sqlx::query!(
+            r#"SELECT e.id, e.game_id, e.title, e.start_dt, e.end_dt, e.cost,
+                e.tickets_available, e.cancelled, e.event_type_id, e.game_system_id, e.group_id,
+                e.age_requirement AS "age_requirement: AgeRequirementDTO",
+                e.required_experience AS "required_experience: ExperienceLevelDTO",
+                COALESCE(el.location_id, r.location_id, sr.location_id)::INTEGER AS building_id,
+                COALESCE(er.room_id, s.room_id) AS room_id, es.section_id AS "section_id?",
+                EXISTS(SELECT 1 FROM tournament_segment ts WHERE ts.event_id = e.id)
+                    AS "in_tournament!",
+                EXISTS(
+                    SELECT 1 FROM materials_material_items mmi
+                    WHERE mmi.materials_id = e.materials_id
+                ) AS "requires_materials!",
+                COALESCE(s.wheelchair_accessible, sr.wheelchair_accessible, r.wheelchair_accessible)
+                    IS TRUE AS "wheelchair_accessible!",
+                COALESCE(s.low_sensory, sr.low_sensory, r.low_sensory) IS TRUE AS "low_sensory!",
+                e.content_tags
+            FROM events e
+            LEFT JOIN event_location el ON el.event_id = e.id
+            LEFT JOIN event_room er ON er.event_id = e.id
+            LEFT JOIN rooms r ON r.id = er.room_id
+            LEFT JOIN event_section es ON es.event_id = e.id
+            LEFT JOIN sections s ON s.id = es.section_id
+            LEFT JOIN rooms sr ON sr.id = s.room_id
+            WHERE e.id = ANY($1)
+            ORDER BY e.start_dt, e.id"#,
+            event_ids,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting changed events")?;
+
+        Ok(updates
+            .into_iter()
+            .map(|record| EventUpdate {
+                event_id: record.id,
+                game_id: record.game_id,
+                title: record.title,
+                start: record
+                    .start_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                end: record
+                    .end_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                cost: record.cost.map(|cost| cost as u32),
+                tickets_available: record.tickets_available as u16,
+                cancelled: record.cancelled,
+                event_type_id: record.event_type_id,
+                game_system_id: record.game_system_id,
+                group_id: record.group_id,
+                building_id: record.building_id,
+                room_id: record.room_id,
+                section_id: record.section_id,
+                age_requirement: record.age_requirement.into(),
+                experience_requirement: record.required_experience.into(),
+                in_tournament: record.in_tournament,
+                requires_materials: record.requires_materials,
+                wheelchair_accessible: record.wheelchair_accessible,
+                low_sensory: record.low_sensory,
+                content_tags: record.content_tags,
+            })
+            .collect())
+    }
+}
+
+/// Checks changed events against event list filters using the database
+pub struct DbUpdateSearcher;
+
+impl domain::live_update::driven_ports::UpdateSearcher for DbUpdateSearcher {
+    #[tracing::instrument(skip_all, fields(total = event_ids.len()))]
+    async fn matching_event_ids(
+        &self,
+        event_ids: &[i64],
+        filter: &EventFilter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<i64>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to search changed events.")?;
+
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT e.id");
+        query_builder
+            .push(EVENT_BUILDING_JOINS)
+            .push(" WHERE e.id = ANY(")
+            .push_bind(event_ids)
+            .push(")");
+        push_filter_conditions(&mut query_builder, filter);
+
+        query_builder
+            .build_query_scalar::<i64>()
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting changed events matching the filter")
+    }
+}
+
+/// Receives event change announcements made by any application instance sharing the database
+pub struct DbChangeListener {
+    listener: PgListener,
+}
+
+impl DbChangeListener {
+    /// Opens a dedicated connection which listens for event change announcements
+    pub async fn connect(db: &PgPool) -> Result<Self, anyhow::Error> {
+        let mut listener = PgListener::connect_with(db)
+            .await
+            .context("Opening connection to listen for event changes")?;
+        listener
+            .listen(EVENT_CHANGES_CHANNEL)
+            .await
+            .context("Listening for event changes")?;
+
+        Ok(Self { listener })
+    }
+
+    /// Waits for the next announcement and returns the IDs of the events it covers. Reconnects
+    /// automatically if the connection drops, though announcements made while disconnected are lost.
+    pub async fn next_changed_ids(&mut self) -> Result<Vec<i64>, anyhow::Error> {
+        let notification = self
+            .listener
+            .recv()
+            .await
+            .context("Receiving event change notification")?;
+
+        notification
+            .payload()
+            .split(',')
+            .filter(|id| !id.is_empty())
+            .map(|id| {
+                id.parse::<i64>()
+                    .with_context(|| format!("Parsing changed event ID {id}"))
+            })
+            .collect()
+    }
+}
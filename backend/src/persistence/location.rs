@@ -2,10 +2,12 @@ use std::cmp::min;
 use std::collections::{HashMap, HashSet};
 
 use crate::domain;
-use crate::domain::location::{LocationOnly, RoomOnly, RoomOnlyRef, SectionOnly, SectionOnlyRef};
+use crate::domain::location::{
+    LocationAliases, LocationOnly, Ref, RefType, RoomOnly, RoomOnlyRef, SectionOnly, SectionOnlyRef,
+};
 use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
 use anyhow::{Context, Error, anyhow};
-use sqlx::{FromRow, Postgres};
+use sqlx::{FromRow, PgConnection, Postgres};
 
 /// Reads locations from the database
 pub struct DbLocationReader;
@@ -147,6 +149,141 @@ impl domain::location::driven_ports::LocationReader for DbLocationReader {
             })
             .collect())
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn read_location_aliases(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<LocationAliases, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read location aliases.")?;
+
+        let location_aliases = sqlx::query!(
+            "SELECT la.alias, l.location_name
+            FROM location_aliases la
+            JOIN locations l ON l.id = la.location_id"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting location aliases")?;
+        let room_aliases = sqlx::query!(
+            "SELECT l.location_name, ra.alias, r.room_name
+            FROM room_aliases ra
+            JOIN rooms r ON r.id = ra.room_id
+            JOIN locations l ON l.id = r.location_id"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting room aliases")?;
+        let section_aliases = sqlx::query!(
+            "SELECT l.location_name, r.room_name, sa.alias, s.section_name
+            FROM section_aliases sa
+            JOIN sections s ON s.id = sa.section_id
+            JOIN rooms r ON r.id = s.room_id
+            JOIN locations l ON l.id = r.location_id"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting section aliases")?;
+
+        Ok(LocationAliases {
+            locations: location_aliases
+                .into_iter()
+                .map(|record| (record.alias, record.location_name))
+                .collect(),
+            rooms: room_aliases
+                .into_iter()
+                .map(|record| ((record.location_name, record.alias), record.room_name))
+                .collect(),
+            sections: section_aliases
+                .into_iter()
+                .map(|record| {
+                    (
+                        (record.location_name, record.room_name, record.alias),
+                        record.section_name,
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+impl domain::location::driven_ports::HierarchyReader for DbLocationReader {
+    #[tracing::instrument(skip_all)]
+    async fn all_locations(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<LocationOnly>, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read all locations.")?;
+
+        let locations = sqlx::query!("SELECT id, location_name FROM locations")
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting all locations")?;
+
+        Ok(locations
+            .into_iter()
+            .map(|record| LocationOnly {
+                id: record.id as i32,
+                name: record.location_name,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn all_rooms(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<RoomOnly>, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read all rooms.")?;
+
+        let rooms = sqlx::query!("SELECT id, location_id, room_name FROM rooms")
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting all rooms")?;
+
+        Ok(rooms
+            .into_iter()
+            .map(|record| RoomOnly {
+                id: record.id,
+                location_id: record.location_id as i32,
+                name: record.room_name,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn all_sections(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<SectionOnly>, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read all sections.")?;
+
+        let sections = sqlx::query!("SELECT id, room_id, section_name FROM sections")
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting all sections")?;
+
+        Ok(sections
+            .into_iter()
+            .map(|record| SectionOnly {
+                id: record.id,
+                room_id: record.room_id,
+                name: record.section_name,
+            })
+            .collect())
+    }
 }
 
 /// Number of bind parameters per location insert (location_name).
@@ -410,3 +547,481 @@ impl domain::location::driven_ports::LocationWriter for DbLocationWriter {
             .collect())
     }
 }
+
+/// Maintains alternate names of locations, rooms, and sections in the database
+pub struct DbLocationAliasWriter;
+
+impl domain::location::driven_ports::LocationAliasWriter for DbLocationAliasWriter {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn save_alias(
+        &self,
+        place: &Ref,
+        alias: &str,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save a location alias.")?;
+
+        let save_query = match place.ref_type {
+            RefType::Location => sqlx::query!(
+                "INSERT INTO location_aliases (alias, location_id)
+                SELECT $1, id FROM locations WHERE id = $2
+                ON CONFLICT (alias) DO UPDATE SET location_id = EXCLUDED.location_id",
+                alias,
+                place.id as i16,
+            ),
+            RefType::Room => sqlx::query!(
+                "INSERT INTO room_aliases (location_id, alias, room_id)
+                SELECT location_id, $1, id FROM rooms WHERE id = $2
+                ON CONFLICT (location_id, alias) DO UPDATE SET room_id = EXCLUDED.room_id",
+                alias,
+                place.id,
+            ),
+            RefType::Section => sqlx::query!(
+                "INSERT INTO section_aliases (room_id, alias, section_id)
+                SELECT room_id, $1, id FROM sections WHERE id = $2
+                ON CONFLICT (room_id, alias) DO UPDATE SET section_id = EXCLUDED.section_id",
+                alias,
+                place.id,
+            ),
+        };
+        let result = save_query
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Upserting location alias")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn delete_alias(
+        &self,
+        place: &Ref,
+        alias: &str,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete a location alias.")?;
+
+        let delete_query = match place.ref_type {
+            RefType::Location => sqlx::query!(
+                "DELETE FROM location_aliases WHERE alias = $1 AND location_id = $2",
+                alias,
+                place.id as i16,
+            ),
+            RefType::Room => sqlx::query!(
+                "DELETE FROM room_aliases WHERE alias = $1 AND room_id = $2",
+                alias,
+                place.id,
+            ),
+            RefType::Section => sqlx::query!(
+                "DELETE FROM section_aliases WHERE alias = $1 AND section_id = $2",
+                alias,
+                place.id,
+            ),
+        };
+        let result = delete_query
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Deleting location alias")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Merges duplicate locations, rooms, and sections in the database. Merges touch several tables,
+/// so they should run inside a transaction.
+pub struct DbLocationMerger;
+
+impl domain::location::driven_ports::LocationMerger for DbLocationMerger {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn merge_locations(
+        &self,
+        target_id: i32,
+        duplicate_id: i32,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to merge locations.")?;
+
+        merge_locations(
+            cxn.borrow_connection(),
+            target_id as i16,
+            duplicate_id as i16,
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn merge_rooms(
+        &self,
+        target_id: i32,
+        duplicate_id: i32,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to merge rooms.")?;
+
+        merge_rooms(cxn.borrow_connection(), target_id, duplicate_id).await
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn merge_sections(
+        &self,
+        target_id: i32,
+        duplicate_id: i32,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to merge sections.")?;
+
+        merge_sections(cxn.borrow_connection(), target_id, duplicate_id).await
+    }
+}
+
+/// Folds the duplicate building into the target. Rooms sharing a name are merged, and the rest
+/// of the duplicate's rooms move to the target.
+async fn merge_locations(
+    cxn: &mut PgConnection,
+    target_id: i16,
+    duplicate_id: i16,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM event_location
+        WHERE location_id = $2
+            AND event_id IN (SELECT event_id FROM event_location WHERE location_id = $1)",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing events already in the target location")?;
+    sqlx::query!(
+        "UPDATE event_location SET location_id = $1 WHERE location_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving events to the target location")?;
+
+    let shared_rooms = sqlx::query!(
+        "SELECT target.id AS target_id, duplicate.id AS duplicate_id
+        FROM rooms duplicate
+        JOIN rooms target ON target.room_name = duplicate.room_name AND target.location_id = $1
+        WHERE duplicate.location_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .fetch_all(&mut *cxn)
+    .await
+    .context("Finding rooms shared by both locations")?;
+    for room in shared_rooms {
+        merge_rooms(&mut *cxn, room.target_id, room.duplicate_id).await?;
+    }
+    sqlx::query!(
+        "DELETE FROM room_aliases duplicate
+        WHERE location_id = $2
+            AND EXISTS (
+                SELECT 1 FROM room_aliases target
+                WHERE target.location_id = $1 AND target.alias = duplicate.alias
+            )",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing room aliases already in the target location")?;
+    sqlx::query!(
+        "UPDATE room_aliases SET location_id = $1 WHERE location_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving room aliases to the target location")?;
+    sqlx::query!(
+        "UPDATE rooms SET location_id = $1 WHERE location_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving rooms to the target location")?;
+
+    sqlx::query!(
+        "INSERT INTO walking_times (location_id, other_location_id, walking_minutes)
+        SELECT LEAST($1, other_location_id), GREATEST($1, other_location_id), walking_minutes
+        FROM (
+            SELECT
+                CASE WHEN location_id = $2 THEN other_location_id ELSE location_id END
+                    AS other_location_id,
+                walking_minutes
+            FROM walking_times
+            WHERE $2 IN (location_id, other_location_id)
+        ) duplicate_times
+        WHERE other_location_id <> $1
+        ON CONFLICT DO NOTHING",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Copying walking times to the target location")?;
+    sqlx::query!(
+        "DELETE FROM walking_times WHERE $1 IN (location_id, other_location_id)",
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing walking times of the duplicate location")?;
+
+    sqlx::query!(
+        "UPDATE location_aliases SET location_id = $1 WHERE location_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving location aliases to the target location")?;
+    sqlx::query!(
+        r#"INSERT INTO location_aliases (alias, location_id)
+        SELECT LOWER(REGEXP_REPLACE(TRIM(location_name), '\s+', ' ', 'g')), $1
+        FROM locations WHERE id = $2
+        ON CONFLICT (alias) DO UPDATE SET location_id = EXCLUDED.location_id"#,
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Recording the duplicate location name as an alias")?;
+    sqlx::query!(
+        "UPDATE locations target
+        SET latitude = duplicate.latitude, longitude = duplicate.longitude
+        FROM locations duplicate
+        WHERE target.id = $1 AND duplicate.id = $2 AND target.latitude IS NULL",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Keeping the duplicate location's coordinates")?;
+    sqlx::query!("DELETE FROM locations WHERE id = $1", duplicate_id)
+        .execute(&mut *cxn)
+        .await
+        .context("Deleting the duplicate location")?;
+
+    Ok(())
+}
+
+/// Folds the duplicate room into the target. Sections sharing a name are merged, and the rest of
+/// the duplicate's sections move to the target.
+async fn merge_rooms(
+    cxn: &mut PgConnection,
+    target_id: i32,
+    duplicate_id: i32,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM event_room
+        WHERE room_id = $2
+            AND event_id IN (SELECT event_id FROM event_room WHERE room_id = $1)",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing events already in the target room")?;
+    sqlx::query!(
+        "UPDATE event_room SET room_id = $1 WHERE room_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving events to the target room")?;
+
+    let shared_sections = sqlx::query!(
+        "SELECT target.id AS target_id, duplicate.id AS duplicate_id
+        FROM sections duplicate
+        JOIN sections target
+            ON target.section_name = duplicate.section_name AND target.room_id = $1
+        WHERE duplicate.room_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .fetch_all(&mut *cxn)
+    .await
+    .context("Finding sections shared by both rooms")?;
+    for section in shared_sections {
+        merge_sections(&mut *cxn, section.target_id, section.duplicate_id).await?;
+    }
+    sqlx::query!(
+        "DELETE FROM section_aliases duplicate
+        WHERE room_id = $2
+            AND EXISTS (
+                SELECT 1 FROM section_aliases target
+                WHERE target.room_id = $1 AND target.alias = duplicate.alias
+            )",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing section aliases already in the target room")?;
+    sqlx::query!(
+        "UPDATE section_aliases SET room_id = $1 WHERE room_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving section aliases to the target room")?;
+    sqlx::query!(
+        "UPDATE sections SET room_id = $1 WHERE room_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving sections to the target room")?;
+
+    sqlx::query!(
+        "DELETE FROM room_aliases duplicate
+        WHERE room_id = $2
+            AND EXISTS (
+                SELECT 1 FROM rooms target, room_aliases target_alias
+                WHERE target.id = $1
+                    AND target_alias.location_id = target.location_id
+                    AND target_alias.alias = duplicate.alias
+            )",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing room aliases already held by the target room")?;
+    sqlx::query!(
+        "UPDATE room_aliases ra SET room_id = $1, location_id = target.location_id
+        FROM rooms target
+        WHERE target.id = $1 AND ra.room_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving room aliases to the target room")?;
+    sqlx::query!(
+        r#"INSERT INTO room_aliases (location_id, alias, room_id)
+        SELECT target.location_id,
+            LOWER(REGEXP_REPLACE(TRIM(duplicate.room_name), '\s+', ' ', 'g')),
+            target.id
+        FROM rooms target, rooms duplicate
+        WHERE target.id = $1 AND duplicate.id = $2 AND target.room_name <> duplicate.room_name
+        ON CONFLICT (location_id, alias) DO UPDATE SET room_id = EXCLUDED.room_id"#,
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Recording the duplicate room name as an alias")?;
+    sqlx::query!(
+        "UPDATE rooms target
+        SET latitude = duplicate.latitude, longitude = duplicate.longitude
+        FROM rooms duplicate
+        WHERE target.id = $1 AND duplicate.id = $2 AND target.latitude IS NULL",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Keeping the duplicate room's coordinates")?;
+    sqlx::query!("DELETE FROM rooms WHERE id = $1", duplicate_id)
+        .execute(&mut *cxn)
+        .await
+        .context("Deleting the duplicate room")?;
+
+    Ok(())
+}
+
+/// Folds the duplicate section into the target
+async fn merge_sections(
+    cxn: &mut PgConnection,
+    target_id: i32,
+    duplicate_id: i32,
+) -> Result<(), Error> {
+    sqlx::query!(
+        "DELETE FROM event_section
+        WHERE section_id = $2
+            AND event_id IN (SELECT event_id FROM event_section WHERE section_id = $1)",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing events already in the target section")?;
+    sqlx::query!(
+        "UPDATE event_section SET section_id = $1 WHERE section_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving events to the target section")?;
+
+    sqlx::query!(
+        "DELETE FROM section_aliases duplicate
+        WHERE section_id = $2
+            AND EXISTS (
+                SELECT 1 FROM sections target, section_aliases target_alias
+                WHERE target.id = $1
+                    AND target_alias.room_id = target.room_id
+                    AND target_alias.alias = duplicate.alias
+            )",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Removing section aliases already held by the target section")?;
+    sqlx::query!(
+        "UPDATE section_aliases sa SET section_id = $1, room_id = target.room_id
+        FROM sections target
+        WHERE target.id = $1 AND sa.section_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving section aliases to the target section")?;
+    sqlx::query!(
+        r#"INSERT INTO section_aliases (room_id, alias, section_id)
+        SELECT target.room_id,
+            LOWER(REGEXP_REPLACE(TRIM(duplicate.section_name), '\s+', ' ', 'g')),
+            target.id
+        FROM sections target, sections duplicate
+        WHERE target.id = $1 AND duplicate.id = $2
+            AND target.section_name <> duplicate.section_name
+        ON CONFLICT (room_id, alias) DO UPDATE SET section_id = EXCLUDED.section_id"#,
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Recording the duplicate section name as an alias")?;
+    sqlx::query!("DELETE FROM sections WHERE id = $1", duplicate_id)
+        .execute(&mut *cxn)
+        .await
+        .context("Deleting the duplicate section")?;
+
+    Ok(())
+}
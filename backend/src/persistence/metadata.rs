@@ -1,11 +1,15 @@
 use crate::domain;
-use crate::domain::metadata::{Contact, EventType, GameSystem, Group, Materials, Website};
+use crate::domain::metadata::{
+    Contact, EventType, EventTypeLabel, GameSystem, Group, MaterialItem, Materials,
+    MetadataAliases, MetadataKind, Website,
+};
 use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
 use crate::persistence::PG_PARAM_LIMIT;
 use anyhow::{Context, Error};
-use sqlx::{Postgres, Row};
+use sqlx::{PgConnection, Postgres, Row};
+use std::collections::HashMap;
 
-/// Persists or retrieves unique event type strings
+/// Persists or retrieves event types by the code in their labels
 pub struct DbEventTypeSaver;
 
 impl domain::unique::driven_ports::UniqueStringSaver<i32, EventType> for DbEventTypeSaver {
@@ -23,7 +27,8 @@ impl domain::unique::driven_ports::UniqueStringSaver<i32, EventType> for DbEvent
         let mut fetched_rows: Vec<Option<EventType>> = Vec::with_capacity(names.len());
 
         for name in names.iter() {
-            let name_id = sqlx::query!("SELECT id FROM event_types WHERE event_type = $1", name)
+            let code = EventTypeLabel::parse(name).code;
+            let name_id = sqlx::query!("SELECT id FROM event_types WHERE code = $1", code)
                 .fetch_optional(cxn_handle.borrow_connection())
                 .await?;
 
@@ -49,12 +54,13 @@ impl domain::unique::driven_ports::UniqueStringSaver<i32, EventType> for DbEvent
 
         let mut saved_ids: Vec<i32> = Vec::with_capacity(new_names.len());
 
-        for name_chunk in new_names.chunks(PG_PARAM_LIMIT) {
+        for name_chunk in new_names.chunks(PG_PARAM_LIMIT / 2) {
             let mut query_builder: sqlx::QueryBuilder<Postgres> =
-                sqlx::QueryBuilder::new("INSERT INTO event_types(event_type)");
+                sqlx::QueryBuilder::new("INSERT INTO event_types(code, display_name)");
 
             query_builder.push_values(name_chunk, |mut builder, name| {
-                builder.push_bind(name);
+                let label = EventTypeLabel::parse(name);
+                builder.push_bind(label.code).push_bind(label.name);
             });
 
             query_builder.push(" RETURNING id");
@@ -424,3 +430,400 @@ impl domain::unique::driven_ports::UniqueStringSaver<i64, Materials> for DbMater
         Ok(saved_ids)
     }
 }
+
+/// UniqueStringSaver implementation for the individual items listed in materials descriptions.
+pub struct DbMaterialItemSaver;
+
+impl domain::unique::driven_ports::UniqueStringSaver<i64, MaterialItem> for DbMaterialItemSaver {
+    #[tracing::instrument(skip_all, fields(first_5 = ?names.get(0..5), total = names.len()))]
+    async fn read_matching(
+        &self,
+        names: &[&str],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<Option<MaterialItem>>, Error> {
+        let mut cxn_handle = ext_cxn
+            .database_cxn()
+            .await
+            .context("Fetching connection for reading material items")?;
+
+        let owned_names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+        let existing_items = sqlx::query!(
+            "SELECT id, item_name FROM material_items WHERE item_name = ANY($1)",
+            &owned_names,
+        )
+        .fetch_all(cxn_handle.borrow_connection())
+        .await
+        .context("Selecting material items")?;
+        let mut ids_by_name: HashMap<String, i64> = existing_items
+            .into_iter()
+            .map(|record| (record.item_name, record.id))
+            .collect();
+
+        Ok(names
+            .iter()
+            .map(|name| {
+                ids_by_name.remove(*name).map(|id| MaterialItem {
+                    id,
+                    item_name: name.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all, fields(first_5 = ?new_names.get(0..5), total = new_names.len()))]
+    async fn bulk_save(
+        &self,
+        new_names: &[&str],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<i64>, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Fetching connection for saving material items")?;
+
+        let mut saved_ids: Vec<i64> = Vec::with_capacity(new_names.len());
+
+        for name_chunk in new_names.chunks(PG_PARAM_LIMIT) {
+            let mut query_builder: sqlx::QueryBuilder<Postgres> =
+                sqlx::QueryBuilder::new("INSERT INTO material_items(item_name)");
+
+            query_builder.push_values(name_chunk, |mut builder, name| {
+                builder.push_bind(name);
+            });
+
+            query_builder.push(" RETURNING id");
+
+            let fetched_ids = query_builder
+                .build()
+                .fetch_all(cxn.borrow_connection())
+                .await
+                .context("Bulk save material items")?;
+            saved_ids.extend(
+                fetched_ids
+                    .into_iter()
+                    .map(|fetched_id| -> i64 { fetched_id.get("id") }),
+            );
+        }
+
+        Ok(saved_ids)
+    }
+}
+
+/// Links materials descriptions to the items they list in the database
+pub struct DbMaterialItemLinker;
+
+impl domain::metadata::driven_ports::MaterialItemLinker for DbMaterialItemLinker {
+    #[tracing::instrument(skip_all, fields(total = item_ids_by_materials.len()))]
+    async fn link_material_items(
+        &self,
+        item_ids_by_materials: &HashMap<i64, Vec<i64>>,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Fetching connection for linking material items")?;
+
+        let materials_ids: Vec<i64> = item_ids_by_materials.keys().copied().collect();
+        sqlx::query!(
+            "DELETE FROM materials_material_items WHERE materials_id = ANY($1)",
+            &materials_ids,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Removing outdated material item links")?;
+
+        let links: Vec<(i64, i64)> = item_ids_by_materials
+            .iter()
+            .flat_map(|(materials_id, item_ids)| {
+                item_ids.iter().map(|item_id| (*materials_id, *item_id))
+            })
+            .collect();
+        for link_chunk in links.chunks(PG_PARAM_LIMIT / 2) {
+            let mut query_builder: sqlx::QueryBuilder<Postgres> = sqlx::QueryBuilder::new(
+                "INSERT INTO materials_material_items(materials_id, material_item_id)",
+            );
+
+            query_builder.push_values(link_chunk, |mut builder, (materials_id, item_id)| {
+                builder.push_bind(materials_id).push_bind(item_id);
+            });
+
+            query_builder
+                .build()
+                .execute(cxn.borrow_connection())
+                .await
+                .context("Linking materials to items")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads game system and group aliases from the database
+pub struct DbMetadataAliasReader;
+
+impl domain::metadata::driven_ports::MetadataAliasReader for DbMetadataAliasReader {
+    #[tracing::instrument(skip_all)]
+    async fn read_metadata_aliases(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<MetadataAliases, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read metadata aliases.")?;
+
+        let game_system_aliases = sqlx::query!(
+            "SELECT gsa.alias, gs.system_name
+            FROM game_system_aliases gsa
+            JOIN game_systems gs ON gs.id = gsa.game_system_id"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting game system aliases")?;
+        let group_aliases = sqlx::query!(
+            "SELECT ga.alias, g.group_name
+            FROM group_aliases ga
+            JOIN groups g ON g.id = ga.group_id"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting group aliases")?;
+
+        Ok(MetadataAliases {
+            game_systems: game_system_aliases
+                .into_iter()
+                .map(|record| (record.alias, record.system_name))
+                .collect(),
+            groups: group_aliases
+                .into_iter()
+                .map(|record| (record.alias, record.group_name))
+                .collect(),
+        })
+    }
+}
+
+/// Saves and deletes game system and group aliases in the database
+pub struct DbMetadataAliasWriter;
+
+impl domain::metadata::driven_ports::MetadataAliasWriter for DbMetadataAliasWriter {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn save_alias(
+        &self,
+        kind: MetadataKind,
+        id: i64,
+        alias: &str,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save a metadata alias.")?;
+
+        let save_query = match kind {
+            MetadataKind::GameSystem => sqlx::query!(
+                "INSERT INTO game_system_aliases (alias, game_system_id)
+                SELECT $1, id FROM game_systems WHERE id = $2
+                ON CONFLICT (alias) DO UPDATE SET game_system_id = EXCLUDED.game_system_id",
+                alias,
+                id,
+            ),
+            MetadataKind::Group => sqlx::query!(
+                "INSERT INTO group_aliases (alias, group_id)
+                SELECT $1, id FROM groups WHERE id = $2
+                ON CONFLICT (alias) DO UPDATE SET group_id = EXCLUDED.group_id",
+                alias,
+                id,
+            ),
+        };
+        let result = save_query
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Upserting metadata alias")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn delete_alias(
+        &self,
+        kind: MetadataKind,
+        id: i64,
+        alias: &str,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete a metadata alias.")?;
+
+        let delete_query = match kind {
+            MetadataKind::GameSystem => sqlx::query!(
+                "DELETE FROM game_system_aliases WHERE alias = $1 AND game_system_id = $2",
+                alias,
+                id,
+            ),
+            MetadataKind::Group => sqlx::query!(
+                "DELETE FROM group_aliases WHERE alias = $1 AND group_id = $2",
+                alias,
+                id,
+            ),
+        };
+        let result = delete_query
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Deleting metadata alias")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Folds duplicate game systems and groups into their canonical records in the database
+pub struct DbMetadataMerger;
+
+impl domain::metadata::driven_ports::MetadataMerger for DbMetadataMerger {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn existing_records(
+        &self,
+        kind: MetadataKind,
+        ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<i64>, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to check metadata records exist.")?;
+
+        let existing_ids = match kind {
+            MetadataKind::GameSystem => {
+                sqlx::query_scalar!("SELECT id FROM game_systems WHERE id = ANY($1)", ids)
+                    .fetch_all(cxn.borrow_connection())
+                    .await
+            }
+            MetadataKind::Group => {
+                sqlx::query_scalar!("SELECT id FROM groups WHERE id = ANY($1)", ids)
+                    .fetch_all(cxn.borrow_connection())
+                    .await
+            }
+        }
+        .context("Selecting existing metadata records")?;
+
+        Ok(existing_ids)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn merge_records(
+        &self,
+        kind: MetadataKind,
+        target_id: i64,
+        duplicate_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<i64>, Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to merge metadata records.")?;
+
+        match kind {
+            MetadataKind::GameSystem => {
+                merge_game_systems(cxn.borrow_connection(), target_id, duplicate_id).await
+            }
+            MetadataKind::Group => {
+                merge_groups(cxn.borrow_connection(), target_id, duplicate_id).await
+            }
+        }
+    }
+}
+
+/// Moves the duplicate game system's events and aliases to the target, records the duplicate's
+/// name as an alias, and deletes the duplicate. Returns the IDs of the events which moved.
+async fn merge_game_systems(
+    cxn: &mut PgConnection,
+    target_id: i64,
+    duplicate_id: i64,
+) -> Result<Vec<i64>, Error> {
+    let moved_event_ids = sqlx::query_scalar!(
+        "UPDATE events SET game_system_id = $1 WHERE game_system_id = $2 RETURNING id",
+        target_id,
+        duplicate_id,
+    )
+    .fetch_all(&mut *cxn)
+    .await
+    .context("Moving events to the target game system")?;
+
+    sqlx::query!(
+        "UPDATE game_system_aliases SET game_system_id = $1 WHERE game_system_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving game system aliases to the target game system")?;
+    // Names too long to be an alias are left out, since they can't be matched against anyway
+    sqlx::query!(
+        r#"INSERT INTO game_system_aliases (alias, game_system_id)
+        SELECT alias, $1
+        FROM (
+            SELECT LOWER(REGEXP_REPLACE(TRIM(system_name), '\s+', ' ', 'g')) AS alias
+            FROM game_systems
+            WHERE id = $2
+        ) duplicate
+        WHERE CHAR_LENGTH(alias) BETWEEN 1 AND 255
+        ON CONFLICT (alias) DO UPDATE SET game_system_id = EXCLUDED.game_system_id"#,
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Recording the duplicate game system name as an alias")?;
+    sqlx::query!("DELETE FROM game_systems WHERE id = $1", duplicate_id)
+        .execute(&mut *cxn)
+        .await
+        .context("Deleting the duplicate game system")?;
+
+    Ok(moved_event_ids)
+}
+
+/// Moves the duplicate group's events and aliases to the target, records the duplicate's name as
+/// an alias, and deletes the duplicate. Returns the IDs of the events which moved.
+async fn merge_groups(
+    cxn: &mut PgConnection,
+    target_id: i64,
+    duplicate_id: i64,
+) -> Result<Vec<i64>, Error> {
+    let moved_event_ids = sqlx::query_scalar!(
+        "UPDATE events SET group_id = $1 WHERE group_id = $2 RETURNING id",
+        target_id,
+        duplicate_id,
+    )
+    .fetch_all(&mut *cxn)
+    .await
+    .context("Moving events to the target group")?;
+
+    sqlx::query!(
+        "UPDATE group_aliases SET group_id = $1 WHERE group_id = $2",
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Moving group aliases to the target group")?;
+    sqlx::query!(
+        r#"INSERT INTO group_aliases (alias, group_id)
+        SELECT LOWER(REGEXP_REPLACE(TRIM(group_name), '\s+', ' ', 'g')), $1
+        FROM groups
+        WHERE id = $2 AND TRIM(group_name) <> ''
+        ON CONFLICT (alias) DO UPDATE SET group_id = EXCLUDED.group_id"#,
+        target_id,
+        duplicate_id,
+    )
+    .execute(&mut *cxn)
+    .await
+    .context("Recording the duplicate group name as an alias")?;
+    sqlx::query!("DELETE FROM groups WHERE id = $1", duplicate_id)
+        .execute(&mut *cxn)
+        .await
+        .context("Deleting the duplicate group")?;
+
+    Ok(moved_event_ids)
+}
@@ -0,0 +1,169 @@
+use crate::domain;
+use crate::domain::metadata::{GameSystem, Group};
+use crate::domain::organizer::{GroupProfile, GroupSummary};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+
+/// Reads organizing groups and summaries of their events from the database
+pub struct DbGroupReader;
+
+impl domain::organizer::driven_ports::GroupReader for DbGroupReader {
+    #[tracing::instrument(skip_all)]
+    async fn all_groups(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<GroupSummary>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read groups.")?;
+
+        let groups = sqlx::query!(
+            r#"SELECT g.id, g.group_name,
+                COUNT(e.id) FILTER (
+                    WHERE NOT e.cancelled AND e.year = (SELECT MAX(year) FROM events)
+                ) AS "total_events!"
+            FROM groups g
+            LEFT JOIN events e ON e.group_id = g.id
+            GROUP BY g.id
+            ORDER BY g.group_name"#
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting groups")?;
+
+        Ok(groups
+            .into_iter()
+            .map(|record| GroupSummary {
+                group: Group {
+                    id: record.id,
+                    name: record.group_name,
+                },
+                total_events: record.total_events as u64,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn read_profile(
+        &self,
+        group_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<GroupProfile>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read a group profile.")?;
+
+        let Some(group) = sqlx::query!(
+            "SELECT id, group_name, share_contact FROM groups WHERE id = $1",
+            group_id,
+        )
+        .fetch_optional(cxn.borrow_connection())
+        .await
+        .context("Selecting group")?
+        else {
+            return Ok(None);
+        };
+
+        let total_events = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "total_events!"
+            FROM events
+            WHERE group_id = $1 AND NOT cancelled
+                AND year = (SELECT MAX(year) FROM events)"#,
+            group_id,
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Counting group events")?;
+        // Events list the website and contact individually, so the group's are whichever its
+        // events list most often
+        let website = sqlx::query_scalar!(
+            "SELECT w.url
+            FROM events e
+            JOIN websites w ON w.id = e.website_id
+            WHERE e.group_id = $1 AND NOT e.cancelled
+                AND e.year = (SELECT MAX(year) FROM events)
+            GROUP BY w.url
+            ORDER BY COUNT(*) DESC, w.url
+            LIMIT 1",
+            group_id,
+        )
+        .fetch_optional(cxn.borrow_connection())
+        .await
+        .context("Selecting group website")?;
+        let contact = sqlx::query_scalar!(
+            "SELECT c.contact_email
+            FROM events e
+            JOIN contacts c ON c.id = e.contact_id
+            WHERE e.group_id = $1 AND NOT e.cancelled
+                AND e.year = (SELECT MAX(year) FROM events)
+            GROUP BY c.contact_email
+            ORDER BY COUNT(*) DESC, c.contact_email
+            LIMIT 1",
+            group_id,
+        )
+        .fetch_optional(cxn.borrow_connection())
+        .await
+        .context("Selecting group contact")?;
+        let game_systems = sqlx::query!(
+            "SELECT DISTINCT gs.id, gs.system_name
+            FROM events e
+            JOIN game_systems gs ON gs.id = e.game_system_id
+            WHERE e.group_id = $1 AND NOT e.cancelled
+                AND e.year = (SELECT MAX(year) FROM events)
+            ORDER BY gs.system_name, gs.id",
+            group_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting group game systems")?;
+
+        Ok(Some(GroupProfile {
+            group: Group {
+                id: group.id,
+                name: group.group_name,
+            },
+            total_events: total_events as u64,
+            website,
+            contact,
+            shares_contact: group.share_contact,
+            game_systems: game_systems
+                .into_iter()
+                .map(|record| GameSystem {
+                    id: record.id,
+                    system_name: record.system_name,
+                })
+                .collect(),
+        }))
+    }
+}
+
+/// Updates organizing groups in the database
+pub struct DbGroupWriter;
+
+impl domain::organizer::driven_ports::GroupWriter for DbGroupWriter {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn set_contact_sharing(
+        &self,
+        group_id: i64,
+        shares_contact: bool,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to update group contact sharing.")?;
+
+        let result = sqlx::query!(
+            "UPDATE groups SET share_contact = $2 WHERE id = $1",
+            group_id,
+            shares_contact,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating group contact sharing")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
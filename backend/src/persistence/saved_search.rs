@@ -0,0 +1,443 @@
+use crate::domain;
+use crate::domain::saved_search::{NewSavedSearch, SavedSearch, SearchMatch};
+use crate::domain::search::{DateRange, EventFilter, TimeSpan};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use crate::persistence::event::{AgeRequirementDTO, ExperienceLevelDTO};
+use anyhow::Context;
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::{Postgres, QueryBuilder};
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+/// JSON form of an event filter stored alongside a saved search. Missing fields match every
+/// event, so searches saved before a filter existed keep working.
+struct SavedFilterDTO {
+    min_available_tickets: Option<u16>,
+    event_type_ids: Option<Vec<i32>>,
+    experience_levels: Option<Vec<ExperienceLevelDTO>>,
+    age_requirements: Option<Vec<AgeRequirementDTO>>,
+    game_system_ids: Option<Vec<i64>>,
+    group_ids: Option<Vec<i64>>,
+    building_ids: Option<Vec<i32>>,
+    room_ids: Option<Vec<i32>>,
+    section_ids: Option<Vec<i32>>,
+    exclude_tournaments: bool,
+    no_materials_required: bool,
+    wheelchair_accessible: bool,
+    low_sensory: bool,
+    content_tags: Option<Vec<String>>,
+    exclude_content_tags: Option<Vec<String>>,
+    earliest_start: Option<NaiveTime>,
+    latest_start: Option<NaiveTime>,
+    earliest_end: Option<NaiveTime>,
+    latest_end: Option<NaiveTime>,
+    fits_within: Option<TimeSpanDTO>,
+    busy: Vec<TimeSpanDTO>,
+    min_duration_hours: Option<f32>,
+    max_duration_hours: Option<f32>,
+    search_text: Option<String>,
+    cost_min: Option<u32>,
+    cost_max: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+/// JSON form of a span of time within a saved filter
+struct TimeSpanDTO {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+impl From<TimeSpan> for TimeSpanDTO {
+    fn from(span: TimeSpan) -> Self {
+        Self {
+            start: span.start.with_timezone(&Utc),
+            end: span.end.with_timezone(&Utc),
+        }
+    }
+}
+
+impl From<TimeSpanDTO> for TimeSpan {
+    fn from(span: TimeSpanDTO) -> Self {
+        Self {
+            start: span
+                .start
+                .with_timezone(&Tz::America__Indiana__Indianapolis),
+            end: span.end.with_timezone(&Tz::America__Indiana__Indianapolis),
+        }
+    }
+}
+
+impl From<&EventFilter> for SavedFilterDTO {
+    fn from(filter: &EventFilter) -> Self {
+        Self {
+            min_available_tickets: filter.min_available_tickets,
+            event_type_ids: filter.event_type_ids.clone(),
+            experience_levels: filter.experience_levels.as_ref().map(|levels| {
+                levels
+                    .iter()
+                    .map(|level| ExperienceLevelDTO::from(*level))
+                    .collect()
+            }),
+            age_requirements: filter.age_requirements.as_ref().map(|ages| {
+                ages.iter()
+                    .map(|age| AgeRequirementDTO::from(*age))
+                    .collect()
+            }),
+            game_system_ids: filter.game_system_ids.clone(),
+            group_ids: filter.group_ids.clone(),
+            building_ids: filter.building_ids.clone(),
+            room_ids: filter.room_ids.clone(),
+            section_ids: filter.section_ids.clone(),
+            exclude_tournaments: filter.exclude_tournaments,
+            no_materials_required: filter.no_materials_required,
+            wheelchair_accessible: filter.wheelchair_accessible,
+            low_sensory: filter.low_sensory,
+            content_tags: filter.content_tags.clone(),
+            exclude_content_tags: filter.exclude_content_tags.clone(),
+            earliest_start: filter.earliest_start,
+            latest_start: filter.latest_start,
+            earliest_end: filter.earliest_end,
+            latest_end: filter.latest_end,
+            fits_within: filter.fits_within.map(TimeSpanDTO::from),
+            busy: filter.busy.iter().copied().map(TimeSpanDTO::from).collect(),
+            min_duration_hours: filter.min_duration_hours,
+            max_duration_hours: filter.max_duration_hours,
+            search_text: filter.search_text.clone(),
+            cost_min: filter.cost_min,
+            cost_max: filter.cost_max,
+        }
+    }
+}
+
+impl From<SavedFilterDTO> for EventFilter {
+    fn from(filter: SavedFilterDTO) -> Self {
+        Self {
+            min_available_tickets: filter.min_available_tickets,
+            event_type_ids: filter.event_type_ids,
+            experience_levels: filter
+                .experience_levels
+                .map(|levels| levels.into_iter().map(Into::into).collect()),
+            age_requirements: filter
+                .age_requirements
+                .map(|ages| ages.into_iter().map(Into::into).collect()),
+            game_system_ids: filter.game_system_ids,
+            group_ids: filter.group_ids,
+            building_ids: filter.building_ids,
+            room_ids: filter.room_ids,
+            section_ids: filter.section_ids,
+            exclude_tournaments: filter.exclude_tournaments,
+            no_materials_required: filter.no_materials_required,
+            wheelchair_accessible: filter.wheelchair_accessible,
+            low_sensory: filter.low_sensory,
+            content_tags: filter.content_tags,
+            exclude_content_tags: filter.exclude_content_tags,
+            earliest_start: filter.earliest_start,
+            latest_start: filter.latest_start,
+            earliest_end: filter.earliest_end,
+            latest_end: filter.latest_end,
+            fits_within: filter.fits_within.map(TimeSpan::from),
+            busy: filter.busy.into_iter().map(TimeSpan::from).collect(),
+            min_duration_hours: filter.min_duration_hours,
+            max_duration_hours: filter.max_duration_hours,
+            search_text: filter.search_text,
+            cost_min: filter.cost_min,
+            cost_max: filter.cost_max,
+        }
+    }
+}
+
+/// DTO for a saved search row
+struct SavedSearchRow {
+    id: i64,
+    user_id: String,
+    search_name: String,
+    query_params: String,
+    filter: Json<SavedFilterDTO>,
+    created_at: DateTime<Utc>,
+    last_checked_at: DateTime<Utc>,
+}
+
+impl From<SavedSearchRow> for SavedSearch {
+    fn from(row: SavedSearchRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: row.user_id,
+            name: row.search_name,
+            query: row.query_params,
+            filter: row.filter.0.into(),
+            created_at: row.created_at,
+            last_checked_at: row.last_checked_at,
+        }
+    }
+}
+
+/// Reads saved searches from the database
+pub struct DbSavedSearchReader;
+
+impl domain::saved_search::driven_ports::SavedSearchReader for DbSavedSearchReader {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn saved_searches(
+        &self,
+        user_id: &str,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<SavedSearch>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read saved searches.")?;
+
+        let searches = sqlx::query_as!(
+            SavedSearchRow,
+            r#"SELECT id, user_id, search_name, query_params,
+                filter AS "filter: Json<SavedFilterDTO>", created_at, last_checked_at
+            FROM saved_searches
+            WHERE user_id = $1
+            ORDER BY created_at, id"#,
+            user_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting saved searches for user")?;
+
+        Ok(searches.into_iter().map(SavedSearch::from).collect())
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn saved_search(
+        &self,
+        user_id: &str,
+        search_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<SavedSearch>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read a saved search.")?;
+
+        let search = sqlx::query_as!(
+            SavedSearchRow,
+            r#"SELECT id, user_id, search_name, query_params,
+                filter AS "filter: Json<SavedFilterDTO>", created_at, last_checked_at
+            FROM saved_searches
+            WHERE user_id = $1 AND id = $2"#,
+            user_id,
+            search_id,
+        )
+        .fetch_optional(cxn.borrow_connection())
+        .await
+        .context("Selecting saved search")?;
+
+        Ok(search.map(SavedSearch::from))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn all_saved_searches(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<SavedSearch>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read all saved searches.")?;
+
+        let searches = sqlx::query_as!(
+            SavedSearchRow,
+            r#"SELECT id, user_id, search_name, query_params,
+                filter AS "filter: Json<SavedFilterDTO>", created_at, last_checked_at
+            FROM saved_searches
+            ORDER BY id"#,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting all saved searches")?;
+
+        Ok(searches.into_iter().map(SavedSearch::from).collect())
+    }
+}
+
+/// Creates, removes, and checks saved searches in the database
+pub struct DbSavedSearchWriter;
+
+impl domain::saved_search::driven_ports::SavedSearchWriter for DbSavedSearchWriter {
+    #[tracing::instrument(skip(self, new_search, ext_cxn))]
+    async fn create_saved_search(
+        &self,
+        user_id: &str,
+        new_search: &NewSavedSearch,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<SavedSearch, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to create a saved search.")?;
+
+        let search = sqlx::query_as!(
+            SavedSearchRow,
+            r#"INSERT INTO saved_searches (user_id, search_name, query_params, filter)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, user_id, search_name, query_params,
+                filter AS "filter: Json<SavedFilterDTO>", created_at, last_checked_at"#,
+            user_id,
+            new_search.name,
+            new_search.query,
+            Json(SavedFilterDTO::from(&new_search.filter)) as _,
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Inserting saved search")?;
+
+        Ok(search.into())
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn delete_saved_search(
+        &self,
+        user_id: &str,
+        search_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete a saved search.")?;
+
+        let result = sqlx::query!(
+            "DELETE FROM saved_searches WHERE user_id = $1 AND id = $2",
+            user_id,
+            search_id,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Deleting saved search")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn take_new_matches(
+        &self,
+        search_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<SearchMatch>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to take new saved search matches.")?;
+
+        // Both updates run even though only the first is read from
+        let matches = sqlx::query!(
+            r#"WITH taken AS (
+                UPDATE saved_search_matches SET seen = true
+                WHERE saved_search_id = $1 AND NOT seen
+                RETURNING event_id, flagged_at
+            ), checked AS (
+                UPDATE saved_searches SET last_checked_at = now() WHERE id = $1
+            )
+            SELECT e.id, e.title, e.start_dt, e.end_dt, e.cost, e.tickets_available,
+                e.max_players, taken.flagged_at
+            FROM taken
+            JOIN events e ON e.id = taken.event_id
+            WHERE NOT e.cancelled
+            ORDER BY e.start_dt, e.id"#,
+            search_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Marking new saved search matches as seen")?;
+
+        Ok(matches
+            .into_iter()
+            .map(|record| SearchMatch {
+                event_id: record.id,
+                title: record.title,
+                start: record
+                    .start_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                end: record
+                    .end_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                cost: record.cost.map(|cost| cost as u32),
+                tickets_available: record.tickets_available as u16,
+                max_players: record.max_players as u16,
+                flagged_at: record.flagged_at,
+            })
+            .collect())
+    }
+}
+
+/// Appends a SELECT of the search's ID and each event of the latest convention matching its
+/// filter, along with whether the matches count as seen
+fn push_matching_events<'args>(
+    query_builder: &mut QueryBuilder<'args, Postgres>,
+    search: &'args SavedSearch,
+    seen: bool,
+) -> Result<(), anyhow::Error> {
+    query_builder
+        .push("SELECT ")
+        .push_bind(search.id)
+        .push(", e.id, ")
+        .push_bind(seen);
+    super::search::push_events_in_range(query_builder, &DateRange::default(), &search.filter)
+}
+
+/// Records which events match saved searches in the database
+pub struct DbSearchMatchRecorder;
+
+impl domain::saved_search::driven_ports::SearchMatchRecorder for DbSearchMatchRecorder {
+    #[tracing::instrument(skip_all, fields(search_id = search.id))]
+    async fn record_existing_matches(
+        &self,
+        search: &SavedSearch,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to record saved search matches.")?;
+
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO saved_search_matches (saved_search_id, event_id, seen) ",
+        );
+        push_matching_events(&mut query_builder, search, true)?;
+        query_builder.push(" ON CONFLICT DO NOTHING");
+        query_builder
+            .build()
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Inserting existing saved search matches")?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, fields(search_id = search.id, total_events = event_ids.len()))]
+    async fn flag_new_matches(
+        &self,
+        search: &SavedSearch,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<u64, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to flag saved search matches.")?;
+
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO saved_search_matches (saved_search_id, event_id, seen) ",
+        );
+        push_matching_events(&mut query_builder, search, false)?;
+        query_builder
+            .push(" AND e.id = ANY(")
+            .push_bind(event_ids)
+            .push(") ON CONFLICT DO NOTHING");
+        let result = query_builder
+            .build()
+            .execute(cxn.borrow_connection())
+            .await
+            .context("Inserting new saved search matches")?;
+
+        Ok(result.rows_affected())
+    }
+}
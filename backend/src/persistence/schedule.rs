@@ -0,0 +1,73 @@
+use crate::domain;
+use crate::domain::location::{Ref, RefType};
+use crate::domain::schedule::ScheduleCandidate;
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+use chrono_tz::Tz;
+
+/// Reads schedule candidates from the database
+pub struct DbCandidateReader;
+
+impl domain::schedule::driven_ports::CandidateReader for DbCandidateReader {
+    #[tracing::instrument(skip_all, fields(first_3 = ?event_ids.get(0..3), total = event_ids.len()))]
+    async fn read_candidates(
+        &self,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<ScheduleCandidate>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read schedule candidates.")?;
+
+        let candidates = sqlx::query!(
+            r#"SELECT e.id, e.title, e.start_dt, e.end_dt, e.cost,
+                COALESCE(el.location_id, r.location_id, sr.location_id)::INTEGER AS building_id,
+                el.location_id AS "location_id?", er.room_id AS "room_id?",
+                es.section_id AS "section_id?"
+            FROM events e
+            LEFT JOIN event_location el ON el.event_id = e.id
+            LEFT JOIN event_room er ON er.event_id = e.id
+            LEFT JOIN rooms r ON r.id = er.room_id
+            LEFT JOIN event_section es ON es.event_id = e.id
+            LEFT JOIN sections s ON s.id = es.section_id
+            LEFT JOIN rooms sr ON sr.id = s.room_id
+            WHERE e.id = ANY($1) AND NOT e.cancelled"#,
+            event_ids,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting schedule candidates by event ID")?;
+
+        Ok(candidates
+            .into_iter()
+            .map(|record| ScheduleCandidate {
+                place: match (record.location_id, record.room_id, record.section_id) {
+                    (_, _, Some(section_id)) => Some(Ref {
+                        id: section_id,
+                        ref_type: RefType::Section,
+                    }),
+                    (_, Some(room_id), None) => Some(Ref {
+                        id: room_id,
+                        ref_type: RefType::Room,
+                    }),
+                    (Some(location_id), None, None) => Some(Ref {
+                        id: location_id as i32,
+                        ref_type: RefType::Location,
+                    }),
+                    (None, None, None) => None,
+                },
+                id: record.id,
+                title: record.title,
+                start: record
+                    .start_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                end: record
+                    .end_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                cost: record.cost.map(|cost| cost as u32),
+                building_id: record.building_id,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,876 @@
+use crate::domain;
+use crate::domain::event::{AgeRequirement, ExperienceLevel};
+use crate::domain::search::{
+    DateRange, DayCount, EventFilter, EventSearchResult, EventSort, FacetRange, NamedCount,
+    NamedFacet, RangeFacet, ResultPosition, SearchPage, SortDirection, SortKey, SortValue,
+    Suggestion, SuggestionKind, ValueCount,
+};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use crate::persistence::event::{AgeRequirementDTO, ExperienceLevelDTO};
+use anyhow::{Context, bail};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use sqlx::{Postgres, QueryBuilder};
+
+/// Joins resolving the building an event takes place in, whether it was assigned to a building,
+/// a room, or a section of a room
+pub(super) const EVENT_BUILDING_JOINS: &str = r#"
+    FROM events e
+    LEFT JOIN event_location el ON el.event_id = e.id
+    LEFT JOIN event_room er ON er.event_id = e.id
+    LEFT JOIN rooms r ON r.id = er.room_id
+    LEFT JOIN event_section es ON es.event_id = e.id
+    LEFT JOIN sections s ON s.id = es.section_id
+    LEFT JOIN rooms sr ON sr.id = s.room_id
+"#;
+
+#[derive(sqlx::FromRow)]
+/// DTO for a single row of event search results
+struct SearchRow {
+    id: i64,
+    title: String,
+    start_dt: DateTime<Utc>,
+    end_dt: DateTime<Utc>,
+    cost: Option<i32>,
+    tickets_available: i16,
+    max_players: i16,
+    sort_number: Option<f64>,
+    sort_text: Option<String>,
+    total_results: i64,
+    position: i64,
+}
+
+#[derive(sqlx::FromRow)]
+/// DTO for the number of matching events on a single day
+struct DayCountRow {
+    day: NaiveDate,
+    total_events: i64,
+}
+
+/// Returns the UTC instants at which the given day begins and ends at the convention
+fn day_bounds(day: NaiveDate) -> Result<(DateTime<Utc>, DateTime<Utc>), anyhow::Error> {
+    let local_start = |date: NaiveDate| {
+        Tz::America__Indiana__Indianapolis
+            .from_local_datetime(&date.and_time(chrono::NaiveTime::MIN))
+            .earliest()
+            .map(|start| start.with_timezone(&Utc))
+            .with_context(|| format!("Determining when {date} begins"))
+    };
+    let next_day = day.succ_opt().context("Determining the day after")?;
+
+    Ok((local_start(day)?, local_start(next_day)?))
+}
+
+/// Appends a condition for each set field of the filter. Every condition is prefixed with AND, so
+/// the query must already contain a WHERE clause.
+pub(super) fn push_filter_conditions<'args>(
+    query_builder: &mut QueryBuilder<'args, Postgres>,
+    filter: &'args EventFilter,
+) {
+    if let Some(min_tickets) = filter.min_available_tickets {
+        query_builder
+            .push(" AND e.tickets_available >= ")
+            .push_bind(min_tickets as i16);
+    }
+    if let Some(event_type_ids) = &filter.event_type_ids {
+        query_builder
+            .push(" AND e.event_type_id = ANY(")
+            .push_bind(event_type_ids)
+            .push(")");
+    }
+    if let Some(experience_levels) = &filter.experience_levels {
+        let levels: Vec<ExperienceLevelDTO> = experience_levels
+            .iter()
+            .map(|level| ExperienceLevelDTO::from(*level))
+            .collect();
+        query_builder
+            .push(" AND e.required_experience = ANY(")
+            .push_bind(levels)
+            .push(")");
+    }
+    if let Some(age_requirements) = &filter.age_requirements {
+        let ages: Vec<AgeRequirementDTO> = age_requirements
+            .iter()
+            .map(|age| AgeRequirementDTO::from(*age))
+            .collect();
+        query_builder
+            .push(" AND e.age_requirement = ANY(")
+            .push_bind(ages)
+            .push(")");
+    }
+    if let Some(game_system_ids) = &filter.game_system_ids {
+        query_builder
+            .push(" AND e.game_system_id = ANY(")
+            .push_bind(game_system_ids)
+            .push(")");
+    }
+    if let Some(group_ids) = &filter.group_ids {
+        query_builder
+            .push(" AND e.group_id = ANY(")
+            .push_bind(group_ids)
+            .push(")");
+    }
+    if let Some(building_ids) = &filter.building_ids {
+        query_builder
+            .push(" AND COALESCE(el.location_id, r.location_id, sr.location_id)::INTEGER = ANY(")
+            .push_bind(building_ids)
+            .push(")");
+    }
+    if let Some(room_ids) = &filter.room_ids {
+        query_builder
+            .push(" AND COALESCE(er.room_id, s.room_id) = ANY(")
+            .push_bind(room_ids)
+            .push(")");
+    }
+    if let Some(section_ids) = &filter.section_ids {
+        query_builder
+            .push(" AND es.section_id = ANY(")
+            .push_bind(section_ids)
+            .push(")");
+    }
+    if filter.exclude_tournaments {
+        query_builder
+            .push(" AND NOT EXISTS(SELECT 1 FROM tournament_segment ts WHERE ts.event_id = e.id)");
+    }
+    if filter.no_materials_required {
+        query_builder.push(
+            " AND NOT EXISTS(SELECT 1 FROM materials_material_items mmi WHERE mmi.materials_id = e.materials_id)",
+        );
+    }
+    // Sections which weren't checked themselves fall back to the accessibility of their room
+    if filter.wheelchair_accessible {
+        query_builder.push(
+            " AND COALESCE(s.wheelchair_accessible, sr.wheelchair_accessible, r.wheelchair_accessible) IS TRUE",
+        );
+    }
+    if filter.low_sensory {
+        query_builder
+            .push(" AND (COALESCE(s.low_sensory, sr.low_sensory, r.low_sensory) IS TRUE OR ")
+            .push_bind(domain::accessibility::LOW_SENSORY_TAG)
+            .push(" = ANY(e.content_tags))");
+    }
+    if let Some(content_tags) = &filter.content_tags {
+        query_builder
+            .push(" AND e.content_tags @> ")
+            .push_bind(content_tags);
+    }
+    if let Some(exclude_content_tags) = &filter.exclude_content_tags {
+        query_builder
+            .push(" AND NOT e.content_tags && ")
+            .push_bind(exclude_content_tags);
+    }
+    if let Some(earliest_start) = filter.earliest_start {
+        query_builder
+            .push(" AND (e.start_dt AT TIME ZONE 'America/Indiana/Indianapolis')::TIME >= ")
+            .push_bind(earliest_start);
+    }
+    if let Some(latest_start) = filter.latest_start {
+        query_builder
+            .push(" AND (e.start_dt AT TIME ZONE 'America/Indiana/Indianapolis')::TIME <= ")
+            .push_bind(latest_start);
+    }
+    // End times are compared against the start day so events ending after midnight count as
+    // ending late rather than early
+    if let Some(earliest_end) = filter.earliest_end {
+        query_builder
+            .push(
+                " AND (e.end_dt AT TIME ZONE 'America/Indiana/Indianapolis') >= \
+                (e.start_dt AT TIME ZONE 'America/Indiana/Indianapolis')::DATE + ",
+            )
+            .push_bind(earliest_end);
+    }
+    if let Some(latest_end) = filter.latest_end {
+        query_builder
+            .push(
+                " AND (e.end_dt AT TIME ZONE 'America/Indiana/Indianapolis') <= \
+                (e.start_dt AT TIME ZONE 'America/Indiana/Indianapolis')::DATE + ",
+            )
+            .push_bind(latest_end);
+    }
+    if let Some(window) = filter.fits_within {
+        query_builder
+            .push(" AND e.start_dt >= ")
+            .push_bind(window.start.with_timezone(&Utc))
+            .push(" AND e.end_dt <= ")
+            .push_bind(window.end.with_timezone(&Utc));
+    }
+    for busy in &filter.busy {
+        query_builder
+            .push(" AND NOT (e.start_dt < ")
+            .push_bind(busy.end.with_timezone(&Utc))
+            .push(" AND e.end_dt > ")
+            .push_bind(busy.start.with_timezone(&Utc))
+            .push(")");
+    }
+    if let Some(min_duration) = filter.min_duration_hours {
+        query_builder
+            .push(" AND EXTRACT(EPOCH FROM e.end_dt - e.start_dt) / 3600 >= ")
+            .push_bind(min_duration as f64);
+    }
+    if let Some(max_duration) = filter.max_duration_hours {
+        query_builder
+            .push(" AND EXTRACT(EPOCH FROM e.end_dt - e.start_dt) / 3600 <= ")
+            .push_bind(max_duration as f64);
+    }
+    if let Some(search_text) = filter.search_text() {
+        // Trigram word similarity catches typos and alternate spellings the full-text parser
+        // can't, like "Pathfnder" or "Pathfinder 2e" for "Pathfinder Second Edition"
+        query_builder
+            .push(" AND (e.search_document @@ websearch_to_tsquery('english', ")
+            .push_bind(search_text)
+            .push(") OR ")
+            .push_bind(search_text)
+            .push(" <% e.title OR EXISTS(SELECT 1 FROM game_systems gs WHERE gs.id = e.game_system_id AND ")
+            .push_bind(search_text)
+            .push(" <% gs.system_name))");
+    }
+    if let Some(cost_min) = filter.cost_min {
+        query_builder
+            .push(" AND (e.cost IS NULL OR e.cost >= ")
+            .push_bind(cost_min as i64)
+            .push(")");
+    }
+    if let Some(cost_max) = filter.cost_max {
+        query_builder
+            .push(" AND (e.cost IS NULL OR e.cost <= ")
+            .push_bind(cost_max as i64)
+            .push(")");
+    }
+}
+
+/// Appends the FROM and WHERE clauses selecting uncancelled events within the date range matching
+/// the filter
+pub(super) fn push_events_in_range<'args>(
+    query_builder: &mut QueryBuilder<'args, Postgres>,
+    dates: &DateRange,
+    filter: &'args EventFilter,
+) -> Result<(), anyhow::Error> {
+    query_builder
+        .push(EVENT_BUILDING_JOINS)
+        .push(" WHERE NOT e.cancelled");
+    match dates.first {
+        Some(first) => {
+            let (range_start, _) = day_bounds(first)?;
+            query_builder
+                .push(" AND e.start_dt >= ")
+                .push_bind(range_start);
+        }
+        None => {
+            query_builder.push(" AND e.year = (SELECT MAX(year) FROM events)");
+        }
+    }
+    if let Some(last) = dates.last {
+        let (_, range_end) = day_bounds(last)?;
+        query_builder
+            .push(" AND e.start_dt < ")
+            .push_bind(range_end);
+    }
+    push_filter_conditions(query_builder, filter);
+
+    Ok(())
+}
+
+/// Appends the expression events are sorted by. Numeric sorts are cast to FLOAT8 so any of them
+/// can be compared against the value held by a page cursor.
+fn push_sort_value<'args>(
+    query_builder: &mut QueryBuilder<'args, Postgres>,
+    filter: &'args EventFilter,
+    sort: EventSort,
+) {
+    match (sort, filter.search_text()) {
+        (EventSort::StartTime, _) | (EventSort::Relevance, None) => {
+            query_builder.push("e.start_dt");
+        }
+        (EventSort::Title, _) => {
+            query_builder.push("lower(e.title)");
+        }
+        (EventSort::Cost, _) => {
+            query_builder.push("COALESCE(e.cost, 0)::FLOAT8");
+        }
+        (EventSort::Duration, _) => {
+            query_builder.push("EXTRACT(EPOCH FROM e.end_dt - e.start_dt)::FLOAT8");
+        }
+        (EventSort::TicketsAvailable, _) => {
+            query_builder.push("e.tickets_available::FLOAT8");
+        }
+        (EventSort::Relevance, Some(search_text)) => {
+            query_builder
+                .push("(ts_rank_cd(e.search_document, websearch_to_tsquery('english', ")
+                .push_bind(search_text)
+                .push(")) + word_similarity(")
+                .push_bind(search_text)
+                .push(", e.title))::FLOAT8");
+        }
+    }
+}
+
+/// Appends a condition selecting ranked events which sort after the key, or before it when
+/// `before` is set. Ties are broken by start time and then ID in ascending order regardless of
+/// direction, matching the order events are ranked in.
+fn push_keyset_condition<'args>(
+    query_builder: &mut QueryBuilder<'args, Postgres>,
+    key: &SortKey,
+    direction: SortDirection,
+    before: bool,
+) {
+    let (value_comparison, tie_comparison) = match (direction, before) {
+        (SortDirection::Ascending, false) => (" > ", " > "),
+        (SortDirection::Ascending, true) => (" < ", " < "),
+        (SortDirection::Descending, false) => (" < ", " > "),
+        (SortDirection::Descending, true) => (" > ", " < "),
+    };
+    let value_column = match key.value {
+        None => "ranked.start_dt",
+        Some(SortValue::Number(_)) => "ranked.sort_number",
+        Some(SortValue::Text(_)) => "ranked.sort_text",
+    };
+    let push_value = |query_builder: &mut QueryBuilder<'args, Postgres>| match &key.value {
+        None => {
+            query_builder.push_bind(key.start);
+        }
+        Some(SortValue::Number(number)) => {
+            query_builder.push_bind(*number);
+        }
+        Some(SortValue::Text(text)) => {
+            query_builder.push_bind(text.clone());
+        }
+    };
+
+    query_builder.push(format!(" WHERE ({value_column}{value_comparison}"));
+    push_value(query_builder);
+    query_builder.push(format!(" OR ({value_column} = "));
+    push_value(query_builder);
+    query_builder
+        .push(format!(
+            " AND (ranked.start_dt, ranked.id){tie_comparison}("
+        ))
+        .push_bind(key.start)
+        .push(", ")
+        .push_bind(key.id)
+        .push(")))");
+}
+
+/// Searches events with dynamically built SQL
+pub struct DbEventSearcher;
+
+impl domain::search::driven_ports::EventSearcher for DbEventSearcher {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn day_has_events(
+        &self,
+        day: NaiveDate,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to look up day.")?;
+        let (day_start, day_end) = day_bounds(day)?;
+
+        let has_events = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM events WHERE start_dt >= $1 AND start_dt < $2
+            ) AS "has_events!""#,
+            day_start,
+            day_end,
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Checking whether events take place on day")?;
+
+        Ok(has_events)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(self, filter, ext_cxn))]
+    async fn search(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        sort: EventSort,
+        direction: SortDirection,
+        position: &ResultPosition,
+        limit: u16,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<SearchPage, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to search events.")?;
+
+        let direction_sql = match direction {
+            SortDirection::Ascending => " ASC",
+            SortDirection::Descending => " DESC",
+        };
+        let (sort_number, sort_text) = match (sort, filter.search_text()) {
+            (EventSort::StartTime, _) | (EventSort::Relevance, None) => (false, false),
+            (EventSort::Title, _) => (false, true),
+            _ => (true, false),
+        };
+
+        // Events are ranked over every match before the page's position is applied, so the
+        // total and each event's position count all matching events
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT * FROM (SELECT e.id, e.title, e.start_dt, e.end_dt, e.cost, \
+            e.tickets_available, e.max_players, ",
+        );
+        if sort_number {
+            push_sort_value(&mut query_builder, filter, sort);
+        } else {
+            query_builder.push("NULL::FLOAT8");
+        }
+        query_builder.push(" AS sort_number, ");
+        if sort_text {
+            push_sort_value(&mut query_builder, filter, sort);
+        } else {
+            query_builder.push("NULL::TEXT");
+        }
+        query_builder
+            .push(" AS sort_text, count(*) OVER() AS total_results, row_number() OVER (ORDER BY ");
+        push_sort_value(&mut query_builder, filter, sort);
+        query_builder
+            .push(direction_sql)
+            .push(", e.start_dt, e.id) AS position");
+        push_events_in_range(&mut query_builder, dates, filter)?;
+        query_builder.push(") ranked");
+
+        match position {
+            ResultPosition::Offset(offset) => {
+                query_builder
+                    .push(" ORDER BY ranked.position LIMIT ")
+                    .push_bind(limit as i64)
+                    .push(" OFFSET ")
+                    .push_bind(*offset as i64);
+            }
+            ResultPosition::After(key) => {
+                push_keyset_condition(&mut query_builder, key, direction, false);
+                query_builder
+                    .push(" ORDER BY ranked.position LIMIT ")
+                    .push_bind(limit as i64);
+            }
+            ResultPosition::Before(key) => {
+                push_keyset_condition(&mut query_builder, key, direction, true);
+                query_builder
+                    .push(" ORDER BY ranked.position DESC LIMIT ")
+                    .push_bind(limit as i64);
+            }
+        }
+
+        let mut rows: Vec<SearchRow> = query_builder
+            .build_query_as()
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Searching events")?;
+        if matches!(position, ResultPosition::Before(_)) {
+            rows.reverse();
+        }
+
+        let total_results = match rows.first() {
+            Some(row) => row.total_results as u64,
+            // Pages past the end have no rows to read the total from
+            None => {
+                let mut count_builder: QueryBuilder<Postgres> =
+                    QueryBuilder::new("SELECT count(*)");
+                push_events_in_range(&mut count_builder, dates, filter)?;
+                let total: i64 = count_builder
+                    .build_query_scalar()
+                    .fetch_one(cxn.borrow_connection())
+                    .await
+                    .context("Counting matching events")?;
+                total as u64
+            }
+        };
+        let events = rows
+            .into_iter()
+            .map(|row| {
+                let value = match (row.sort_number, row.sort_text) {
+                    (Some(number), _) => Some(SortValue::Number(number)),
+                    (None, Some(text)) => Some(SortValue::Text(text)),
+                    (None, None) => None,
+                };
+
+                EventSearchResult {
+                    id: row.id,
+                    title: row.title,
+                    start: row
+                        .start_dt
+                        .with_timezone(&Tz::America__Indiana__Indianapolis),
+                    end: row
+                        .end_dt
+                        .with_timezone(&Tz::America__Indiana__Indianapolis),
+                    cost: row.cost.map(|cost| cost as u32),
+                    tickets_available: row.tickets_available as u16,
+                    max_players: row.max_players as u16,
+                    sort_key: SortKey {
+                        value,
+                        start: row.start_dt,
+                        id: row.id,
+                    },
+                    position: row.position as u64,
+                }
+            })
+            .collect();
+
+        Ok(SearchPage {
+            events,
+            total_results,
+        })
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn count_by_day(
+        &self,
+        filter: &EventFilter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<DayCount>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to count events.")?;
+
+        // Counting with FILTER rather than WHERE keeps days without matches in the results
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT (e.start_dt AT TIME ZONE 'America/Indiana/Indianapolis')::DATE AS day, \
+            count(*) FILTER (WHERE NOT e.cancelled",
+        );
+        push_filter_conditions(&mut query_builder, filter);
+        query_builder
+            .push(") AS total_events")
+            .push(EVENT_BUILDING_JOINS)
+            .push(" WHERE e.year = (SELECT MAX(year) FROM events) GROUP BY day ORDER BY day");
+
+        let rows: Vec<DayCountRow> = query_builder
+            .build_query_as()
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Counting events by day")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DayCount {
+                date: row.day,
+                total_events: row.total_events as u64,
+            })
+            .collect())
+    }
+}
+
+/// Maintains the full-text search documents stored alongside events
+pub struct DbSearchIndexer;
+
+impl domain::search::driven_ports::SearchIndexer for DbSearchIndexer {
+    #[tracing::instrument(skip_all, fields(total = event_ids.len()))]
+    async fn refresh_search_documents(
+        &self,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to refresh search documents.")?;
+
+        // Titles weigh the most, then game systems and groups, then game masters, then
+        // descriptions. Queries are parsed with the same english configuration so both sides
+        // are stemmed alike.
+        sqlx::query!(
+            r#"UPDATE events e SET search_document =
+                setweight(to_tsvector('english', src.title), 'A')
+                || setweight(to_tsvector('english', COALESCE(gs.system_name, '')), 'B')
+                || setweight(to_tsvector('english', COALESCE(g.group_name, '')), 'B')
+                || setweight(to_tsvector('english', COALESCE(gms.gm_names, '')), 'C')
+                || setweight(to_tsvector('english', src.description), 'D')
+            FROM events src
+            LEFT JOIN game_systems gs ON gs.id = src.game_system_id
+            LEFT JOIN groups g ON g.id = src.group_id
+            LEFT JOIN LATERAL (
+                SELECT string_agg(gm.gm_name, ' ') AS gm_names
+                FROM event_game_masters egm
+                JOIN game_masters gm ON gm.id = egm.gm_id
+                WHERE egm.event_id = src.id
+            ) gms ON TRUE
+            WHERE e.id = src.id AND src.id = ANY($1)"#,
+            event_ids,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Rebuilding event search documents")?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+/// DTO for the number of events associated with a named item
+struct NamedCountRow {
+    id: i64,
+    name: String,
+    total_events: i64,
+}
+
+#[derive(sqlx::FromRow)]
+/// DTO for the number of events with a particular enum value
+struct ValueCountRow<T> {
+    value: T,
+    total_events: i64,
+}
+
+/// Counts matching events per facet with dynamically built SQL
+pub struct DbFacetCounter;
+
+impl domain::search::driven_ports::FacetCounter for DbFacetCounter {
+    #[tracing::instrument(skip(self, filter, ext_cxn))]
+    async fn count_named(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        facet: NamedFacet,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<NamedCount>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to count events.")?;
+
+        let (id_column, name_column, named_join) = match facet {
+            NamedFacet::EventType => (
+                "et.id",
+                "et.display_name",
+                " JOIN event_types et ON et.id = matching.event_type_id",
+            ),
+            NamedFacet::GameSystem => (
+                "gs.id",
+                "gs.system_name",
+                " JOIN game_systems gs ON gs.id = matching.game_system_id",
+            ),
+            NamedFacet::Group => (
+                "g.id",
+                "g.group_name",
+                " JOIN groups g ON g.id = matching.group_id",
+            ),
+            NamedFacet::Building => (
+                "loc.id",
+                "loc.location_name",
+                " JOIN locations loc ON loc.id = matching.building_id",
+            ),
+        };
+
+        // Matching events are selected in a subquery so joining the named items can't change them
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+            "SELECT {id_column}::BIGINT AS id, {name_column} AS name, count(*) AS total_events \
+            FROM (SELECT e.event_type_id, e.game_system_id, e.group_id, \
+            COALESCE(el.location_id, r.location_id, sr.location_id) AS building_id"
+        ));
+        push_events_in_range(&mut query_builder, dates, filter)?;
+        query_builder.push(format!(
+            ") matching{named_join} GROUP BY {id_column}, {name_column} ORDER BY total_events DESC, name"
+        ));
+
+        let rows: Vec<NamedCountRow> = query_builder
+            .build_query_as()
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .with_context(|| format!("Counting events by {facet:?}"))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| NamedCount {
+                id: row.id,
+                name: row.name,
+                count: row.total_events as u64,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, filter, ext_cxn))]
+    async fn count_age_requirements(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<ValueCount<AgeRequirement>>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to count events.")?;
+
+        let mut query_builder: QueryBuilder<Postgres> =
+            QueryBuilder::new("SELECT e.age_requirement AS value, count(*) AS total_events");
+        push_events_in_range(&mut query_builder, dates, filter)?;
+        query_builder.push(" GROUP BY e.age_requirement ORDER BY e.age_requirement");
+
+        let rows: Vec<ValueCountRow<AgeRequirementDTO>> = query_builder
+            .build_query_as()
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Counting events by age requirement")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ValueCount {
+                value: row.value.into(),
+                count: row.total_events as u64,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, filter, ext_cxn))]
+    async fn count_experience_levels(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<ValueCount<ExperienceLevel>>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to count events.")?;
+
+        let mut query_builder: QueryBuilder<Postgres> =
+            QueryBuilder::new("SELECT e.required_experience AS value, count(*) AS total_events");
+        push_events_in_range(&mut query_builder, dates, filter)?;
+        query_builder.push(" GROUP BY e.required_experience ORDER BY e.required_experience");
+
+        let rows: Vec<ValueCountRow<ExperienceLevelDTO>> = query_builder
+            .build_query_as()
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Counting events by experience level")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ValueCount {
+                value: row.value.into(),
+                count: row.total_events as u64,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, filter, ext_cxn))]
+    async fn count_ranges(
+        &self,
+        dates: &DateRange,
+        filter: &EventFilter,
+        facet: RangeFacet,
+        ranges: &[FacetRange],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<u64>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to count events.")?;
+
+        let measured_value = match facet {
+            RangeFacet::Cost => "COALESCE(e.cost, 0)",
+            RangeFacet::Duration => "EXTRACT(EPOCH FROM e.end_dt - e.start_dt) / 3600",
+        };
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT ARRAY[");
+        let mut range_counts = query_builder.separated(", ");
+        for range in ranges {
+            range_counts
+                .push(format!("count(*) FILTER (WHERE {measured_value} >= "))
+                .push_bind_unseparated(range.min as f64);
+            if let Some(max) = range.max {
+                range_counts
+                    .push_unseparated(format!(" AND {measured_value} < "))
+                    .push_bind_unseparated(max as f64);
+            }
+            range_counts.push_unseparated(")");
+        }
+        query_builder.push("]::BIGINT[] AS counts");
+        push_events_in_range(&mut query_builder, dates, filter)?;
+
+        let counts: Vec<i64> = query_builder
+            .build_query_scalar()
+            .fetch_one(cxn.borrow_connection())
+            .await
+            .with_context(|| format!("Counting events by {facet:?} range"))?;
+
+        Ok(counts.into_iter().map(|count| count as u64).collect())
+    }
+}
+
+/// Escapes characters with special meaning in LIKE patterns
+pub(super) fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Suggests completions of search text from the names of things events can be filtered by
+pub struct DbSuggestionReader;
+
+impl domain::search::driven_ports::SuggestionReader for DbSuggestionReader {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn suggest(
+        &self,
+        text: &str,
+        limit: u16,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<Suggestion>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to suggest search text.")?;
+        let escaped_text = escape_like(text);
+        let contains_pattern = format!("%{escaped_text}%");
+        let prefix_pattern = format!("{escaped_text}%");
+
+        // Names containing the text are matched even when too short for trigrams to be similar,
+        // and names starting with the text are listed first
+        let suggestion_rows = sqlx::query!(
+            r#"WITH candidates AS (
+                SELECT 'event-title' AS kind, NULL::BIGINT AS id, title AS text,
+                    word_similarity($1, title) AS score
+                FROM (
+                    SELECT DISTINCT title FROM events
+                    WHERE year = (SELECT MAX(year) FROM events) AND NOT cancelled
+                        AND ($1 <% title OR title ILIKE $2)
+                ) titles
+                UNION ALL
+                SELECT 'game-system', id, system_name, word_similarity($1, system_name)
+                FROM game_systems WHERE $1 <% system_name OR system_name ILIKE $2
+                UNION ALL
+                SELECT 'group', id, group_name, word_similarity($1, group_name)
+                FROM groups WHERE $1 <% group_name OR group_name ILIKE $2
+                UNION ALL
+                SELECT 'game-master', id, gm_name, word_similarity($1, gm_name)
+                FROM game_masters WHERE $1 <% gm_name OR gm_name ILIKE $2
+                UNION ALL
+                SELECT 'location', id::BIGINT, location_name, word_similarity($1, location_name)
+                FROM locations WHERE $1 <% location_name OR location_name ILIKE $2
+            )
+            SELECT kind AS "kind!", id, text AS "text!", score AS "score!"
+            FROM candidates
+            ORDER BY text ILIKE $3 DESC, score DESC, text
+            LIMIT $4"#,
+            text,
+            contains_pattern,
+            prefix_pattern,
+            limit as i64,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting search suggestions")?;
+
+        suggestion_rows
+            .into_iter()
+            .map(|row| {
+                let kind = match row.kind.as_str() {
+                    "event-title" => SuggestionKind::EventTitle,
+                    "game-system" => SuggestionKind::GameSystem,
+                    "group" => SuggestionKind::Group,
+                    "game-master" => SuggestionKind::GameMaster,
+                    "location" => SuggestionKind::Location,
+                    unknown_kind => bail!("Unrecognized suggestion kind {unknown_kind}"),
+                };
+
+                Ok(Suggestion {
+                    kind,
+                    id: row.id,
+                    text: row.text,
+                    score: row.score,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,66 @@
+use crate::domain;
+use crate::domain::session::{EventSession, SessionGroup};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+use chrono_tz::Tz;
+
+/// Reads event sessions from the database
+pub struct DbSessionReader;
+
+impl domain::session::driven_ports::SessionReader for DbSessionReader {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn read_session_group(
+        &self,
+        event_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<SessionGroup>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read event sessions.")?;
+
+        let sessions = sqlx::query!(
+            r#"SELECT sibling.id, sibling.game_id, sibling.start_dt, sibling.end_dt,
+                sibling.tickets_available, sibling.cancelled,
+                sibling.id = requested.id AS "is_requested!"
+            FROM events requested
+            INNER JOIN events sibling
+                ON sibling.title = requested.title
+                AND sibling.year = requested.year
+                AND sibling.game_system_id IS NOT DISTINCT FROM requested.game_system_id
+                AND sibling.group_id IS NOT DISTINCT FROM requested.group_id
+                AND (NOT sibling.cancelled OR sibling.id = requested.id)
+            WHERE requested.id = $1"#,
+            event_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting sibling sessions of an event")?;
+
+        let mut requested: Option<EventSession> = None;
+        let mut siblings: Vec<EventSession> = Vec::with_capacity(sessions.len());
+        for record in sessions {
+            let session = EventSession {
+                id: record.id,
+                game_id: record.game_id,
+                start: record
+                    .start_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                end: record
+                    .end_dt
+                    .with_timezone(&Tz::America__Indiana__Indianapolis),
+                tickets_available: record.tickets_available as u16,
+                cancelled: record.cancelled,
+            };
+            if record.is_requested {
+                requested = Some(session.clone());
+            }
+            siblings.push(session);
+        }
+
+        Ok(requested.map(|requested| SessionGroup {
+            requested,
+            siblings,
+        }))
+    }
+}
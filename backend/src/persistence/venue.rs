@@ -0,0 +1,204 @@
+use crate::domain;
+use crate::domain::venue::{Coordinates, MappedRoom, VenueMap, WalkingTime};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+
+/// Pairs up a latitude and longitude read from the database, which are either both set or both
+/// null
+fn coordinates(latitude: Option<f64>, longitude: Option<f64>) -> Option<Coordinates> {
+    Some(Coordinates {
+        latitude: latitude?,
+        longitude: longitude?,
+    })
+}
+
+/// Reads venue geography from the database
+pub struct DbVenueMapReader;
+
+impl domain::venue::driven_ports::VenueMapReader for DbVenueMapReader {
+    #[tracing::instrument(skip_all)]
+    async fn read_venue_map(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<VenueMap, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read the venue map.")?;
+
+        let locations = sqlx::query!("SELECT id, latitude, longitude FROM locations")
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting location coordinates")?;
+        let rooms = sqlx::query!("SELECT id, location_id, latitude, longitude FROM rooms")
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting room coordinates")?;
+        let sections = sqlx::query!("SELECT id, room_id FROM sections")
+            .fetch_all(cxn.borrow_connection())
+            .await
+            .context("Selecting section rooms")?;
+        let walking_times = sqlx::query!(
+            "SELECT location_id, other_location_id, walking_minutes FROM walking_times"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting walking times")?;
+
+        Ok(VenueMap {
+            location_coordinates: locations
+                .into_iter()
+                .filter_map(|record| {
+                    coordinates(record.latitude, record.longitude)
+                        .map(|coordinates| (record.id as i32, coordinates))
+                })
+                .collect(),
+            rooms: rooms
+                .into_iter()
+                .map(|record| {
+                    (
+                        record.id,
+                        MappedRoom {
+                            location_id: record.location_id as i32,
+                            coordinates: coordinates(record.latitude, record.longitude),
+                        },
+                    )
+                })
+                .collect(),
+            section_rooms: sections
+                .into_iter()
+                .map(|record| (record.id, record.room_id))
+                .collect(),
+            walking_minutes: walking_times
+                .into_iter()
+                .map(|record| {
+                    (
+                        (record.location_id as i32, record.other_location_id as i32),
+                        record.walking_minutes as u16,
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Saves venue geography to the database
+pub struct DbVenueMapWriter;
+
+impl domain::venue::driven_ports::VenueMapWriter for DbVenueMapWriter {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn set_location_coordinates(
+        &self,
+        location_id: i32,
+        coordinates: Option<Coordinates>,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save location coordinates.")?;
+
+        let result = sqlx::query!(
+            "UPDATE locations SET latitude = $2, longitude = $3 WHERE id = $1",
+            location_id as i16,
+            coordinates.map(|coordinates| coordinates.latitude),
+            coordinates.map(|coordinates| coordinates.longitude),
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating location coordinates")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn set_room_coordinates(
+        &self,
+        room_id: i32,
+        coordinates: Option<Coordinates>,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save room coordinates.")?;
+
+        let result = sqlx::query!(
+            "UPDATE rooms SET latitude = $2, longitude = $3 WHERE id = $1",
+            room_id,
+            coordinates.map(|coordinates| coordinates.latitude),
+            coordinates.map(|coordinates| coordinates.longitude),
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating room coordinates")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn save_walking_time(
+        &self,
+        walking_time: &WalkingTime,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<i32>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to save a walking time.")?;
+
+        let location_ids = [
+            walking_time.location_id as i16,
+            walking_time.other_location_id as i16,
+        ];
+        let existing_ids =
+            sqlx::query_scalar!("SELECT id FROM locations WHERE id = ANY($1)", &location_ids)
+                .fetch_all(cxn.borrow_connection())
+                .await
+                .context("Checking walking time locations exist")?;
+        if let Some(missing_id) = location_ids
+            .iter()
+            .find(|location_id| !existing_ids.contains(location_id))
+        {
+            return Ok(Some(*missing_id as i32));
+        }
+
+        sqlx::query!(
+            "INSERT INTO walking_times (location_id, other_location_id, walking_minutes)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (location_id, other_location_id)
+                DO UPDATE SET walking_minutes = EXCLUDED.walking_minutes",
+            location_ids[0],
+            location_ids[1],
+            walking_time.minutes as i16,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Upserting walking time")?;
+
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn delete_walking_time(
+        &self,
+        location_pair: (i32, i32),
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete a walking time.")?;
+
+        let result = sqlx::query!(
+            "DELETE FROM walking_times WHERE location_id = $1 AND other_location_id = $2",
+            location_pair.0 as i16,
+            location_pair.1 as i16,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Deleting walking time")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
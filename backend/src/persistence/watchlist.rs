@@ -0,0 +1,206 @@
+use crate::domain;
+use crate::domain::location::{Ref, RefType};
+use crate::domain::watchlist::EventSnapshot;
+use crate::domain::watchlist::driven_ports::AddWatchError;
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::Context;
+use chrono_tz::Tz;
+use std::collections::HashMap;
+
+/// Reads the watched state of events from the database
+pub struct DbEventSnapshotReader;
+
+impl domain::watchlist::driven_ports::EventSnapshotReader for DbEventSnapshotReader {
+    #[tracing::instrument(skip_all, fields(first_3 = ?event_ids.get(0..3), total = event_ids.len()))]
+    async fn read_snapshots(
+        &self,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<EventSnapshot>, anyhow::Error> {
+        if event_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read event snapshots.")?;
+
+        let snapshots = sqlx::query!(
+            r#"SELECT e.id, e.title, e.start_dt, e.end_dt, e.tickets_available, e.cancelled,
+                el.location_id AS "location_id?", er.room_id AS "room_id?",
+                es.section_id AS "section_id?"
+            FROM events e
+            LEFT JOIN event_location el ON el.event_id = e.id
+            LEFT JOIN event_room er ON er.event_id = e.id
+            LEFT JOIN event_section es ON es.event_id = e.id
+            WHERE e.id = ANY($1)"#,
+            event_ids,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting event snapshots")?;
+
+        Ok(snapshots
+            .into_iter()
+            .map(|record| {
+                let location = match (record.location_id, record.room_id, record.section_id) {
+                    (_, _, Some(section_id)) => Some(Ref {
+                        id: section_id,
+                        ref_type: RefType::Section,
+                    }),
+                    (_, Some(room_id), None) => Some(Ref {
+                        id: room_id,
+                        ref_type: RefType::Room,
+                    }),
+                    (Some(location_id), None, None) => Some(Ref {
+                        id: location_id as i32,
+                        ref_type: RefType::Location,
+                    }),
+                    (None, None, None) => None,
+                };
+
+                EventSnapshot {
+                    event_id: record.id,
+                    title: record.title,
+                    start: record
+                        .start_dt
+                        .with_timezone(&Tz::America__Indiana__Indianapolis),
+                    end: record
+                        .end_dt
+                        .with_timezone(&Tz::America__Indiana__Indianapolis),
+                    tickets_available: record.tickets_available as u16,
+                    location,
+                    cancelled: record.cancelled,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Reads watchlist entries from the database
+pub struct DbWatchlistReader;
+
+impl domain::watchlist::driven_ports::WatchlistReader for DbWatchlistReader {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn watched_event_ids(
+        &self,
+        user_id: &str,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<i64>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read a watchlist.")?;
+
+        let event_ids = sqlx::query!(
+            "SELECT event_id FROM watchlist_entries WHERE user_id = $1 ORDER BY created_at",
+            user_id,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting watched events for user")?;
+
+        Ok(event_ids
+            .into_iter()
+            .map(|record| record.event_id)
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all, fields(first_3 = ?event_ids.get(0..3), total = event_ids.len()))]
+    async fn watchers_of_events(
+        &self,
+        event_ids: &[i64],
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<HashMap<i64, Vec<String>>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read event watchers.")?;
+
+        let watch_entries = sqlx::query!(
+            "SELECT user_id, event_id FROM watchlist_entries WHERE event_id = ANY($1)",
+            event_ids,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting watchers of events")?;
+
+        let mut watchers_by_event: HashMap<i64, Vec<String>> = HashMap::new();
+        for entry in watch_entries {
+            watchers_by_event
+                .entry(entry.event_id)
+                .or_default()
+                .push(entry.user_id);
+        }
+
+        Ok(watchers_by_event)
+    }
+}
+
+/// Adds and removes watchlist entries in the database
+pub struct DbWatchlistWriter;
+
+impl domain::watchlist::driven_ports::WatchlistWriter for DbWatchlistWriter {
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn add_watch(
+        &self,
+        user_id: &str,
+        event_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), AddWatchError> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to add a watchlist entry.")
+            .map_err(AddWatchError::PortError)?;
+
+        let event_exists = sqlx::query!(
+            r#"SELECT EXISTS(SELECT 1 FROM events WHERE id = $1) AS "exists!""#,
+            event_id,
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Checking event exists before watching it")
+        .map_err(AddWatchError::PortError)?;
+        if !event_exists.exists {
+            return Err(AddWatchError::EventDoesNotExist(event_id));
+        }
+
+        sqlx::query!(
+            "INSERT INTO watchlist_entries(user_id, event_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            user_id,
+            event_id,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Inserting watchlist entry")
+        .map_err(AddWatchError::PortError)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn remove_watch(
+        &self,
+        user_id: &str,
+        event_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to remove a watchlist entry.")?;
+
+        let delete_result = sqlx::query!(
+            "DELETE FROM watchlist_entries WHERE user_id = $1 AND event_id = $2",
+            user_id,
+            event_id,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Deleting watchlist entry")?;
+
+        Ok(delete_result.rows_affected() > 0)
+    }
+}
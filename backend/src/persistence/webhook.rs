@@ -0,0 +1,343 @@
+use crate::domain;
+use crate::domain::webhook::{
+    AttemptOutcome, DeliveryRecord, DeliveryStatus, NewSubscription, PendingDelivery, Subscription,
+    Topic, WebhookMessage,
+};
+use crate::external_connections::{ConnectionHandle, ExternalConnectivity};
+use anyhow::{Context, anyhow};
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Stores webhook subscriptions in the database
+pub struct DbSubscriptionStore;
+
+/// Converts topic names stored in the database back into topics
+fn parse_topics(topics: Vec<String>) -> Result<Vec<Topic>, anyhow::Error> {
+    topics
+        .iter()
+        .map(|topic| topic.parse::<Topic>().map_err(anyhow::Error::from))
+        .collect()
+}
+
+fn topic_names(topics: &[Topic]) -> Vec<String> {
+    topics.iter().map(ToString::to_string).collect()
+}
+
+fn to_subscription(
+    id: i64,
+    target_url: String,
+    secret: String,
+    topics: Vec<String>,
+    created_at: DateTime<Utc>,
+) -> Result<Subscription, anyhow::Error> {
+    Ok(Subscription {
+        id,
+        target_url,
+        secret,
+        topics: parse_topics(topics)
+            .with_context(|| format!("Reading topics of webhook subscription {id}"))?,
+        created_at,
+    })
+}
+
+impl domain::webhook::driven_ports::SubscriptionStore for DbSubscriptionStore {
+    #[tracing::instrument(skip_all)]
+    async fn create_subscription(
+        &self,
+        subscription: &NewSubscription,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Subscription, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to create a webhook subscription.")?;
+
+        let created = sqlx::query!(
+            "INSERT INTO webhook_subscriptions(target_url, secret, topics) VALUES ($1, $2, $3)
+            RETURNING id, target_url, secret, topics, created_at",
+            subscription.target_url,
+            subscription.secret,
+            &topic_names(&subscription.topics),
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Inserting webhook subscription")?;
+
+        to_subscription(
+            created.id,
+            created.target_url,
+            created.secret,
+            created.topics,
+            created.created_at,
+        )
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn list_subscriptions(
+        &self,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<Subscription>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to list webhook subscriptions.")?;
+
+        let subscriptions = sqlx::query!(
+            "SELECT id, target_url, secret, topics, created_at FROM webhook_subscriptions ORDER BY id"
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting webhook subscriptions")?;
+
+        subscriptions
+            .into_iter()
+            .map(|record| {
+                to_subscription(
+                    record.id,
+                    record.target_url,
+                    record.secret,
+                    record.topics,
+                    record.created_at,
+                )
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn read_subscription(
+        &self,
+        subscription_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Option<Subscription>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to read a webhook subscription.")?;
+
+        let subscription = sqlx::query!(
+            "SELECT id, target_url, secret, topics, created_at FROM webhook_subscriptions
+            WHERE id = $1",
+            subscription_id,
+        )
+        .fetch_optional(cxn.borrow_connection())
+        .await
+        .context("Selecting webhook subscription")?;
+
+        subscription
+            .map(|record| {
+                to_subscription(
+                    record.id,
+                    record.target_url,
+                    record.secret,
+                    record.topics,
+                    record.created_at,
+                )
+            })
+            .transpose()
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn delete_subscription(
+        &self,
+        subscription_id: i64,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<bool, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to delete a webhook subscription.")?;
+
+        let delete_result = sqlx::query!(
+            "DELETE FROM webhook_subscriptions WHERE id = $1",
+            subscription_id,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Deleting webhook subscription")?;
+
+        Ok(delete_result.rows_affected() > 0)
+    }
+}
+
+/// Records webhook deliveries in the database
+pub struct DbDeliveryLog;
+
+fn parse_status(status: &str) -> Result<DeliveryStatus, anyhow::Error> {
+    match status {
+        "pending" => Ok(DeliveryStatus::Pending),
+        "succeeded" => Ok(DeliveryStatus::Succeeded),
+        "failed" => Ok(DeliveryStatus::Failed),
+        unknown => Err(anyhow!("Unrecognized webhook delivery status: {unknown}")),
+    }
+}
+
+impl domain::webhook::driven_ports::DeliveryLog for DbDeliveryLog {
+    #[tracing::instrument(skip(self, message, ext_cxn), fields(topic = %message.topic))]
+    async fn record_pending(
+        &self,
+        subscription_id: i64,
+        message: &WebhookMessage,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<i64, anyhow::Error> {
+        let payload =
+            serde_json::to_value(message).context("Serializing webhook message for the log")?;
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to log a webhook delivery.")?;
+
+        let new_delivery = sqlx::query!(
+            "INSERT INTO webhook_deliveries(subscription_id, topic, payload) VALUES ($1, $2, $3)
+            RETURNING id",
+            subscription_id,
+            message.topic.to_string(),
+            payload,
+        )
+        .fetch_one(cxn.borrow_connection())
+        .await
+        .context("Inserting webhook delivery")?;
+
+        Ok(new_delivery.id)
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn claim_due_deliveries(
+        &self,
+        limit: u16,
+        lease: Duration,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<PendingDelivery>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to claim due webhook deliveries.")?;
+
+        // Skipping locked rows lets several servers claim deliveries at once without waiting on
+        // each other or claiming the same delivery twice
+        let claimed = sqlx::query!(
+            "WITH due AS (
+                SELECT id FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= now()
+                ORDER BY next_attempt_at, id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE webhook_deliveries wd
+            SET next_attempt_at = now() + make_interval(secs => $2)
+            FROM due, webhook_subscriptions ws
+            WHERE wd.id = due.id AND ws.id = wd.subscription_id
+            RETURNING wd.id, wd.payload, wd.attempts, ws.id AS subscription_id, ws.target_url,
+                ws.secret, ws.topics, ws.created_at",
+            limit as i64,
+            lease.as_secs_f64(),
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Claiming due webhook deliveries")?;
+
+        claimed
+            .into_iter()
+            .map(|record| {
+                Ok(PendingDelivery {
+                    delivery_id: record.id,
+                    subscription: to_subscription(
+                        record.subscription_id,
+                        record.target_url,
+                        record.secret,
+                        record.topics,
+                        record.created_at,
+                    )?,
+                    message: serde_json::from_value(record.payload).with_context(|| {
+                        format!(
+                            "Reading the message logged for webhook delivery {}",
+                            record.id
+                        )
+                    })?,
+                    attempts: record.attempts as u16,
+                })
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn record_attempt(
+        &self,
+        delivery_id: i64,
+        outcome: &AttemptOutcome,
+        status: DeliveryStatus,
+        next_attempt_at: Option<DateTime<Utc>>,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<(), anyhow::Error> {
+        let (status_code, error) = match outcome {
+            AttemptOutcome::Responded(status_code) => (Some(*status_code as i16), None),
+            AttemptOutcome::Unreachable(reason) => (None, Some(reason.as_str())),
+        };
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to record a webhook delivery attempt.")?;
+
+        sqlx::query!(
+            "UPDATE webhook_deliveries
+            SET status = $1, attempts = attempts + 1, last_status_code = $2, last_error = $3,
+                last_attempt_at = now(), next_attempt_at = $4
+            WHERE id = $5",
+            status.to_string(),
+            status_code,
+            error,
+            next_attempt_at,
+            delivery_id,
+        )
+        .execute(cxn.borrow_connection())
+        .await
+        .context("Updating webhook delivery")?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, ext_cxn))]
+    async fn list_deliveries(
+        &self,
+        subscription_id: i64,
+        limit: u16,
+        ext_cxn: &mut impl ExternalConnectivity,
+    ) -> Result<Vec<DeliveryRecord>, anyhow::Error> {
+        let mut cxn = ext_cxn
+            .database_cxn()
+            .await
+            .context("Acquiring database connection to list webhook deliveries.")?;
+
+        let deliveries = sqlx::query!(
+            "SELECT id, subscription_id, topic, payload, status, attempts, last_status_code,
+                last_error, created_at, last_attempt_at, next_attempt_at
+            FROM webhook_deliveries
+            WHERE subscription_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2",
+            subscription_id,
+            limit as i64,
+        )
+        .fetch_all(cxn.borrow_connection())
+        .await
+        .context("Selecting webhook deliveries")?;
+
+        deliveries
+            .into_iter()
+            .map(|record| {
+                Ok(DeliveryRecord {
+                    id: record.id,
+                    subscription_id: record.subscription_id,
+                    topic: record.topic.parse()?,
+                    payload: record.payload,
+                    status: parse_status(&record.status)?,
+                    attempts: record.attempts as u16,
+                    last_status_code: record.last_status_code.map(|code| code as u16),
+                    last_error: record.last_error,
+                    created_at: record.created_at,
+                    last_attempt_at: record.last_attempt_at,
+                    next_attempt_at: record.next_attempt_at,
+                })
+            })
+            .collect()
+    }
+}